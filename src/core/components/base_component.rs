@@ -1,12 +1,15 @@
 use std::{cell::RefCell, hash::Hash, rc::Rc};
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::core::{
     components::properties::{
         boundaries::Boundaries, margin::Margin, padding::Padding, position::Position, size::Size,
     },
-    window::events::{event_system::EventSystem, types::EventType},
+    window::events::{
+        event_system::EventSystem,
+        types::{EventPayload, EventType},
+    },
 };
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -35,6 +38,9 @@ pub struct BaseComponent {
     margin: Margin,
     padding: Padding,
     visible: bool,
+    // Multiplies the alpha of everything the component and its children paint
+    #[serde(default = "full_opacity", deserialize_with = "normalized_opacity")]
+    opacity: f32,
     wrap_mode: WrapMode,
     sizing_mode: BoundarySizingMode,
     #[serde(skip)]
@@ -49,6 +55,7 @@ impl PartialEq for BaseComponent {
             && self.margin == other.margin
             && self.padding == other.padding
             && self.visible == other.visible
+            && self.opacity == other.opacity
             && self.sizing_mode == other.sizing_mode
     }
 }
@@ -63,6 +70,7 @@ impl Hash for BaseComponent {
         self.margin.hash(state);
         self.padding.hash(state);
         self.visible.hash(state);
+        self.opacity.to_bits().hash(state);
         self.sizing_mode.hash(state);
     }
 }
@@ -104,6 +112,7 @@ impl BaseComponent {
             margin,
             padding,
             visible: true,
+            opacity: 1.0,
             wrap_mode: WrapMode::default(),
             sizing_mode: BoundarySizingMode::default(),
             event_system,
@@ -236,10 +245,30 @@ impl BaseComponent {
         }
     }
 
+    /// Clamped to 0..=1, where 0 is fully transparent. NaN is taken as fully
+    /// opaque
+    pub fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = normalize_opacity(opacity);
+    }
+
     fn invalidate_bounds(&mut self) {
         self.bounds = None;
     }
 
+    pub fn emit(&self, event: EventType) {
+        self.event_system.borrow_mut().emit(event, self.id);
+    }
+
+    pub fn emit_with(&self, event: EventType, payload: EventPayload) {
+        self.event_system
+            .borrow_mut()
+            .emit_with(event, self.id, payload);
+    }
+
+    pub fn hit_test(&self, point: Position) -> bool {
+        self.visible && self.frame().contains(point)
+    }
+
     // Getters inmutables
     pub fn size(&self) -> &Size {
         &self.size
@@ -260,6 +289,31 @@ impl BaseComponent {
         self.bounds.as_ref()
     }
 
+    // Outer rectangle of the component, used for hit testing and painting
+    pub fn frame(&self) -> Boundaries {
+        Boundaries::from_rect(self.position, self.size)
+    }
+
+    // Frame minus padding, where the component lays out its own content
+    pub fn content_frame(&self) -> Boundaries {
+        let x = self.position.x().saturating_add(self.padding.left);
+        let y = self.position.y().saturating_add(self.padding.top);
+        let width = self
+            .size
+            .width()
+            .saturating_sub(self.padding.left.saturating_add(self.padding.right));
+        let height = self
+            .size
+            .height()
+            .saturating_sub(self.padding.top.saturating_add(self.padding.bottom));
+
+        Boundaries::from_rect(Position::new(x, y), Size::new(height, width))
+    }
+
+    pub fn event_system(&self) -> &Rc<RefCell<EventSystem>> {
+        &self.event_system
+    }
+
     pub fn id(&self) -> &usize {
         &self.id
     }
@@ -268,7 +322,31 @@ impl BaseComponent {
         &self.visible
     }
 
+    pub fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
     pub fn wrap_mode(&self) -> &WrapMode {
         &self.wrap_mode
     }
 }
+
+fn full_opacity() -> f32 {
+    1.0
+}
+
+// One value per opacity, so equal components hash the same: NaN becomes 1 and
+// -0 becomes 0
+fn normalize_opacity(opacity: f32) -> f32 {
+    if opacity.is_nan() {
+        return 1.0;
+    }
+    opacity.clamp(0.0, 1.0) + 0.0
+}
+
+fn normalized_opacity<'de, D>(deserializer: D) -> Result<f32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    f32::deserialize(deserializer).map(normalize_opacity)
+}
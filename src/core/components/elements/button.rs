@@ -2,33 +2,307 @@ use std::{fmt::Debug, hash::Hash};
 
 use serde::{Deserialize, Serialize};
 
-use crate::core::components::{
-    base_component::BaseComponent, elements::icon::Icon, properties::position::Position,
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::icon::Icon,
+        properties::{
+            boundaries::Boundaries, graphics::action_state::ActionState, position::Position,
+        },
+        styles::button_style::ButtonStyle,
+    },
+    ui::{click_tracker::ClickTracker, render::render_context::RenderContext},
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::{InputEvent, Key},
+        types::EventType,
+    },
 };
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+const ICON_SPACING: u16 = 4;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum IconPosition {
     Start,
     End,
     Located(Position),
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct Button {
     base: BaseComponent,
     icon: Option<(Icon, IconPosition)>,
     text: String,
     enabled: bool,
+    #[serde(default)]
+    style: ButtonStyle,
+    #[serde(skip)]
+    tracker: ClickTracker,
+    #[serde(skip)]
+    focused: bool,
 }
 
 impl Debug for Button {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        todo!("Implement Debug for Button");
+        f.debug_struct("Button")
+            .field("base", &self.base)
+            .field("icon", &self.icon)
+            .field("text", &self.text)
+            .field("enabled", &self.enabled)
+            .field("state", &self.state())
+            .field("focused", &self.focused)
+            .finish()
     }
 }
 
 impl Hash for Button {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        todo!("Implement Hash for Button");
+        // Transient interaction state is left out on purpose
+        self.base.hash(state);
+        self.icon.hash(state);
+        self.text.hash(state);
+        self.enabled.hash(state);
+        self.style.hash(state);
+    }
+}
+
+impl Button {
+    pub fn new(base: BaseComponent, text: &str) -> Self {
+        Self {
+            base,
+            icon: None,
+            text: text.to_string(),
+            enabled: true,
+            style: ButtonStyle::default(),
+            tracker: ClickTracker::new(),
+            focused: false,
+        }
+    }
+
+    pub fn with_icon(mut self, icon: Icon, position: IconPosition) -> Self {
+        self.icon = Some((icon, position));
+        self
+    }
+
+    pub fn with_style(mut self, style: ButtonStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+    }
+
+    pub fn set_icon(&mut self, icon: Option<(Icon, IconPosition)>) {
+        self.icon = icon;
+    }
+
+    pub fn set_style(&mut self, style: ButtonStyle) {
+        self.style = style;
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if self.enabled == enabled {
+            return;
+        }
+
+        self.enabled = enabled;
+        self.tracker.reset();
+    }
+
+    /// Runs the click logic as if the user had activated the button
+    pub fn perform_click(&mut self) {
+        if self.enabled {
+            self.base.emit(EventType::MouseButtonClicked);
+        }
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn icon(&self) -> Option<&(Icon, IconPosition)> {
+        self.icon.as_ref()
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn style(&self) -> &ButtonStyle {
+        &self.style
+    }
+
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn state(&self) -> ActionState {
+        // A deserialized button has no transient state, so derive it from `enabled`
+        if !self.enabled {
+            ActionState::Disabled
+        } else {
+            self.tracker.state()
+        }
+    }
+
+    /// Positions of the icon (if any) and the text inside the content frame
+    pub fn content_layout(&self) -> (Option<Position>, Position) {
+        let content = self.base.content_frame();
+        let origin = content.top_left();
+        let text_size = self.style.typography.measure_text(&self.text);
+
+        let center_y = |height: u16| {
+            origin
+                .y()
+                .saturating_add(content.height().saturating_sub(height) / 2)
+        };
+        let centered_text = || {
+            let x = origin
+                .x()
+                .saturating_add(content.width().saturating_sub(text_size.width()) / 2);
+            Position::new(x, center_y(text_size.height()))
+        };
+
+        let Some((icon, icon_position)) = &self.icon else {
+            return (None, centered_text());
+        };
+
+        let icon_width = icon.size.0.min(u16::MAX as u32) as u16;
+        let icon_height = icon.size.1.min(u16::MAX as u32) as u16;
+        let spacing = if self.text.is_empty() {
+            0
+        } else {
+            ICON_SPACING
+        };
+        let group_width = icon_width
+            .saturating_add(spacing)
+            .saturating_add(text_size.width());
+        let start_x = origin
+            .x()
+            .saturating_add(content.width().saturating_sub(group_width) / 2);
+
+        match icon_position {
+            IconPosition::Start => {
+                let icon_at = Position::new(start_x, center_y(icon_height));
+                let text_x = start_x.saturating_add(icon_width).saturating_add(spacing);
+                (
+                    Some(icon_at),
+                    Position::new(text_x, center_y(text_size.height())),
+                )
+            }
+            IconPosition::End => {
+                let text_at = Position::new(start_x, center_y(text_size.height()));
+                let icon_x = start_x
+                    .saturating_add(text_size.width())
+                    .saturating_add(spacing);
+                (Some(Position::new(icon_x, center_y(icon_height))), text_at)
+            }
+            IconPosition::Located(offset) => {
+                let position = self.base.position();
+                let icon_at = Position::new(
+                    position.x().saturating_add(offset.x()),
+                    position.y().saturating_add(offset.y()),
+                );
+                (Some(icon_at), centered_text())
+            }
+        }
+    }
+}
+
+impl Interactive for Button {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() {
+            return false;
+        }
+
+        if self.focused
+            && matches!(
+                input,
+                InputEvent::KeyPressed {
+                    key: Key::Enter,
+                    ..
+                }
+            )
+        {
+            self.perform_click();
+            return true;
+        }
+
+        let response =
+            self.tracker
+                .handle_input(input, |point| self.base.hit_test(point), self.focused);
+        match response.hover_changed {
+            Some(true) => self.base.emit(EventType::HoverEntered),
+            Some(false) => self.base.emit(EventType::HoverExited),
+            None => {}
+        }
+        if response.clicked {
+            self.perform_click();
+        }
+        response.consumed
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled && *self.base.visible()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        if !focused {
+            self.tracker.release_key();
+        }
+    }
+}
+
+impl Renderable for Button {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        let state = self.state();
+        let frame: Boundaries = self.base.frame();
+
+        ctx.fill_rect(frame, self.style.background(&state));
+        if self.focused && !state.is_disabled() {
+            ctx.stroke_rect(frame, &self.style.focus_border);
+        } else {
+            ctx.stroke_rect(frame, &self.style.border);
+        }
+
+        let (icon_at, text_at) = self.content_layout();
+        ctx.push_clip(frame);
+        if let (Some((icon, _)), Some(position)) = (&self.icon, icon_at) {
+            ctx.draw_icon(position, icon);
+        }
+        if !self.text.is_empty() {
+            ctx.draw_text(
+                text_at,
+                &self.text,
+                &self.style.typography,
+                self.style.text_color(&state),
+            );
+        }
+        ctx.pop_clip();
+    }
+}
+
+impl Component for Button {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
     }
 }
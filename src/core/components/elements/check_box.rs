@@ -0,0 +1,293 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        properties::{
+            boundaries::Boundaries,
+            check_state::CheckState,
+            graphics::{
+                action_state::ActionState,
+                background::{Background, BackgroundShape},
+            },
+            position::Position,
+            size::Size,
+        },
+        styles::toggle_style::ToggleStyle,
+    },
+    ui::{click_tracker::ClickTracker, render::render_context::RenderContext},
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::InputEvent,
+        types::{EventPayload, EventType},
+    },
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckBox {
+    base: BaseComponent,
+    text: String,
+    check_state: CheckState,
+    three_state: bool,
+    enabled: bool,
+    #[serde(default)]
+    style: ToggleStyle,
+    #[serde(skip)]
+    tracker: ClickTracker,
+    #[serde(skip)]
+    focused: bool,
+}
+
+impl CheckBox {
+    pub fn new(base: BaseComponent, text: &str) -> Self {
+        Self {
+            base,
+            text: text.to_string(),
+            check_state: CheckState::Unchecked,
+            three_state: false,
+            enabled: true,
+            style: ToggleStyle::default(),
+            tracker: ClickTracker::new(),
+            focused: false,
+        }
+    }
+
+    pub fn with_check_state(mut self, check_state: CheckState) -> Self {
+        self.check_state = check_state;
+        self
+    }
+
+    pub fn with_three_state(mut self, three_state: bool) -> Self {
+        self.three_state = three_state;
+        self
+    }
+
+    pub fn with_style(mut self, style: ToggleStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    // Setters
+    pub fn set_check_state(&mut self, check_state: CheckState) {
+        if self.check_state != check_state {
+            self.check_state = check_state;
+            self.base.emit_with(
+                EventType::FieldValueChanged,
+                EventPayload::CheckState(check_state),
+            );
+        }
+    }
+
+    pub fn set_checked(&mut self, checked: bool) {
+        self.set_check_state(CheckState::from(checked));
+    }
+
+    pub fn set_three_state(&mut self, three_state: bool) {
+        self.three_state = three_state;
+    }
+
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if self.enabled != enabled {
+            self.enabled = enabled;
+            self.tracker.reset();
+        }
+    }
+
+    pub fn set_style(&mut self, style: ToggleStyle) {
+        self.style = style;
+    }
+
+    /// Advances the check state as a user click would
+    pub fn toggle(&mut self) {
+        if self.enabled {
+            self.set_check_state(self.check_state.next(self.three_state));
+        }
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn check_state(&self) -> CheckState {
+        self.check_state
+    }
+
+    pub fn checked(&self) -> bool {
+        self.check_state.is_checked()
+    }
+
+    pub fn three_state(&self) -> bool {
+        self.three_state
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn style(&self) -> &ToggleStyle {
+        &self.style
+    }
+
+    pub fn state(&self) -> ActionState {
+        if !self.enabled {
+            ActionState::Disabled
+        } else {
+            self.tracker.state()
+        }
+    }
+
+    /// Bounds of the square check glyph, vertically centered in the content frame
+    pub fn glyph_bounds(&self) -> Boundaries {
+        let content = self.base.content_frame();
+        let size = self.style.box_size;
+        let y = content
+            .top_left()
+            .y()
+            .saturating_add(content.height().saturating_sub(size) / 2);
+
+        Boundaries::from_rect(
+            Position::new(content.top_left().x(), y),
+            Size::new(size, size),
+        )
+    }
+}
+
+impl Interactive for CheckBox {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() {
+            return false;
+        }
+
+        let response =
+            self.tracker
+                .handle_input(input, |point| self.base.hit_test(point), self.focused);
+        match response.hover_changed {
+            Some(true) => self.base.emit(EventType::HoverEntered),
+            Some(false) => self.base.emit(EventType::HoverExited),
+            None => {}
+        }
+        if response.clicked {
+            self.toggle();
+        }
+        response.consumed
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled && *self.base.visible()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        if !focused {
+            self.tracker.release_key();
+        }
+    }
+}
+
+impl Renderable for CheckBox {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        let state = self.state();
+        let glyph = self.glyph_bounds();
+        let (x, size) = (glyph.top_left().x(), self.style.box_size);
+
+        render_check_glyph(ctx, glyph, self.check_state, &self.style, &state);
+
+        if !self.text.is_empty() {
+            let typography = &self.style.typography;
+            let text_size = typography.measure_text(&self.text);
+            let content = self.base.content_frame();
+            let text_at = Position::new(
+                x.saturating_add(size).saturating_add(self.style.spacing),
+                content
+                    .top_left()
+                    .y()
+                    .saturating_add(content.height().saturating_sub(text_size.height()) / 2),
+            );
+
+            ctx.draw_text(
+                text_at,
+                &self.text,
+                typography,
+                self.style.text_color(&state),
+            );
+            if self.focused {
+                ctx.stroke_rect(
+                    Boundaries::from_rect(text_at, text_size),
+                    &self.style.focus_border,
+                );
+            }
+        }
+    }
+}
+
+impl Component for CheckBox {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
+
+/// Paints a check box square with its mark, shared with the controls that
+/// embed check boxes in their rows
+pub fn render_check_glyph(
+    ctx: &mut RenderContext,
+    bounds: Boundaries,
+    check_state: CheckState,
+    style: &ToggleStyle,
+    state: &ActionState,
+) {
+    let (x, y) = (bounds.top_left().x(), bounds.top_left().y());
+    let size = bounds.width().min(bounds.height());
+
+    ctx.fill_rect(
+        bounds,
+        &Background::new_solid(style.fill(state).clone(), BackgroundShape::Rectangle),
+    );
+    ctx.stroke_rect(bounds, &style.border);
+
+    let mark = style.mark(state);
+    match check_state {
+        CheckState::Checked => {
+            let left = Position::new(x + 3, y + size / 2);
+            let bottom = Position::new(x + size / 2 - 1, y + size.saturating_sub(4));
+            let right = Position::new(x + size.saturating_sub(3), y + 3);
+            ctx.draw_line(left, bottom, mark, 2);
+            ctx.draw_line(bottom, right, mark, 2);
+        }
+        CheckState::Indeterminate => {
+            let inner = Boundaries::from_rect(
+                Position::new(x + 3, y + 3),
+                Size::new(size.saturating_sub(6), size.saturating_sub(6)),
+            );
+            ctx.fill_rect(
+                inner,
+                &Background::new_solid(mark.clone(), BackgroundShape::Rectangle),
+            );
+        }
+        CheckState::Unchecked => {}
+    }
+}
@@ -0,0 +1,711 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::text_box::TextBox,
+        properties::{
+            boundaries::Boundaries,
+            graphics::{
+                background::{Background, BackgroundShape},
+                border::Border,
+                color::{Color, RGBA, hsv_to_rgb, rgb_to_hsv},
+                gradient::{Gradient, GradientType},
+            },
+            position::Position,
+            size::Size,
+        },
+        styles::color_picker_style::ColorPickerStyle,
+    },
+    ui::{inline_editor::InlineEditor, render::render_context::RenderContext},
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::{InputEvent, Key, MouseButton},
+        types::{EventPayload, EventType},
+    },
+};
+
+/// Representation the picked color is handed out in
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ColorFormat {
+    #[default]
+    Rgba,
+    Rgb,
+    Hsv,
+    Hex,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorPickerPart {
+    // Saturation grows to the right and value to the top
+    Square,
+    Hue,
+    Alpha,
+    Preview,
+    // Entry of the recent colors palette
+    Swatch(usize),
+}
+
+/// Color editor with a saturation/value square, hue and alpha bars, a hex
+/// field and a palette of recently picked colors. The color is kept as hue,
+/// saturation, value and alpha, so the hue survives greys, and handed out as
+/// a `Color` in the chosen `ColorFormat`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "RawColorPicker")]
+pub struct ColorPicker {
+    base: BaseComponent,
+    // Degrees in 0..360
+    hue: f32,
+    // Saturation, value and alpha in 0..=1
+    saturation: f32,
+    value: f32,
+    alpha: f32,
+    format: ColorFormat,
+    recent: Vec<Color>,
+    recent_capacity: usize,
+    enabled: bool,
+    #[serde(default)]
+    style: ColorPickerStyle,
+    #[serde(skip)]
+    editor: InlineEditor,
+    // Area being dragged with the mouse
+    #[serde(skip)]
+    dragging: Option<ColorPickerPart>,
+    #[serde(skip)]
+    hovered: Option<ColorPickerPart>,
+    #[serde(skip)]
+    focused: bool,
+}
+
+// Serialized form of ColorPicker so deserialized pickers get their editor
+#[derive(Deserialize)]
+struct RawColorPicker {
+    base: BaseComponent,
+    hue: f32,
+    saturation: f32,
+    value: f32,
+    alpha: f32,
+    format: ColorFormat,
+    recent: Vec<Color>,
+    recent_capacity: usize,
+    enabled: bool,
+    #[serde(default)]
+    style: ColorPickerStyle,
+}
+
+impl From<RawColorPicker> for ColorPicker {
+    fn from(raw: RawColorPicker) -> Self {
+        let mut picker = Self {
+            base: raw.base,
+            hue: raw.hue,
+            saturation: raw.saturation,
+            value: raw.value,
+            alpha: raw.alpha,
+            format: raw.format,
+            recent: raw.recent,
+            recent_capacity: raw.recent_capacity,
+            enabled: raw.enabled,
+            style: raw.style,
+            editor: InlineEditor::default(),
+            dragging: None,
+            hovered: None,
+            focused: false,
+        };
+        picker.sync_editor();
+        picker.editor.set_enabled(picker.enabled);
+        picker
+    }
+}
+
+impl ColorPicker {
+    pub fn new(base: BaseComponent) -> Self {
+        let mut picker = Self {
+            base,
+            hue: 0.0,
+            saturation: 0.0,
+            value: 0.0,
+            alpha: 1.0,
+            format: ColorFormat::default(),
+            recent: Vec::new(),
+            recent_capacity: 10,
+            enabled: true,
+            style: ColorPickerStyle::default(),
+            editor: InlineEditor::default(),
+            dragging: None,
+            hovered: None,
+            focused: false,
+        };
+        picker.sync_editor();
+        picker
+    }
+
+    pub fn with_color(mut self, color: &Color) -> Self {
+        self.load(color);
+        self.sync_editor();
+        self
+    }
+
+    pub fn with_format(mut self, format: ColorFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn with_recent_capacity(mut self, capacity: usize) -> Self {
+        self.set_recent_capacity(capacity);
+        self
+    }
+
+    pub fn with_style(mut self, style: ColorPickerStyle) -> Self {
+        self.set_style(style);
+        self
+    }
+
+    // Setters
+    pub fn set_color(&mut self, color: &Color) {
+        let previous = self.rgba();
+        self.load(color);
+        self.changed(previous);
+    }
+
+    /// Hue in degrees, wrapped into 0..360
+    pub fn set_hue(&mut self, hue: f32) {
+        let previous = self.rgba();
+        self.hue = hue.rem_euclid(360.0);
+        self.changed(previous);
+    }
+
+    pub fn set_saturation(&mut self, saturation: f32) {
+        let previous = self.rgba();
+        self.saturation = saturation.clamp(0.0, 1.0);
+        self.changed(previous);
+    }
+
+    pub fn set_value(&mut self, value: f32) {
+        let previous = self.rgba();
+        self.value = value.clamp(0.0, 1.0);
+        self.changed(previous);
+    }
+
+    pub fn set_alpha(&mut self, alpha: f32) {
+        let previous = self.rgba();
+        self.alpha = alpha.clamp(0.0, 1.0);
+        self.changed(previous);
+    }
+
+    pub fn set_format(&mut self, format: ColorFormat) {
+        self.format = format;
+    }
+
+    /// Puts a color first in the recent palette, dropping the oldest ones
+    /// past the capacity
+    pub fn add_recent(&mut self, color: Color) {
+        let rgba = color.to_rgba();
+        self.recent.retain(|recent| recent.to_rgba() != rgba);
+        self.recent.insert(0, color);
+        self.recent.truncate(self.recent_capacity);
+    }
+
+    pub fn clear_recent(&mut self) {
+        self.recent.clear();
+    }
+
+    pub fn set_recent_capacity(&mut self, capacity: usize) {
+        self.recent_capacity = capacity;
+        self.recent.truncate(capacity);
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.dragging = None;
+            self.hovered = None;
+        }
+        self.editor.set_enabled(enabled);
+    }
+
+    pub fn set_style(&mut self, style: ColorPickerStyle) {
+        self.style = style;
+        self.editor.clear();
+        self.sync_editor();
+    }
+
+    /// Applies the typed text, which may also be a color name or `rgb()`,
+    /// reverting it when it is not a color
+    pub fn commit_text(&mut self) {
+        let Some(text) = self
+            .editor
+            .text_box()
+            .map(|editor| editor.text().to_string())
+        else {
+            return;
+        };
+        match Color::parse(&text) {
+            Ok(color) => {
+                self.set_color(&Color::RGBA(color.to_rgba()));
+                self.add_recent(self.color());
+            }
+            Err(_) => self
+                .base
+                .emit_with(EventType::FieldValidating, EventPayload::Text(text)),
+        }
+        self.sync_editor();
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    /// Picked color in the chosen format
+    pub fn color(&self) -> Color {
+        let rgba = self.rgba();
+        match self.format {
+            ColorFormat::Rgba => Color::RGBA(rgba),
+            ColorFormat::Rgb => Color::RGB(rgba.to_rgb()),
+            // Hue in half degrees to fit a byte
+            ColorFormat::Hsv => Color::hsv(
+                (self.hue / 2.0).round() as u8 % 180,
+                (self.saturation * 255.0).round() as u8,
+                (self.value * 255.0).round() as u8,
+            ),
+            ColorFormat::Hex => Color::HEX(rgba.to_hex()),
+        }
+    }
+
+    pub fn hue(&self) -> f32 {
+        self.hue
+    }
+
+    pub fn saturation(&self) -> f32 {
+        self.saturation
+    }
+
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    pub fn format(&self) -> ColorFormat {
+        self.format
+    }
+
+    /// Recently picked colors, newest first
+    pub fn recent_colors(&self) -> &[Color] {
+        &self.recent
+    }
+
+    pub fn recent_capacity(&self) -> usize {
+        self.recent_capacity
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn style(&self) -> &ColorPickerStyle {
+        &self.style
+    }
+
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Text in the hex field, which may differ from the color while typing
+    pub fn text(&self) -> &str {
+        self.editor.text()
+    }
+
+    pub fn editor(&self) -> Option<&TextBox> {
+        self.editor.text_box()
+    }
+
+    /// Area of a part, `None` for swatches that are empty or do not fit
+    pub fn part_bounds(&self, part: ColorPickerPart) -> Option<Boundaries> {
+        let style = &self.style;
+        let frame = self.base.frame();
+        let left = frame.top_left().x() + style.padding;
+        let top = frame.top_left().y() + style.padding;
+        let square = style.square_size;
+        let below = top + square + style.spacing;
+        let rect = |x: u16, y: u16, width: u16, height: u16| {
+            Some(Boundaries::from_rect(
+                Position::new(x, y),
+                Size::new(height, width),
+            ))
+        };
+
+        match part {
+            ColorPickerPart::Square => rect(left, top, square, square),
+            ColorPickerPart::Hue => rect(
+                left + square + style.spacing,
+                top,
+                style.slider_width,
+                square,
+            ),
+            ColorPickerPart::Alpha => rect(
+                left + square + style.spacing * 2 + style.slider_width,
+                top,
+                style.slider_width,
+                square,
+            ),
+            ColorPickerPart::Preview => rect(left, below, style.preview_width, style.field_height),
+            ColorPickerPart::Swatch(index) => {
+                let step = style.swatch_size + style.swatch_spacing;
+                let fits = (self.content_width() + style.swatch_spacing) / step.max(1);
+                if index >= self.recent.len() || index >= fits as usize {
+                    return None;
+                }
+                rect(
+                    left + index as u16 * step,
+                    below + style.field_height + style.spacing,
+                    style.swatch_size,
+                    style.swatch_size,
+                )
+            }
+        }
+    }
+
+    /// Area of the hex field, right of the preview
+    pub fn field_bounds(&self) -> Boundaries {
+        let style = &self.style;
+        let frame = self.base.frame();
+        let x = frame.top_left().x() + style.padding + style.preview_width + style.spacing;
+        Boundaries::from_rect(
+            Position::new(
+                x,
+                frame.top_left().y() + style.padding + style.square_size + style.spacing,
+            ),
+            Size::new(
+                style.field_height,
+                self.content_width()
+                    .saturating_sub(style.preview_width + style.spacing),
+            ),
+        )
+    }
+
+    // Width taken by the square and the two bars
+    fn content_width(&self) -> u16 {
+        self.style.square_size + (self.style.spacing + self.style.slider_width) * 2
+    }
+
+    fn part_at(&self, point: Position) -> Option<ColorPickerPart> {
+        [
+            ColorPickerPart::Square,
+            ColorPickerPart::Hue,
+            ColorPickerPart::Alpha,
+            ColorPickerPart::Preview,
+        ]
+        .into_iter()
+        .chain((0..self.recent.len()).map(ColorPickerPart::Swatch))
+        .find(|part| {
+            self.part_bounds(*part)
+                .is_some_and(|bounds| bounds.contains(point))
+        })
+    }
+
+    fn rgba(&self) -> RGBA {
+        hsv_to_rgb(self.hue, self.saturation, self.value)
+            .to_rgba((self.alpha * 255.0).round() as u8)
+    }
+
+    // Takes a color, keeping the hue when it is grey
+    fn load(&mut self, color: &Color) {
+        let rgba = color.to_rgba();
+        let (hue, saturation, value) = rgb_to_hsv(rgba.to_rgb());
+        if saturation > 0.0 && value > 0.0 {
+            self.hue = hue;
+        }
+        self.saturation = saturation;
+        self.value = value;
+        self.alpha = rgba.a as f32 / 255.0;
+    }
+
+    fn changed(&mut self, previous: RGBA) {
+        if self.rgba() != previous {
+            self.sync_editor();
+            self.base.emit_with(
+                EventType::FieldValueChanged,
+                EventPayload::Color(self.color()),
+            );
+        }
+    }
+
+    // Moves the dragged part to follow the pointer
+    fn drag_to(&mut self, part: ColorPickerPart, point: Position) {
+        let Some(bounds) = self.part_bounds(part) else {
+            return;
+        };
+        let fraction =
+            |offset: u16, length: u16| offset as f32 / length.saturating_sub(1).max(1) as f32;
+        let x = point
+            .x()
+            .clamp(bounds.top_left().x(), bounds.bottom_right().x() - 1);
+        let y = point
+            .y()
+            .clamp(bounds.top_left().y(), bounds.bottom_right().y() - 1);
+        let across = fraction(x - bounds.top_left().x(), bounds.width());
+        let down = fraction(y - bounds.top_left().y(), bounds.height());
+
+        let previous = self.rgba();
+        match part {
+            ColorPickerPart::Square => {
+                self.saturation = across;
+                self.value = 1.0 - down;
+            }
+            // Red at both ends of the bar
+            ColorPickerPart::Hue => self.hue = (down * 360.0).min(359.99),
+            ColorPickerPart::Alpha => self.alpha = 1.0 - down,
+            _ => {}
+        }
+        self.changed(previous);
+    }
+
+    // Keeps the editor in place and showing the color
+    fn sync_editor(&mut self) {
+        let text = self.rgba().to_hex().value().to_string();
+        self.place_editor();
+        self.editor.show(&text);
+    }
+
+    // Moves the editor over the hex field for the current frame
+    fn place_editor(&mut self) {
+        let bounds = self.field_bounds();
+        self.editor
+            .place(&self.base, &self.style.typography, bounds, "");
+    }
+}
+
+fn solid(color: Color) -> Background {
+    Background::new_solid(color, BackgroundShape::Rectangle)
+}
+
+fn gradient(angle: i16, stops: Vec<(Color, u16)>) -> Background {
+    Background::new_gradient(
+        Gradient::new(GradientType::Linear(angle), stops),
+        BackgroundShape::Rectangle,
+    )
+}
+
+// Horizontal mark across a bar at `fraction` of its height
+fn render_thumb(ctx: &mut RenderContext, bar: Boundaries, fraction: f32, border: &Border) {
+    let y = bar.top_left().y() + (fraction * bar.height().saturating_sub(1) as f32).round() as u16;
+    ctx.stroke_rect(
+        Boundaries::from_rect(
+            Position::new(bar.top_left().x().saturating_sub(1), y.saturating_sub(1)),
+            Size::new(3, bar.width() + 2),
+        ),
+        border,
+    );
+}
+
+impl Interactive for ColorPicker {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() {
+            return false;
+        }
+        // The frame may have moved since the editor was placed
+        self.place_editor();
+
+        match *input {
+            InputEvent::MouseMoved(position) => {
+                if let Some(part) = self.dragging {
+                    self.drag_to(part, position);
+                    return true;
+                }
+                self.hovered = self.part_at(position);
+                self.editor.handle_input(input) || self.hovered.is_some()
+            }
+            InputEvent::MouseButtonPressed {
+                button: MouseButton::Left,
+                position,
+                ..
+            } if self.base.hit_test(position) => {
+                match self.part_at(position) {
+                    Some(
+                        part @ (ColorPickerPart::Square
+                        | ColorPickerPart::Hue
+                        | ColorPickerPart::Alpha),
+                    ) => {
+                        self.dragging = Some(part);
+                        self.drag_to(part, position);
+                    }
+                    Some(ColorPickerPart::Swatch(index)) => {
+                        let color = self.recent[index].clone();
+                        self.set_color(&color);
+                        self.add_recent(color);
+                    }
+                    _ => {
+                        self.editor.handle_input(input);
+                    }
+                }
+                true
+            }
+            InputEvent::MouseButtonReleased {
+                button: MouseButton::Left,
+                ..
+            } => {
+                // A finished drag is remembered in the palette
+                if self.dragging.take().is_some() {
+                    self.add_recent(self.color());
+                    return true;
+                }
+                self.editor.handle_input(input)
+            }
+            // The wheel moves the bar under the pointer
+            InputEvent::MouseScrolled {
+                delta_y, position, ..
+            } if self.base.hit_test(position) => {
+                match self.part_at(position) {
+                    Some(ColorPickerPart::Hue) => self.set_hue(self.hue - delta_y as f32 * 5.0),
+                    Some(ColorPickerPart::Alpha) => {
+                        self.set_alpha(self.alpha + delta_y as f32 * 0.05)
+                    }
+                    _ => return false,
+                }
+                true
+            }
+            InputEvent::KeyPressed {
+                key: Key::Enter, ..
+            } if self.focused => {
+                self.commit_text();
+                true
+            }
+            _ if self.focused => self.editor.handle_input(input),
+            _ => false,
+        }
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled && *self.base.visible()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        if self.focused == focused {
+            return;
+        }
+
+        self.focused = focused;
+        self.editor.set_focused(focused);
+        if !focused {
+            self.dragging = None;
+            self.commit_text();
+        }
+    }
+}
+
+impl Renderable for ColorPicker {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        let style = &self.style;
+        let frame = self.base.frame();
+        ctx.fill_rect(frame, &style.background);
+        let rgba = self.rgba();
+
+        // Saturation across a white to hue blend, value down to black
+        if let Some(square) = self.part_bounds(ColorPickerPart::Square) {
+            let hue = hsv_to_rgb(self.hue, 1.0, 1.0);
+            ctx.fill_rect(
+                square,
+                &gradient(
+                    90,
+                    vec![(Color::rgb(255, 255, 255), 0), (Color::RGB(hue), 100)],
+                ),
+            );
+            ctx.fill_rect(
+                square,
+                &gradient(
+                    180,
+                    vec![(Color::rgba(0, 0, 0, 0), 0), (Color::rgb(0, 0, 0), 100)],
+                ),
+            );
+            ctx.stroke_rect(square, &style.area_border);
+
+            let span = |length: u16| length.saturating_sub(1) as f32;
+            let x = square.top_left().x() + (self.saturation * span(square.width())).round() as u16;
+            let y =
+                square.top_left().y() + ((1.0 - self.value) * span(square.height())).round() as u16;
+            ctx.stroke_rect(
+                Boundaries::from_rect(
+                    Position::new(x.saturating_sub(4), y.saturating_sub(4)),
+                    Size::new(9, 9),
+                ),
+                &style.marker_border,
+            );
+        }
+
+        if let Some(bar) = self.part_bounds(ColorPickerPart::Hue) {
+            let stops = (0..=6)
+                .map(|step| {
+                    let hue = hsv_to_rgb(step as f32 * 60.0, 1.0, 1.0);
+                    (Color::RGB(hue), (step * 100 / 6) as u16)
+                })
+                .collect();
+            ctx.fill_rect(bar, &gradient(180, stops));
+            ctx.stroke_rect(bar, &style.area_border);
+            render_thumb(ctx, bar, self.hue / 360.0, &style.thumb_border);
+        }
+
+        if let Some(bar) = self.part_bounds(ColorPickerPart::Alpha) {
+            ctx.fill_rect(
+                bar,
+                &gradient(
+                    180,
+                    vec![
+                        (Color::RGB(rgba.to_rgb()), 0),
+                        (Color::RGBA(RGBA { a: 0, ..rgba }), 100),
+                    ],
+                ),
+            );
+            ctx.stroke_rect(bar, &style.area_border);
+            render_thumb(ctx, bar, 1.0 - self.alpha, &style.thumb_border);
+        }
+
+        if let Some(preview) = self.part_bounds(ColorPickerPart::Preview) {
+            ctx.fill_rect(preview, &solid(Color::RGBA(rgba)));
+            ctx.stroke_rect(preview, &style.area_border);
+        }
+
+        let field = self.field_bounds();
+        ctx.fill_rect(field, &style.field_background);
+        self.editor.paint(ctx, self.field_bounds());
+        if self.focused {
+            ctx.stroke_rect(field, &style.focus_border);
+        } else {
+            ctx.stroke_rect(field, &style.field_border);
+        }
+
+        for (index, color) in self.recent.iter().enumerate() {
+            let Some(swatch) = self.part_bounds(ColorPickerPart::Swatch(index)) else {
+                break;
+            };
+            ctx.fill_rect(swatch, &solid(color.clone()));
+            if self.hovered == Some(ColorPickerPart::Swatch(index)) {
+                ctx.stroke_rect(swatch, &style.hovered_swatch_border);
+            } else {
+                ctx.stroke_rect(swatch, &style.swatch_border);
+            }
+        }
+
+        ctx.stroke_rect(frame, &style.border);
+    }
+}
+
+impl Component for ColorPicker {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
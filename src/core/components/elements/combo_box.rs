@@ -0,0 +1,652 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::text_box::TextBox,
+        properties::{boundaries::Boundaries, position::Position, size::Size},
+        styles::combo_box_style::ComboBoxStyle,
+    },
+    ui::{
+        inline_editor::InlineEditor, popup::Popup, render::render_context::RenderContext,
+        type_ahead::TypeAhead, virtual_list::VirtualList,
+    },
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::{InputEvent, Key, Modifiers, MouseButton},
+        types::{EventPayload, EventType},
+    },
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DropDownMode {
+    // Text can only come from the list
+    #[default]
+    DropDownList,
+    // The text area is a regular text box, the list only suggests values
+    Editable,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "RawComboBox")]
+pub struct ComboBox {
+    base: BaseComponent,
+    items: Vec<String>,
+    selected: Option<usize>,
+    mode: DropDownMode,
+    max_drop_down_items: usize,
+    enabled: bool,
+    #[serde(default)]
+    style: ComboBoxStyle,
+    #[serde(skip)]
+    editor: InlineEditor,
+    #[serde(skip)]
+    popup: Popup,
+    #[serde(skip)]
+    list: VirtualList,
+    #[serde(skip)]
+    highlighted: Option<usize>,
+    #[serde(skip)]
+    type_ahead: TypeAhead,
+    #[serde(skip)]
+    focused: bool,
+}
+
+// Serialized form of ComboBox so deserialized combo boxes select an existing
+// item and get their editor in editable mode
+#[derive(Deserialize)]
+struct RawComboBox {
+    base: BaseComponent,
+    items: Vec<String>,
+    selected: Option<usize>,
+    mode: DropDownMode,
+    max_drop_down_items: usize,
+    enabled: bool,
+    #[serde(default)]
+    style: ComboBoxStyle,
+}
+
+impl From<RawComboBox> for ComboBox {
+    fn from(raw: RawComboBox) -> Self {
+        let mut combo_box = Self::new(raw.base)
+            .with_max_drop_down_items(raw.max_drop_down_items)
+            .with_style(raw.style);
+        combo_box.items = raw.items;
+        combo_box.selected = raw.selected.filter(|index| *index < combo_box.items.len());
+        combo_box.list.set_item_count(combo_box.items.len());
+        combo_box.mode = raw.mode;
+        combo_box.enabled = raw.enabled;
+        combo_box.sync_editor();
+        combo_box.editor.set_enabled(raw.enabled);
+        combo_box
+    }
+}
+
+impl ComboBox {
+    pub fn new(base: BaseComponent) -> Self {
+        Self {
+            base,
+            items: Vec::new(),
+            selected: None,
+            mode: DropDownMode::default(),
+            max_drop_down_items: 8,
+            enabled: true,
+            style: ComboBoxStyle::default(),
+            editor: InlineEditor::default(),
+            popup: Popup::default(),
+            list: VirtualList::default(),
+            highlighted: None,
+            type_ahead: TypeAhead::new(),
+            focused: false,
+        }
+    }
+
+    pub fn with_items<S: AsRef<str>>(mut self, items: &[S]) -> Self {
+        self.set_items(items);
+        self
+    }
+
+    pub fn with_mode(mut self, mode: DropDownMode) -> Self {
+        self.set_mode(mode);
+        self
+    }
+
+    pub fn with_max_drop_down_items(mut self, max_drop_down_items: usize) -> Self {
+        self.max_drop_down_items = max_drop_down_items.max(1);
+        self
+    }
+
+    pub fn with_style(mut self, style: ComboBoxStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    // Setters
+    pub fn set_items<S: AsRef<str>>(&mut self, items: &[S]) {
+        self.items = items.iter().map(|item| item.as_ref().to_string()).collect();
+        self.selected = self.selected.filter(|index| *index < self.items.len());
+        self.highlighted = None;
+        self.list.set_item_count(self.items.len());
+    }
+
+    pub fn add_item(&mut self, item: &str) {
+        self.items.push(item.to_string());
+        self.list.set_item_count(self.items.len());
+    }
+
+    pub fn set_mode(&mut self, mode: DropDownMode) {
+        self.mode = mode;
+        self.editor.clear();
+        self.sync_editor();
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.close();
+        }
+        self.editor.set_enabled(enabled);
+    }
+
+    pub fn set_style(&mut self, style: ComboBoxStyle) {
+        self.style = style;
+    }
+
+    /// Area the drop-down must stay inside, usually the window client area
+    pub fn set_viewport(&mut self, viewport: Boundaries) {
+        self.popup.set_viewport(viewport);
+    }
+
+    pub fn set_selected_index(&mut self, index: Option<usize>) {
+        let index = index.filter(|index| *index < self.items.len());
+        if self.selected == index {
+            return;
+        }
+
+        self.selected = index;
+        if let Some(index) = index {
+            let text = self.items[index].clone();
+            self.editor.show(&text);
+            self.base
+                .emit_with(EventType::FieldValueChanged, EventPayload::Index(index));
+        }
+    }
+
+    pub fn open(&mut self) {
+        if !self.enabled || self.items.is_empty() || self.popup.is_open() {
+            return;
+        }
+
+        let item_height = self.style.list.item_height;
+        let rows = self.items.len().min(self.max_drop_down_items.max(1)) as u16;
+        let frame = self.base.frame();
+        let viewport_height = rows.saturating_mul(item_height);
+
+        self.list = VirtualList::new(self.items.len(), item_height, viewport_height);
+        self.highlighted = self.selected;
+        if let Some(index) = self.selected {
+            self.list.ensure_visible(index);
+        }
+        // One pixel of border on each side of the list
+        self.popup
+            .open(frame, Size::new(viewport_height + 2, frame.width()));
+    }
+
+    pub fn close(&mut self) {
+        self.popup.close();
+        self.highlighted = None;
+        self.type_ahead.reset();
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn items(&self) -> &[String] {
+        &self.items
+    }
+
+    pub fn mode(&self) -> DropDownMode {
+        self.mode
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn style(&self) -> &ComboBoxStyle {
+        &self.style
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        self.selected
+    }
+
+    pub fn selected_item(&self) -> Option<&str> {
+        self.selected.map(|index| self.items[index].as_str())
+    }
+
+    /// Current text: the editor contents in editable mode, the selected item otherwise
+    pub fn text(&self) -> &str {
+        match self.editor.text_box() {
+            Some(editor) => editor.text(),
+            None => self.selected_item().unwrap_or_default(),
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.popup.is_open()
+    }
+
+    pub fn popup_bounds(&self) -> Option<Boundaries> {
+        self.popup.bounds()
+    }
+
+    pub fn highlighted_index(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    /// Rows currently materialized in the drop-down
+    pub fn visible_items(&self) -> std::ops::Range<usize> {
+        if self.is_open() {
+            self.list.visible_range()
+        } else {
+            0..0
+        }
+    }
+
+    pub fn editor(&self) -> Option<&TextBox> {
+        self.editor.text_box()
+    }
+
+    // Layout
+    fn button_bounds(&self) -> Boundaries {
+        let frame = self.base.frame();
+        let width = self.style.button_width.min(frame.width());
+        Boundaries::from_rect(
+            Position::new(
+                frame.bottom_right().x().saturating_sub(width),
+                frame.top_left().y(),
+            ),
+            Size::new(frame.height(), width),
+        )
+    }
+
+    fn text_bounds(&self) -> Boundaries {
+        let frame = self.base.frame();
+        Boundaries::from_rect(
+            frame.top_left(),
+            frame
+                .size()
+                .with_width(frame.width().saturating_sub(self.style.button_width)),
+        )
+    }
+
+    // Inner area of the popup where rows are laid out
+    fn list_bounds(&self) -> Option<Boundaries> {
+        self.popup.bounds().map(|bounds| {
+            Boundaries::from_rect(
+                Position::new(bounds.top_left().x() + 1, bounds.top_left().y() + 1),
+                Size::new(
+                    bounds.height().saturating_sub(2),
+                    bounds.width().saturating_sub(2),
+                ),
+            )
+        })
+    }
+
+    fn row_at(&self, point: Position) -> Option<usize> {
+        let list = self.list_bounds()?;
+        if !list.contains(point) {
+            return None;
+        }
+        self.list.row_at(point.y() - list.top_left().y())
+    }
+
+    // Editable mode keeps a text box over the text area. Input places it
+    // again, as the frame may have moved since
+    fn sync_editor(&mut self) {
+        if self.mode != DropDownMode::Editable {
+            self.editor.clear();
+            return;
+        }
+
+        let bounds = self.text_bounds();
+        let text = self
+            .selected
+            .map(|index| self.items[index].as_str())
+            .unwrap_or_default();
+        self.editor
+            .place(&self.base, &self.style.typography, bounds, text);
+    }
+
+    fn move_highlight(&mut self, target: usize) {
+        let target = target.min(self.items.len().saturating_sub(1));
+        self.highlighted = Some(target);
+        self.list.ensure_visible(target);
+    }
+
+    fn commit_highlight(&mut self) {
+        let highlighted = self.highlighted;
+        self.close();
+        if highlighted.is_some() {
+            self.set_selected_index(highlighted);
+        }
+    }
+
+    // Target of a navigation key relative to `current`, shared by the open and closed states
+    fn navigation_target(&self, key: Key, current: Option<usize>) -> Option<usize> {
+        let last = self.items.len().checked_sub(1)?;
+        let page = self.list.page_size().max(1);
+
+        Some(match (key, current) {
+            (Key::Up, Some(index)) => index.saturating_sub(1),
+            (Key::Down, Some(index)) => (index + 1).min(last),
+            (Key::PageUp, Some(index)) => index.saturating_sub(page),
+            (Key::PageDown, Some(index)) => (index + page).min(last),
+            (Key::Up | Key::PageUp | Key::Home, _) => 0,
+            (Key::Down | Key::PageDown, None) => 0,
+            (Key::End, _) => last,
+            _ => return None,
+        })
+    }
+
+    fn handle_open(&mut self, input: &InputEvent) -> bool {
+        if self.popup.is_dismissal(input, self.base.frame()) {
+            self.close();
+            return true;
+        }
+
+        match *input {
+            InputEvent::MouseMoved(position) => {
+                if let Some(row) = self.row_at(position) {
+                    self.highlighted = Some(row);
+                }
+                self.popup.contains(position)
+            }
+            InputEvent::MouseScrolled {
+                delta_y, position, ..
+            } if self.popup.contains(position) => {
+                self.list.scroll_by(-(delta_y as isize) * 3);
+                true
+            }
+            InputEvent::MouseButtonPressed {
+                button: MouseButton::Left,
+                position,
+                ..
+            } => {
+                // Pressing the anchor again closes the list
+                if !self.popup.contains(position) {
+                    self.close();
+                }
+                true
+            }
+            InputEvent::MouseButtonReleased {
+                button: MouseButton::Left,
+                position,
+                ..
+            } => {
+                if let Some(row) = self.row_at(position) {
+                    self.highlighted = Some(row);
+                    self.commit_highlight();
+                }
+                true
+            }
+            InputEvent::KeyPressed { key, modifiers } => match key {
+                Key::Enter | Key::F(4) => {
+                    self.commit_highlight();
+                    true
+                }
+                Key::Up if modifiers.alt => {
+                    self.commit_highlight();
+                    true
+                }
+                Key::Tab => {
+                    self.commit_highlight();
+                    false
+                }
+                _ => match self.navigation_target(key, self.highlighted) {
+                    Some(target) => {
+                        self.move_highlight(target);
+                        true
+                    }
+                    None => self.editor.handle_input(input),
+                },
+            },
+            InputEvent::CharTyped(c) if !c.is_control() => {
+                if self.mode == DropDownMode::Editable {
+                    self.editor.handle_input(input);
+                    let text = self.text().to_lowercase();
+                    if let Some(index) = self
+                        .items
+                        .iter()
+                        .position(|item| !text.is_empty() && item.to_lowercase().starts_with(&text))
+                    {
+                        self.move_highlight(index);
+                    }
+                } else if let Some(index) = self.type_ahead.search(c, &self.items, self.highlighted)
+                {
+                    self.move_highlight(index);
+                }
+                true
+            }
+            _ => self.editor.handle_input(input),
+        }
+    }
+
+    fn handle_closed(&mut self, input: &InputEvent) -> bool {
+        match *input {
+            InputEvent::MouseButtonPressed {
+                button: MouseButton::Left,
+                position,
+                ..
+            } if self.base.hit_test(position) => {
+                self.set_focused(true);
+                if self.mode == DropDownMode::Editable && !self.button_bounds().contains(position) {
+                    self.editor.handle_input(input)
+                } else {
+                    self.open();
+                    true
+                }
+            }
+            InputEvent::KeyPressed { key, modifiers } if self.focused => {
+                let opens = matches!(key, Key::F(4)) || (key == Key::Down && modifiers.alt);
+                if opens {
+                    self.open();
+                    return true;
+                }
+
+                // Home and End belong to the text box in editable mode
+                let editor_key =
+                    self.mode == DropDownMode::Editable && matches!(key, Key::Home | Key::End);
+                match self.navigation_target(key, self.selected) {
+                    Some(target) if !editor_key && modifiers == Modifiers::none() => {
+                        self.set_selected_index(Some(target));
+                        true
+                    }
+                    _ => self.editor.handle_input(input),
+                }
+            }
+            InputEvent::CharTyped(c) if self.focused && self.mode == DropDownMode::DropDownList => {
+                if !c.is_control()
+                    && let Some(index) = self.type_ahead.search(c, &self.items, self.selected)
+                {
+                    self.set_selected_index(Some(index));
+                }
+                true
+            }
+            _ => self.editor.handle_input(input),
+        }
+    }
+}
+
+impl Interactive for ComboBox {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() {
+            return false;
+        }
+
+        self.sync_editor();
+        if self.popup.is_open() {
+            self.handle_open(input)
+        } else {
+            self.handle_closed(input)
+        }
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled && *self.base.visible()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        if self.focused == focused {
+            return;
+        }
+
+        self.focused = focused;
+        if !focused {
+            self.close();
+        }
+        if let Some(editor) = self.editor.text_box_mut() {
+            editor.set_focused(focused);
+        } else if focused {
+            self.base.emit(EventType::FieldFocused);
+        } else {
+            self.base.emit(EventType::FieldFocusedLost);
+        }
+    }
+}
+
+impl Renderable for ComboBox {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        let style = &self.style;
+        let frame = self.base.frame();
+        let background = if self.enabled {
+            &style.background
+        } else {
+            &style.disabled_background
+        };
+        ctx.fill_rect(frame, background);
+
+        match self.editor.text_box() {
+            Some(_) => self.editor.paint(ctx, self.text_bounds()),
+            None => {
+                let text_bounds = self.text_bounds();
+                let text_size = style.typography.measure_text(self.text());
+                let text_at = Position::new(
+                    text_bounds.top_left().x() + style.list.item_padding,
+                    text_bounds.top_left().y()
+                        + text_bounds.height().saturating_sub(text_size.height()) / 2,
+                );
+                let color = if self.enabled {
+                    &style.text_color
+                } else {
+                    &style.disabled_text_color
+                };
+                ctx.push_clip(text_bounds);
+                ctx.draw_text(text_at, self.text(), &style.typography, color);
+                ctx.pop_clip();
+            }
+        }
+
+        // Drop-down button with a small chevron
+        let button = self.button_bounds();
+        ctx.fill_rect(button, &style.button_background);
+        let center_x = button.top_left().x() + button.width() / 2;
+        let center_y = button.top_left().y() + button.height() / 2;
+        let left = Position::new(center_x.saturating_sub(4), center_y.saturating_sub(2));
+        let tip = Position::new(center_x, center_y + 2);
+        let right = Position::new(center_x + 4, center_y.saturating_sub(2));
+        ctx.draw_line(left, tip, &style.arrow_color, 1);
+        ctx.draw_line(tip, right, &style.arrow_color, 1);
+
+        if self.focused {
+            ctx.stroke_rect(frame, &style.focus_border);
+        } else {
+            ctx.stroke_rect(frame, &style.border);
+        }
+
+        let (Some(popup), Some(list_bounds)) = (self.popup.bounds(), self.list_bounds()) else {
+            return;
+        };
+
+        let list_style = &style.list;
+        ctx.push_layer();
+        ctx.fill_rect(popup, &list_style.background);
+        ctx.stroke_rect(popup, &list_style.border);
+        ctx.push_clip(list_bounds);
+
+        let thumb = self.list.thumb(list_bounds.height());
+        let row_width = if thumb.is_some() {
+            list_bounds
+                .width()
+                .saturating_sub(list_style.scrollbar_width)
+        } else {
+            list_bounds.width()
+        };
+
+        // Only the rows inside the viewport are painted
+        for index in self.list.visible_range() {
+            let Some(top) = self.list.row_top(index) else {
+                continue;
+            };
+            let row = Boundaries::from_rect(
+                Position::new(list_bounds.top_left().x(), list_bounds.top_left().y() + top),
+                Size::new(list_style.item_height, row_width),
+            );
+            let highlighted = self.highlighted == Some(index);
+            if highlighted {
+                ctx.fill_rect(row, &list_style.highlight_background);
+            }
+
+            let text_size = list_style.typography.measure_text(&self.items[index]);
+            let text_at = Position::new(
+                row.top_left().x() + list_style.item_padding,
+                row.top_left().y() + row.height().saturating_sub(text_size.height()) / 2,
+            );
+            let color = if highlighted {
+                &list_style.highlight_text_color
+            } else {
+                &list_style.text_color
+            };
+            ctx.draw_text(text_at, &self.items[index], &list_style.typography, color);
+        }
+
+        if let Some((offset, length)) = thumb {
+            let thumb_bounds = Boundaries::from_rect(
+                Position::new(
+                    list_bounds.top_left().x() + row_width,
+                    list_bounds.top_left().y() + offset,
+                ),
+                Size::new(length, list_style.scrollbar_width),
+            );
+            ctx.fill_rect(thumb_bounds, &list_style.scrollbar_thumb);
+        }
+
+        ctx.pop_clip();
+        ctx.pop_layer();
+    }
+}
+
+impl Component for ComboBox {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
@@ -0,0 +1,237 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::{
+            menu_item::MenuItem,
+            menu_popup::{MenuAction, MenuPopup},
+        },
+        properties::{boundaries::Boundaries, position::Position, size::Size},
+        styles::menu_style::MenuStyle,
+    },
+    ui::{
+        accelerator_table::AcceleratorTable, popup::PopupPlacement,
+        render::render_context::RenderContext,
+    },
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::{InputEvent, Key, MouseButton},
+        types::{EventPayload, EventType},
+    },
+};
+
+/// Pop-up menu opened with a right click inside its frame, which usually
+/// covers the component it belongs to, or with Shift+F10.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextMenu {
+    base: BaseComponent,
+    root: MenuItem,
+    enabled: bool,
+    #[serde(default)]
+    style: MenuStyle,
+    #[serde(skip)]
+    popup: MenuPopup,
+}
+
+impl ContextMenu {
+    pub fn new(base: BaseComponent) -> Self {
+        Self {
+            base,
+            root: MenuItem::new(""),
+            enabled: true,
+            style: MenuStyle::default(),
+            popup: MenuPopup::default(),
+        }
+    }
+
+    pub fn with_item(mut self, item: MenuItem) -> Self {
+        self.add_item(item);
+        self
+    }
+
+    pub fn with_style(mut self, style: MenuStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    // Setters
+    pub fn add_item(&mut self, item: MenuItem) {
+        self.root.add_item(item);
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.close();
+        }
+    }
+
+    pub fn set_style(&mut self, style: MenuStyle) {
+        self.style = style;
+    }
+
+    /// Area the menu must stay inside, usually the window client area
+    pub fn set_viewport(&mut self, viewport: Boundaries) {
+        self.popup.set_viewport(viewport);
+    }
+
+    pub fn set_checked(&mut self, command: usize, checked: bool) {
+        if let Some(item) = self.root.find_command_mut(command) {
+            item.set_checked(checked);
+        }
+    }
+
+    pub fn set_item_enabled(&mut self, command: usize, enabled: bool) {
+        if let Some(item) = self.root.find_command_mut(command) {
+            item.set_enabled(enabled);
+        }
+    }
+
+    /// Runs a command as if its item was clicked, see `MenuStrip::invoke`
+    pub fn invoke(&mut self, command: usize) -> bool {
+        let Some(item) = self.root.find_command_mut(command) else {
+            return false;
+        };
+        if !item.selectable() || item.has_submenu() {
+            return false;
+        }
+
+        if item.checkable() {
+            item.set_checked(!item.checked());
+        }
+        self.base
+            .emit_with(EventType::CommandInvoked, EventPayload::Index(command));
+        true
+    }
+
+    pub fn register_shortcuts(&self, table: &mut AcceleratorTable) {
+        table.register_menu(&self.root);
+    }
+
+    /// Opens the menu with its top-left corner at a point
+    pub fn show(&mut self, at: Position, keyboard: bool) {
+        if !self.enabled || self.root.items().is_empty() {
+            return;
+        }
+
+        let anchor = Boundaries::from_rect(at, Size::new(0, 0));
+        self.popup.set_show_mnemonics(keyboard);
+        self.popup.open(
+            &self.root,
+            &self.style,
+            anchor,
+            PopupPlacement::Below,
+            keyboard,
+        );
+    }
+
+    pub fn close(&mut self) {
+        self.popup.close();
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn items(&self) -> &[MenuItem] {
+        self.root.items()
+    }
+
+    pub fn item(&self, command: usize) -> Option<&MenuItem> {
+        self.root.find_command(command)
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn style(&self) -> &MenuStyle {
+        &self.style
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.popup.is_open()
+    }
+
+    pub fn open_depth(&self) -> usize {
+        self.popup.depth()
+    }
+
+    pub fn highlighted_path(&self) -> Option<Vec<usize>> {
+        self.popup.highlighted_path()
+    }
+
+    pub fn popup_bounds(&self, depth: usize) -> Option<Boundaries> {
+        self.popup.bounds(depth)
+    }
+
+    pub fn item_bounds(&self, depth: usize, index: usize) -> Option<Boundaries> {
+        self.popup
+            .item_bounds(&self.root, &self.style, depth, index)
+    }
+}
+
+impl Interactive for ContextMenu {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() {
+            return false;
+        }
+
+        if self.popup.is_open() {
+            return match self.popup.handle_input(&self.root, &self.style, input) {
+                MenuAction::Invoked(path) => {
+                    if let Some(command) = self.root.item_at(&path).and_then(MenuItem::command) {
+                        self.invoke(command);
+                    }
+                    true
+                }
+                MenuAction::Ignored => false,
+                _ => true,
+            };
+        }
+
+        match *input {
+            InputEvent::MouseButtonReleased {
+                button: MouseButton::Right,
+                position,
+                ..
+            } if self.base.hit_test(position) => {
+                self.show(position, false);
+                true
+            }
+            // Shift+F10 opens the menu at the top-left corner of the frame
+            InputEvent::KeyPressed {
+                key: Key::F(10),
+                modifiers,
+            } if modifiers.shift && !modifiers.ctrl && !modifiers.alt => {
+                self.show(self.base.frame().top_left(), true);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Renderable for ContextMenu {
+    fn render(&self, ctx: &mut RenderContext) {
+        if *self.base.visible() {
+            self.popup.render(ctx, &self.root, &self.style);
+        }
+    }
+}
+
+impl Component for ContextMenu {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
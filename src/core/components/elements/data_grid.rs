@@ -0,0 +1,1399 @@
+use std::{cell::RefCell, fmt, ops::Range, rc::Rc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::{check_box::render_check_glyph, combo_box::ComboBox, text_box::TextBox},
+        properties::{
+            boundaries::Boundaries, cell_value::CellValue, check_state::CheckState,
+            graphics::action_state::ActionState, position::Position, size::Size,
+        },
+        styles::{data_grid_style::DataGridStyle, text_box_style::TextBoxStyle},
+    },
+    ui::{render::render_context::RenderContext, virtual_list::VirtualList},
+    utils::traits::{
+        component::Component, data_source::DataSource, interactive::Interactive,
+        renderable::Renderable,
+    },
+    window::events::{
+        event_system::EventSystem,
+        input::{InputEvent, Key, Modifiers, MouseButton},
+        types::{EventPayload, EventType},
+    },
+};
+
+// Pointer travel before a pressed header turns into a column drag
+const DRAG_THRESHOLD: u16 = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ColumnKind {
+    Text,
+    // Edited as text, stored as `CellValue::Number` and right aligned
+    Number,
+    CheckBox,
+    // Edited with a drop-down of fixed choices, stored as text
+    ComboBox(Vec<String>),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DataGridColumn {
+    pub header: String,
+    pub kind: ColumnKind,
+    pub width: u16,
+    // Column of the data source shown in this column
+    pub source_column: usize,
+    pub sortable: bool,
+    pub read_only: bool,
+}
+
+impl DataGridColumn {
+    pub fn new(header: &str, kind: ColumnKind, source_column: usize) -> Self {
+        Self {
+            header: header.to_string(),
+            kind,
+            width: 100,
+            source_column,
+            sortable: true,
+            read_only: false,
+        }
+    }
+
+    pub fn text(header: &str, source_column: usize) -> Self {
+        Self::new(header, ColumnKind::Text, source_column)
+    }
+
+    pub fn number(header: &str, source_column: usize) -> Self {
+        Self::new(header, ColumnKind::Number, source_column)
+    }
+
+    pub fn check_box(header: &str, source_column: usize) -> Self {
+        Self::new(header, ColumnKind::CheckBox, source_column)
+    }
+
+    pub fn combo_box<S: AsRef<str>>(header: &str, source_column: usize, choices: &[S]) -> Self {
+        let choices = choices.iter().map(|c| c.as_ref().to_string()).collect();
+        Self::new(header, ColumnKind::ComboBox(choices), source_column)
+    }
+
+    pub fn with_width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn with_sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+enum CellEditor {
+    Text(Box<TextBox>),
+    Combo(Box<ComboBox>),
+}
+
+#[derive(Debug, Clone)]
+struct ActiveEdit {
+    row: usize,
+    column: usize,
+    editor: CellEditor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HeaderDrag {
+    Resize {
+        column: usize,
+        start_x: u16,
+        start_width: u16,
+    },
+    // Pressed but not moved yet; releasing it sorts
+    Pressed {
+        column: usize,
+        start_x: u16,
+    },
+    Move {
+        column: usize,
+        x: u16,
+    },
+}
+
+/// Grid of typed columns over a `DataSource`. Rows are virtualized: only the
+/// ones inside the viewport are read from the source and painted.
+///
+/// Rows are addressed in display order, after sorting; events carry the row
+/// of the data source and the index of the grid column.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DataGrid {
+    base: BaseComponent,
+    columns: Vec<DataGridColumn>,
+    // Column indices from left to right
+    display_order: Vec<usize>,
+    // Leading columns in display order that do not scroll horizontally
+    frozen_columns: usize,
+    sort: Option<(usize, SortDirection)>,
+    read_only: bool,
+    enabled: bool,
+    #[serde(default)]
+    style: DataGridStyle,
+    #[serde(skip)]
+    source: Option<Rc<RefCell<dyn DataSource>>>,
+    // Source row shown at each display row
+    #[serde(skip)]
+    row_order: Vec<usize>,
+    #[serde(skip)]
+    list: VirtualList,
+    #[serde(skip)]
+    scroll_x: u16,
+    #[serde(skip)]
+    current: Option<(usize, usize)>,
+    #[serde(skip)]
+    edit: Option<ActiveEdit>,
+    #[serde(skip)]
+    drag: Option<HeaderDrag>,
+    #[serde(skip)]
+    viewport: Option<Boundaries>,
+    // Frame of the last refresh, `None` until the first one
+    #[serde(skip)]
+    laid_out: Option<Boundaries>,
+    #[serde(skip)]
+    focused: bool,
+}
+
+impl fmt::Debug for DataGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DataGrid")
+            .field("base", &self.base)
+            .field("columns", &self.columns)
+            .field("display_order", &self.display_order)
+            .field("frozen_columns", &self.frozen_columns)
+            .field("sort", &self.sort)
+            .field("read_only", &self.read_only)
+            .field("enabled", &self.enabled)
+            .field("rows", &self.row_order.len())
+            .field("current", &self.current)
+            .finish()
+    }
+}
+
+impl DataGrid {
+    pub fn new(base: BaseComponent) -> Self {
+        let mut data_grid = Self {
+            base,
+            columns: Vec::new(),
+            display_order: Vec::new(),
+            frozen_columns: 0,
+            sort: None,
+            read_only: false,
+            enabled: true,
+            style: DataGridStyle::default(),
+            source: None,
+            row_order: Vec::new(),
+            list: VirtualList::default(),
+            scroll_x: 0,
+            current: None,
+            edit: None,
+            drag: None,
+            viewport: None,
+            laid_out: None,
+            focused: false,
+        };
+        data_grid.refresh();
+        data_grid
+    }
+
+    pub fn with_source(mut self, source: Rc<RefCell<dyn DataSource>>) -> Self {
+        self.set_source(source);
+        self
+    }
+
+    pub fn with_column(mut self, column: DataGridColumn) -> Self {
+        self.add_column(column);
+        self
+    }
+
+    pub fn with_frozen_columns(mut self, frozen_columns: usize) -> Self {
+        self.set_frozen_columns(frozen_columns);
+        self
+    }
+
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    pub fn with_style(mut self, style: DataGridStyle) -> Self {
+        self.set_style(style);
+        self
+    }
+
+    // Setters
+    pub fn set_source(&mut self, source: Rc<RefCell<dyn DataSource>>) {
+        self.cancel_edit();
+        self.source = Some(source);
+        self.current = None;
+        self.list.scroll_to(0);
+        self.refresh_data();
+    }
+
+    pub fn add_column(&mut self, column: DataGridColumn) {
+        self.display_order.push(self.columns.len());
+        self.columns.push(column);
+    }
+
+    pub fn set_frozen_columns(&mut self, frozen_columns: usize) {
+        self.frozen_columns = frozen_columns.min(self.columns.len());
+        self.scroll_x = self.scroll_x.min(self.max_scroll_x());
+    }
+
+    pub fn set_column_width(&mut self, column: usize, width: u16) {
+        let min_width = self.style.min_column_width;
+        if let Some(column) = self.columns.get_mut(column) {
+            column.width = width.max(min_width);
+        }
+        self.scroll_x = self.scroll_x.min(self.max_scroll_x());
+        self.reposition_editor();
+    }
+
+    /// Moves a column to another place in the display order
+    pub fn move_column(&mut self, column: usize, display_index: usize) {
+        let Some(from) = self.display_position(column) else {
+            return;
+        };
+        let to = display_index.min(self.display_order.len() - 1);
+        self.display_order.remove(from);
+        self.display_order.insert(to, column);
+        self.reposition_editor();
+    }
+
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+        if read_only {
+            self.cancel_edit();
+        }
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.cancel_edit();
+            self.drag = None;
+        }
+    }
+
+    pub fn set_style(&mut self, style: DataGridStyle) {
+        self.style = style;
+        self.layout_rows();
+    }
+
+    /// Area drop-down editors must stay inside, usually the window client area
+    pub fn set_viewport(&mut self, viewport: Boundaries) {
+        self.viewport = Some(viewport);
+    }
+
+    /// Sorts the rows by a column; the current cell stays on the same data row
+    pub fn sort_by(&mut self, column: usize, direction: SortDirection) {
+        if column >= self.columns.len() {
+            return;
+        }
+        self.sort = Some((column, direction));
+        self.refresh_data();
+    }
+
+    pub fn clear_sort(&mut self) {
+        self.sort = None;
+        self.refresh_data();
+    }
+
+    /// Re-reads the row count and re-applies the sort, after the source changed
+    pub fn refresh_data(&mut self) {
+        let current_source = self
+            .current
+            .and_then(|(row, column)| Some((*self.row_order.get(row)?, column)));
+        let Some(source) = self.source.clone() else {
+            self.row_order.clear();
+            self.layout_rows();
+            return;
+        };
+
+        let source = source.borrow();
+        let mut order: Vec<usize> = (0..source.row_count()).collect();
+        if let Some((column, direction)) = self.sort {
+            let source_column = self.columns[column].source_column;
+            let mut keyed: Vec<(usize, CellValue)> = order
+                .iter()
+                .map(|row| (*row, source.value(*row, source_column)))
+                .collect();
+            keyed.sort_by(|(_, a), (_, b)| match direction {
+                SortDirection::Ascending => a.sort_cmp(b),
+                SortDirection::Descending => b.sort_cmp(a),
+            });
+            order = keyed.into_iter().map(|(row, _)| row).collect();
+        }
+        drop(source);
+
+        self.row_order = order;
+        self.current = current_source.and_then(|(source_row, column)| {
+            let row = self.row_order.iter().position(|row| *row == source_row)?;
+            Some((row, column))
+        });
+        if let Some(edit) = &mut self.edit {
+            match self.current {
+                Some((row, _)) => edit.row = row,
+                None => self.edit = None,
+            }
+        }
+        self.layout_rows();
+        if let Some((row, _)) = self.current {
+            self.list.ensure_visible(row);
+        }
+        self.reposition_editor();
+    }
+
+    /// Re-lays out the rows and the editor. Input does it on its own once the
+    /// frame has changed, and rendering paints the cells where they would go
+    pub fn refresh(&mut self) {
+        self.laid_out = Some(self.base.frame());
+        self.layout_rows();
+        self.scroll_x = self.scroll_x.min(self.max_scroll_x());
+        self.reposition_editor();
+    }
+
+    /// Moves the current cell, committing any edit in progress
+    pub fn set_current_cell(&mut self, row: usize, column: usize) {
+        if row >= self.row_order.len() || column >= self.columns.len() {
+            return;
+        }
+        if self.edit.is_some() && !self.commit_edit() {
+            return;
+        }
+
+        self.ensure_cell_visible(row, column);
+        if self.current != Some((row, column)) {
+            self.current = Some((row, column));
+            self.base.emit_with(
+                EventType::FieldFocusMove,
+                EventPayload::Cell(self.row_order[row], column),
+            );
+        }
+    }
+
+    /// Starts editing the current cell; check box cells toggle right away
+    pub fn begin_edit(&mut self) -> bool {
+        let Some((row, column)) = self.current else {
+            return false;
+        };
+        if self.edit.is_some() || !self.cell_editable(column) {
+            return false;
+        }
+
+        let value = self.cell_value(row, column);
+        let Some(bounds) = self.cell_bounds(row, column) else {
+            return false;
+        };
+        // Editors report to a private event system, listeners only see the grid
+        let base = BaseComponent::new(
+            bounds.size(),
+            bounds.top_left(),
+            Default::default(),
+            Default::default(),
+            Rc::new(RefCell::new(EventSystem::default())),
+        );
+
+        let editor = match &self.columns[column].kind {
+            ColumnKind::CheckBox => {
+                self.write_cell(row, column, CellValue::Bool(!value.as_bool()));
+                return true;
+            }
+            ColumnKind::Text | ColumnKind::Number => {
+                let style = TextBoxStyle {
+                    typography: self.style.list.typography.clone(),
+                    ..TextBoxStyle::default()
+                };
+                let mut text_box = TextBox::new(base)
+                    .with_text(&value.to_string())
+                    .with_style(style);
+                text_box.set_focused(true);
+                text_box.select_all();
+                CellEditor::Text(Box::new(text_box))
+            }
+            ColumnKind::ComboBox(choices) => {
+                let mut combo_box = ComboBox::new(base).with_items(choices);
+                if let Some(viewport) = self.viewport {
+                    combo_box.set_viewport(viewport);
+                }
+                let text = value.to_string();
+                combo_box.set_selected_index(choices.iter().position(|c| *c == text));
+                combo_box.set_focused(true);
+                combo_box.open();
+                CellEditor::Combo(Box::new(combo_box))
+            }
+        };
+        self.edit = Some(ActiveEdit {
+            row,
+            column,
+            editor,
+        });
+        true
+    }
+
+    /// Writes the editor value back to the source. Returns false, keeping the
+    /// editor open, when the text is not valid for the column.
+    pub fn commit_edit(&mut self) -> bool {
+        let Some(edit) = &self.edit else {
+            return true;
+        };
+
+        let value = match (&edit.editor, &self.columns[edit.column].kind) {
+            (CellEditor::Text(text_box), ColumnKind::Number) => {
+                let text = text_box.text().trim();
+                if text.is_empty() {
+                    CellValue::Empty
+                } else {
+                    match text.parse::<f64>() {
+                        Ok(number) => CellValue::Number(number),
+                        Err(_) => return false,
+                    }
+                }
+            }
+            (CellEditor::Text(text_box), _) => CellValue::Text(text_box.text().to_string()),
+            (CellEditor::Combo(combo_box), _) => match combo_box.selected_item() {
+                Some(item) => CellValue::Text(item.to_string()),
+                None => CellValue::Empty,
+            },
+        };
+        let (row, column) = (edit.row, edit.column);
+        self.edit = None;
+        if self.cell_value(row, column) != value {
+            self.write_cell(row, column, value);
+        }
+        true
+    }
+
+    pub fn cancel_edit(&mut self) {
+        self.edit = None;
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn columns(&self) -> &[DataGridColumn] {
+        &self.columns
+    }
+
+    /// Column indices from left to right
+    pub fn column_order(&self) -> &[usize] {
+        &self.display_order
+    }
+
+    pub fn frozen_columns(&self) -> usize {
+        self.frozen_columns
+    }
+
+    pub fn sort(&self) -> Option<(usize, SortDirection)> {
+        self.sort
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.row_order.len()
+    }
+
+    /// Data source row shown at a display row
+    pub fn source_row(&self, row: usize) -> Option<usize> {
+        self.row_order.get(row).copied()
+    }
+
+    pub fn cell_value(&self, row: usize, column: usize) -> CellValue {
+        match (
+            &self.source,
+            self.row_order.get(row),
+            self.columns.get(column),
+        ) {
+            (Some(source), Some(source_row), Some(column)) => {
+                source.borrow().value(*source_row, column.source_column)
+            }
+            _ => CellValue::Empty,
+        }
+    }
+
+    pub fn current_cell(&self) -> Option<(usize, usize)> {
+        self.current
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.edit.is_some()
+    }
+
+    /// Text in the editor of the cell being edited
+    pub fn editor_text(&self) -> Option<&str> {
+        self.edit.as_ref().map(|edit| match &edit.editor {
+            CellEditor::Text(text_box) => text_box.text(),
+            CellEditor::Combo(combo_box) => combo_box.text(),
+        })
+    }
+
+    /// Display rows currently read from the source
+    pub fn visible_rows(&self) -> Range<usize> {
+        self.list.visible_range()
+    }
+
+    pub fn first_visible_row(&self) -> usize {
+        self.list.offset()
+    }
+
+    pub fn scroll_x(&self) -> u16 {
+        self.scroll_x
+    }
+
+    pub fn read_only(&self) -> bool {
+        self.read_only
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn style(&self) -> &DataGridStyle {
+        &self.style
+    }
+
+    /// Header cell of a column as currently laid out, if any of it is visible
+    pub fn header_bounds(&self, column: usize) -> Option<Boundaries> {
+        let header = self.header_area();
+        self.column_span(column, header, self.scroll_x)
+    }
+
+    /// Bounds of a cell, if any of it is visible
+    pub fn cell_bounds(&self, row: usize, column: usize) -> Option<Boundaries> {
+        self.cell_frame(&self.list, self.scroll_x, row, column)
+    }
+
+    // Layout
+    // Moved or resized since the last refresh, or just deserialized
+    fn stale(&self) -> bool {
+        self.laid_out != Some(self.base.frame())
+    }
+
+    fn inner(&self) -> Boundaries {
+        let frame = self.base.frame();
+        Boundaries::from_rect(
+            Position::new(frame.top_left().x() + 1, frame.top_left().y() + 1),
+            Size::new(
+                frame.height().saturating_sub(2),
+                frame.width().saturating_sub(2),
+            ),
+        )
+    }
+
+    fn header_area(&self) -> Boundaries {
+        let inner = self.inner();
+        Boundaries::from_rect(
+            inner.top_left(),
+            inner
+                .size()
+                .with_height(self.style.header_height.min(inner.height())),
+        )
+    }
+
+    // Area below the header, left of the vertical scrollbar
+    fn body_area(&self) -> Boundaries {
+        let inner = self.inner();
+        let header = self.style.header_height.min(inner.height());
+        let height = inner.height() - header;
+        let rows_fit = (height / self.style.list.item_height.max(1)) as usize;
+        let width = if self.row_order.len() > rows_fit {
+            inner
+                .width()
+                .saturating_sub(self.style.list.scrollbar_width)
+        } else {
+            inner.width()
+        };
+        Boundaries::from_rect(
+            Position::new(inner.top_left().x(), inner.top_left().y() + header),
+            Size::new(height, width),
+        )
+    }
+
+    fn frozen_width(&self) -> u16 {
+        self.display_order
+            .iter()
+            .take(self.frozen_columns)
+            .map(|column| self.columns[*column].width)
+            .fold(0, u16::saturating_add)
+    }
+
+    fn scrolling_width(&self) -> u16 {
+        self.display_order
+            .iter()
+            .skip(self.frozen_columns)
+            .map(|column| self.columns[*column].width)
+            .fold(0, u16::saturating_add)
+    }
+
+    fn max_scroll_x(&self) -> u16 {
+        let visible = self.body_area().width().saturating_sub(self.frozen_width());
+        self.scrolling_width().saturating_sub(visible)
+    }
+
+    fn display_position(&self, column: usize) -> Option<usize> {
+        self.display_order.iter().position(|c| *c == column)
+    }
+
+    // Left edge of a column relative to the grid at a horizontal scroll,
+    // before clipping
+    fn column_left(&self, column: usize, scroll_x: u16) -> Option<i32> {
+        let position = self.display_position(column)?;
+        let offset: i32 = self.display_order[..position]
+            .iter()
+            .map(|c| self.columns[*c].width as i32)
+            .sum();
+        if position < self.frozen_columns {
+            Some(offset)
+        } else {
+            Some(offset - scroll_x as i32)
+        }
+    }
+
+    // Visible part of a column inside a horizontal band (header or row)
+    fn column_span(&self, column: usize, band: Boundaries, scroll_x: u16) -> Option<Boundaries> {
+        let left = self.column_left(column, scroll_x)?;
+        let band_x = band.top_left().x() as i32;
+        let band_right = band_x + self.body_area().width() as i32;
+        let clip_left = if self.display_position(column)? < self.frozen_columns {
+            band_x
+        } else {
+            band_x + self.frozen_width() as i32
+        };
+
+        let x0 = (band_x + left).max(clip_left);
+        let x1 = (band_x + left + self.columns[column].width as i32).min(band_right);
+        if x1 <= x0 {
+            return None;
+        }
+        Some(Boundaries::from_rect(
+            Position::new(x0 as u16, band.top_left().y()),
+            Size::new(band.height(), (x1 - x0) as u16),
+        ))
+    }
+
+    fn column_at(&self, x: u16, band: Boundaries) -> Option<usize> {
+        let probe = Position::new(x, band.top_left().y());
+        self.display_order.iter().copied().find(|column| {
+            self.column_span(*column, band, self.scroll_x)
+                .is_some_and(|span| span.contains(probe))
+        })
+    }
+
+    // Column whose right header edge is within grip distance of `x`
+    fn resize_edge_at(&self, x: u16) -> Option<usize> {
+        let header = self.header_area();
+        let grip = self.style.resize_grip as i32;
+        self.display_order.iter().copied().find(|column| {
+            let Some(left) = self.column_left(*column, self.scroll_x) else {
+                return false;
+            };
+            let right = header.top_left().x() as i32 + left + self.columns[*column].width as i32;
+            self.column_span(*column, header, self.scroll_x).is_some()
+                && (x as i32 - right).abs() <= grip
+        })
+    }
+
+    fn cell_at(&self, point: Position) -> Option<(usize, usize)> {
+        let body = self.body_area();
+        if !body.contains(point) {
+            return None;
+        }
+        let row = self.list.row_at(point.y() - body.top_left().y())?;
+        let band = Boundaries::from_rect(
+            Position::new(body.top_left().x(), point.y()),
+            body.size().with_height(1),
+        );
+        Some((row, self.column_at(point.x(), band)?))
+    }
+
+    fn layout_rows(&mut self) {
+        self.list = self.fitted_list();
+    }
+
+    // Copy of the row list fitted to the current frame
+    fn fitted_list(&self) -> VirtualList {
+        let mut list = self.list;
+        list.set_item_count(self.row_order.len());
+        list.set_row_height(self.style.list.item_height);
+        list.set_viewport_height(self.body_area().height());
+        list
+    }
+
+    // Frame of a cell for a row list and horizontal scroll, if any of it is visible
+    fn cell_frame(
+        &self,
+        list: &VirtualList,
+        scroll_x: u16,
+        row: usize,
+        column: usize,
+    ) -> Option<Boundaries> {
+        let top = list.row_top(row)?;
+        let body = self.body_area();
+        let row_height = self.style.list.item_height;
+        let row_area = Boundaries::from_rect(
+            Position::new(body.top_left().x(), body.top_left().y() + top),
+            body.size().with_height(row_height),
+        );
+        self.column_span(column, row_area, scroll_x)
+    }
+
+    fn ensure_cell_visible(&mut self, row: usize, column: usize) {
+        self.list.ensure_visible(row);
+        if let Some(position) = self.display_position(column)
+            && position >= self.frozen_columns
+            && let Some(left) = self.column_left(column, self.scroll_x)
+        {
+            let visible = self.body_area().width().saturating_sub(self.frozen_width()) as i32;
+            let start = left - self.frozen_width() as i32 + self.scroll_x as i32;
+            let end = start + self.columns[column].width as i32;
+            let scroll = self.scroll_x as i32;
+            let scroll = if start < scroll {
+                start
+            } else if end > scroll + visible {
+                (end - visible).min(start)
+            } else {
+                scroll
+            };
+            self.scroll_x = (scroll.max(0) as u16).min(self.max_scroll_x());
+        }
+        self.reposition_editor();
+    }
+
+    fn reposition_editor(&mut self) {
+        let Some((row, column)) = self.edit.as_ref().map(|edit| (edit.row, edit.column)) else {
+            return;
+        };
+        let bounds = self.cell_bounds(row, column);
+        let Some(edit) = &mut self.edit else {
+            return;
+        };
+        let base = match &mut edit.editor {
+            CellEditor::Text(text_box) => text_box.base_mut(),
+            CellEditor::Combo(combo_box) => combo_box.base_mut(),
+        };
+        match bounds {
+            Some(bounds) => {
+                base.set_visible(true);
+                base.set_position(bounds.top_left());
+                base.set_size(bounds.size());
+            }
+            None => base.set_visible(false),
+        }
+    }
+
+    fn cell_editable(&self, column: usize) -> bool {
+        !self.read_only && self.source.is_some() && !self.columns[column].read_only
+    }
+
+    fn write_cell(&mut self, row: usize, column: usize, value: CellValue) {
+        let (Some(source), Some(source_row)) = (self.source.clone(), self.source_row(row)) else {
+            return;
+        };
+        let source_column = self.columns[column].source_column;
+        if source
+            .borrow_mut()
+            .set_value(source_row, source_column, value)
+        {
+            self.base.emit_with(
+                EventType::FieldValueChanged,
+                EventPayload::Cell(source_row, column),
+            );
+        }
+    }
+
+    fn toggle_sort(&mut self, column: usize) {
+        if !self.columns[column].sortable {
+            return;
+        }
+        let direction = match self.sort {
+            Some((sorted, SortDirection::Ascending)) if sorted == column => {
+                SortDirection::Descending
+            }
+            _ => SortDirection::Ascending,
+        };
+        self.sort_by(column, direction);
+    }
+
+    // Display position a dragged column lands on when released at `x`
+    fn drop_position(&self, x: u16) -> usize {
+        let header = self.header_area();
+        self.display_order
+            .iter()
+            .position(|column| {
+                self.column_span(*column, header, self.scroll_x)
+                    .is_some_and(|span| x < span.top_left().x() + span.width() / 2)
+            })
+            .unwrap_or(self.display_order.len().saturating_sub(1))
+    }
+
+    // Current cell moved by whole cells, clamped to the grid
+    fn step(&self, rows: isize, columns: isize) -> Option<(usize, usize)> {
+        let last_row = self.row_order.len().checked_sub(1)?;
+        let last_position = self.display_order.len().checked_sub(1)?;
+        let Some((row, column)) = self.current else {
+            return Some((0, self.display_order[0]));
+        };
+        let position = self.display_position(column)?;
+        let row = row.saturating_add_signed(rows).min(last_row);
+        let position = position.saturating_add_signed(columns).min(last_position);
+        Some((row, self.display_order[position]))
+    }
+
+    // Tab order walks the cells row by row, wrapping at the ends of each row
+    fn tab_target(&self, backwards: bool) -> Option<(usize, usize)> {
+        let (row, column) = self.current?;
+        let position = self.display_position(column)?;
+        let last_position = self.display_order.len() - 1;
+        let (row, position) = match (backwards, position) {
+            (false, p) if p < last_position => (row, p + 1),
+            (false, _) if row + 1 < self.row_order.len() => (row + 1, 0),
+            (true, p) if p > 0 => (row, p - 1),
+            (true, _) if row > 0 => (row - 1, last_position),
+            _ => return None,
+        };
+        Some((row, self.display_order[position]))
+    }
+
+    fn handle_key(&mut self, key: Key, modifiers: Modifiers) -> bool {
+        let page = self.list.page_size() as isize;
+        let target = match key {
+            Key::Up => self.step(-1, 0),
+            Key::Down | Key::Enter => self.step(1, 0),
+            Key::Left => self.step(0, -1),
+            Key::Right => self.step(0, 1),
+            Key::PageUp => self.step(-page, 0),
+            Key::PageDown => self.step(page, 0),
+            Key::Home if modifiers.ctrl => self.step(isize::MIN, isize::MIN),
+            Key::End if modifiers.ctrl => self.step(isize::MAX, isize::MAX),
+            Key::Home => self.step(0, isize::MIN),
+            Key::End => self.step(0, isize::MAX),
+            Key::Tab => match self.tab_target(modifiers.shift) {
+                Some(target) => Some(target),
+                // Tab leaves the grid from its first or last cell
+                None => return false,
+            },
+            Key::F(2) => return self.begin_edit(),
+            Key::Space => {
+                if let Some((_, column)) = self.current
+                    && self.columns[column].kind == ColumnKind::CheckBox
+                {
+                    self.begin_edit();
+                }
+                return true;
+            }
+            _ => return false,
+        };
+
+        if let Some((row, column)) = target {
+            self.set_current_cell(row, column);
+        }
+        true
+    }
+
+    fn handle_editing(&mut self, input: &InputEvent) -> Option<bool> {
+        let (row, column) = self.edit.as_ref().map(|edit| (edit.row, edit.column))?;
+        let cell = self.cell_bounds(row, column);
+        let edit = self.edit.as_mut()?;
+
+        match (&mut edit.editor, *input) {
+            // An open drop-down owns the input, closing it ends the edit
+            (CellEditor::Combo(combo_box), _) if combo_box.is_open() => {
+                let consumed = combo_box.handle_input(input);
+                if !combo_box.is_open() {
+                    match *input {
+                        InputEvent::KeyPressed {
+                            key: Key::Escape, ..
+                        } => self.cancel_edit(),
+                        InputEvent::KeyPressed {
+                            key: Key::Tab,
+                            modifiers,
+                        } => {
+                            self.commit_edit();
+                            return Some(self.handle_key(Key::Tab, modifiers));
+                        }
+                        _ => {
+                            self.commit_edit();
+                        }
+                    }
+                }
+                Some(consumed)
+            }
+            (_, InputEvent::KeyPressed { key, modifiers }) => match key {
+                Key::Escape => {
+                    self.cancel_edit();
+                    Some(true)
+                }
+                Key::Enter | Key::Tab | Key::Up | Key::Down => {
+                    if self.commit_edit() {
+                        Some(self.handle_key(key, modifiers))
+                    } else {
+                        Some(true)
+                    }
+                }
+                _ => Some(self.forward_to_editor(input)),
+            },
+            (
+                _,
+                InputEvent::MouseButtonPressed {
+                    button: MouseButton::Left,
+                    position,
+                    ..
+                },
+            ) => {
+                if cell.is_some_and(|cell| cell.contains(position)) {
+                    Some(self.forward_to_editor(input))
+                } else if self.commit_edit() {
+                    // Let the click act on the grid
+                    None
+                } else {
+                    Some(true)
+                }
+            }
+            _ => {
+                self.forward_to_editor(input);
+                match input {
+                    InputEvent::MouseScrolled { .. } => None,
+                    _ => Some(true),
+                }
+            }
+        }
+    }
+
+    fn forward_to_editor(&mut self, input: &InputEvent) -> bool {
+        match self.edit.as_mut().map(|edit| &mut edit.editor) {
+            Some(CellEditor::Text(text_box)) => text_box.handle_input(input),
+            Some(CellEditor::Combo(combo_box)) => combo_box.handle_input(input),
+            None => false,
+        }
+    }
+
+    fn handle_mouse_press(&mut self, position: Position) -> bool {
+        if self.header_area().contains(position) {
+            if let Some(column) = self.resize_edge_at(position.x()) {
+                self.drag = Some(HeaderDrag::Resize {
+                    column,
+                    start_x: position.x(),
+                    start_width: self.columns[column].width,
+                });
+            } else if let Some(column) = self.column_at(position.x(), self.header_area()) {
+                self.drag = Some(HeaderDrag::Pressed {
+                    column,
+                    start_x: position.x(),
+                });
+            }
+            return true;
+        }
+
+        if let Some((row, column)) = self.cell_at(position) {
+            let was_current = self.current == Some((row, column));
+            self.set_current_cell(row, column);
+            if self.columns[column].kind == ColumnKind::CheckBox || was_current {
+                self.begin_edit();
+            }
+        }
+        true
+    }
+
+    fn handle_drag(&mut self, x: u16, released: bool) -> bool {
+        let Some(drag) = self.drag else {
+            return false;
+        };
+
+        self.drag = match drag {
+            HeaderDrag::Resize {
+                column,
+                start_x,
+                start_width,
+            } => {
+                let width = (start_width as i32 + x as i32 - start_x as i32).max(0) as u16;
+                self.set_column_width(column, width);
+                Some(drag)
+            }
+            HeaderDrag::Pressed { column, .. } if released => {
+                self.toggle_sort(column);
+                None
+            }
+            HeaderDrag::Pressed { column, start_x } => {
+                if x.abs_diff(start_x) > DRAG_THRESHOLD {
+                    Some(HeaderDrag::Move { column, x })
+                } else {
+                    Some(drag)
+                }
+            }
+            HeaderDrag::Move { column, .. } if released => {
+                self.move_column(column, self.drop_position(x));
+                None
+            }
+            HeaderDrag::Move { column, .. } => Some(HeaderDrag::Move { column, x }),
+        };
+        if released {
+            self.drag = None;
+        }
+        true
+    }
+}
+
+impl Interactive for DataGrid {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() {
+            return false;
+        }
+        if self.stale() {
+            self.refresh();
+        }
+
+        if let Some(consumed) = self.handle_editing(input) {
+            return consumed;
+        }
+
+        match *input {
+            InputEvent::MouseButtonPressed {
+                button: MouseButton::Left,
+                position,
+                ..
+            } if self.base.hit_test(position) => {
+                self.set_focused(true);
+                self.handle_mouse_press(position)
+            }
+            InputEvent::MouseMoved(position) => self.handle_drag(position.x(), false),
+            InputEvent::MouseButtonReleased {
+                button: MouseButton::Left,
+                position,
+                ..
+            } => self.handle_drag(position.x(), true),
+            InputEvent::MouseScrolled {
+                delta_x,
+                delta_y,
+                position,
+            } if self.base.hit_test(position) => {
+                self.list.scroll_by(-(delta_y as isize) * 3);
+                let columns = self.style.list.item_height as i32 * 2;
+                let scroll_x = self.scroll_x as i32 - delta_x as i32 * columns;
+                self.scroll_x = (scroll_x.max(0) as u16).min(self.max_scroll_x());
+                self.reposition_editor();
+                true
+            }
+            InputEvent::KeyPressed { key, modifiers } if self.focused => {
+                self.handle_key(key, modifiers)
+            }
+            // Typing on a text cell starts editing it with the typed character
+            InputEvent::CharTyped(c) if self.focused && !c.is_control() && c != ' ' => {
+                let Some((_, column)) = self.current else {
+                    return false;
+                };
+                if matches!(
+                    self.columns[column].kind,
+                    ColumnKind::Text | ColumnKind::Number
+                ) && self.begin_edit()
+                    && let Some(ActiveEdit {
+                        editor: CellEditor::Text(text_box),
+                        ..
+                    }) = &mut self.edit
+                {
+                    text_box.set_text("");
+                    text_box.handle_input(input);
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled && *self.base.visible()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        if self.focused == focused {
+            return;
+        }
+
+        self.focused = focused;
+        if focused {
+            if self.current.is_none() && !self.row_order.is_empty() && !self.columns.is_empty() {
+                self.current = Some((0, self.display_order[0]));
+            }
+            self.base.emit(EventType::FieldFocused);
+        } else {
+            if !self.commit_edit() {
+                self.cancel_edit();
+            }
+            self.drag = None;
+            self.base.emit(EventType::FieldFocusedLost);
+        }
+    }
+}
+
+impl DataGrid {
+    fn render_header(&self, ctx: &mut RenderContext, scroll_x: u16) {
+        let style = &self.style;
+        let header = self.header_area();
+        ctx.fill_rect(header, &style.header_background);
+
+        for column in self.display_order.iter().copied() {
+            let Some(span) = self.column_span(column, header, scroll_x) else {
+                continue;
+            };
+            let Some(left) = self.column_left(column, scroll_x) else {
+                continue;
+            };
+            let data = &self.columns[column];
+            let x = (header.top_left().x() as i32 + left).max(0) as u16;
+            let text_size = style.list.typography.measure_text(&data.header);
+
+            ctx.push_clip(span);
+            ctx.draw_text(
+                Position::new(
+                    x + style.list.item_padding,
+                    header.top_left().y() + header.height().saturating_sub(text_size.height()) / 2,
+                ),
+                &data.header,
+                &style.list.typography,
+                &style.header_text_color,
+            );
+
+            // Small chevron on the right, pointing up when ascending
+            if let Some((sorted, direction)) = self.sort
+                && sorted == column
+            {
+                let center_x = x.saturating_add(data.width).saturating_sub(10);
+                let center_y = header.top_left().y() + header.height() / 2;
+                let (tip_y, base_y) = match direction {
+                    SortDirection::Ascending => (center_y.saturating_sub(2), center_y + 2),
+                    SortDirection::Descending => (center_y + 2, center_y.saturating_sub(2)),
+                };
+                let tip = Position::new(center_x, tip_y);
+                ctx.draw_line(
+                    Position::new(center_x.saturating_sub(4), base_y),
+                    tip,
+                    &style.sort_glyph_color,
+                    1,
+                );
+                ctx.draw_line(
+                    tip,
+                    Position::new(center_x + 4, base_y),
+                    &style.sort_glyph_color,
+                    1,
+                );
+            }
+            ctx.pop_clip();
+
+            let right = span.bottom_right().x().saturating_sub(1);
+            ctx.draw_line(
+                Position::new(right, header.top_left().y()),
+                Position::new(right, header.bottom_right().y()),
+                style.grid_line.color(),
+                1,
+            );
+        }
+
+        ctx.draw_line(
+            Position::new(
+                header.top_left().x(),
+                header.bottom_right().y().saturating_sub(1),
+            ),
+            Position::new(
+                header.bottom_right().x(),
+                header.bottom_right().y().saturating_sub(1),
+            ),
+            style.grid_line.color(),
+            1,
+        );
+    }
+
+    fn render_cell(
+        &self,
+        ctx: &mut RenderContext,
+        (row, column): (usize, usize),
+        cell: Boundaries,
+        scroll_x: u16,
+    ) {
+        let style = &self.style;
+        let list = &style.list;
+        let current = self.current == Some((row, column));
+        if current {
+            ctx.fill_rect(cell, &list.highlight_background);
+        }
+        let text_color = if !self.enabled {
+            &list.disabled_text_color
+        } else if current {
+            &list.highlight_text_color
+        } else {
+            &list.text_color
+        };
+
+        let left = self.column_left(column, scroll_x).unwrap_or_default();
+        let x = (self.body_area().top_left().x() as i32 + left).max(0) as u16;
+        let width = self.columns[column].width;
+        let value = self.cell_value(row, column);
+        match self.columns[column].kind {
+            ColumnKind::CheckBox => {
+                let size = style.check_box.box_size;
+                let glyph = Boundaries::from_rect(
+                    Position::new(
+                        x + width.saturating_sub(size) / 2,
+                        cell.top_left().y() + cell.height().saturating_sub(size) / 2,
+                    ),
+                    Size::new(size, size),
+                );
+                let state = if self.enabled {
+                    ActionState::Normal
+                } else {
+                    ActionState::Disabled
+                };
+                render_check_glyph(
+                    ctx,
+                    glyph,
+                    CheckState::from(value.as_bool()),
+                    &style.check_box,
+                    &state,
+                );
+            }
+            ref kind => {
+                let text = value.to_string();
+                let text_size = list.typography.measure_text(&text);
+                let text_x = if *kind == ColumnKind::Number {
+                    (x + width).saturating_sub(text_size.width() + list.item_padding)
+                } else {
+                    x + list.item_padding
+                };
+                ctx.draw_text(
+                    Position::new(
+                        text_x,
+                        cell.top_left().y() + cell.height().saturating_sub(text_size.height()) / 2,
+                    ),
+                    &text,
+                    &list.typography,
+                    text_color,
+                );
+            }
+        }
+
+        // Grid lines on the right and bottom edges
+        let right = cell.bottom_right().x().saturating_sub(1);
+        let bottom = cell.bottom_right().y().saturating_sub(1);
+        ctx.draw_line(
+            Position::new(right, cell.top_left().y()),
+            Position::new(right, bottom),
+            style.grid_line.color(),
+            1,
+        );
+        ctx.draw_line(
+            Position::new(cell.top_left().x(), bottom),
+            Position::new(right, bottom),
+            style.grid_line.color(),
+            1,
+        );
+        if current && self.focused {
+            ctx.stroke_rect(cell, &style.current_cell_border);
+        }
+    }
+}
+
+impl Renderable for DataGrid {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+        let list = self.fitted_list();
+        let scroll_x = self.scroll_x.min(self.max_scroll_x());
+
+        let style = &self.style;
+        let frame = self.base.frame();
+        ctx.fill_rect(frame, &style.list.background);
+        self.render_header(ctx, scroll_x);
+
+        // Only the rows inside the viewport are read from the source
+        let body = self.body_area();
+        ctx.push_clip(body);
+        for row in list.visible_range() {
+            for column in self.display_order.iter().copied() {
+                if let Some(cell) = self.cell_frame(&list, scroll_x, row, column) {
+                    self.render_cell(ctx, (row, column), cell, scroll_x);
+                }
+            }
+        }
+        if self.frozen_columns > 0 {
+            let x = body.top_left().x() + self.frozen_width();
+            ctx.draw_line(
+                Position::new(x, body.top_left().y()),
+                Position::new(x, body.bottom_right().y()),
+                style.frozen_divider.color(),
+                1,
+            );
+        }
+        ctx.pop_clip();
+
+        let inner = self.inner();
+        if let Some((offset, length)) = list.thumb(body.height()) {
+            let thumb = Boundaries::from_rect(
+                Position::new(body.bottom_right().x(), body.top_left().y() + offset),
+                Size::new(length, style.list.scrollbar_width),
+            );
+            ctx.fill_rect(thumb, &style.list.scrollbar_thumb);
+        }
+
+        // An editor placed for another frame is shifted onto its cell
+        if let Some(edit) = &self.edit
+            && let Some(cell) = self.cell_frame(&list, scroll_x, edit.row, edit.column)
+        {
+            let editor: &dyn Component = match &edit.editor {
+                CellEditor::Text(text_box) => text_box.as_ref(),
+                CellEditor::Combo(combo_box) => combo_box.as_ref(),
+            };
+            let placed = editor.base().position();
+            ctx.push_clip(inner);
+            ctx.push_translation(
+                cell.top_left().x() as i32 - placed.x() as i32,
+                cell.top_left().y() as i32 - placed.y() as i32,
+            );
+            editor.paint(ctx);
+            ctx.pop_translation();
+            ctx.pop_clip();
+        }
+
+        if self.focused {
+            ctx.stroke_rect(frame, &style.list.focus_border);
+        } else {
+            ctx.stroke_rect(frame, &style.list.border);
+        }
+    }
+}
+
+impl Component for DataGrid {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
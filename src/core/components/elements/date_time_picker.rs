@@ -0,0 +1,989 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::text_box::TextBox,
+        properties::{
+            boundaries::Boundaries,
+            date_time::{Date, DateTime, Weekday},
+            graphics::color::Color,
+            position::Position,
+            size::Size,
+            typography::TypoGraphy,
+        },
+        styles::date_time_picker_style::DateTimePickerStyle,
+    },
+    ui::{
+        date_locale::{DateLocale, DateTimeFormat},
+        inline_editor::InlineEditor,
+        popup::Popup,
+        render::render_context::RenderContext,
+    },
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::{InputEvent, Key, Modifiers, MouseButton},
+        types::{EventPayload, EventType},
+    },
+};
+
+/// Clickable part of the calendar popup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CalendarPart {
+    PreviousYear,
+    PreviousMonth,
+    NextMonth,
+    NextYear,
+    Day(Date),
+    // Fields of the time row, shown when time selection is on
+    Hour,
+    Minute,
+}
+
+/// Text field holding a date, with a button opening a month calendar below
+/// it. Typed text is read in the locale's pattern on Enter or when the focus
+/// leaves; text that is not a date, falls outside `min_date..=max_date` or
+/// names a disabled day raises `FieldValidating` and is reverted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "RawDateTimePicker")]
+pub struct DateTimePicker {
+    base: BaseComponent,
+    value: DateTime,
+    min_date: DateTime,
+    max_date: DateTime,
+    // Days that cannot be picked even inside the range
+    disabled_days: Vec<Date>,
+    disabled_weekdays: Vec<Weekday>,
+    // Adds the time to the field and a time row to the calendar
+    show_time: bool,
+    format: DateTimeFormat,
+    #[serde(default)]
+    locale: DateLocale,
+    enabled: bool,
+    #[serde(default)]
+    style: DateTimePickerStyle,
+    #[serde(skip)]
+    editor: InlineEditor,
+    #[serde(skip)]
+    popup: Popup,
+    // Day the keyboard sits on while the calendar is open; its month is shown
+    #[serde(skip)]
+    highlighted: Option<Date>,
+    #[serde(skip)]
+    hovered: Option<CalendarPart>,
+    // Time field taking the arrow keys instead of the day grid
+    #[serde(skip)]
+    time_field: Option<CalendarPart>,
+    #[serde(skip)]
+    focused: bool,
+}
+
+// Unchecked form of DateTimePicker so deserialized pickers get their range
+// ordered and the value inside it as `set_range` leaves them, and an editor
+#[derive(Deserialize)]
+struct RawDateTimePicker {
+    base: BaseComponent,
+    value: DateTime,
+    min_date: DateTime,
+    max_date: DateTime,
+    disabled_days: Vec<Date>,
+    disabled_weekdays: Vec<Weekday>,
+    show_time: bool,
+    format: DateTimeFormat,
+    #[serde(default)]
+    locale: DateLocale,
+    enabled: bool,
+    #[serde(default)]
+    style: DateTimePickerStyle,
+}
+
+impl From<RawDateTimePicker> for DateTimePicker {
+    fn from(raw: RawDateTimePicker) -> Self {
+        let min_date = raw.min_date.min(raw.max_date);
+        let max_date = raw.max_date.max(raw.min_date);
+        let mut picker = Self {
+            base: raw.base,
+            value: raw.value.max(min_date).min(max_date),
+            min_date,
+            max_date,
+            disabled_days: raw.disabled_days,
+            disabled_weekdays: raw.disabled_weekdays,
+            show_time: raw.show_time,
+            format: raw.format,
+            locale: raw.locale,
+            enabled: raw.enabled,
+            style: raw.style,
+            editor: InlineEditor::default(),
+            popup: Popup::default(),
+            highlighted: None,
+            hovered: None,
+            time_field: None,
+            focused: false,
+        };
+        picker.sync_editor();
+        picker.editor.set_enabled(picker.enabled);
+        picker
+    }
+}
+
+impl DateTimePicker {
+    pub fn new(base: BaseComponent) -> Self {
+        let mut picker = Self {
+            base,
+            value: DateTime::now(),
+            min_date: DateTime::MIN,
+            max_date: DateTime::MAX,
+            disabled_days: Vec::new(),
+            disabled_weekdays: Vec::new(),
+            show_time: false,
+            format: DateTimeFormat::default(),
+            locale: DateLocale::default(),
+            enabled: true,
+            style: DateTimePickerStyle::default(),
+            editor: InlineEditor::default(),
+            popup: Popup::default(),
+            highlighted: None,
+            hovered: None,
+            time_field: None,
+            focused: false,
+        };
+        picker.sync_editor();
+        picker
+    }
+
+    pub fn with_value(mut self, value: DateTime) -> Self {
+        self.value = self.clamp(value);
+        self.sync_editor();
+        self
+    }
+
+    pub fn with_range(mut self, min_date: DateTime, max_date: DateTime) -> Self {
+        self.min_date = min_date.min(max_date);
+        self.max_date = max_date.max(min_date);
+        self.value = self.clamp(self.value);
+        self.sync_editor();
+        self
+    }
+
+    pub fn with_disabled_days(mut self, days: Vec<Date>) -> Self {
+        self.disabled_days = days;
+        self
+    }
+
+    pub fn with_disabled_weekdays(mut self, weekdays: Vec<Weekday>) -> Self {
+        self.disabled_weekdays = weekdays;
+        self
+    }
+
+    pub fn with_show_time(mut self, show_time: bool) -> Self {
+        self.show_time = show_time;
+        self.sync_editor();
+        self
+    }
+
+    pub fn with_format(mut self, format: DateTimeFormat) -> Self {
+        self.format = format;
+        self.sync_editor();
+        self
+    }
+
+    pub fn with_locale(mut self, locale: DateLocale) -> Self {
+        self.locale = locale;
+        self.sync_editor();
+        self
+    }
+
+    pub fn with_style(mut self, style: DateTimePickerStyle) -> Self {
+        self.set_style(style);
+        self
+    }
+
+    // Setters
+    /// Changes the value, returning whether it was accepted. Values outside
+    /// the range or on disabled days raise `FieldValidating` instead.
+    pub fn set_value(&mut self, value: DateTime) -> bool {
+        if self.try_set(value) {
+            return true;
+        }
+        let text = self.locale.format(&value, &self.pattern());
+        self.reject(text);
+        false
+    }
+
+    /// Limits the value to `min_date..=max_date`, moving it inside if needed
+    pub fn set_range(&mut self, min_date: DateTime, max_date: DateTime) {
+        self.min_date = min_date.min(max_date);
+        self.max_date = max_date.max(min_date);
+        let value = self.clamp(self.value);
+        if value != self.value {
+            self.value = value;
+            self.emit_value();
+        }
+        self.sync_editor();
+    }
+
+    pub fn set_disabled_days(&mut self, days: Vec<Date>) {
+        self.disabled_days = days;
+    }
+
+    pub fn set_disabled_weekdays(&mut self, weekdays: Vec<Weekday>) {
+        self.disabled_weekdays = weekdays;
+    }
+
+    pub fn set_show_time(&mut self, show_time: bool) {
+        self.show_time = show_time;
+        self.close();
+        self.sync_editor();
+    }
+
+    pub fn set_format(&mut self, format: DateTimeFormat) {
+        self.format = format;
+        self.sync_editor();
+    }
+
+    pub fn set_locale(&mut self, locale: DateLocale) {
+        self.locale = locale;
+        self.sync_editor();
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.close();
+        }
+        self.editor.set_enabled(enabled);
+    }
+
+    pub fn set_style(&mut self, style: DateTimePickerStyle) {
+        self.style = style;
+        self.editor.clear();
+        self.sync_editor();
+    }
+
+    /// Area the calendar must stay inside, usually the window client area
+    pub fn set_viewport(&mut self, viewport: Boundaries) {
+        self.popup.set_viewport(viewport);
+    }
+
+    /// Opens the calendar on the month of the value
+    pub fn open(&mut self) {
+        if !self.enabled || self.popup.is_open() {
+            return;
+        }
+
+        self.commit_text();
+        self.highlighted = Some(self.value.date);
+        self.time_field = None;
+        let padding = self.style.calendar_padding;
+        let rows = if self.show_time { 8 } else { 7 };
+        let size = Size::new(
+            self.style.header_height + self.style.cell_height * rows + padding * 2,
+            self.style.cell_width * 7 + padding * 2,
+        );
+        self.popup.open(self.base.frame(), size);
+    }
+
+    pub fn close(&mut self) {
+        self.popup.close();
+        self.highlighted = None;
+        self.hovered = None;
+        self.time_field = None;
+    }
+
+    /// Applies the typed text, reverting it when it is not an allowed date
+    pub fn commit_text(&mut self) {
+        let Some(text) = self
+            .editor
+            .text_box()
+            .map(|editor| editor.text().to_string())
+        else {
+            return;
+        };
+        let accepted = self
+            .locale
+            .parse(&text, &self.pattern(), &self.value)
+            .is_some_and(|value| value == self.value || self.try_set(value));
+        if !accepted {
+            self.reject(text);
+        }
+        self.sync_editor();
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn value(&self) -> DateTime {
+        self.value
+    }
+
+    pub fn min_date(&self) -> DateTime {
+        self.min_date
+    }
+
+    pub fn max_date(&self) -> DateTime {
+        self.max_date
+    }
+
+    pub fn disabled_days(&self) -> &[Date] {
+        &self.disabled_days
+    }
+
+    pub fn disabled_weekdays(&self) -> &[Weekday] {
+        &self.disabled_weekdays
+    }
+
+    pub fn show_time(&self) -> bool {
+        self.show_time
+    }
+
+    pub fn format(&self) -> &DateTimeFormat {
+        &self.format
+    }
+
+    pub fn locale(&self) -> &DateLocale {
+        &self.locale
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn style(&self) -> &DateTimePickerStyle {
+        &self.style
+    }
+
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Text in the field, which may differ from the value while typing
+    pub fn text(&self) -> &str {
+        self.editor.text()
+    }
+
+    pub fn editor(&self) -> Option<&TextBox> {
+        self.editor.text_box()
+    }
+
+    /// Pattern the field is shown and read in
+    pub fn pattern(&self) -> String {
+        self.locale.pattern(&self.format, self.show_time)
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.popup.is_open()
+    }
+
+    pub fn popup_bounds(&self) -> Option<Boundaries> {
+        self.popup.bounds()
+    }
+
+    /// First day of the month shown by the open calendar
+    pub fn displayed_month(&self) -> Option<Date> {
+        self.highlighted.map(|date| date.first_of_month())
+    }
+
+    pub fn highlighted_day(&self) -> Option<Date> {
+        self.highlighted
+    }
+
+    /// Whether a day can be picked: inside the range and not disabled
+    pub fn is_selectable(&self, date: Date) -> bool {
+        date >= self.min_date.date
+            && date <= self.max_date.date
+            && !self.disabled_days.contains(&date)
+            && !self.disabled_weekdays.contains(&date.weekday())
+    }
+
+    pub fn button_bounds(&self) -> Boundaries {
+        let frame = self.base.frame();
+        let width = self.style.button_width.min(frame.width());
+        Boundaries::from_rect(
+            Position::new(frame.bottom_right().x() - width, frame.top_left().y()),
+            Size::new(frame.height(), width),
+        )
+    }
+
+    /// Area of a part of the open calendar, `None` for days of other months
+    pub fn part_bounds(&self, part: CalendarPart) -> Option<Boundaries> {
+        let inner = self.calendar_inner()?;
+        let style = &self.style;
+        let (cell, header) = (style.cell_width, style.header_height);
+        let (left, top) = (inner.top_left().x(), inner.top_left().y());
+        let right = inner.bottom_right().x();
+        let rect = |x: u16, y: u16, width: u16, height: u16| {
+            Some(Boundaries::from_rect(
+                Position::new(x, y),
+                Size::new(height, width),
+            ))
+        };
+
+        match part {
+            CalendarPart::PreviousYear => rect(left, top, cell, header),
+            CalendarPart::PreviousMonth => rect(left + cell, top, cell, header),
+            CalendarPart::NextMonth => rect(right - cell * 2, top, cell, header),
+            CalendarPart::NextYear => rect(right - cell, top, cell, header),
+            CalendarPart::Day(date) => {
+                let month = self.displayed_month()?;
+                if date.first_of_month() != month {
+                    return None;
+                }
+                let index = self.first_column(month) + date.day() as usize - 1;
+                rect(
+                    left + (index % 7) as u16 * cell,
+                    top + header + style.cell_height * (1 + (index / 7) as u16),
+                    cell,
+                    style.cell_height,
+                )
+            }
+            CalendarPart::Hour | CalendarPart::Minute if self.show_time => {
+                let y = top + header + style.cell_height * 7;
+                let center = left + cell * 7 / 2;
+                let width = cell + 8;
+                let x = match part {
+                    CalendarPart::Hour => center.saturating_sub(width + 4),
+                    _ => center + 4,
+                };
+                rect(x, y, width, style.cell_height)
+            }
+            CalendarPart::Hour | CalendarPart::Minute => None,
+        }
+    }
+
+    // Calendar frame without its padding
+    fn calendar_inner(&self) -> Option<Boundaries> {
+        let bounds = self.popup.bounds()?;
+        let padding = self.style.calendar_padding;
+        Some(Boundaries::from_rect(
+            Position::new(
+                bounds.top_left().x() + padding,
+                bounds.top_left().y() + padding,
+            ),
+            Size::new(
+                bounds.height().saturating_sub(padding * 2),
+                bounds.width().saturating_sub(padding * 2),
+            ),
+        ))
+    }
+
+    // Column of the first day of `month`, counting from the locale's first
+    // day of the week
+    fn first_column(&self, month: Date) -> usize {
+        (month.weekday().index() + 7 - self.locale.first_day_of_week.index()) % 7
+    }
+
+    fn part_at(&self, point: Position) -> Option<CalendarPart> {
+        let month = self.displayed_month()?;
+        [
+            CalendarPart::PreviousYear,
+            CalendarPart::PreviousMonth,
+            CalendarPart::NextMonth,
+            CalendarPart::NextYear,
+            CalendarPart::Hour,
+            CalendarPart::Minute,
+        ]
+        .into_iter()
+        .chain(
+            (1..=month.last_of_month().day()).filter_map(|day| {
+                Date::new(month.year(), month.month(), day).map(CalendarPart::Day)
+            }),
+        )
+        .find(|part| {
+            self.part_bounds(*part)
+                .is_some_and(|bounds| bounds.contains(point))
+        })
+    }
+
+    fn text_bounds(&self) -> Boundaries {
+        let frame = self.base.frame();
+        Boundaries::from_rect(
+            frame.top_left(),
+            frame
+                .size()
+                .with_width(frame.width().saturating_sub(self.style.button_width)),
+        )
+    }
+
+    fn clamp(&self, value: DateTime) -> DateTime {
+        value.max(self.min_date).min(self.max_date)
+    }
+
+    // Takes the value if it is allowed, reporting the change
+    fn try_set(&mut self, value: DateTime) -> bool {
+        if value != self.clamp(value) || !self.is_selectable(value.date) {
+            return false;
+        }
+        if value != self.value {
+            self.value = value;
+            self.sync_editor();
+            self.emit_value();
+        }
+        true
+    }
+
+    fn reject(&self, text: String) {
+        self.base
+            .emit_with(EventType::FieldValidating, EventPayload::Text(text));
+    }
+
+    fn emit_value(&self) {
+        self.base.emit_with(
+            EventType::FieldValueChanged,
+            EventPayload::DateTime(self.value),
+        );
+    }
+
+    // Keeps the editor in place and showing the value
+    fn sync_editor(&mut self) {
+        let text = self.locale.format(&self.value, &self.pattern());
+        self.place_editor();
+        self.editor.show(&text);
+    }
+
+    // Moves the editor over the text area for the current frame
+    fn place_editor(&mut self) {
+        let bounds = self.text_bounds();
+        self.editor
+            .place(&self.base, &self.style.typography, bounds, "");
+    }
+
+    // Moves the keyboard to another day, never past the range
+    fn move_highlight(&mut self, date: Date) {
+        self.highlighted = Some(date.max(self.min_date.date).min(self.max_date.date));
+    }
+
+    // Picks a day keeping the time of the value
+    fn select_day(&mut self, date: Date) -> bool {
+        self.is_selectable(date) && self.try_set(self.clamp(DateTime::new(date, self.value.time)))
+    }
+
+    fn step_time(&mut self, field: CalendarPart, delta: i32) {
+        let time = self.value.time;
+        let time = match field {
+            CalendarPart::Hour => time.with_hour((time.hour() as i32 + delta).rem_euclid(24) as u8),
+            _ => time.with_minute((time.minute() as i32 + delta).rem_euclid(60) as u8),
+        };
+        self.try_set(self.clamp(DateTime::new(self.value.date, time)));
+    }
+
+    fn activate(&mut self, part: CalendarPart) {
+        let Some(highlighted) = self.highlighted else {
+            return;
+        };
+        match part {
+            CalendarPart::PreviousYear => self.move_highlight(highlighted.add_years(-1)),
+            CalendarPart::PreviousMonth => self.move_highlight(highlighted.add_months(-1)),
+            CalendarPart::NextMonth => self.move_highlight(highlighted.add_months(1)),
+            CalendarPart::NextYear => self.move_highlight(highlighted.add_years(1)),
+            CalendarPart::Day(date) => {
+                if self.select_day(date) {
+                    self.highlighted = Some(date);
+                    self.time_field = None;
+                    // With time selection the calendar stays for the time row
+                    if !self.show_time {
+                        self.close();
+                    }
+                }
+            }
+            CalendarPart::Hour | CalendarPart::Minute => self.time_field = Some(part),
+        }
+    }
+
+    fn handle_open_key(&mut self, key: Key, modifiers: Modifiers) -> bool {
+        let Some(highlighted) = self.highlighted else {
+            return false;
+        };
+        if let Some(field) = self.time_field {
+            match key {
+                Key::Up | Key::Down => {
+                    self.step_time(field, if key == Key::Up { 1 } else { -1 });
+                    return true;
+                }
+                Key::Left | Key::Right => {
+                    self.time_field = Some(match field {
+                        CalendarPart::Hour => CalendarPart::Minute,
+                        _ => CalendarPart::Hour,
+                    });
+                    return true;
+                }
+                _ => {}
+            }
+        }
+
+        match key {
+            Key::Enter => {
+                if self.time_field.is_none() {
+                    self.select_day(highlighted);
+                }
+                self.close();
+            }
+            Key::F(4) => self.close(),
+            Key::Up if modifiers.alt => self.close(),
+            // Tab walks from the days to the hour and the minute
+            Key::Tab if self.show_time => {
+                self.time_field = match self.time_field {
+                    None => Some(CalendarPart::Hour),
+                    Some(CalendarPart::Hour) => Some(CalendarPart::Minute),
+                    _ => None,
+                };
+            }
+            Key::Left => self.move_highlight(highlighted.add_days(-1)),
+            Key::Right => self.move_highlight(highlighted.add_days(1)),
+            Key::Up => self.move_highlight(highlighted.add_days(-7)),
+            Key::Down => self.move_highlight(highlighted.add_days(7)),
+            Key::PageUp if modifiers.ctrl => self.move_highlight(highlighted.add_years(-1)),
+            Key::PageDown if modifiers.ctrl => self.move_highlight(highlighted.add_years(1)),
+            Key::PageUp => self.move_highlight(highlighted.add_months(-1)),
+            Key::PageDown => self.move_highlight(highlighted.add_months(1)),
+            Key::Home => self.move_highlight(highlighted.first_of_month()),
+            Key::End => self.move_highlight(highlighted.last_of_month()),
+            _ => {}
+        }
+        true
+    }
+
+    fn handle_open(&mut self, input: &InputEvent) -> bool {
+        if self.popup.is_dismissal(input, self.base.frame()) {
+            self.close();
+            return true;
+        }
+
+        match *input {
+            InputEvent::MouseMoved(position) => {
+                self.hovered = self.part_at(position);
+                self.popup.contains(position)
+            }
+            // The wheel turns the months, or the time field under it
+            InputEvent::MouseScrolled {
+                delta_y, position, ..
+            } if self.popup.contains(position) => {
+                match self.part_at(position) {
+                    Some(field @ (CalendarPart::Hour | CalendarPart::Minute)) => {
+                        self.step_time(field, delta_y.signum() as i32)
+                    }
+                    _ => {
+                        if let Some(highlighted) = self.highlighted {
+                            self.move_highlight(highlighted.add_months(-delta_y.signum() as i32));
+                        }
+                    }
+                }
+                true
+            }
+            InputEvent::MouseButtonPressed {
+                button: MouseButton::Left,
+                position,
+                ..
+            } => {
+                // Pressing the field again closes the calendar
+                if !self.popup.contains(position) {
+                    self.close();
+                } else if let Some(part) = self.part_at(position) {
+                    self.activate(part);
+                }
+                true
+            }
+            InputEvent::MouseButtonReleased { position, .. } => self.popup.contains(position),
+            InputEvent::KeyPressed { key, modifiers } => self.handle_open_key(key, modifiers),
+            // The calendar is modal while open
+            _ if input.is_keyboard() => true,
+            _ => false,
+        }
+    }
+
+    fn render_calendar(&self, ctx: &mut RenderContext) {
+        let (Some(bounds), Some(month)) = (self.popup.bounds(), self.displayed_month()) else {
+            return;
+        };
+        let style = &self.style;
+
+        ctx.push_layer();
+        ctx.fill_rect(bounds, &style.calendar_background);
+        ctx.stroke_rect(bounds, &style.calendar_border);
+
+        // Header: year and month arrows around the month name
+        for (part, left, double) in [
+            (CalendarPart::PreviousYear, true, true),
+            (CalendarPart::PreviousMonth, true, false),
+            (CalendarPart::NextMonth, false, false),
+            (CalendarPart::NextYear, false, true),
+        ] {
+            let Some(button) = self.part_bounds(part) else {
+                continue;
+            };
+            if self.hovered == Some(part) {
+                ctx.fill_rect(button, &style.hover_background);
+            }
+            let cx = button.top_left().x() + button.width() / 2;
+            let cy = button.top_left().y() + button.height() / 2;
+            if double {
+                render_chevron(ctx, cx.saturating_sub(2), cy, left, &style.arrow_color);
+                render_chevron(ctx, cx + 2, cy, left, &style.arrow_color);
+            } else {
+                render_chevron(ctx, cx, cy, left, &style.arrow_color);
+            }
+        }
+        if let (Some(first), Some(last)) = (
+            self.part_bounds(CalendarPart::PreviousMonth),
+            self.part_bounds(CalendarPart::NextMonth),
+        ) {
+            let title = Boundaries::from_rect(
+                Position::new(first.bottom_right().x(), first.top_left().y()),
+                Size::new(
+                    first.height(),
+                    last.top_left().x().saturating_sub(first.bottom_right().x()),
+                ),
+            );
+            let text = self.locale.format(&DateTime::from_date(month), "MMMM yyyy");
+            render_centered(
+                ctx,
+                title,
+                &text,
+                &style.title_typography,
+                &style.title_color,
+            );
+        }
+
+        // Weekday names, starting on the locale's first day of the week
+        let Some(inner) = self.calendar_inner() else {
+            ctx.pop_layer();
+            return;
+        };
+        for column in 0..7u16 {
+            let weekday =
+                Weekday::from_index(self.locale.first_day_of_week.index() + column as usize);
+            let cell = Boundaries::from_rect(
+                Position::new(
+                    inner.top_left().x() + column * style.cell_width,
+                    inner.top_left().y() + style.header_height,
+                ),
+                Size::new(style.cell_height, style.cell_width),
+            );
+            render_centered(
+                ctx,
+                cell,
+                &self.locale.day_abbreviations[weekday.index()],
+                &style.typography,
+                &style.day_name_color,
+            );
+        }
+
+        for day in 1..=month.last_of_month().day() {
+            let Some(date) = Date::new(month.year(), month.month(), day) else {
+                continue;
+            };
+            let Some(cell) = self.part_bounds(CalendarPart::Day(date)) else {
+                continue;
+            };
+            let selectable = self.is_selectable(date);
+            let color = if date == self.value.date {
+                ctx.fill_rect(cell, &style.selected_background);
+                &style.selected_color
+            } else {
+                if selectable && self.hovered == Some(CalendarPart::Day(date)) {
+                    ctx.fill_rect(cell, &style.hover_background);
+                }
+                if selectable {
+                    &style.day_color
+                } else {
+                    &style.disabled_day_color
+                }
+            };
+            render_centered(ctx, cell, &day.to_string(), &style.typography, color);
+            if self.highlighted == Some(date) && self.time_field.is_none() {
+                ctx.stroke_rect(cell, &style.highlight_border);
+            }
+        }
+
+        // Time row: hour and minute separated by a colon
+        if let (Some(hour), Some(minute)) = (
+            self.part_bounds(CalendarPart::Hour),
+            self.part_bounds(CalendarPart::Minute),
+        ) {
+            for (part, field, value) in [
+                (CalendarPart::Hour, hour, self.value.time.hour()),
+                (CalendarPart::Minute, minute, self.value.time.minute()),
+            ] {
+                ctx.fill_rect(field, &style.time_background);
+                let border = if self.time_field == Some(part) {
+                    &style.focus_border
+                } else {
+                    &style.time_border
+                };
+                ctx.stroke_rect(field, border);
+                render_centered(
+                    ctx,
+                    field,
+                    &format!("{value:02}"),
+                    &style.typography,
+                    &style.day_color,
+                );
+            }
+            let colon = Boundaries::from_rect(
+                Position::new(hour.bottom_right().x(), hour.top_left().y()),
+                Size::new(
+                    hour.height(),
+                    minute
+                        .top_left()
+                        .x()
+                        .saturating_sub(hour.bottom_right().x()),
+                ),
+            );
+            render_centered(ctx, colon, ":", &style.typography, &style.day_color);
+        }
+        ctx.pop_layer();
+    }
+}
+
+// Small chevron pointing left or right, centered on `x` and `y`
+fn render_chevron(ctx: &mut RenderContext, x: u16, y: u16, left: bool, color: &Color) {
+    let (tip, base) = if left {
+        (x.saturating_sub(2), x + 1)
+    } else {
+        (x + 1, x.saturating_sub(2))
+    };
+    ctx.draw_line(
+        Position::new(base, y.saturating_sub(3)),
+        Position::new(tip, y),
+        color,
+        1,
+    );
+    ctx.draw_line(Position::new(tip, y), Position::new(base, y + 3), color, 1);
+}
+
+fn render_centered(
+    ctx: &mut RenderContext,
+    bounds: Boundaries,
+    text: &str,
+    typography: &TypoGraphy,
+    color: &Color,
+) {
+    let size = typography.measure_text(text);
+    ctx.draw_text(
+        Position::new(
+            bounds.top_left().x() + bounds.width().saturating_sub(size.width()) / 2,
+            bounds.top_left().y() + bounds.height().saturating_sub(size.height()) / 2,
+        ),
+        text,
+        typography,
+        color,
+    );
+}
+
+impl Interactive for DateTimePicker {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() {
+            return false;
+        }
+        // The frame may have moved since the editor was placed
+        self.place_editor();
+
+        if self.popup.is_open() {
+            return self.handle_open(input);
+        }
+
+        match *input {
+            InputEvent::MouseButtonPressed {
+                button: MouseButton::Left,
+                position,
+                ..
+            } if self.base.hit_test(position) => {
+                if self.button_bounds().contains(position) {
+                    self.open();
+                } else {
+                    self.editor.handle_input(input);
+                }
+                true
+            }
+            InputEvent::KeyPressed { key, modifiers } if self.focused => match key {
+                Key::Down if modifiers.alt => {
+                    self.open();
+                    true
+                }
+                Key::F(4) if modifiers.is_empty() => {
+                    self.open();
+                    true
+                }
+                Key::Enter => {
+                    self.commit_text();
+                    true
+                }
+                _ => self.editor.handle_input(input),
+            },
+            _ if self.focused => self.editor.handle_input(input),
+            InputEvent::MouseMoved(_) | InputEvent::MouseButtonReleased { .. } => {
+                self.editor.handle_input(input)
+            }
+            _ => false,
+        }
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled && *self.base.visible()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        if self.focused == focused {
+            return;
+        }
+
+        self.focused = focused;
+        self.editor.set_focused(focused);
+        // After the editor so its report of the raw text comes first
+        if !focused {
+            self.close();
+            self.commit_text();
+        }
+    }
+}
+
+impl Renderable for DateTimePicker {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        let style = &self.style;
+        let frame = self.base.frame();
+        if self.enabled {
+            ctx.fill_rect(frame, &style.background);
+        } else {
+            ctx.fill_rect(frame, &style.disabled_background);
+        }
+        self.editor.paint(ctx, self.text_bounds());
+
+        // Drop-down button with a small chevron
+        let button = self.button_bounds();
+        ctx.fill_rect(button, &style.button_background);
+        let cx = button.top_left().x() + button.width() / 2;
+        let cy = button.top_left().y() + button.height() / 2;
+        let left = Position::new(cx.saturating_sub(4), cy.saturating_sub(2));
+        let tip = Position::new(cx, cy + 2);
+        let right = Position::new(cx + 4, cy.saturating_sub(2));
+        ctx.draw_line(left, tip, &style.arrow_color, 1);
+        ctx.draw_line(tip, right, &style.arrow_color, 1);
+
+        if self.focused {
+            ctx.stroke_rect(frame, &style.focus_border);
+        } else {
+            ctx.stroke_rect(frame, &style.border);
+        }
+
+        self.render_calendar(ctx);
+    }
+}
+
+impl Component for DateTimePicker {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
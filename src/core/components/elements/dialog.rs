@@ -0,0 +1,527 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::{button::Button, icon::Icon, text_box::TextBox},
+        properties::{
+            boundaries::Boundaries, dialog_result::DialogResult, margin::Margin, padding::Padding,
+            position::Position, size::Size,
+        },
+        styles::dialog_style::DialogStyle,
+    },
+    ui::render::render_context::RenderContext,
+    utils::traits::{
+        component::Component, event_listener::EventListener, interactive::Interactive,
+        renderable::Renderable,
+    },
+    window::events::{
+        input::{InputEvent, Key, MouseButton},
+        types::{EventPayload, EventType},
+    },
+};
+
+// Collects the clicks of the dialog buttons, which report them as events
+#[derive(Debug, Default)]
+struct ClickRelay {
+    clicked: Vec<usize>,
+}
+
+impl EventListener for ClickRelay {
+    fn on_event(&mut self, event: &EventType, caller_id: usize) {
+        if *event == EventType::MouseButtonClicked {
+            self.clicked.push(caller_id);
+        }
+    }
+}
+
+/// Modal dialog with a title bar, an optional icon, a message, an optional
+/// text box and a row of buttons, each closing it with a `DialogResult`.
+///
+/// While open the dialog takes every input, so the owner routes input to it
+/// first. Enter clicks the focused button, or the accept button from the text
+/// box, Escape clicks the cancel button and Tab moves between the controls.
+/// `DialogHost::show_dialog` runs one until it closes.
+#[derive(Debug)]
+pub struct Dialog {
+    base: BaseComponent,
+    title: String,
+    text: String,
+    icon: Option<Icon>,
+    editor: Option<TextBox>,
+    buttons: Vec<(Button, DialogResult)>,
+    accept_button: Option<usize>,
+    cancel_button: Option<usize>,
+    style: DialogStyle,
+    viewport: Boundaries,
+    open: bool,
+    result: DialogResult,
+    // Index in the tab order: the text box, if any, then the buttons
+    focus: usize,
+    clicks: Rc<RefCell<ClickRelay>>,
+}
+
+impl Dialog {
+    pub fn new(base: BaseComponent, title: &str) -> Self {
+        let clicks = Rc::new(RefCell::new(ClickRelay::default()));
+        base.event_system()
+            .borrow_mut()
+            .subscribe(EventType::MouseButtonClicked, Rc::downgrade(&clicks));
+
+        Self {
+            base,
+            title: title.to_string(),
+            text: String::new(),
+            icon: None,
+            editor: None,
+            buttons: Vec::new(),
+            accept_button: None,
+            cancel_button: None,
+            style: DialogStyle::default(),
+            viewport: Boundaries::from_rect(Position::new(0, 0), Size::new(0, 0)),
+            open: false,
+            result: DialogResult::None,
+            focus: 0,
+            clicks,
+        }
+    }
+
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.text = text.to_string();
+        self
+    }
+
+    pub fn with_icon(mut self, icon: Option<Icon>) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    /// Adds a text box under the message, e.g. for input prompts
+    pub fn with_editor(mut self, text: &str) -> Self {
+        let base = self.child_base();
+        self.editor = Some(
+            TextBox::new(base)
+                .with_text(text)
+                .with_style(self.style.text_box.clone()),
+        );
+        self
+    }
+
+    pub fn with_button(mut self, result: DialogResult) -> Self {
+        self.add_button(result);
+        self
+    }
+
+    /// Button clicked by Enter when the focus is not on a button
+    pub fn with_accept_button(mut self, result: DialogResult) -> Self {
+        self.accept_button = self.button_index(result);
+        self
+    }
+
+    /// Button clicked by Escape; without one Escape does nothing
+    pub fn with_cancel_button(mut self, result: DialogResult) -> Self {
+        self.cancel_button = self.button_index(result);
+        self
+    }
+
+    pub fn with_style(mut self, style: DialogStyle) -> Self {
+        self.set_style(style);
+        self
+    }
+
+    // Setters
+    pub fn set_title(&mut self, title: &str) {
+        self.title = title.to_string();
+    }
+
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+        self.layout();
+    }
+
+    pub fn add_button(&mut self, result: DialogResult) {
+        let base = self.child_base();
+        let button = Button::new(base, result.label()).with_style(self.style.button.clone());
+        self.buttons.push((button, result));
+        self.layout();
+    }
+
+    pub fn set_style(&mut self, style: DialogStyle) {
+        for (button, _) in &mut self.buttons {
+            button.set_style(style.button.clone());
+        }
+        if let Some(editor) = &mut self.editor {
+            editor.set_style(style.text_box.clone());
+        }
+        self.style = style;
+        self.layout();
+    }
+
+    /// Shows the dialog centered in `viewport`, usually the window client area
+    pub fn open(&mut self, viewport: Boundaries) {
+        self.viewport = viewport;
+        self.open = true;
+        self.result = DialogResult::None;
+        self.clicks.borrow_mut().clicked.clear();
+        self.layout();
+
+        // The text box starts focused, else the accept button
+        let focus = match (&self.editor, self.accept_button) {
+            (Some(_), _) | (None, None) => 0,
+            (None, Some(accept)) => accept,
+        };
+        self.set_focus(focus);
+        self.base.emit(EventType::WindowOpened);
+    }
+
+    /// Closes the dialog as if a button giving `result` was clicked
+    pub fn close(&mut self, result: DialogResult) {
+        if !self.open {
+            return;
+        }
+
+        self.open = false;
+        self.result = result;
+        self.base
+            .emit_with(EventType::WindowClosed, EventPayload::DialogResult(result));
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn icon(&self) -> Option<&Icon> {
+        self.icon.as_ref()
+    }
+
+    /// Text typed in the text box, if the dialog has one
+    pub fn input(&self) -> Option<&str> {
+        self.editor.as_ref().map(TextBox::text)
+    }
+
+    pub fn editor(&self) -> Option<&TextBox> {
+        self.editor.as_ref()
+    }
+
+    pub fn buttons(&self) -> impl Iterator<Item = (&Button, DialogResult)> {
+        self.buttons
+            .iter()
+            .map(|(button, result)| (button, *result))
+    }
+
+    pub fn style(&self) -> &DialogStyle {
+        &self.style
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// How the dialog was closed, `DialogResult::None` while open
+    pub fn result(&self) -> DialogResult {
+        self.result
+    }
+
+    /// Button that has the focus, if the focus is not on the text box
+    pub fn focused_button(&self) -> Option<DialogResult> {
+        let index = self.focus.checked_sub(self.editor_count())?;
+        self.buttons.get(index).map(|(_, result)| *result)
+    }
+
+    pub fn button_bounds(&self, result: DialogResult) -> Option<Boundaries> {
+        self.button_index(result)
+            .map(|index| self.buttons[index].0.base().frame())
+    }
+
+    pub fn title_bounds(&self) -> Boundaries {
+        let frame = self.base.frame();
+        Boundaries::from_rect(
+            frame.top_left(),
+            Size::new(self.style.title_height, frame.width()),
+        )
+    }
+
+    fn child_base(&self) -> BaseComponent {
+        BaseComponent::new(
+            Size::new(0, 0),
+            Position::new(0, 0),
+            Margin::default(),
+            Padding::default(),
+            self.base.event_system().clone(),
+        )
+    }
+
+    fn button_index(&self, result: DialogResult) -> Option<usize> {
+        self.buttons.iter().position(|(_, r)| *r == result)
+    }
+
+    fn editor_count(&self) -> usize {
+        self.editor.is_some() as usize
+    }
+
+    fn focus_count(&self) -> usize {
+        self.editor_count() + self.buttons.len()
+    }
+
+    fn set_focus(&mut self, focus: usize) {
+        self.focus = focus;
+        let editor_count = self.editor_count();
+        if let Some(editor) = &mut self.editor {
+            editor.set_focused(focus == 0);
+        }
+        for (index, (button, _)) in self.buttons.iter_mut().enumerate() {
+            button.set_focused(index + editor_count == focus);
+        }
+    }
+
+    fn move_focus(&mut self, forward: bool) {
+        let count = self.focus_count();
+        if count == 0 {
+            return;
+        }
+        let focus = if forward {
+            (self.focus + 1) % count
+        } else {
+            (self.focus + count - 1) % count
+        };
+        self.set_focus(focus);
+    }
+
+    // Size of the message as (width, height), one line per `\n`
+    fn text_size(&self) -> (u16, u16) {
+        self.text.lines().fold((0, 0), |(width, height), line| {
+            let size = self.style.typography.measure_text(line);
+            (width.max(size.width()), height + size.height())
+        })
+    }
+
+    // Position of the message, right of the icon
+    fn text_position(&self) -> Position {
+        let frame = self.base.frame();
+        let style = &self.style;
+        let icon_width = self
+            .icon
+            .as_ref()
+            .map_or(0, |icon| icon.size.0 as u16 + style.spacing);
+        Position::new(
+            frame.top_left().x() + style.padding + icon_width,
+            frame.top_left().y() + style.title_height + style.padding,
+        )
+    }
+
+    // Sizes the dialog around its content and centers it in the viewport
+    fn layout(&mut self) {
+        let style = &self.style;
+        let (icon_width, icon_height) = self.icon.as_ref().map_or((0, 0), |icon| {
+            (icon.size.0 as u16 + style.spacing, icon.size.1 as u16)
+        });
+        let (text_width, text_height) = self.text_size();
+        let buttons = self.buttons.len() as u16;
+        let buttons_width =
+            (buttons * style.button_size.width()) + buttons.saturating_sub(1) * style.spacing;
+
+        let mut content_height = text_height.max(icon_height);
+        if self.editor.is_some() {
+            content_height += style.spacing + style.editor_height;
+        }
+        let width = (icon_width + text_width)
+            .max(buttons_width)
+            .saturating_add(style.padding * 2)
+            .max(style.min_width);
+        let height =
+            style.title_height + style.padding * 3 + content_height + style.button_size.height();
+
+        let viewport = self.viewport;
+        let x = viewport.top_left().x() + viewport.width().saturating_sub(width) / 2;
+        let y = viewport.top_left().y() + viewport.height().saturating_sub(height) / 2;
+        self.base.set_size(Size::new(height, width));
+        self.base.set_position(Position::new(x, y));
+
+        let text_at = self.text_position();
+        let style = &self.style;
+        if let Some(editor) = &mut self.editor {
+            let editor_y = text_at.y() + text_height.max(icon_height) + style.spacing;
+            let editor_width = (x + width).saturating_sub(style.padding + text_at.x());
+            editor
+                .base_mut()
+                .set_size(Size::new(style.editor_height, editor_width));
+            editor
+                .base_mut()
+                .set_position(Position::new(text_at.x(), editor_y));
+        }
+
+        // Buttons are aligned to the right, in the order they were added
+        let button_y = (y + height).saturating_sub(style.padding + style.button_size.height());
+        let mut button_x = (x + width).saturating_sub(style.padding + buttons_width);
+        for (button, _) in &mut self.buttons {
+            button.base_mut().set_size(style.button_size);
+            button
+                .base_mut()
+                .set_position(Position::new(button_x, button_y));
+            button_x += style.button_size.width() + style.spacing;
+        }
+    }
+
+    // Closes the dialog if one of its buttons was clicked
+    fn take_click(&mut self) {
+        let clicked = std::mem::take(&mut self.clicks.borrow_mut().clicked);
+        let result = clicked.iter().find_map(|id| {
+            self.buttons
+                .iter()
+                .find(|(button, _)| button.base().id() == id)
+                .map(|(_, result)| *result)
+        });
+        if let Some(result) = result {
+            self.close(result);
+        }
+    }
+
+    fn click(&mut self, index: Option<usize>) {
+        if let Some((button, _)) = index.and_then(|index| self.buttons.get_mut(index)) {
+            button.perform_click();
+        }
+    }
+}
+
+impl Interactive for Dialog {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.open {
+            return false;
+        }
+
+        match *input {
+            InputEvent::KeyPressed {
+                key: Key::Escape, ..
+            } => self.click(self.cancel_button),
+            InputEvent::KeyPressed {
+                key: Key::Enter, ..
+            } => {
+                let focused = self.focus.checked_sub(self.editor_count());
+                self.click(focused.or(self.accept_button));
+            }
+            InputEvent::KeyPressed {
+                key: Key::Tab,
+                modifiers,
+            } => self.move_focus(!modifiers.shift),
+            // Arrows move between the buttons, as in the Windows message box
+            InputEvent::KeyPressed {
+                key: key @ (Key::Left | Key::Right),
+                ..
+            } if self.focused_button().is_some() => self.move_focus(key == Key::Right),
+            _ => {
+                // Pressing a control moves the focus to it
+                if let InputEvent::MouseButtonPressed {
+                    button: MouseButton::Left,
+                    position,
+                    ..
+                } = *input
+                {
+                    let hit = self
+                        .editor
+                        .iter()
+                        .map(|editor| editor.base())
+                        .chain(self.buttons.iter().map(|(button, _)| button.base()))
+                        .position(|base| base.hit_test(position));
+                    if let Some(hit) = hit {
+                        self.set_focus(hit);
+                    }
+                }
+
+                if let Some(editor) = &mut self.editor {
+                    editor.handle_input(input);
+                }
+                for (button, _) in &mut self.buttons {
+                    button.handle_input(input);
+                }
+            }
+        }
+
+        self.take_click();
+        // Modal: nothing behind the dialog gets the input
+        true
+    }
+
+    fn focusable(&self) -> bool {
+        self.open
+    }
+
+    fn set_focused(&mut self, _focused: bool) {}
+}
+
+impl Renderable for Dialog {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.open {
+            return;
+        }
+
+        let style = &self.style;
+        let frame = self.base.frame();
+        ctx.push_layer();
+        ctx.fill_rect(self.viewport, &style.overlay);
+        ctx.fill_rect(frame, &style.background);
+
+        let title_bounds = self.title_bounds();
+        ctx.fill_rect(title_bounds, &style.title_background);
+        let title_y = title_bounds.top_left().y()
+            + title_bounds
+                .height()
+                .saturating_sub(style.title_typography.line_height())
+                / 2;
+        ctx.draw_text(
+            Position::new(frame.top_left().x() + style.padding, title_y),
+            &self.title,
+            &style.title_typography,
+            &style.title_color,
+        );
+        ctx.stroke_rect(frame, &style.border);
+
+        let text_at = self.text_position();
+        if let Some(icon) = &self.icon {
+            ctx.draw_icon(
+                Position::new(frame.top_left().x() + style.padding, text_at.y()),
+                icon,
+            );
+        }
+        let mut y = text_at.y();
+        for line in self.text.lines() {
+            ctx.draw_text(
+                Position::new(text_at.x(), y),
+                line,
+                &style.typography,
+                &style.text_color,
+            );
+            y += style.typography.line_height();
+        }
+
+        if let Some(editor) = &self.editor {
+            editor.paint(ctx);
+        }
+        for (button, _) in &self.buttons {
+            button.paint(ctx);
+        }
+        ctx.pop_layer();
+    }
+}
+
+impl Component for Dialog {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
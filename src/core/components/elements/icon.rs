@@ -1,7 +1,25 @@
+use std::rc::Rc;
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+use crate::core::ui::image::{bitmap::Bitmap, decoder::ImageError, image_cache::ImageCache};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Icon {
     pub path: String,
     pub size: (u32, u32),
 }
+
+impl Icon {
+    pub fn new(path: &str, size: (u32, u32)) -> Self {
+        Self {
+            path: path.to_string(),
+            size,
+        }
+    }
+
+    /// Pixels of the icon at its size, decoded once and then taken from the cache
+    pub fn bitmap(&self, cache: &mut ImageCache) -> Result<Rc<Bitmap>, ImageError> {
+        cache.load_sized(&self.path, self.size)
+    }
+}
@@ -1,4 +1,264 @@
+use std::{cell::RefCell, rc::Rc};
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct Link {}
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        properties::{
+            boundaries::Boundaries, graphics::action_state::ActionState, position::Position,
+        },
+        styles::link_style::LinkStyle,
+    },
+    ui::{
+        click_tracker::ClickTracker,
+        render::render_context::RenderContext,
+        systems::navigation_system::{LinkTarget, NavigationSystem},
+    },
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::{InputEvent, Key},
+        types::{EventPayload, EventType},
+    },
+};
+
+/// Hyperlink text. Activating it marks it as visited, emits
+/// `MouseButtonClicked` with the target and opens the target through the
+/// `NavigationSystem`, if one is attached.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Link {
+    base: BaseComponent,
+    text: String,
+    target: LinkTarget,
+    visited: bool,
+    enabled: bool,
+    #[serde(default)]
+    style: LinkStyle,
+    #[serde(skip)]
+    navigation: Option<Rc<RefCell<NavigationSystem>>>,
+    #[serde(skip)]
+    tracker: ClickTracker,
+    #[serde(skip)]
+    focused: bool,
+}
+
+impl Link {
+    pub fn new(base: BaseComponent, text: &str, target: LinkTarget) -> Self {
+        Self {
+            base,
+            text: text.to_string(),
+            target,
+            visited: false,
+            enabled: true,
+            style: LinkStyle::default(),
+            navigation: None,
+            tracker: ClickTracker::new(),
+            focused: false,
+        }
+    }
+
+    pub fn with_navigation(mut self, navigation: Rc<RefCell<NavigationSystem>>) -> Self {
+        self.navigation = Some(navigation);
+        self
+    }
+
+    pub fn with_style(mut self, style: LinkStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    // Setters
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+    }
+
+    pub fn set_target(&mut self, target: LinkTarget) {
+        if self.target != target {
+            self.target = target;
+            self.visited = false;
+        }
+    }
+
+    pub fn set_visited(&mut self, visited: bool) {
+        self.visited = visited;
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if self.enabled == enabled {
+            return;
+        }
+
+        self.enabled = enabled;
+        self.tracker.reset();
+    }
+
+    pub fn set_navigation(&mut self, navigation: Option<Rc<RefCell<NavigationSystem>>>) {
+        self.navigation = navigation;
+    }
+
+    pub fn set_style(&mut self, style: LinkStyle) {
+        self.style = style;
+    }
+
+    /// Follows the link as if the user had clicked it, returning whether the
+    /// target was opened. The navigation system is borrowed while the URI
+    /// handler runs
+    pub fn activate(&mut self) -> bool {
+        if !self.enabled {
+            return false;
+        }
+
+        self.visited = true;
+        self.base.emit_with(
+            EventType::MouseButtonClicked,
+            EventPayload::Text(self.target.as_str().to_string()),
+        );
+        self.navigation
+            .as_ref()
+            .is_some_and(|navigation| navigation.borrow_mut().open(&self.target))
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn target(&self) -> &LinkTarget {
+        &self.target
+    }
+
+    pub fn visited(&self) -> bool {
+        self.visited
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn style(&self) -> &LinkStyle {
+        &self.style
+    }
+
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn state(&self) -> ActionState {
+        if !self.enabled {
+            ActionState::Disabled
+        } else {
+            self.tracker.state()
+        }
+    }
+
+    pub fn underlined(&self) -> bool {
+        self.style.underlined(&self.state())
+    }
+
+    /// Area covered by the text, the only part of the frame that reacts to the pointer
+    pub fn link_bounds(&self) -> Boundaries {
+        let content = self.base.content_frame();
+        let size = self.style.typography.measure_text(&self.text);
+        let width = size.width().min(content.width());
+        let height = size.height().min(content.height());
+        let y = content.top_left().y() + (content.height() - height) / 2;
+        Boundaries::from_rect(
+            Position::new(content.top_left().x(), y),
+            size.with_width(width).with_height(height),
+        )
+    }
+}
+
+impl Interactive for Link {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() {
+            return false;
+        }
+
+        if self.focused
+            && matches!(
+                input,
+                InputEvent::KeyPressed {
+                    key: Key::Enter,
+                    ..
+                }
+            )
+        {
+            self.activate();
+            return true;
+        }
+
+        let bounds = self.link_bounds();
+        let response = self.tracker.handle_input(
+            input,
+            |point| self.base.hit_test(point) && bounds.contains(point),
+            self.focused,
+        );
+        match response.hover_changed {
+            Some(true) => self.base.emit(EventType::HoverEntered),
+            Some(false) => self.base.emit(EventType::HoverExited),
+            None => {}
+        }
+        if response.clicked {
+            self.activate();
+        }
+        response.consumed
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled && *self.base.visible()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        if !focused {
+            self.tracker.release_key();
+        }
+    }
+}
+
+impl Renderable for Link {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        let state = self.state();
+        let bounds = self.link_bounds();
+        let color = self.style.text_color(&state, self.visited);
+
+        ctx.push_clip(self.base.frame());
+        ctx.draw_text(bounds.top_left(), &self.text, &self.style.typography, color);
+        if self.underlined() && bounds.width() > 0 {
+            let y = bounds.bottom_right().y().saturating_sub(1);
+            ctx.draw_line(
+                Position::new(bounds.top_left().x(), y),
+                Position::new(bounds.bottom_right().x() - 1, y),
+                color,
+                1,
+            );
+        }
+        if self.focused && !state.is_disabled() {
+            ctx.stroke_rect(bounds, &self.style.focus_border);
+        }
+        ctx.pop_clip();
+    }
+}
+
+impl Component for Link {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
@@ -0,0 +1,526 @@
+use std::{borrow::Cow, cell::RefCell, rc::Rc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::list_item::ListItem,
+        properties::{
+            boundaries::Boundaries, position::Position, selection_mode::SelectionMode, size::Size,
+        },
+        styles::list_style::ListStyle,
+    },
+    ui::{
+        render::render_context::RenderContext, selection_model::SelectionModel,
+        type_ahead::TypeAhead, virtual_list::VirtualList,
+    },
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        event_system::EventSystem,
+        input::{InputEvent, Key, Modifiers, MouseButton},
+        types::{EventPayload, EventType},
+    },
+};
+
+/// Scrollable list of strings. Only the rows inside the viewport are backed by
+/// `ListItem` components, which are recycled as the list scrolls.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListBox {
+    base: BaseComponent,
+    items: Vec<String>,
+    selection: SelectionModel,
+    enabled: bool,
+    #[serde(default)]
+    style: ListStyle,
+    #[serde(skip)]
+    list: VirtualList,
+    #[serde(skip)]
+    rows: Vec<ListItem>,
+    // Frame the rows were laid out for, `None` until the first refresh
+    #[serde(skip)]
+    laid_out: Option<Boundaries>,
+    #[serde(skip)]
+    type_ahead: TypeAhead,
+    #[serde(skip)]
+    focused: bool,
+}
+
+impl ListBox {
+    pub fn new(base: BaseComponent) -> Self {
+        let mut list_box = Self {
+            base,
+            items: Vec::new(),
+            selection: SelectionModel::new(SelectionMode::One),
+            enabled: true,
+            style: ListStyle::default(),
+            list: VirtualList::default(),
+            rows: Vec::new(),
+            laid_out: None,
+            type_ahead: TypeAhead::new(),
+            focused: false,
+        };
+        list_box.refresh();
+        list_box
+    }
+
+    pub fn with_items<S: AsRef<str>>(mut self, items: &[S]) -> Self {
+        self.set_items(items);
+        self
+    }
+
+    pub fn with_selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection.set_mode(mode);
+        self
+    }
+
+    pub fn with_style(mut self, style: ListStyle) -> Self {
+        self.set_style(style);
+        self
+    }
+
+    // Setters
+    pub fn set_items<S: AsRef<str>>(&mut self, items: &[S]) {
+        self.items = items.iter().map(|item| item.as_ref().to_string()).collect();
+        let changed = self.selection.set_item_count(self.items.len());
+        self.list.scroll_to(0);
+        self.refresh();
+        if changed {
+            self.emit_selection();
+        }
+    }
+
+    pub fn add_item(&mut self, item: &str) {
+        self.items.push(item.to_string());
+        self.refresh();
+    }
+
+    pub fn remove_item(&mut self, index: usize) -> Option<String> {
+        if index >= self.items.len() {
+            return None;
+        }
+
+        let removed = self.items.remove(index);
+        // Later indices shift down by one
+        let before = self.selection.selected();
+        let shift = |i: usize| (i != index).then(|| if i > index { i - 1 } else { i });
+        let caret = self.selection.caret().and_then(shift);
+        self.selection.clear();
+        for selected in before.iter().copied().filter_map(shift) {
+            self.selection.set_selected(selected, true);
+        }
+        self.selection.set_caret(caret);
+        self.refresh();
+        if before != self.selection.selected() {
+            self.emit_selection();
+        }
+        Some(removed)
+    }
+
+    pub fn set_selection_mode(&mut self, mode: SelectionMode) {
+        if self.selection.set_mode(mode) {
+            self.emit_selection();
+        }
+        self.refresh();
+    }
+
+    pub fn set_selected(&mut self, index: usize, selected: bool) {
+        if index < self.items.len() && self.selection.set_selected(index, selected) {
+            self.emit_selection();
+        }
+        self.refresh();
+    }
+
+    pub fn set_selected_index(&mut self, index: Option<usize>) {
+        let changed = match index.filter(|index| *index < self.items.len()) {
+            Some(index) => {
+                let changed = self.selection.select_only(index);
+                self.list.ensure_visible(index);
+                changed
+            }
+            None => self.selection.clear(),
+        };
+        self.refresh();
+        if changed {
+            self.emit_selection();
+        }
+    }
+
+    pub fn clear_selection(&mut self) {
+        if self.selection.clear() {
+            self.emit_selection();
+        }
+        self.refresh();
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.refresh();
+    }
+
+    pub fn set_style(&mut self, style: ListStyle) {
+        self.style = style;
+        self.refresh();
+    }
+
+    /// Scrolls so `index` is the first visible row, as far as the list allows
+    pub fn set_top_index(&mut self, index: usize) {
+        self.list.scroll_to(index);
+        self.refresh();
+    }
+
+    pub fn ensure_visible(&mut self, index: usize) {
+        self.list.ensure_visible(index);
+        self.refresh();
+    }
+
+    /// Lays the rows out again. Input does it on its own once the frame has
+    /// changed, and rendering paints the rows where they would go
+    pub fn refresh(&mut self) {
+        self.laid_out = Some(self.base.frame());
+        self.list = self.fitted_list();
+
+        let frames = self.row_frames(&self.list);
+        let mut rows = std::mem::take(&mut self.rows);
+        rows.truncate(frames.len());
+        while rows.len() < frames.len() {
+            let base = BaseComponent::new(
+                Size::new(0, 0),
+                self.viewport().top_left(),
+                Default::default(),
+                Default::default(),
+                self.base.event_system().clone(),
+            );
+            rows.push(ListItem::new(base, self.style.clone()));
+        }
+        for (row, (index, frame)) in rows.iter_mut().zip(frames) {
+            row.place(frame.top_left(), frame.size());
+            self.bind_row(row, index);
+        }
+        self.rows = rows;
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn items(&self) -> &[String] {
+        &self.items
+    }
+
+    pub fn selection_mode(&self) -> SelectionMode {
+        self.selection.mode()
+    }
+
+    pub fn selected_indices(&self) -> Vec<usize> {
+        self.selection.selected()
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        self.selection.first_selected()
+    }
+
+    pub fn selected_items(&self) -> Vec<&str> {
+        self.selection
+            .selected()
+            .into_iter()
+            .map(|index| self.items[index].as_str())
+            .collect()
+    }
+
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selection.is_selected(index)
+    }
+
+    /// Item with the focus rectangle
+    pub fn caret_index(&self) -> Option<usize> {
+        self.selection.caret()
+    }
+
+    pub fn top_index(&self) -> usize {
+        self.list.offset()
+    }
+
+    /// Rows currently backed by a component, in display order
+    pub fn visible_rows(&self) -> &[ListItem] {
+        &self.rows
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn style(&self) -> &ListStyle {
+        &self.style
+    }
+
+    // Layout
+    // Moved or resized since the last refresh, or just deserialized
+    fn stale(&self) -> bool {
+        self.laid_out != Some(self.base.frame())
+    }
+
+    // Inside of the one pixel border
+    fn viewport(&self) -> Boundaries {
+        let frame = self.base.frame();
+        Boundaries::from_rect(
+            Position::new(frame.top_left().x() + 1, frame.top_left().y() + 1),
+            Size::new(
+                frame.height().saturating_sub(2),
+                frame.width().saturating_sub(2),
+            ),
+        )
+    }
+
+    fn row_width(&self, list: &VirtualList, viewport: Boundaries) -> u16 {
+        if list.thumb(viewport.height()).is_some() {
+            viewport.width().saturating_sub(self.style.scrollbar_width)
+        } else {
+            viewport.width()
+        }
+    }
+
+    // Scroll state fitted to the current frame and items
+    fn fitted_list(&self) -> VirtualList {
+        let mut list = self.list;
+        list.set_item_count(self.items.len());
+        list.set_row_height(self.style.item_height);
+        list.set_viewport_height(self.viewport().height());
+        list
+    }
+
+    // Item and frame of every row `list` shows
+    fn row_frames(&self, list: &VirtualList) -> Vec<(usize, Boundaries)> {
+        let viewport = self.viewport();
+        let size = Size::new(self.style.item_height, self.row_width(list, viewport));
+        list.visible_range()
+            .map(|index| {
+                let top = list.row_top(index).unwrap_or_default();
+                let position =
+                    Position::new(viewport.top_left().x(), viewport.top_left().y() + top);
+                (index, Boundaries::from_rect(position, size))
+            })
+            .collect()
+    }
+
+    fn bind_row(&self, row: &mut ListItem, index: usize) {
+        row.bind(index, &self.items[index], None);
+        row.set_style(&self.style);
+        row.set_enabled(self.enabled);
+        row.set_selected(self.selection.is_selected(index));
+        row.set_caret(self.focused && self.selection.caret() == Some(index));
+    }
+
+    // Rows for a frame the list was not laid out for. They report to a private
+    // event system, so painting them takes no ids and emits nothing
+    fn detached_rows(&self, list: &VirtualList) -> Vec<ListItem> {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        self.row_frames(list)
+            .into_iter()
+            .map(|(index, frame)| {
+                let base = BaseComponent::new(
+                    frame.size(),
+                    frame.top_left(),
+                    Default::default(),
+                    Default::default(),
+                    event_system.clone(),
+                );
+                let mut row = ListItem::new(base, self.style.clone());
+                self.bind_row(&mut row, index);
+                row
+            })
+            .collect()
+    }
+
+    fn row_at(&self, point: Position) -> Option<usize> {
+        let viewport = self.viewport();
+        if !viewport.contains(point)
+            || point.x() >= viewport.top_left().x() + self.row_width(&self.list, viewport)
+        {
+            return None;
+        }
+        self.list.row_at(point.y() - viewport.top_left().y())
+    }
+
+    fn emit_selection(&self) {
+        self.base.emit_with(
+            EventType::FieldValueChanged,
+            EventPayload::Indices(self.selection.selected()),
+        );
+    }
+
+    fn navigation_target(&self, key: Key) -> Option<usize> {
+        let last = self.items.len().checked_sub(1)?;
+        let page = self.list.page_size();
+        let current = self.selection.caret();
+
+        Some(match (key, current) {
+            (Key::Up, Some(index)) => index.saturating_sub(1),
+            (Key::Down, Some(index)) => (index + 1).min(last),
+            (Key::PageUp, Some(index)) => index.saturating_sub(page - 1),
+            (Key::PageDown, Some(index)) => (index + page - 1).min(last),
+            (Key::Up | Key::Down | Key::PageUp | Key::PageDown | Key::Home, _) => 0,
+            (Key::End, _) => last,
+            _ => return None,
+        })
+    }
+
+    fn handle_key(&mut self, key: Key, modifiers: Modifiers) -> bool {
+        let changed = match key {
+            Key::Char('a') | Key::Char('A') if modifiers.ctrl => {
+                self.selection.select_all(self.items.len())
+            }
+            Key::Space => self.selection.toggle_caret(),
+            _ => match self.navigation_target(key) {
+                Some(target) => {
+                    self.list.ensure_visible(target);
+                    self.selection.navigate(target, modifiers)
+                }
+                None => return false,
+            },
+        };
+
+        if changed {
+            self.emit_selection();
+        }
+        true
+    }
+}
+
+impl Interactive for ListBox {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() {
+            return false;
+        }
+        if self.stale() {
+            self.refresh();
+        }
+
+        let consumed = match *input {
+            InputEvent::MouseButtonPressed {
+                button: MouseButton::Left,
+                position,
+                modifiers,
+            } if self.base.hit_test(position) => {
+                self.set_focused(true);
+                if let Some(index) = self.row_at(position)
+                    && self.selection.click(index, modifiers)
+                {
+                    self.list.ensure_visible(index);
+                    self.emit_selection();
+                }
+                true
+            }
+            InputEvent::MouseScrolled {
+                delta_y, position, ..
+            } if self.base.hit_test(position) => {
+                self.list.scroll_by(-(delta_y as isize) * 3);
+                true
+            }
+            InputEvent::KeyPressed { key, modifiers } if self.focused => {
+                self.handle_key(key, modifiers)
+            }
+            InputEvent::CharTyped(c) if self.focused && !c.is_control() => {
+                if let Some(index) = self
+                    .type_ahead
+                    .search(c, &self.items, self.selection.caret())
+                {
+                    self.list.ensure_visible(index);
+                    if self.selection.navigate(index, Modifiers::none()) {
+                        self.emit_selection();
+                    }
+                }
+                true
+            }
+            _ => false,
+        };
+
+        if consumed {
+            self.refresh();
+        }
+        consumed
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled && *self.base.visible()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        if self.focused == focused {
+            return;
+        }
+
+        self.focused = focused;
+        if focused {
+            if self.selection.caret().is_none() && !self.items.is_empty() {
+                let caret = self.selection.first_selected().unwrap_or(0);
+                self.selection.set_caret(Some(caret));
+            }
+            self.base.emit(EventType::FieldFocused);
+        } else {
+            self.type_ahead.reset();
+            self.base.emit(EventType::FieldFocusedLost);
+        }
+        self.refresh();
+    }
+}
+
+impl Renderable for ListBox {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+        let list = self.fitted_list();
+        let rows = if self.stale() {
+            Cow::Owned(self.detached_rows(&list))
+        } else {
+            Cow::Borrowed(self.rows.as_slice())
+        };
+
+        let style = &self.style;
+        let frame = self.base.frame();
+        let viewport = self.viewport();
+        ctx.fill_rect(frame, &style.background);
+
+        ctx.push_clip(viewport);
+        for row in rows.iter() {
+            row.paint(ctx);
+        }
+        if let Some((offset, length)) = list.thumb(viewport.height()) {
+            let thumb = Boundaries::from_rect(
+                Position::new(
+                    viewport.top_left().x() + self.row_width(&list, viewport),
+                    viewport.top_left().y() + offset,
+                ),
+                Size::new(length, style.scrollbar_width),
+            );
+            ctx.fill_rect(thumb, &style.scrollbar_thumb);
+        }
+        ctx.pop_clip();
+
+        if self.focused {
+            ctx.stroke_rect(frame, &style.focus_border);
+        } else {
+            ctx.stroke_rect(frame, &style.border);
+        }
+    }
+}
+
+impl Component for ListBox {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
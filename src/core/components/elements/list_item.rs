@@ -0,0 +1,244 @@
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::icon::Icon,
+        properties::{
+            boundaries::Boundaries, graphics::color::Color, position::Position, size::Size,
+        },
+        styles::list_style::ListStyle,
+    },
+    ui::render::render_context::RenderContext,
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::input::InputEvent,
+};
+
+const ICON_SPACING: u16 = 4;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemLayout {
+    // Icon and text side by side, optionally followed by more cells
+    #[default]
+    Row,
+    // Icon on top with the text centered below it
+    Tile,
+}
+
+/// On-screen row of a virtualized list. Lists only create these for the items
+/// inside their viewport and rebind them to other items while scrolling.
+#[derive(Debug, Clone)]
+pub struct ListItem {
+    base: BaseComponent,
+    index: usize,
+    text: String,
+    // Extra cells as (text, width), drawn after the first column
+    cells: Vec<(String, u16)>,
+    first_column_width: Option<u16>,
+    icon: Option<Icon>,
+    layout: ItemLayout,
+    selected: bool,
+    caret: bool,
+    enabled: bool,
+    style: ListStyle,
+}
+
+impl ListItem {
+    pub fn new(base: BaseComponent, style: ListStyle) -> Self {
+        Self {
+            base,
+            index: 0,
+            text: String::new(),
+            cells: Vec::new(),
+            first_column_width: None,
+            icon: None,
+            layout: ItemLayout::Row,
+            selected: false,
+            caret: false,
+            enabled: true,
+            style,
+        }
+    }
+
+    /// Points this row at another item
+    pub fn bind(&mut self, index: usize, text: &str, icon: Option<&Icon>) {
+        self.index = index;
+        self.text.clear();
+        self.text.push_str(text);
+        self.icon = icon.cloned();
+        self.cells.clear();
+        self.first_column_width = None;
+    }
+
+    pub fn set_cells(&mut self, first_column_width: u16, cells: Vec<(String, u16)>) {
+        self.first_column_width = Some(first_column_width);
+        self.cells = cells;
+    }
+
+    pub fn set_layout(&mut self, layout: ItemLayout) {
+        self.layout = layout;
+    }
+
+    pub fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+    }
+
+    pub fn set_caret(&mut self, caret: bool) {
+        self.caret = caret;
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    pub fn set_style(&mut self, style: &ListStyle) {
+        if &self.style != style {
+            self.style = style.clone();
+        }
+    }
+
+    pub fn place(&mut self, position: Position, size: Size) {
+        self.base.set_position(position);
+        self.base.set_size(size);
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn selected(&self) -> bool {
+        self.selected
+    }
+
+    pub fn caret(&self) -> bool {
+        self.caret
+    }
+
+    fn icon_size(&self) -> (u16, u16) {
+        self.icon.as_ref().map_or((0, 0), |icon| {
+            (
+                icon.size.0.min(u16::MAX as u32) as u16,
+                icon.size.1.min(u16::MAX as u32) as u16,
+            )
+        })
+    }
+
+    fn render_row(&self, ctx: &mut RenderContext, frame: Boundaries) {
+        let style = &self.style;
+        let text_size = style.typography.measure_text(&self.text);
+        let (icon_width, icon_height) = self.icon_size();
+        let mut x = frame.top_left().x() + style.item_padding;
+        let center_y =
+            |height: u16| frame.top_left().y() + frame.height().saturating_sub(height) / 2;
+
+        if let Some(icon) = &self.icon {
+            ctx.draw_icon(Position::new(x, center_y(icon_height)), icon);
+            x += icon_width + ICON_SPACING;
+        }
+        ctx.draw_text(
+            Position::new(x, center_y(text_size.height())),
+            &self.text,
+            &style.typography,
+            self.text_color(),
+        );
+
+        let Some(first_width) = self.first_column_width else {
+            return;
+        };
+        let mut cell_x = frame.top_left().x().saturating_add(first_width);
+        for (text, width) in &self.cells {
+            let cell = Boundaries::from_rect(
+                Position::new(cell_x, frame.top_left().y()),
+                Size::new(frame.height(), *width),
+            );
+            ctx.push_clip(cell);
+            ctx.draw_text(
+                Position::new(cell_x + style.item_padding, center_y(text_size.height())),
+                text,
+                &style.typography,
+                self.text_color(),
+            );
+            ctx.pop_clip();
+            cell_x = cell_x.saturating_add(*width);
+        }
+    }
+
+    fn render_tile(&self, ctx: &mut RenderContext, frame: Boundaries) {
+        let style = &self.style;
+        let text_size = style.typography.measure_text(&self.text);
+        let (icon_width, icon_height) = self.icon_size();
+        let center_x = |width: u16| frame.top_left().x() + frame.width().saturating_sub(width) / 2;
+        let top = frame.top_left().y() + style.item_padding;
+
+        if let Some(icon) = &self.icon {
+            ctx.draw_icon(Position::new(center_x(icon_width), top), icon);
+        }
+        ctx.draw_text(
+            Position::new(
+                center_x(text_size.width()),
+                top + icon_height + ICON_SPACING,
+            ),
+            &self.text,
+            &style.typography,
+            self.text_color(),
+        );
+    }
+
+    fn text_color(&self) -> &Color {
+        if !self.enabled {
+            &self.style.disabled_text_color
+        } else if self.selected {
+            &self.style.highlight_text_color
+        } else {
+            &self.style.text_color
+        }
+    }
+}
+
+// Rows take no input themselves, their list handles it
+impl Interactive for ListItem {
+    fn handle_input(&mut self, _input: &InputEvent) -> bool {
+        false
+    }
+}
+
+impl Renderable for ListItem {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        let frame = self.base.frame();
+        if self.selected {
+            ctx.fill_rect(frame, &self.style.highlight_background);
+        }
+
+        ctx.push_clip(frame);
+        match self.layout {
+            ItemLayout::Row => self.render_row(ctx, frame),
+            ItemLayout::Tile => self.render_tile(ctx, frame),
+        }
+        ctx.pop_clip();
+
+        if self.caret {
+            ctx.stroke_rect(frame, &self.style.focus_border);
+        }
+    }
+}
+
+impl Component for ListItem {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
@@ -0,0 +1,761 @@
+use std::{borrow::Cow, cell::RefCell, rc::Rc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::{
+            icon::Icon,
+            list_item::{ItemLayout, ListItem},
+        },
+        properties::{
+            boundaries::Boundaries, position::Position, selection_mode::SelectionMode, size::Size,
+        },
+        styles::list_view_style::ListViewStyle,
+    },
+    ui::{
+        render::render_context::RenderContext, selection_model::SelectionModel,
+        type_ahead::TypeAhead, virtual_list::VirtualList,
+    },
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        event_system::EventSystem,
+        input::{InputEvent, Key, Modifiers, MouseButton},
+        types::{EventPayload, EventType},
+    },
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ListViewMode {
+    // One row per item with a column header and sub item cells
+    #[default]
+    Details,
+    // Items flow top to bottom in columns that scroll horizontally
+    List,
+    // Large tiles flowing left to right in rows
+    Icon,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ListViewItem {
+    pub text: String,
+    // Values for the second column onwards in details view
+    pub sub_items: Vec<String>,
+    pub icon: Option<Icon>,
+}
+
+impl ListViewItem {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            sub_items: Vec::new(),
+            icon: None,
+        }
+    }
+
+    pub fn with_sub_items<S: AsRef<str>>(mut self, sub_items: &[S]) -> Self {
+        self.sub_items = sub_items.iter().map(|s| s.as_ref().to_string()).collect();
+        self
+    }
+
+    pub fn with_icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ListViewColumn {
+    pub header: String,
+    pub width: u16,
+}
+
+impl ListViewColumn {
+    pub fn new(header: &str, width: u16) -> Self {
+        Self {
+            header: header.to_string(),
+            width,
+        }
+    }
+}
+
+// Items are laid out in lines: rows in details and icon view, columns in list view.
+// Only the lines inside the viewport are realized.
+#[derive(Debug, Clone, Copy)]
+struct LineLayout {
+    viewport: Boundaries,
+    per_line: usize,
+    // Height of a row, or width of a column when `horizontal`
+    extent: u16,
+    horizontal: bool,
+}
+
+/// WinForms-like list view with details, list and icon views over the same
+/// items. Like `ListBox`, only the items on screen get a `ListItem`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListView {
+    base: BaseComponent,
+    columns: Vec<ListViewColumn>,
+    items: Vec<ListViewItem>,
+    mode: ListViewMode,
+    selection: SelectionModel,
+    enabled: bool,
+    #[serde(default)]
+    style: ListViewStyle,
+    #[serde(skip)]
+    lines: VirtualList,
+    #[serde(skip)]
+    rows: Vec<ListItem>,
+    // Frame the items were laid out for, `None` until the first refresh
+    #[serde(skip)]
+    laid_out: Option<Boundaries>,
+    #[serde(skip)]
+    type_ahead: TypeAhead,
+    #[serde(skip)]
+    focused: bool,
+}
+
+impl ListView {
+    pub fn new(base: BaseComponent) -> Self {
+        let mut list_view = Self {
+            base,
+            columns: Vec::new(),
+            items: Vec::new(),
+            mode: ListViewMode::default(),
+            selection: SelectionModel::new(SelectionMode::MultiExtended),
+            enabled: true,
+            style: ListViewStyle::default(),
+            lines: VirtualList::default(),
+            rows: Vec::new(),
+            laid_out: None,
+            type_ahead: TypeAhead::new(),
+            focused: false,
+        };
+        list_view.refresh();
+        list_view
+    }
+
+    pub fn with_column(mut self, column: ListViewColumn) -> Self {
+        self.columns.push(column);
+        self.refresh();
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<ListViewItem>) -> Self {
+        self.set_items(items);
+        self
+    }
+
+    pub fn with_mode(mut self, mode: ListViewMode) -> Self {
+        self.set_mode(mode);
+        self
+    }
+
+    pub fn with_selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection.set_mode(mode);
+        self
+    }
+
+    pub fn with_style(mut self, style: ListViewStyle) -> Self {
+        self.set_style(style);
+        self
+    }
+
+    // Setters
+    pub fn set_items(&mut self, items: Vec<ListViewItem>) {
+        self.items = items;
+        let changed = self.selection.set_item_count(self.items.len());
+        self.lines.scroll_to(0);
+        self.refresh();
+        if changed {
+            self.emit_selection();
+        }
+    }
+
+    pub fn add_item(&mut self, item: ListViewItem) {
+        self.items.push(item);
+        self.refresh();
+    }
+
+    pub fn add_column(&mut self, column: ListViewColumn) {
+        self.columns.push(column);
+        self.refresh();
+    }
+
+    pub fn set_column_width(&mut self, column: usize, width: u16) {
+        if let Some(column) = self.columns.get_mut(column) {
+            column.width = width;
+            self.refresh();
+        }
+    }
+
+    /// Switches the view, keeping the caret item on screen
+    pub fn set_mode(&mut self, mode: ListViewMode) {
+        self.mode = mode;
+        self.lines = VirtualList::default();
+        self.refresh();
+        if let Some(caret) = self.selection.caret() {
+            self.ensure_visible(caret);
+        }
+    }
+
+    pub fn set_selection_mode(&mut self, mode: SelectionMode) {
+        if self.selection.set_mode(mode) {
+            self.emit_selection();
+        }
+        self.refresh();
+    }
+
+    pub fn set_selected(&mut self, index: usize, selected: bool) {
+        if index < self.items.len() && self.selection.set_selected(index, selected) {
+            self.emit_selection();
+        }
+        self.refresh();
+    }
+
+    pub fn clear_selection(&mut self) {
+        if self.selection.clear() {
+            self.emit_selection();
+        }
+        self.refresh();
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.refresh();
+    }
+
+    pub fn set_style(&mut self, style: ListViewStyle) {
+        self.style = style;
+        self.refresh();
+    }
+
+    pub fn ensure_visible(&mut self, index: usize) {
+        let per_line = self.layout().per_line;
+        self.lines.ensure_visible(index / per_line);
+        self.refresh();
+    }
+
+    /// Lays the visible items out again. Input does it on its own once the
+    /// frame has changed, and rendering paints the items where they would go
+    pub fn refresh(&mut self) {
+        self.laid_out = Some(self.base.frame());
+        self.lines = self.fitted_lines();
+
+        let frames = self.row_frames(&self.lines);
+        let mut rows = std::mem::take(&mut self.rows);
+        rows.truncate(frames.len());
+        while rows.len() < frames.len() {
+            let base = BaseComponent::new(
+                Size::new(0, 0),
+                self.layout().viewport.top_left(),
+                Default::default(),
+                Default::default(),
+                self.base.event_system().clone(),
+            );
+            rows.push(ListItem::new(base, self.style.list.clone()));
+        }
+        for (row, (index, frame)) in rows.iter_mut().zip(frames) {
+            row.place(frame.top_left(), frame.size());
+            self.bind_row(row, index);
+        }
+        self.rows = rows;
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn columns(&self) -> &[ListViewColumn] {
+        &self.columns
+    }
+
+    pub fn items(&self) -> &[ListViewItem] {
+        &self.items
+    }
+
+    pub fn mode(&self) -> ListViewMode {
+        self.mode
+    }
+
+    pub fn selection_mode(&self) -> SelectionMode {
+        self.selection.mode()
+    }
+
+    pub fn selected_indices(&self) -> Vec<usize> {
+        self.selection.selected()
+    }
+
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selection.is_selected(index)
+    }
+
+    pub fn caret_index(&self) -> Option<usize> {
+        self.selection.caret()
+    }
+
+    /// Items currently backed by a component
+    pub fn visible_items(&self) -> std::ops::Range<usize> {
+        self.items_shown(&self.lines)
+    }
+
+    pub fn visible_rows(&self) -> &[ListItem] {
+        &self.rows
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn style(&self) -> &ListViewStyle {
+        &self.style
+    }
+
+    // Layout
+    // Moved or resized since the last refresh, or just deserialized
+    fn stale(&self) -> bool {
+        self.laid_out != Some(self.base.frame())
+    }
+
+    fn header_bounds(&self) -> Option<Boundaries> {
+        if self.mode != ListViewMode::Details || self.columns.is_empty() {
+            return None;
+        }
+        let frame = self.base.frame();
+        Some(Boundaries::from_rect(
+            Position::new(frame.top_left().x() + 1, frame.top_left().y() + 1),
+            Size::new(self.style.header_height, frame.width().saturating_sub(2)),
+        ))
+    }
+
+    fn layout(&self) -> LineLayout {
+        let frame = self.base.frame();
+        let header = self.header_bounds().map_or(0, |header| header.height());
+        let viewport = Boundaries::from_rect(
+            Position::new(frame.top_left().x() + 1, frame.top_left().y() + 1 + header),
+            Size::new(
+                frame.height().saturating_sub(2 + header),
+                frame.width().saturating_sub(2),
+            ),
+        );
+        let style = &self.style;
+        let layout = |viewport: Boundaries| match self.mode {
+            ListViewMode::Details => LineLayout {
+                viewport,
+                per_line: 1,
+                extent: style.list.item_height.max(1),
+                horizontal: false,
+            },
+            ListViewMode::List => LineLayout {
+                viewport,
+                per_line: (viewport.height() / style.list.item_height.max(1)).max(1) as usize,
+                extent: style.list_column_width.max(1),
+                horizontal: true,
+            },
+            ListViewMode::Icon => LineLayout {
+                viewport,
+                per_line: (viewport.width() / style.tile_width.max(1)).max(1) as usize,
+                extent: style.tile_height.max(1),
+                horizontal: false,
+            },
+        };
+
+        // Leave room for the scrollbar when the lines overflow
+        let full = layout(viewport);
+        let span = if full.horizontal {
+            viewport.width()
+        } else {
+            viewport.height()
+        };
+        let lines = self.items.len().div_ceil(full.per_line);
+        if lines <= (span / full.extent) as usize {
+            return full;
+        }
+        let scrollbar = style.list.scrollbar_width;
+        let size = if full.horizontal {
+            viewport
+                .size()
+                .with_height(viewport.height().saturating_sub(scrollbar))
+        } else {
+            viewport
+                .size()
+                .with_width(viewport.width().saturating_sub(scrollbar))
+        };
+        layout(Boundaries::from_rect(viewport.top_left(), size))
+    }
+
+    fn item_bounds(
+        lines: &VirtualList,
+        style: &ListViewStyle,
+        layout: LineLayout,
+        index: usize,
+    ) -> Boundaries {
+        let line_start = lines.row_top(index / layout.per_line).unwrap_or_default();
+        let slot = (index % layout.per_line) as u16;
+        let (x, y) = (
+            layout.viewport.top_left().x(),
+            layout.viewport.top_left().y(),
+        );
+        let item_height = style.list.item_height;
+
+        match (layout.horizontal, layout.per_line) {
+            (true, _) => Boundaries::from_rect(
+                Position::new(x + line_start, y + slot * item_height),
+                Size::new(item_height, layout.extent),
+            ),
+            (false, 1) => Boundaries::from_rect(
+                Position::new(x, y + line_start),
+                Size::new(layout.extent, layout.viewport.width()),
+            ),
+            (false, _) => Boundaries::from_rect(
+                Position::new(x + slot * style.tile_width, y + line_start),
+                Size::new(layout.extent, style.tile_width),
+            ),
+        }
+    }
+
+    // Scroll state fitted to the current frame, mode and items
+    fn fitted_lines(&self) -> VirtualList {
+        let layout = self.layout();
+        let span = if layout.horizontal {
+            layout.viewport.width()
+        } else {
+            layout.viewport.height()
+        };
+        let mut lines = self.lines;
+        lines.set_item_count(self.items.len().div_ceil(layout.per_line));
+        lines.set_row_height(layout.extent);
+        lines.set_viewport_height(span);
+        lines
+    }
+
+    fn items_shown(&self, lines: &VirtualList) -> std::ops::Range<usize> {
+        let per_line = self.layout().per_line;
+        let lines = lines.visible_range();
+        (lines.start * per_line).min(self.items.len())..(lines.end * per_line).min(self.items.len())
+    }
+
+    // Item and frame of everything `lines` shows
+    fn row_frames(&self, lines: &VirtualList) -> Vec<(usize, Boundaries)> {
+        let layout = self.layout();
+        self.items_shown(lines)
+            .map(|index| (index, Self::item_bounds(lines, &self.style, layout, index)))
+            .collect()
+    }
+
+    fn bind_row(&self, row: &mut ListItem, index: usize) {
+        let item = &self.items[index];
+        row.bind(index, &item.text, item.icon.as_ref());
+        if self.mode == ListViewMode::Details
+            && let Some((first, rest)) = self.columns.split_first()
+        {
+            let cells = rest
+                .iter()
+                .zip(item.sub_items.iter())
+                .map(|(column, text)| (text.clone(), column.width))
+                .collect();
+            row.set_cells(first.width, cells);
+        }
+        row.set_layout(match self.mode {
+            ListViewMode::Icon => ItemLayout::Tile,
+            _ => ItemLayout::Row,
+        });
+        row.set_style(&self.style.list);
+        row.set_enabled(self.enabled);
+        row.set_selected(self.selection.is_selected(index));
+        row.set_caret(self.focused && self.selection.caret() == Some(index));
+    }
+
+    // Items for a frame the view was not laid out for. They report to a
+    // private event system, so painting them takes no ids and emits nothing
+    fn detached_rows(&self, lines: &VirtualList) -> Vec<ListItem> {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        self.row_frames(lines)
+            .into_iter()
+            .map(|(index, frame)| {
+                let base = BaseComponent::new(
+                    frame.size(),
+                    frame.top_left(),
+                    Default::default(),
+                    Default::default(),
+                    event_system.clone(),
+                );
+                let mut row = ListItem::new(base, self.style.list.clone());
+                self.bind_row(&mut row, index);
+                row
+            })
+            .collect()
+    }
+
+    fn item_at(&self, point: Position) -> Option<usize> {
+        let layout = self.layout();
+        if !layout.viewport.contains(point) {
+            return None;
+        }
+        self.visible_items().find(|index| {
+            Self::item_bounds(&self.lines, &self.style, layout, *index).contains(point)
+        })
+    }
+
+    fn emit_selection(&self) {
+        self.base.emit_with(
+            EventType::FieldValueChanged,
+            EventPayload::Indices(self.selection.selected()),
+        );
+    }
+
+    // Arrow keys step one item along a line and a whole line across lines
+    fn navigation_target(&self, key: Key) -> Option<usize> {
+        let last = self.items.len().checked_sub(1)?;
+        let line = self.layout().per_line;
+        let page = self.lines.page_size().saturating_sub(1).max(1) * line;
+        let Some(current) = self.selection.caret() else {
+            return match key {
+                Key::End => Some(last),
+                Key::Up | Key::Down | Key::Left | Key::Right => Some(0),
+                Key::Home | Key::PageUp | Key::PageDown => Some(0),
+                _ => None,
+            };
+        };
+
+        Some(match (self.mode, key) {
+            (_, Key::Home) => 0,
+            (_, Key::End) => last,
+            (_, Key::PageUp) => current.saturating_sub(page),
+            (_, Key::PageDown) => (current + page).min(last),
+            (ListViewMode::Details | ListViewMode::List, Key::Up)
+            | (ListViewMode::Icon, Key::Left) => current.saturating_sub(1),
+            (ListViewMode::Details | ListViewMode::List, Key::Down)
+            | (ListViewMode::Icon, Key::Right) => (current + 1).min(last),
+            (ListViewMode::List, Key::Left) | (ListViewMode::Icon, Key::Up) => {
+                current.checked_sub(line).unwrap_or(current)
+            }
+            (ListViewMode::List, Key::Right) | (ListViewMode::Icon, Key::Down) => {
+                if current + line <= last {
+                    current + line
+                } else {
+                    current
+                }
+            }
+            _ => return None,
+        })
+    }
+
+    fn handle_key(&mut self, key: Key, modifiers: Modifiers) -> bool {
+        let changed = match key {
+            Key::Char('a') | Key::Char('A') if modifiers.ctrl => {
+                self.selection.select_all(self.items.len())
+            }
+            Key::Space => self.selection.toggle_caret(),
+            _ => match self.navigation_target(key) {
+                Some(target) => {
+                    self.lines.ensure_visible(target / self.layout().per_line);
+                    self.selection.navigate(target, modifiers)
+                }
+                None => return false,
+            },
+        };
+
+        if changed {
+            self.emit_selection();
+        }
+        true
+    }
+}
+
+impl Interactive for ListView {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() {
+            return false;
+        }
+        if self.stale() {
+            self.refresh();
+        }
+
+        let consumed = match *input {
+            InputEvent::MouseButtonPressed {
+                button: MouseButton::Left,
+                position,
+                modifiers,
+            } if self.base.hit_test(position) => {
+                self.set_focused(true);
+                let on_header = self.header_bounds().is_some_and(|h| h.contains(position));
+                match self.item_at(position) {
+                    Some(index) if self.selection.click(index, modifiers) => self.emit_selection(),
+                    // Clicking empty space deselects, like WinForms
+                    None if !on_header && !modifiers.ctrl && self.selection.clear() => {
+                        self.emit_selection()
+                    }
+                    _ => {}
+                }
+                true
+            }
+            InputEvent::MouseScrolled {
+                delta_x,
+                delta_y,
+                position,
+            } if self.base.hit_test(position) => {
+                let lines = match self.mode {
+                    ListViewMode::Details => -(delta_y as isize) * 3,
+                    ListViewMode::List => -((delta_y + delta_x) as isize),
+                    ListViewMode::Icon => -(delta_y as isize),
+                };
+                self.lines.scroll_by(lines);
+                true
+            }
+            InputEvent::KeyPressed { key, modifiers } if self.focused => {
+                self.handle_key(key, modifiers)
+            }
+            InputEvent::CharTyped(c) if self.focused && !c.is_control() => {
+                let texts: Vec<&str> = self.items.iter().map(|item| item.text.as_str()).collect();
+                if let Some(index) = self.type_ahead.search(c, &texts, self.selection.caret()) {
+                    self.lines.ensure_visible(index / self.layout().per_line);
+                    if self.selection.navigate(index, Modifiers::none()) {
+                        self.emit_selection();
+                    }
+                }
+                true
+            }
+            _ => false,
+        };
+
+        if consumed {
+            self.refresh();
+        }
+        consumed
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled && *self.base.visible()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        if self.focused == focused {
+            return;
+        }
+
+        self.focused = focused;
+        if focused {
+            if self.selection.caret().is_none() && !self.items.is_empty() {
+                let caret = self.selection.first_selected().unwrap_or(0);
+                self.selection.set_caret(Some(caret));
+            }
+            self.base.emit(EventType::FieldFocused);
+        } else {
+            self.type_ahead.reset();
+            self.base.emit(EventType::FieldFocusedLost);
+        }
+        self.refresh();
+    }
+}
+
+impl Renderable for ListView {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+        let lines = self.fitted_lines();
+        let rows = if self.stale() {
+            Cow::Owned(self.detached_rows(&lines))
+        } else {
+            Cow::Borrowed(self.rows.as_slice())
+        };
+
+        let style = &self.style;
+        let frame = self.base.frame();
+        ctx.fill_rect(frame, &style.list.background);
+
+        if let Some(header) = self.header_bounds() {
+            ctx.fill_rect(header, &style.header_background);
+            ctx.push_clip(header);
+            let mut x = header.top_left().x();
+            for column in &self.columns {
+                let cell = Boundaries::from_rect(
+                    Position::new(x, header.top_left().y()),
+                    Size::new(header.height(), column.width),
+                );
+                let text_size = style.list.typography.measure_text(&column.header);
+                ctx.draw_text(
+                    Position::new(
+                        x + style.list.item_padding,
+                        cell.top_left().y() + cell.height().saturating_sub(text_size.height()) / 2,
+                    ),
+                    &column.header,
+                    &style.list.typography,
+                    &style.header_text_color,
+                );
+                // Divider on the right edge of each column
+                let right = cell.bottom_right().x();
+                ctx.draw_line(
+                    Position::new(right, cell.top_left().y()),
+                    Position::new(right, cell.bottom_right().y()),
+                    style.header_border.color(),
+                    1,
+                );
+                x = x.saturating_add(column.width);
+            }
+            ctx.pop_clip();
+        }
+
+        let layout = self.layout();
+        let viewport = layout.viewport;
+        ctx.push_clip(viewport);
+        for row in rows.iter() {
+            row.paint(ctx);
+        }
+        let track = if layout.horizontal {
+            viewport.width()
+        } else {
+            viewport.height()
+        };
+        ctx.pop_clip();
+
+        if let Some((offset, length)) = lines.thumb(track) {
+            let scrollbar = style.list.scrollbar_width;
+            let thumb = if layout.horizontal {
+                Boundaries::from_rect(
+                    Position::new(
+                        viewport.top_left().x() + offset,
+                        viewport.bottom_right().y(),
+                    ),
+                    Size::new(scrollbar, length),
+                )
+            } else {
+                Boundaries::from_rect(
+                    Position::new(
+                        viewport.bottom_right().x(),
+                        viewport.top_left().y() + offset,
+                    ),
+                    Size::new(length, scrollbar),
+                )
+            };
+            ctx.fill_rect(thumb, &style.list.scrollbar_thumb);
+        }
+
+        if self.focused {
+            ctx.stroke_rect(frame, &style.list.focus_border);
+        } else {
+            ctx.stroke_rect(frame, &style.list.border);
+        }
+    }
+}
+
+impl Component for ListView {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
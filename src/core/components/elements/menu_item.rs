@@ -0,0 +1,209 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::window::events::input::KeyCombination;
+
+/// Entry of a menu: a command, a submenu when it has items, or a separator.
+///
+/// The text marks its mnemonic with `&` (`&File` underlines the F and opens
+/// with Alt+F); `&&` stands for a literal ampersand.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MenuItem {
+    text: String,
+    // Id reported by `CommandInvoked` when the item is clicked
+    command: Option<usize>,
+    shortcut: Option<KeyCombination>,
+    checkable: bool,
+    checked: bool,
+    enabled: bool,
+    separator: bool,
+    items: Vec<MenuItem>,
+}
+
+impl MenuItem {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            command: None,
+            shortcut: None,
+            checkable: false,
+            checked: false,
+            enabled: true,
+            separator: false,
+            items: Vec::new(),
+        }
+    }
+
+    pub fn separator() -> Self {
+        Self {
+            separator: true,
+            ..Self::new("")
+        }
+    }
+
+    pub fn with_command(mut self, command: usize) -> Self {
+        self.command = Some(command);
+        self
+    }
+
+    pub fn with_shortcut(mut self, shortcut: KeyCombination) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
+    pub fn with_checkable(mut self, checkable: bool) -> Self {
+        self.checkable = checkable;
+        self
+    }
+
+    pub fn with_checked(mut self, checked: bool) -> Self {
+        self.checkable = true;
+        self.checked = checked;
+        self
+    }
+
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    pub fn with_item(mut self, item: MenuItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    // Setters
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+    }
+
+    pub fn set_shortcut(&mut self, shortcut: Option<KeyCombination>) {
+        self.shortcut = shortcut;
+    }
+
+    pub fn set_checked(&mut self, checked: bool) {
+        self.checked = checked;
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    pub fn add_item(&mut self, item: MenuItem) {
+        self.items.push(item);
+    }
+
+    // Getters
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn command(&self) -> Option<usize> {
+        self.command
+    }
+
+    pub fn shortcut(&self) -> Option<KeyCombination> {
+        self.shortcut
+    }
+
+    pub fn checkable(&self) -> bool {
+        self.checkable
+    }
+
+    pub fn checked(&self) -> bool {
+        self.checked
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn is_separator(&self) -> bool {
+        self.separator
+    }
+
+    pub fn has_submenu(&self) -> bool {
+        !self.items.is_empty()
+    }
+
+    pub fn items(&self) -> &[MenuItem] {
+        &self.items
+    }
+
+    pub fn items_mut(&mut self) -> &mut Vec<MenuItem> {
+        &mut self.items
+    }
+
+    /// Whether the item reacts to clicks and keys
+    pub fn selectable(&self) -> bool {
+        !self.separator && self.enabled
+    }
+
+    /// Text without the mnemonic markers
+    pub fn display_text(&self) -> String {
+        self.parse_mnemonic().0
+    }
+
+    /// Mnemonic letter, lowercase
+    pub fn mnemonic(&self) -> Option<char> {
+        let (text, index) = self.parse_mnemonic();
+        text.chars()
+            .nth(index?)
+            .map(|c| c.to_lowercase().next().unwrap_or(c))
+    }
+
+    /// Position of the underlined character within `display_text`
+    pub fn mnemonic_index(&self) -> Option<usize> {
+        self.parse_mnemonic().1
+    }
+
+    fn parse_mnemonic(&self) -> (String, Option<usize>) {
+        let mut text = String::with_capacity(self.text.len());
+        let mut index = None;
+        let mut chars = self.text.chars();
+        let mut count = 0;
+        while let Some(c) = chars.next() {
+            if c == '&' {
+                match chars.next() {
+                    Some('&') => {}
+                    Some(next) => {
+                        index.get_or_insert(count);
+                        text.push(next);
+                        count += 1;
+                        continue;
+                    }
+                    None => break,
+                }
+            }
+            text.push(c);
+            count += 1;
+        }
+        (text, index)
+    }
+
+    /// Item at a path of indices below this one
+    pub fn item_at(&self, path: &[usize]) -> Option<&MenuItem> {
+        path.iter()
+            .try_fold(self, |item, index| item.items.get(*index))
+    }
+
+    /// First item with the given command, searching the whole submenu
+    pub fn find_command(&self, command: usize) -> Option<&MenuItem> {
+        self.items.iter().find_map(|item| {
+            if item.command == Some(command) {
+                Some(item)
+            } else {
+                item.find_command(command)
+            }
+        })
+    }
+
+    pub fn find_command_mut(&mut self, command: usize) -> Option<&mut MenuItem> {
+        self.items.iter_mut().find_map(|item| {
+            if item.command == Some(command) {
+                Some(item)
+            } else {
+                item.find_command_mut(command)
+            }
+        })
+    }
+}
@@ -0,0 +1,545 @@
+use crate::core::{
+    components::{
+        elements::menu_item::MenuItem,
+        properties::{
+            boundaries::Boundaries, graphics::color::Color, position::Position, size::Size,
+        },
+        styles::menu_style::MenuStyle,
+    },
+    ui::{
+        popup::{Popup, PopupPlacement},
+        render::render_context::RenderContext,
+    },
+    window::events::input::{InputEvent, Key, MouseButton},
+};
+
+/// What a `MenuPopup` did with an input event
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MenuAction {
+    // The input is not meant for the menu
+    Ignored,
+    Handled,
+    // An item was clicked, given as its path from the root; the menu closed
+    Invoked(Vec<usize>),
+    // Closed by Escape or by a press outside every popup
+    Dismissed,
+    // Right or Left pressed with nothing to open or close, for menu bars
+    Next,
+    Previous,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct MenuLevel {
+    // Path from the root to the item whose submenu this level shows
+    path: Vec<usize>,
+    popup: Popup,
+    highlighted: Option<usize>,
+}
+
+/// Cascade of drop-down popups showing a `MenuItem` tree, shared by menu bars
+/// and context menus. The owner keeps the items and passes them in on every
+/// call, so the cascade only holds which submenus are open.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct MenuPopup {
+    levels: Vec<MenuLevel>,
+    viewport: Option<Boundaries>,
+    show_mnemonics: bool,
+}
+
+impl MenuPopup {
+    /// Opens the submenu of `root` against `anchor`, optionally highlighting
+    /// its first item as keyboard navigation does
+    pub fn open(
+        &mut self,
+        root: &MenuItem,
+        style: &MenuStyle,
+        anchor: Boundaries,
+        placement: PopupPlacement,
+        highlight_first: bool,
+    ) {
+        self.levels.clear();
+        self.push_level(root, style, Vec::new(), anchor, placement, highlight_first);
+    }
+
+    pub fn close(&mut self) {
+        self.levels.clear();
+    }
+
+    /// Area the popups must stay inside, usually the window client area
+    pub fn set_viewport(&mut self, viewport: Boundaries) {
+        self.viewport = Some(viewport);
+    }
+
+    /// Underlines mnemonics, as Windows does when a menu is opened from the keyboard
+    pub fn set_show_mnemonics(&mut self, show_mnemonics: bool) {
+        self.show_mnemonics = show_mnemonics;
+    }
+
+    // Getters
+    pub fn is_open(&self) -> bool {
+        !self.levels.is_empty()
+    }
+
+    /// Number of popups open, the root one included
+    pub fn depth(&self) -> usize {
+        self.levels.len()
+    }
+
+    pub fn bounds(&self, depth: usize) -> Option<Boundaries> {
+        self.levels.get(depth)?.popup.bounds()
+    }
+
+    /// Path of the highlighted item of the innermost popup
+    pub fn highlighted_path(&self) -> Option<Vec<usize>> {
+        let level = self.levels.last()?;
+        let mut path = level.path.clone();
+        path.push(level.highlighted?);
+        Some(path)
+    }
+
+    pub fn show_mnemonics(&self) -> bool {
+        self.show_mnemonics
+    }
+
+    /// Row of an item inside the popup at `depth`
+    pub fn item_bounds(
+        &self,
+        root: &MenuItem,
+        style: &MenuStyle,
+        depth: usize,
+        index: usize,
+    ) -> Option<Boundaries> {
+        let level = self.levels.get(depth)?;
+        let bounds = level.popup.bounds()?;
+        let items = root.item_at(&level.path)?.items();
+        items.get(index)?;
+        let top: u16 = items[..index]
+            .iter()
+            .map(|item| Self::row_height(item, style))
+            .sum();
+        Some(Boundaries::from_rect(
+            Position::new(bounds.top_left().x() + 1, bounds.top_left().y() + 1 + top),
+            Size::new(
+                Self::row_height(&items[index], style),
+                bounds.width().saturating_sub(2),
+            ),
+        ))
+    }
+
+    // Layout
+    fn row_height(item: &MenuItem, style: &MenuStyle) -> u16 {
+        if item.is_separator() {
+            style.separator_height
+        } else {
+            style.item_height
+        }
+    }
+
+    fn popup_size(menu: &MenuItem, style: &MenuStyle) -> Size {
+        let items = menu.items();
+        let measure = |text: &str| style.typography.measure_text(text).width();
+        let text_width = items
+            .iter()
+            .map(|item| measure(&item.display_text()))
+            .max()
+            .unwrap_or_default();
+        let shortcut_width = items
+            .iter()
+            .filter_map(|item| item.shortcut())
+            .map(|shortcut| measure(&shortcut.to_string()))
+            .max()
+            .map_or(0, |width| width + style.shortcut_spacing);
+        let height: u16 = items.iter().map(|item| Self::row_height(item, style)).sum();
+
+        Size::new(
+            height + 2,
+            style.gutter_width + text_width + shortcut_width + style.arrow_width + 2,
+        )
+    }
+
+    fn push_level(
+        &mut self,
+        root: &MenuItem,
+        style: &MenuStyle,
+        path: Vec<usize>,
+        anchor: Boundaries,
+        placement: PopupPlacement,
+        highlight_first: bool,
+    ) {
+        let Some(menu) = root.item_at(&path) else {
+            return;
+        };
+        let mut popup = Popup::new(placement);
+        if let Some(viewport) = self.viewport {
+            popup.set_viewport(viewport);
+        }
+        popup.open(anchor, Self::popup_size(menu, style));
+
+        let highlighted = if highlight_first {
+            Self::step(menu.items(), None, true)
+        } else {
+            None
+        };
+        self.levels.push(MenuLevel {
+            path,
+            popup,
+            highlighted,
+        });
+    }
+
+    fn open_submenu(
+        &mut self,
+        root: &MenuItem,
+        style: &MenuStyle,
+        depth: usize,
+        index: usize,
+        highlight_first: bool,
+    ) {
+        let Some(anchor) = self.item_bounds(root, style, depth, index) else {
+            return;
+        };
+        let mut path = self.levels[depth].path.clone();
+        path.push(index);
+        if let Some(level) = self.levels.get_mut(depth + 1)
+            && level.path == path
+        {
+            if highlight_first && level.highlighted.is_none() {
+                let items = root.item_at(&path).map_or(&[][..], MenuItem::items);
+                level.highlighted = Self::step(items, None, true);
+            }
+            return;
+        }
+
+        self.levels.truncate(depth + 1);
+        self.push_level(
+            root,
+            style,
+            path,
+            anchor,
+            PopupPlacement::Right,
+            highlight_first,
+        );
+    }
+
+    // Next item that is not a separator, wrapping around the ends
+    fn step(items: &[MenuItem], from: Option<usize>, forward: bool) -> Option<usize> {
+        let count = items.len();
+        (1..=count)
+            .map(|offset| match (from, forward) {
+                (Some(from), true) => (from + offset) % count,
+                (Some(from), false) => (from + count * 2 - offset) % count,
+                (None, true) => offset - 1,
+                (None, false) => count - offset,
+            })
+            .find(|index| !items[*index].is_separator())
+    }
+
+    // Innermost popup under a point and the row at that point, if any
+    fn hit(
+        &self,
+        root: &MenuItem,
+        style: &MenuStyle,
+        point: Position,
+    ) -> Option<(usize, Option<usize>)> {
+        let depth = (0..self.levels.len())
+            .rev()
+            .find(|depth| self.levels[*depth].popup.contains(point))?;
+        let count = root.item_at(&self.levels[depth].path)?.items().len();
+        let row = (0..count).find(|index| {
+            self.item_bounds(root, style, depth, *index)
+                .is_some_and(|bounds| bounds.contains(point))
+        });
+        Some((depth, row))
+    }
+
+    // Enter on an item: opens its submenu or invokes it
+    fn activate(
+        &mut self,
+        root: &MenuItem,
+        style: &MenuStyle,
+        depth: usize,
+        index: usize,
+    ) -> MenuAction {
+        let mut path = self.levels[depth].path.clone();
+        path.push(index);
+        let Some(item) = root.item_at(&path) else {
+            return MenuAction::Handled;
+        };
+        if !item.selectable() {
+            return MenuAction::Handled;
+        }
+        if item.has_submenu() {
+            self.open_submenu(root, style, depth, index, true);
+            return MenuAction::Handled;
+        }
+        self.close();
+        MenuAction::Invoked(path)
+    }
+
+    fn handle_key(&mut self, root: &MenuItem, style: &MenuStyle, key: Key) -> MenuAction {
+        let depth = self.levels.len() - 1;
+        let Some(items) = root.item_at(&self.levels[depth].path).map(MenuItem::items) else {
+            return MenuAction::Handled;
+        };
+        let highlighted = self.levels[depth].highlighted;
+
+        match key {
+            Key::Up | Key::Down => {
+                self.levels[depth].highlighted = Self::step(items, highlighted, key == Key::Down);
+            }
+            Key::Home => self.levels[depth].highlighted = Self::step(items, None, true),
+            Key::End => self.levels[depth].highlighted = Self::step(items, None, false),
+            Key::Right => match highlighted {
+                Some(index) if items[index].has_submenu() && items[index].enabled() => {
+                    self.open_submenu(root, style, depth, index, true);
+                }
+                _ => return MenuAction::Next,
+            },
+            Key::Left if depth > 0 => {
+                self.levels.pop();
+            }
+            Key::Left => return MenuAction::Previous,
+            Key::Enter | Key::Space => {
+                if let Some(index) = highlighted {
+                    return self.activate(root, style, depth, index);
+                }
+            }
+            Key::Escape if depth > 0 => {
+                self.levels.pop();
+            }
+            Key::Escape => {
+                self.close();
+                return MenuAction::Dismissed;
+            }
+            _ => {}
+        }
+        MenuAction::Handled
+    }
+
+    // A mnemonic activates its item when it is unique in the popup, otherwise
+    // it cycles the highlight through the items sharing it
+    fn handle_mnemonic(&mut self, root: &MenuItem, style: &MenuStyle, c: char) -> MenuAction {
+        let depth = self.levels.len() - 1;
+        let Some(items) = root.item_at(&self.levels[depth].path).map(MenuItem::items) else {
+            return MenuAction::Handled;
+        };
+        let c = c.to_lowercase().next().unwrap_or(c);
+        let matches: Vec<usize> = (0..items.len())
+            .filter(|index| items[*index].selectable() && items[*index].mnemonic() == Some(c))
+            .collect();
+
+        match matches[..] {
+            [] => MenuAction::Handled,
+            [index] => self.activate(root, style, depth, index),
+            _ => {
+                let highlighted = self.levels[depth].highlighted;
+                let next = matches
+                    .iter()
+                    .copied()
+                    .find(|index| Some(*index) > highlighted)
+                    .unwrap_or(matches[0]);
+                self.levels[depth].highlighted = Some(next);
+                MenuAction::Handled
+            }
+        }
+    }
+
+    pub fn handle_input(
+        &mut self,
+        root: &MenuItem,
+        style: &MenuStyle,
+        input: &InputEvent,
+    ) -> MenuAction {
+        if !self.is_open() {
+            return MenuAction::Ignored;
+        }
+
+        match *input {
+            InputEvent::MouseMoved(position) => {
+                let Some((depth, row)) = self.hit(root, style, position) else {
+                    return MenuAction::Ignored;
+                };
+                self.levels.truncate(depth + 1);
+                let path = &self.levels[depth].path;
+                let items = root.item_at(path).map_or(&[][..], MenuItem::items);
+                let row = row.filter(|index| !items[*index].is_separator());
+                self.levels[depth].highlighted = row;
+                if let Some(index) = row
+                    && items[index].has_submenu()
+                    && items[index].enabled()
+                {
+                    self.open_submenu(root, style, depth, index, false);
+                }
+                MenuAction::Handled
+            }
+            InputEvent::MouseButtonPressed { position, .. } => {
+                if self.hit(root, style, position).is_some() {
+                    MenuAction::Handled
+                } else {
+                    self.close();
+                    MenuAction::Dismissed
+                }
+            }
+            // Items run on release, so press-drag-release through a menu works
+            InputEvent::MouseButtonReleased {
+                button: MouseButton::Left | MouseButton::Right,
+                position,
+                ..
+            } => match self.hit(root, style, position) {
+                Some((depth, Some(index))) => {
+                    let path = &self.levels[depth].path;
+                    let submenu = root
+                        .item_at(path)
+                        .and_then(|menu| menu.items().get(index))
+                        .is_some_and(MenuItem::has_submenu);
+                    if submenu {
+                        MenuAction::Handled
+                    } else {
+                        self.activate(root, style, depth, index)
+                    }
+                }
+                Some(_) => MenuAction::Handled,
+                None => MenuAction::Ignored,
+            },
+            InputEvent::KeyPressed { key, .. } => self.handle_key(root, style, key),
+            InputEvent::CharTyped(c) if !c.is_control() => self.handle_mnemonic(root, style, c),
+            // Menus are modal while open, the rest of the keyboard is swallowed
+            _ if input.is_keyboard() => MenuAction::Handled,
+            _ => MenuAction::Ignored,
+        }
+    }
+
+    pub fn render(&self, ctx: &mut RenderContext, root: &MenuItem, style: &MenuStyle) {
+        for (depth, level) in self.levels.iter().enumerate() {
+            let (Some(bounds), Some(menu)) = (level.popup.bounds(), root.item_at(&level.path))
+            else {
+                continue;
+            };
+
+            ctx.push_layer();
+            ctx.fill_rect(bounds, &style.popup_background);
+            ctx.stroke_rect(bounds, &style.popup_border);
+            for (index, item) in menu.items().iter().enumerate() {
+                if let Some(row) = self.item_bounds(root, style, depth, index) {
+                    self.render_item(ctx, style, item, row, level.highlighted == Some(index));
+                }
+            }
+        }
+        for _ in &self.levels {
+            ctx.pop_layer();
+        }
+    }
+
+    fn render_item(
+        &self,
+        ctx: &mut RenderContext,
+        style: &MenuStyle,
+        item: &MenuItem,
+        row: Boundaries,
+        highlighted: bool,
+    ) {
+        let (x, y) = (row.top_left().x(), row.top_left().y());
+        let right = row.bottom_right().x();
+        if item.is_separator() {
+            let middle = y + row.height() / 2;
+            ctx.draw_line(
+                Position::new(x + style.gutter_width, middle),
+                Position::new(right.saturating_sub(2), middle),
+                &style.separator_color,
+                1,
+            );
+            return;
+        }
+
+        if highlighted {
+            ctx.fill_rect(row, &style.highlight_background);
+            ctx.stroke_rect(row, &style.highlight_border);
+        }
+        let color = if item.enabled() {
+            &style.text_color
+        } else {
+            &style.disabled_text_color
+        };
+        let glyph = if item.enabled() {
+            &style.glyph_color
+        } else {
+            &style.disabled_text_color
+        };
+        let middle = y + row.height() / 2;
+
+        if item.checked() {
+            let cx = x + style.gutter_width / 2;
+            ctx.draw_line(
+                Position::new(cx.saturating_sub(4), middle),
+                Position::new(cx.saturating_sub(1), middle + 3),
+                glyph,
+                2,
+            );
+            ctx.draw_line(
+                Position::new(cx.saturating_sub(1), middle + 3),
+                Position::new(cx + 4, middle.saturating_sub(3)),
+                glyph,
+                2,
+            );
+        }
+
+        let text = item.display_text();
+        let text_size = style.typography.measure_text(&text);
+        let text_at = Position::new(
+            x + style.gutter_width,
+            y + row.height().saturating_sub(text_size.height()) / 2,
+        );
+        ctx.draw_text(text_at, &text, &style.typography, color);
+        if self.show_mnemonics
+            && let Some(index) = item.mnemonic_index()
+        {
+            render_mnemonic(ctx, style, text_at, index, color);
+        }
+
+        if let Some(shortcut) = item.shortcut() {
+            let label = shortcut.to_string();
+            let width = style.typography.measure_text(&label).width();
+            ctx.draw_text(
+                Position::new(right.saturating_sub(style.arrow_width + width), text_at.y()),
+                &label,
+                &style.typography,
+                color,
+            );
+        }
+
+        if item.has_submenu() {
+            let cx = right.saturating_sub(style.arrow_width / 2);
+            ctx.draw_line(
+                Position::new(cx.saturating_sub(2), middle.saturating_sub(4)),
+                Position::new(cx + 2, middle),
+                glyph,
+                1,
+            );
+            ctx.draw_line(
+                Position::new(cx + 2, middle),
+                Position::new(cx.saturating_sub(2), middle + 4),
+                glyph,
+                1,
+            );
+        }
+    }
+}
+
+/// Underline below the mnemonic character of a text drawn at `text_at`
+pub fn render_mnemonic(
+    ctx: &mut RenderContext,
+    style: &MenuStyle,
+    text_at: Position,
+    index: usize,
+    color: &Color,
+) {
+    let char_width = style.typography.char_width();
+    let x = text_at.x() + index as u16 * char_width;
+    let y = text_at.y() + style.typography.line_height().saturating_sub(1);
+    ctx.draw_line(
+        Position::new(x, y),
+        Position::new(x + char_width.saturating_sub(1), y),
+        color,
+        1,
+    );
+}
@@ -0,0 +1,435 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::{
+            menu_item::MenuItem,
+            menu_popup::{MenuAction, MenuPopup, render_mnemonic},
+        },
+        properties::{boundaries::Boundaries, position::Position, size::Size},
+        styles::menu_style::MenuStyle,
+    },
+    ui::{
+        accelerator_table::AcceleratorTable, popup::PopupPlacement,
+        render::render_context::RenderContext,
+    },
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::{InputEvent, Key, Modifiers},
+        types::{EventPayload, EventType},
+    },
+};
+
+/// Menu bar along the top of a window. Each top-level item opens a drop-down
+/// on click or with Alt plus its mnemonic, F10 moves the keyboard to the bar.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MenuStrip {
+    base: BaseComponent,
+    // Holds the top-level items, its own text is unused
+    root: MenuItem,
+    enabled: bool,
+    #[serde(default)]
+    style: MenuStyle,
+    #[serde(skip)]
+    popup: MenuPopup,
+    // Top-level item whose menu is open or that the keyboard sits on
+    #[serde(skip)]
+    active: Option<usize>,
+    #[serde(skip)]
+    hovered: Option<usize>,
+}
+
+impl MenuStrip {
+    pub fn new(base: BaseComponent) -> Self {
+        Self {
+            base,
+            root: MenuItem::new(""),
+            enabled: true,
+            style: MenuStyle::default(),
+            popup: MenuPopup::default(),
+            active: None,
+            hovered: None,
+        }
+    }
+
+    pub fn with_menu(mut self, menu: MenuItem) -> Self {
+        self.add_menu(menu);
+        self
+    }
+
+    pub fn with_style(mut self, style: MenuStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    // Setters
+    pub fn add_menu(&mut self, menu: MenuItem) {
+        self.root.add_item(menu);
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.close();
+        }
+    }
+
+    pub fn set_style(&mut self, style: MenuStyle) {
+        self.style = style;
+    }
+
+    /// Area the drop-downs must stay inside, usually the window client area
+    pub fn set_viewport(&mut self, viewport: Boundaries) {
+        self.popup.set_viewport(viewport);
+    }
+
+    /// Checks or unchecks every item bound to a command
+    pub fn set_checked(&mut self, command: usize, checked: bool) {
+        if let Some(item) = self.root.find_command_mut(command) {
+            item.set_checked(checked);
+        }
+    }
+
+    pub fn set_item_enabled(&mut self, command: usize, enabled: bool) {
+        if let Some(item) = self.root.find_command_mut(command) {
+            item.set_enabled(enabled);
+        }
+    }
+
+    /// Runs a command as if its item was clicked: checkable items toggle and
+    /// `CommandInvoked` is emitted. Disabled or unknown commands do nothing.
+    pub fn invoke(&mut self, command: usize) -> bool {
+        let Some(item) = self.root.find_command_mut(command) else {
+            return false;
+        };
+        if !item.selectable() || item.has_submenu() {
+            return false;
+        }
+
+        if item.checkable() {
+            item.set_checked(!item.checked());
+        }
+        self.base
+            .emit_with(EventType::CommandInvoked, EventPayload::Index(command));
+        true
+    }
+
+    /// Binds the shortcut of every item with a command
+    pub fn register_shortcuts(&self, table: &mut AcceleratorTable) {
+        table.register_menu(&self.root);
+    }
+
+    /// Opens the drop-down of a top-level item, highlighting its first entry
+    /// when opened from the keyboard
+    pub fn open_menu(&mut self, index: usize, keyboard: bool) {
+        let Some(anchor) = self.menu_bounds(index) else {
+            return;
+        };
+        let menu = &self.root.items()[index];
+        self.active = Some(index);
+        self.popup.set_show_mnemonics(keyboard);
+        if menu.has_submenu() && menu.enabled() {
+            self.popup
+                .open(menu, &self.style, anchor, PopupPlacement::Below, keyboard);
+        } else {
+            self.popup.close();
+        }
+    }
+
+    pub fn close(&mut self) {
+        self.popup.close();
+        self.popup.set_show_mnemonics(false);
+        self.active = None;
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn menus(&self) -> &[MenuItem] {
+        self.root.items()
+    }
+
+    pub fn item(&self, command: usize) -> Option<&MenuItem> {
+        self.root.find_command(command)
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn style(&self) -> &MenuStyle {
+        &self.style
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.popup.is_open()
+    }
+
+    pub fn active_menu(&self) -> Option<usize> {
+        self.active
+    }
+
+    /// Number of drop-downs open, submenus included
+    pub fn open_depth(&self) -> usize {
+        self.popup.depth()
+    }
+
+    pub fn highlighted_path(&self) -> Option<Vec<usize>> {
+        self.popup.highlighted_path()
+    }
+
+    /// Area of a top-level item on the bar
+    pub fn menu_bounds(&self, index: usize) -> Option<Boundaries> {
+        let frame = self.base.frame();
+        let mut x = frame.top_left().x();
+        for (i, menu) in self.root.items().iter().enumerate() {
+            let width = self
+                .style
+                .typography
+                .measure_text(&menu.display_text())
+                .width()
+                + self.style.bar_item_padding * 2;
+            if i == index {
+                return Some(Boundaries::from_rect(
+                    Position::new(x, frame.top_left().y()),
+                    Size::new(frame.height(), width),
+                ));
+            }
+            x += width;
+        }
+        None
+    }
+
+    /// Drop-down at `depth`, 0 being the one under the bar
+    pub fn popup_bounds(&self, depth: usize) -> Option<Boundaries> {
+        self.popup.bounds(depth)
+    }
+
+    /// Row of an item inside the drop-down at `depth`
+    pub fn item_bounds(&self, depth: usize, index: usize) -> Option<Boundaries> {
+        let menu = self.root.items().get(self.active?)?;
+        self.popup.item_bounds(menu, &self.style, depth, index)
+    }
+
+    fn menu_at(&self, point: Position) -> Option<usize> {
+        (0..self.root.items().len()).find(|index| {
+            self.menu_bounds(*index)
+                .is_some_and(|bounds| bounds.contains(point))
+        })
+    }
+
+    fn menu_with_mnemonic(&self, c: char) -> Option<usize> {
+        let c = c.to_lowercase().next().unwrap_or(c);
+        self.root
+            .items()
+            .iter()
+            .position(|menu| menu.enabled() && menu.mnemonic() == Some(c))
+    }
+
+    // Left or Right along the bar, wrapping around
+    fn step(&self, from: usize, forward: bool) -> usize {
+        let count = self.root.items().len();
+        if forward {
+            (from + 1) % count
+        } else {
+            (from + count - 1) % count
+        }
+    }
+
+    // Runs the item at a path below the top-level item `menu`
+    fn invoke_path(&mut self, menu: usize, path: &[usize]) {
+        let command = self.root.items()[menu]
+            .item_at(path)
+            .and_then(MenuItem::command);
+        if let Some(command) = command {
+            self.invoke(command);
+        }
+    }
+
+    fn handle_open(&mut self, active: usize, input: &InputEvent) -> bool {
+        let keyboard = self.popup.show_mnemonics();
+        let menu = &self.root.items()[active];
+        match self.popup.handle_input(menu, &self.style, input) {
+            MenuAction::Invoked(path) => {
+                self.close();
+                self.invoke_path(active, &path);
+                true
+            }
+            MenuAction::Next => {
+                self.open_menu(self.step(active, true), true);
+                true
+            }
+            MenuAction::Previous => {
+                self.open_menu(self.step(active, false), true);
+                true
+            }
+            // Escape leaves the bar active, a click away closes everything
+            MenuAction::Dismissed => {
+                if input.is_keyboard() {
+                    self.popup.set_show_mnemonics(true);
+                    return true;
+                }
+                self.close();
+                match input.mouse_position().and_then(|p| self.menu_at(p)) {
+                    Some(index) if index != active => self.open_menu(index, false),
+                    Some(_) => {}
+                    None => return false,
+                }
+                true
+            }
+            MenuAction::Handled => true,
+            // Sliding over the bar switches to another drop-down
+            MenuAction::Ignored => match *input {
+                InputEvent::MouseMoved(position) => {
+                    self.hovered = self.menu_at(position);
+                    if let Some(index) = self.hovered
+                        && index != active
+                    {
+                        self.open_menu(index, keyboard);
+                    }
+                    self.hovered.is_some()
+                }
+                _ => false,
+            },
+        }
+    }
+
+    // Bar active from the keyboard with no drop-down open
+    fn handle_bar_key(&mut self, active: usize, input: &InputEvent) -> bool {
+        match *input {
+            InputEvent::KeyPressed { key, .. } => {
+                match key {
+                    Key::Left => self.active = Some(self.step(active, false)),
+                    Key::Right => self.active = Some(self.step(active, true)),
+                    Key::Down | Key::Enter | Key::Space => self.open_menu(active, true),
+                    Key::Escape | Key::F(10) => self.close(),
+                    _ => {}
+                }
+                true
+            }
+            InputEvent::CharTyped(c) => {
+                if let Some(index) = self.menu_with_mnemonic(c) {
+                    self.open_menu(index, true);
+                }
+                true
+            }
+            _ => true,
+        }
+    }
+}
+
+impl Interactive for MenuStrip {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() || self.root.items().is_empty() {
+            return false;
+        }
+
+        // Alt plus a mnemonic opens a menu, even while another one is open
+        if let InputEvent::KeyPressed {
+            key: Key::Char(c),
+            modifiers: Modifiers { alt: true, .. },
+        } = *input
+            && let Some(index) = self.menu_with_mnemonic(c)
+        {
+            self.open_menu(index, true);
+            return true;
+        }
+
+        match self.active {
+            Some(active) if self.popup.is_open() => return self.handle_open(active, input),
+            Some(active) if input.is_keyboard() => return self.handle_bar_key(active, input),
+            _ => {}
+        }
+
+        match *input {
+            InputEvent::MouseMoved(position) => {
+                self.hovered = self.menu_at(position);
+                self.hovered.is_some()
+            }
+            InputEvent::MouseButtonPressed { position, .. } => {
+                let Some(index) = self.menu_at(position) else {
+                    self.close();
+                    return false;
+                };
+                let menu = &self.root.items()[index];
+                if menu.has_submenu() {
+                    self.open_menu(index, false);
+                } else if let Some(command) = menu.command() {
+                    self.close();
+                    self.invoke(command);
+                }
+                true
+            }
+            InputEvent::KeyPressed {
+                key: Key::F(10),
+                modifiers,
+            } if modifiers.is_empty() => {
+                self.active = Some(0);
+                self.popup.set_show_mnemonics(true);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Renderable for MenuStrip {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        let style = &self.style;
+        ctx.fill_rect(self.base.frame(), &style.bar_background);
+        for (index, menu) in self.root.items().iter().enumerate() {
+            let Some(bounds) = self.menu_bounds(index) else {
+                continue;
+            };
+            let highlighted = self.active == Some(index)
+                || (self.active.is_none() && self.hovered == Some(index));
+            if highlighted && self.enabled && menu.enabled() {
+                ctx.fill_rect(bounds, &style.bar_highlight_background);
+                ctx.stroke_rect(bounds, &style.bar_highlight_border);
+            }
+
+            let color = if self.enabled && menu.enabled() {
+                &style.text_color
+            } else {
+                &style.disabled_text_color
+            };
+            let text = menu.display_text();
+            let text_size = style.typography.measure_text(&text);
+            let text_at = Position::new(
+                bounds.top_left().x() + style.bar_item_padding,
+                bounds.top_left().y() + bounds.height().saturating_sub(text_size.height()) / 2,
+            );
+            ctx.draw_text(text_at, &text, &style.typography, color);
+            if self.popup.show_mnemonics()
+                && let Some(mnemonic) = menu.mnemonic_index()
+            {
+                render_mnemonic(ctx, style, text_at, mnemonic, color);
+            }
+        }
+
+        if let Some(menu) = self.active.and_then(|index| self.root.items().get(index)) {
+            self.popup.render(ctx, menu, style);
+        }
+    }
+}
+
+impl Component for MenuStrip {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
@@ -0,0 +1,148 @@
+use crate::core::{
+    components::{
+        elements::{dialog::Dialog, icon::Icon},
+        properties::dialog_result::DialogResult,
+        styles::dialog_style::DialogStyle,
+    },
+    ui::dialog_host::DialogHost,
+};
+
+/// Buttons of a message box, as in WinForms
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageBoxButtons {
+    #[default]
+    Ok,
+    OkCancel,
+    AbortRetryIgnore,
+    YesNoCancel,
+    YesNo,
+    RetryCancel,
+}
+
+impl MessageBoxButtons {
+    /// Results of the buttons, in the order they are shown
+    pub fn results(&self) -> &'static [DialogResult] {
+        match self {
+            MessageBoxButtons::Ok => &[DialogResult::Ok],
+            MessageBoxButtons::OkCancel => &[DialogResult::Ok, DialogResult::Cancel],
+            MessageBoxButtons::AbortRetryIgnore => &[
+                DialogResult::Abort,
+                DialogResult::Retry,
+                DialogResult::Ignore,
+            ],
+            MessageBoxButtons::YesNoCancel => {
+                &[DialogResult::Yes, DialogResult::No, DialogResult::Cancel]
+            }
+            MessageBoxButtons::YesNo => &[DialogResult::Yes, DialogResult::No],
+            MessageBoxButtons::RetryCancel => &[DialogResult::Retry, DialogResult::Cancel],
+        }
+    }
+
+    /// Result of Escape. A lone OK button also answers it, while boxes that
+    /// need a decision, like Yes/No, ignore it
+    pub fn cancel_result(&self) -> Option<DialogResult> {
+        match self {
+            MessageBoxButtons::Ok => Some(DialogResult::Ok),
+            MessageBoxButtons::AbortRetryIgnore | MessageBoxButtons::YesNo => None,
+            _ => Some(DialogResult::Cancel),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageBoxIcon {
+    #[default]
+    None,
+    Information,
+    Warning,
+    Error,
+    Question,
+}
+
+impl MessageBoxIcon {
+    pub fn icon(&self, style: &DialogStyle) -> Option<Icon> {
+        match self {
+            MessageBoxIcon::None => None,
+            MessageBoxIcon::Information => Some(style.information_icon.clone()),
+            MessageBoxIcon::Warning => Some(style.warning_icon.clone()),
+            MessageBoxIcon::Error => Some(style.error_icon.clone()),
+            MessageBoxIcon::Question => Some(style.question_icon.clone()),
+        }
+    }
+}
+
+/// Standard message box: a message with an icon and a set of buttons
+pub struct MessageBox;
+
+impl MessageBox {
+    /// The dialog `show` runs, for owners that drive it themselves
+    pub fn build(
+        host: &DialogHost,
+        title: &str,
+        text: &str,
+        buttons: MessageBoxButtons,
+        icon: MessageBoxIcon,
+    ) -> Dialog {
+        let mut dialog = host
+            .create_dialog(title)
+            .with_text(text)
+            .with_icon(icon.icon(host.style()));
+        for result in buttons.results() {
+            dialog.add_button(*result);
+        }
+
+        // The first button is the default one, as in Windows
+        dialog = dialog.with_accept_button(buttons.results()[0]);
+        match buttons.cancel_result() {
+            Some(cancel) => dialog.with_cancel_button(cancel),
+            None => dialog,
+        }
+    }
+
+    pub fn show(
+        host: &mut DialogHost,
+        title: &str,
+        text: &str,
+        buttons: MessageBoxButtons,
+        icon: MessageBoxIcon,
+    ) -> DialogResult {
+        let mut dialog = Self::build(host, title, text, buttons, icon);
+        host.show_dialog(&mut dialog)
+    }
+
+    /// Asks a yes or no question, true being yes
+    pub fn confirm(host: &mut DialogHost, title: &str, text: &str) -> bool {
+        let result = Self::show(
+            host,
+            title,
+            text,
+            MessageBoxButtons::YesNo,
+            MessageBoxIcon::Question,
+        );
+        result == DialogResult::Yes
+    }
+}
+
+/// Prompt for a line of text with OK and Cancel buttons
+pub struct InputBox;
+
+impl InputBox {
+    pub fn build(host: &DialogHost, title: &str, prompt: &str, default: &str) -> Dialog {
+        host.create_dialog(title)
+            .with_text(prompt)
+            .with_editor(default)
+            .with_button(DialogResult::Ok)
+            .with_button(DialogResult::Cancel)
+            .with_accept_button(DialogResult::Ok)
+            .with_cancel_button(DialogResult::Cancel)
+    }
+
+    /// The text typed, or `None` if the prompt was cancelled
+    pub fn show(host: &mut DialogHost, title: &str, prompt: &str, default: &str) -> Option<String> {
+        let mut dialog = Self::build(host, title, prompt, default);
+        match host.show_dialog(&mut dialog) {
+            DialogResult::Ok => dialog.input().map(str::to_string),
+            _ => None,
+        }
+    }
+}
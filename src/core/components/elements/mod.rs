@@ -1,3 +1,28 @@
 pub mod button;
+pub mod check_box;
+pub mod color_picker;
+pub mod combo_box;
+pub mod context_menu;
+pub mod data_grid;
+pub mod date_time_picker;
+pub mod dialog;
 pub mod icon;
 pub mod link;
+pub mod list_box;
+pub mod list_item;
+pub mod list_view;
+pub mod menu_item;
+pub mod menu_popup;
+pub mod menu_strip;
+pub mod message_box;
+pub mod numeric_up_down;
+pub mod picture_box;
+pub mod progress_bar;
+pub mod radio_button;
+pub mod slider;
+pub mod status_strip;
+pub mod text_box;
+pub mod tool_strip;
+pub mod tool_strip_item;
+pub mod tree_item;
+pub mod tree_view;
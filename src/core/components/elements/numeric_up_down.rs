@@ -0,0 +1,435 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::text_box::TextBox,
+        properties::{
+            boundaries::Boundaries,
+            graphics::{
+                action_state::ActionState,
+                background::{Background, BackgroundShape},
+            },
+            position::Position,
+            size::Size,
+        },
+        styles::numeric_up_down_style::NumericUpDownStyle,
+    },
+    ui::{
+        inline_editor::InlineEditor, range_model::RangeModel, render::render_context::RenderContext,
+    },
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::{InputEvent, Key, MouseButton},
+        types::{EventPayload, EventType},
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpinButton {
+    Up,
+    Down,
+}
+
+/// Text field holding a number from a `RangeModel`, with spin buttons on the
+/// right. Typed text is only applied on Enter or when the focus leaves;
+/// invalid text is replaced by the current value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "RawNumericUpDown")]
+pub struct NumericUpDown {
+    base: BaseComponent,
+    range: RangeModel,
+    // Digits shown after the decimal point; values are rounded to them
+    decimals: u8,
+    enabled: bool,
+    #[serde(default)]
+    style: NumericUpDownStyle,
+    #[serde(skip)]
+    editor: InlineEditor,
+    #[serde(skip)]
+    hovered: Option<SpinButton>,
+    #[serde(skip)]
+    pressed: Option<SpinButton>,
+    #[serde(skip)]
+    focused: bool,
+}
+
+// Serialized form of NumericUpDown so deserialized fields get their editor
+#[derive(Deserialize)]
+struct RawNumericUpDown {
+    base: BaseComponent,
+    range: RangeModel,
+    decimals: u8,
+    enabled: bool,
+    #[serde(default)]
+    style: NumericUpDownStyle,
+}
+
+impl From<RawNumericUpDown> for NumericUpDown {
+    fn from(raw: RawNumericUpDown) -> Self {
+        let mut numeric_up_down = Self::new(raw.base)
+            .with_range(raw.range)
+            .with_decimals(raw.decimals)
+            .with_style(raw.style);
+        numeric_up_down.enabled = raw.enabled;
+        numeric_up_down.editor.set_enabled(raw.enabled);
+        numeric_up_down
+    }
+}
+
+impl NumericUpDown {
+    pub fn new(base: BaseComponent) -> Self {
+        let mut numeric_up_down = Self {
+            base,
+            range: RangeModel::default(),
+            decimals: 0,
+            enabled: true,
+            style: NumericUpDownStyle::default(),
+            editor: InlineEditor::default(),
+            hovered: None,
+            pressed: None,
+            focused: false,
+        };
+        numeric_up_down.sync_editor();
+        numeric_up_down
+    }
+
+    pub fn with_range(mut self, range: RangeModel) -> Self {
+        self.range = range;
+        self.sync_editor();
+        self
+    }
+
+    pub fn with_decimals(mut self, decimals: u8) -> Self {
+        self.decimals = decimals;
+        self.sync_editor();
+        self
+    }
+
+    pub fn with_style(mut self, style: NumericUpDownStyle) -> Self {
+        self.style = style;
+        self.editor.clear();
+        self.sync_editor();
+        self
+    }
+
+    // Setters
+    pub fn set_value(&mut self, value: f64) {
+        let factor = 10f64.powi(self.decimals as i32);
+        let changed = self.range.set_value((value * factor).round() / factor);
+        self.sync_editor();
+        if changed {
+            self.base.emit_with(
+                EventType::FieldValueChanged,
+                EventPayload::Number(self.range.value()),
+            );
+        }
+    }
+
+    pub fn set_range(&mut self, range: RangeModel) {
+        let value = range.value();
+        self.range = range;
+        self.set_value(value);
+    }
+
+    pub fn set_decimals(&mut self, decimals: u8) {
+        self.decimals = decimals;
+        self.set_value(self.range.value());
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.hovered = None;
+            self.pressed = None;
+        }
+        self.editor.set_enabled(enabled);
+    }
+
+    pub fn set_style(&mut self, style: NumericUpDownStyle) {
+        self.style = style;
+        self.editor.clear();
+        self.sync_editor();
+    }
+
+    /// Moves the value by a number of small changes, as the spin buttons do
+    pub fn increment(&mut self, times: i32) {
+        self.commit_text();
+        let mut range = self.range;
+        range.increment(times);
+        self.set_value(range.value());
+    }
+
+    /// Applies the typed text, reverting it when it is not a number
+    pub fn commit_text(&mut self) {
+        let parsed = self
+            .editor
+            .text_box()
+            .and_then(|editor| editor.text().trim().parse::<f64>().ok());
+        match parsed {
+            Some(value) => self.set_value(value),
+            None => self.sync_editor(),
+        }
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn value(&self) -> f64 {
+        self.range.value()
+    }
+
+    pub fn range(&self) -> &RangeModel {
+        &self.range
+    }
+
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn style(&self) -> &NumericUpDownStyle {
+        &self.style
+    }
+
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Text in the field, which may differ from the value while typing
+    pub fn text(&self) -> &str {
+        self.editor.text()
+    }
+
+    pub fn editor(&self) -> Option<&TextBox> {
+        self.editor.text_box()
+    }
+
+    pub fn button_state(&self, button: SpinButton) -> ActionState {
+        if !self.enabled {
+            ActionState::Disabled
+        } else if self.pressed == Some(button) {
+            ActionState::Pressed
+        } else if self.hovered == Some(button) {
+            ActionState::Hovered
+        } else {
+            ActionState::Normal
+        }
+    }
+
+    pub fn button_bounds(&self, button: SpinButton) -> Boundaries {
+        let frame = self.base.frame();
+        let width = self.style.button_width;
+        let upper = frame.height() / 2;
+        let x = frame.bottom_right().x().saturating_sub(width);
+        match button {
+            SpinButton::Up => Boundaries::from_rect(
+                Position::new(x, frame.top_left().y()),
+                Size::new(upper, width),
+            ),
+            SpinButton::Down => Boundaries::from_rect(
+                Position::new(x, frame.top_left().y() + upper),
+                Size::new(frame.height() - upper, width),
+            ),
+        }
+    }
+
+    fn text_bounds(&self) -> Boundaries {
+        let frame = self.base.frame();
+        Boundaries::from_rect(
+            frame.top_left(),
+            frame
+                .size()
+                .with_width(frame.width().saturating_sub(self.style.button_width)),
+        )
+    }
+
+    fn button_at(&self, point: Position) -> Option<SpinButton> {
+        [SpinButton::Up, SpinButton::Down]
+            .into_iter()
+            .find(|button| self.button_bounds(*button).contains(point))
+    }
+
+    fn value_text(&self) -> String {
+        format!("{:.*}", self.decimals as usize, self.range.value())
+    }
+
+    // Keeps the editor in place and showing the value
+    fn sync_editor(&mut self) {
+        let text = self.value_text();
+        self.place_editor();
+        self.editor.show(&text);
+    }
+
+    // Moves the editor over the text area for the current frame
+    fn place_editor(&mut self) {
+        let bounds = self.text_bounds();
+        self.editor
+            .place(&self.base, &self.style.typography, bounds, "");
+    }
+
+    // Characters that can be part of a number in this range
+    fn accepts(&self, c: char) -> bool {
+        c.is_ascii_digit()
+            || (c == '-' && self.range.minimum() < 0.0)
+            || (c == '.' && self.decimals > 0)
+    }
+}
+
+impl Interactive for NumericUpDown {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() {
+            return false;
+        }
+        // The frame may have moved since the editor was placed
+        self.place_editor();
+
+        match *input {
+            InputEvent::MouseMoved(position) => {
+                self.hovered = self.button_at(position);
+                self.editor.handle_input(input) || self.hovered.is_some()
+            }
+            InputEvent::MouseButtonPressed {
+                button: MouseButton::Left,
+                position,
+                ..
+            } if self.base.hit_test(position) => {
+                match self.button_at(position) {
+                    Some(button) => {
+                        self.pressed = Some(button);
+                        self.increment(if button == SpinButton::Up { 1 } else { -1 });
+                    }
+                    None => {
+                        self.editor.handle_input(input);
+                    }
+                }
+                true
+            }
+            InputEvent::MouseButtonReleased {
+                button: MouseButton::Left,
+                ..
+            } => {
+                let pressed = self.pressed.take().is_some();
+                self.editor.handle_input(input) || pressed
+            }
+            InputEvent::MouseScrolled {
+                delta_y, position, ..
+            } if self.base.hit_test(position) => {
+                self.increment(delta_y as i32);
+                true
+            }
+            InputEvent::KeyPressed { key, modifiers } if self.focused && modifiers.is_empty() => {
+                match key {
+                    Key::Up => self.increment(1),
+                    Key::Down => self.increment(-1),
+                    Key::PageUp | Key::PageDown => {
+                        self.commit_text();
+                        let mut range = self.range;
+                        range.page(if key == Key::PageUp { 1 } else { -1 });
+                        self.set_value(range.value());
+                    }
+                    Key::Enter => self.commit_text(),
+                    _ => return self.editor.handle_input(input),
+                }
+                true
+            }
+            // Characters that cannot form a number are swallowed
+            InputEvent::CharTyped(c) if self.focused => {
+                if self.accepts(c) {
+                    self.editor.handle_input(input);
+                }
+                true
+            }
+            _ if self.focused => self.editor.handle_input(input),
+            _ => false,
+        }
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled && *self.base.visible()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        if self.focused == focused {
+            return;
+        }
+
+        self.focused = focused;
+        if !focused {
+            self.pressed = None;
+            self.commit_text();
+        }
+        self.editor.set_focused(focused);
+    }
+}
+
+impl Renderable for NumericUpDown {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        let style = &self.style;
+        let frame = self.base.frame();
+        if self.enabled {
+            ctx.fill_rect(frame, &style.background);
+        } else {
+            ctx.fill_rect(frame, &style.disabled_background);
+        }
+        self.editor.paint(ctx, self.text_bounds());
+
+        for button in [SpinButton::Up, SpinButton::Down] {
+            let bounds = self.button_bounds(button);
+            let state = self.button_state(button);
+            let fill =
+                Background::new_solid(style.button(&state).clone(), BackgroundShape::Rectangle);
+            ctx.fill_rect(bounds, &fill);
+
+            // Small chevron pointing up or down
+            let cx = bounds.top_left().x() + bounds.width() / 2;
+            let cy = bounds.top_left().y() + bounds.height() / 2;
+            let (tip, base) = match button {
+                SpinButton::Up => (cy.saturating_sub(2), cy + 1),
+                SpinButton::Down => (cy + 1, cy.saturating_sub(2)),
+            };
+            let color = style.arrow(&state);
+            ctx.draw_line(
+                Position::new(cx.saturating_sub(3), base),
+                Position::new(cx, tip),
+                color,
+                1,
+            );
+            ctx.draw_line(
+                Position::new(cx, tip),
+                Position::new(cx + 3, base),
+                color,
+                1,
+            );
+        }
+
+        if self.focused {
+            ctx.stroke_rect(frame, &style.focus_border);
+        } else {
+            ctx.stroke_rect(frame, &style.border);
+        }
+    }
+}
+
+impl Component for NumericUpDown {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
@@ -0,0 +1,280 @@
+use std::{cell::RefCell, rc::Rc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        properties::{boundaries::Boundaries, position::Position, size::Size},
+        styles::picture_box_style::PictureBoxStyle,
+    },
+    ui::{
+        image::{bitmap::Bitmap, decoder::ImageError, image_cache::ImageCache},
+        render::render_context::RenderContext,
+    },
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::input::InputEvent,
+};
+
+/// How the image is placed in the box, as `PictureBoxSizeMode` in WinForms
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PictureBoxSizeMode {
+    // At its own size from the top left corner, cut at the edges
+    #[default]
+    Normal,
+    // Stretched to fill the box
+    Stretch,
+    // As large as fits keeping its proportions, centered
+    Zoom,
+    // At its own size in the middle, cut at the edges
+    Center,
+    // The box resizes to the image
+    AutoSize,
+}
+
+// Where the image goes: its scaled size and the offset of its top left corner
+// from the content frame, negative when it starts outside
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Placement {
+    width: u32,
+    height: u32,
+    x: i64,
+    y: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PictureBox {
+    base: BaseComponent,
+    // Source of the image, loaded again through `reload` after deserializing
+    image_path: Option<String>,
+    size_mode: PictureBoxSizeMode,
+    #[serde(default)]
+    style: PictureBoxStyle,
+    #[serde(skip)]
+    image: Option<Rc<Bitmap>>,
+    // Last image sent to render with the placement and bounds it was cut for
+    #[serde(skip)]
+    rendered: RefCell<Option<(Placement, Boundaries, Rc<Bitmap>)>>,
+}
+
+impl PictureBox {
+    pub fn new(base: BaseComponent) -> Self {
+        Self {
+            base,
+            image_path: None,
+            size_mode: PictureBoxSizeMode::default(),
+            style: PictureBoxStyle::default(),
+            image: None,
+            rendered: RefCell::new(None),
+        }
+    }
+
+    pub fn with_image(mut self, image: Rc<Bitmap>) -> Self {
+        self.set_image(Some(image));
+        self
+    }
+
+    pub fn with_size_mode(mut self, size_mode: PictureBoxSizeMode) -> Self {
+        self.set_size_mode(size_mode);
+        self
+    }
+
+    pub fn with_style(mut self, style: PictureBoxStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    // Setters
+    /// Shows an image that did not come from a file
+    pub fn set_image(&mut self, image: Option<Rc<Bitmap>>) {
+        self.image_path = None;
+        self.image = image;
+        self.image_changed();
+    }
+
+    /// Shows the image at a path, keeping the current one if it cannot be read
+    pub fn load(&mut self, path: &str, cache: &mut ImageCache) -> Result<(), ImageError> {
+        let image = cache.load(path)?;
+        self.image_path = Some(path.to_string());
+        self.image = Some(image);
+        self.image_changed();
+        Ok(())
+    }
+
+    /// Loads the image from the stored path again, e.g. after deserializing
+    pub fn reload(&mut self, cache: &mut ImageCache) -> Result<(), ImageError> {
+        match self.image_path.clone() {
+            Some(path) => self.load(&path, cache),
+            None => Ok(()),
+        }
+    }
+
+    pub fn set_size_mode(&mut self, size_mode: PictureBoxSizeMode) {
+        self.size_mode = size_mode;
+        self.fit_to_image();
+    }
+
+    pub fn set_style(&mut self, style: PictureBoxStyle) {
+        self.style = style;
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn image(&self) -> Option<&Rc<Bitmap>> {
+        self.image.as_ref()
+    }
+
+    pub fn image_path(&self) -> Option<&str> {
+        self.image_path.as_deref()
+    }
+
+    pub fn size_mode(&self) -> PictureBoxSizeMode {
+        self.size_mode
+    }
+
+    pub fn style(&self) -> &PictureBoxStyle {
+        &self.style
+    }
+
+    /// Part of the content frame covered by the image
+    pub fn image_bounds(&self) -> Option<Boundaries> {
+        let placement = self.placement()?;
+        let content = self.base.content_frame();
+        let left = placement.x.max(0);
+        let top = placement.y.max(0);
+        let right = (placement.x + placement.width as i64).min(content.width() as i64);
+        let bottom = (placement.y + placement.height as i64).min(content.height() as i64);
+        (right > left && bottom > top).then(|| {
+            Boundaries::from_rect(
+                Position::new(
+                    content.top_left().x() + left as u16,
+                    content.top_left().y() + top as u16,
+                ),
+                Size::new((bottom - top) as u16, (right - left) as u16),
+            )
+        })
+    }
+
+    fn placement(&self) -> Option<Placement> {
+        let image = self.image.as_ref().filter(|image| !image.is_empty())?;
+        let content = self.base.content_frame();
+        let (frame_width, frame_height) = (content.width() as u32, content.height() as u32);
+        let (width, height) = match self.size_mode {
+            PictureBoxSizeMode::Stretch => (frame_width, frame_height),
+            PictureBoxSizeMode::Zoom => {
+                let scale = (frame_width as f64 / image.width() as f64)
+                    .min(frame_height as f64 / image.height() as f64);
+                (
+                    (image.width() as f64 * scale).round() as u32,
+                    (image.height() as f64 * scale).round() as u32,
+                )
+            }
+            _ => image.size(),
+        };
+        let centered = |frame: u32, length: u32| (frame as i64 - length as i64) / 2;
+        let (x, y) = match self.size_mode {
+            PictureBoxSizeMode::Zoom | PictureBoxSizeMode::Center => {
+                (centered(frame_width, width), centered(frame_height, height))
+            }
+            _ => (0, 0),
+        };
+        (width > 0 && height > 0).then_some(Placement {
+            width,
+            height,
+            x,
+            y,
+        })
+    }
+
+    // Scaled and cut image for the current placement, reused between frames
+    fn rendered_image(&self, placement: Placement, bounds: Boundaries) -> Rc<Bitmap> {
+        if let Some((cached, cut, bitmap)) = &*self.rendered.borrow()
+            && (*cached, *cut) == (placement, bounds)
+        {
+            return bitmap.clone();
+        }
+
+        let Some(image) = self.image.as_ref() else {
+            return Rc::new(Bitmap::new(0, 0));
+        };
+        let content = self.base.content_frame();
+        let scaled = if image.size() == (placement.width, placement.height) {
+            image.clone()
+        } else {
+            Rc::new(image.scaled(placement.width, placement.height))
+        };
+        let skip_x = (bounds.top_left().x() - content.top_left().x()) as i64 - placement.x;
+        let skip_y = (bounds.top_left().y() - content.top_left().y()) as i64 - placement.y;
+        let visible = (bounds.width() as u32, bounds.height() as u32);
+        let bitmap = if (skip_x, skip_y) == (0, 0) && visible == scaled.size() {
+            scaled
+        } else {
+            Rc::new(scaled.crop(skip_x as u32, skip_y as u32, visible.0, visible.1))
+        };
+        *self.rendered.borrow_mut() = Some((placement, bounds, bitmap.clone()));
+        bitmap
+    }
+
+    fn image_changed(&mut self) {
+        self.rendered.borrow_mut().take();
+        self.fit_to_image();
+    }
+
+    // AutoSize makes the content frame match the image
+    fn fit_to_image(&mut self) {
+        if self.size_mode != PictureBoxSizeMode::AutoSize {
+            return;
+        }
+        if let Some(image) = &self.image {
+            let padding = self.base.padding();
+            let clamp = |length: u32, extra: u16| {
+                (length.min(u16::MAX as u32) as u16).saturating_add(extra)
+            };
+            let size = Size::new(
+                clamp(image.height(), padding.top + padding.bottom),
+                clamp(image.width(), padding.left + padding.right),
+            );
+            self.base.set_size(size);
+        }
+    }
+}
+
+impl Interactive for PictureBox {
+    fn handle_input(&mut self, _input: &InputEvent) -> bool {
+        false
+    }
+}
+
+impl Renderable for PictureBox {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        let frame = self.base.frame();
+        ctx.fill_rect(frame, &self.style.background);
+        if let (Some(placement), Some(bounds)) = (self.placement(), self.image_bounds()) {
+            ctx.draw_image(bounds.top_left(), &self.rendered_image(placement, bounds));
+        }
+        if let Some(border) = &self.style.border {
+            ctx.stroke_rect(frame, border);
+        }
+    }
+}
+
+impl Component for PictureBox {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
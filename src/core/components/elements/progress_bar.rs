@@ -0,0 +1,213 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        properties::{boundaries::Boundaries, position::Position, size::Size},
+        styles::progress_bar_style::ProgressBarStyle,
+    },
+    ui::{range_model::RangeModel, render::render_context::RenderContext},
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::InputEvent,
+        types::{EventPayload, EventType},
+    },
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProgressMode {
+    // The fill shows the value of the range
+    #[default]
+    Determinate,
+    // A block slides across the bar while the amount of work is unknown
+    Indeterminate,
+}
+
+/// Horizontal bar showing the progress of an operation. It does not take
+/// input; the indeterminate animation advances through `tick`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressBar {
+    base: BaseComponent,
+    range: RangeModel,
+    mode: ProgressMode,
+    #[serde(default)]
+    style: ProgressBarStyle,
+    // Leading edge of the sliding block, in pixels from the left
+    #[serde(skip)]
+    marquee: f64,
+}
+
+impl ProgressBar {
+    pub fn new(base: BaseComponent) -> Self {
+        Self {
+            base,
+            range: RangeModel::default(),
+            mode: ProgressMode::default(),
+            style: ProgressBarStyle::default(),
+            marquee: 0.0,
+        }
+    }
+
+    pub fn with_range(mut self, range: RangeModel) -> Self {
+        self.range = range;
+        self
+    }
+
+    pub fn with_mode(mut self, mode: ProgressMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn with_style(mut self, style: ProgressBarStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    // Setters
+    pub fn set_value(&mut self, value: f64) {
+        if self.range.set_value(value) {
+            self.emit_value();
+        }
+    }
+
+    pub fn set_range(&mut self, range: RangeModel) {
+        let changed = range.value() != self.range.value();
+        self.range = range;
+        if changed {
+            self.emit_value();
+        }
+    }
+
+    /// Advances the value by the small change of the range, like
+    /// `PerformStep` in WinForms
+    pub fn perform_step(&mut self) {
+        if self.range.increment(1) {
+            self.emit_value();
+        }
+    }
+
+    pub fn set_mode(&mut self, mode: ProgressMode) {
+        self.mode = mode;
+        self.marquee = 0.0;
+    }
+
+    pub fn set_style(&mut self, style: ProgressBarStyle) {
+        self.style = style;
+    }
+
+    /// Moves the indeterminate animation forward by the time elapsed since the
+    /// previous tick, returning whether the bar needs repainting
+    pub fn tick(&mut self, elapsed: Duration) -> bool {
+        if self.mode != ProgressMode::Indeterminate || !self.base.visible() {
+            return false;
+        }
+
+        // The block enters from the left and leaves completely before wrapping
+        let cycle = (self.inner().width() + self.style.marquee_length) as f64;
+        if cycle <= 0.0 {
+            return false;
+        }
+        self.marquee =
+            (self.marquee + self.style.marquee_speed as f64 * elapsed.as_secs_f64()) % cycle;
+        true
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn value(&self) -> f64 {
+        self.range.value()
+    }
+
+    pub fn range(&self) -> &RangeModel {
+        &self.range
+    }
+
+    pub fn mode(&self) -> ProgressMode {
+        self.mode
+    }
+
+    pub fn style(&self) -> &ProgressBarStyle {
+        &self.style
+    }
+
+    // Frame without the border
+    fn inner(&self) -> Boundaries {
+        let frame = self.base.frame();
+        Boundaries::from_rect(
+            Position::new(frame.top_left().x() + 1, frame.top_left().y() + 1),
+            Size::new(
+                frame.height().saturating_sub(2),
+                frame.width().saturating_sub(2),
+            ),
+        )
+    }
+
+    /// Filled part of the bar, `None` when nothing is filled
+    pub fn fill_bounds(&self) -> Option<Boundaries> {
+        let inner = self.inner();
+        let (start, length) = match self.mode {
+            ProgressMode::Determinate => (
+                0,
+                (self.range.fraction() * inner.width() as f64).round() as u16,
+            ),
+            ProgressMode::Indeterminate => {
+                let lead = self.marquee as u16;
+                let start = lead.saturating_sub(self.style.marquee_length);
+                (start, lead.min(inner.width()).saturating_sub(start))
+            }
+        };
+        (length > 0).then(|| {
+            Boundaries::from_rect(
+                Position::new(inner.top_left().x() + start, inner.top_left().y()),
+                Size::new(inner.height(), length),
+            )
+        })
+    }
+
+    fn emit_value(&self) {
+        self.base.emit_with(
+            EventType::FieldValueChanged,
+            EventPayload::Number(self.range.value()),
+        );
+    }
+}
+
+impl Interactive for ProgressBar {
+    fn handle_input(&mut self, _input: &InputEvent) -> bool {
+        false
+    }
+}
+
+impl Renderable for ProgressBar {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        let frame = self.base.frame();
+        ctx.fill_rect(frame, &self.style.background);
+        if let Some(fill) = self.fill_bounds() {
+            ctx.fill_rect(fill, &self.style.fill);
+        }
+        ctx.stroke_rect(frame, &self.style.border);
+    }
+}
+
+impl Component for ProgressBar {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
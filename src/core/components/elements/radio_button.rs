@@ -0,0 +1,246 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        properties::{
+            boundaries::Boundaries,
+            graphics::{
+                action_state::ActionState,
+                background::{Background, BackgroundShape},
+                color::Color,
+            },
+            position::Position,
+            size::Size,
+        },
+        styles::toggle_style::ToggleStyle,
+    },
+    ui::{click_tracker::ClickTracker, render::render_context::RenderContext},
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::InputEvent,
+        types::{EventPayload, EventType},
+    },
+};
+
+/// A single option of a `RadioGroup`. Clicking it only ever checks it, the
+/// group is responsible for unchecking its siblings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RadioButton {
+    base: BaseComponent,
+    text: String,
+    checked: bool,
+    enabled: bool,
+    #[serde(default)]
+    style: ToggleStyle,
+    #[serde(skip)]
+    tracker: ClickTracker,
+    #[serde(skip)]
+    focused: bool,
+}
+
+impl RadioButton {
+    pub fn new(base: BaseComponent, text: &str) -> Self {
+        Self {
+            base,
+            text: text.to_string(),
+            checked: false,
+            enabled: true,
+            style: ToggleStyle::default(),
+            tracker: ClickTracker::new(),
+            focused: false,
+        }
+    }
+
+    pub fn with_checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    pub fn with_style(mut self, style: ToggleStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    // Setters
+    pub fn set_checked(&mut self, checked: bool) {
+        if self.checked != checked {
+            self.checked = checked;
+            self.base
+                .emit_with(EventType::FieldValueChanged, EventPayload::Bool(checked));
+        }
+    }
+
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if self.enabled != enabled {
+            self.enabled = enabled;
+            self.tracker.reset();
+        }
+    }
+
+    pub fn set_style(&mut self, style: ToggleStyle) {
+        self.style = style;
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn checked(&self) -> bool {
+        self.checked
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn style(&self) -> &ToggleStyle {
+        &self.style
+    }
+
+    pub fn state(&self) -> ActionState {
+        if !self.enabled {
+            ActionState::Disabled
+        } else {
+            self.tracker.state()
+        }
+    }
+
+    /// Bounds of the round glyph, vertically centered in the content frame
+    pub fn glyph_bounds(&self) -> Boundaries {
+        let content = self.base.content_frame();
+        let size = self.style.box_size;
+        let y = content
+            .top_left()
+            .y()
+            .saturating_add(content.height().saturating_sub(size) / 2);
+
+        Boundaries::from_rect(
+            Position::new(content.top_left().x(), y),
+            Size::new(size, size),
+        )
+    }
+}
+
+impl Interactive for RadioButton {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() {
+            return false;
+        }
+
+        let response =
+            self.tracker
+                .handle_input(input, |point| self.base.hit_test(point), self.focused);
+        match response.hover_changed {
+            Some(true) => self.base.emit(EventType::HoverEntered),
+            Some(false) => self.base.emit(EventType::HoverExited),
+            None => {}
+        }
+        if response.clicked {
+            self.set_checked(true);
+        }
+        response.consumed
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled && *self.base.visible()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        if !focused {
+            self.tracker.release_key();
+        }
+    }
+}
+
+impl Renderable for RadioButton {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        let state = self.state();
+        let glyph = self.glyph_bounds();
+        let (x, y) = (glyph.top_left().x(), glyph.top_left().y());
+        let size = self.style.box_size;
+        let circle = |inset: u16, color: &Color| {
+            let diameter = size.saturating_sub(inset * 2);
+            (
+                Boundaries::from_rect(
+                    Position::new(x + inset, y + inset),
+                    Size::new(diameter, diameter),
+                ),
+                Background::new_solid(
+                    color.clone(),
+                    BackgroundShape::Circle {
+                        radius: diameter as f32 / 2.0,
+                    },
+                ),
+            )
+        };
+
+        // Outer ring in the border color, then the fill, then the dot
+        let (bounds, ring) = circle(0, self.style.border.color());
+        ctx.fill_rect(bounds, &ring);
+        let (bounds, fill) = circle(1, self.style.fill(&state));
+        ctx.fill_rect(bounds, &fill);
+        if self.checked {
+            let (bounds, dot) = circle(4, self.style.mark(&state));
+            ctx.fill_rect(bounds, &dot);
+        }
+
+        if !self.text.is_empty() {
+            let typography = &self.style.typography;
+            let text_size = typography.measure_text(&self.text);
+            let content = self.base.content_frame();
+            let text_at = Position::new(
+                x.saturating_add(size).saturating_add(self.style.spacing),
+                content
+                    .top_left()
+                    .y()
+                    .saturating_add(content.height().saturating_sub(text_size.height()) / 2),
+            );
+
+            ctx.draw_text(
+                text_at,
+                &self.text,
+                typography,
+                self.style.text_color(&state),
+            );
+            if self.focused {
+                ctx.stroke_rect(
+                    Boundaries::from_rect(text_at, text_size),
+                    &self.style.focus_border,
+                );
+            }
+        }
+    }
+}
+
+impl Component for RadioButton {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
@@ -0,0 +1,455 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        properties::{
+            boundaries::Boundaries,
+            graphics::{
+                action_state::ActionState,
+                background::{Background, BackgroundShape},
+            },
+            orientation::Orientation,
+            position::Position,
+            size::Size,
+        },
+        styles::slider_style::SliderStyle,
+    },
+    ui::{range_model::RangeModel, render::render_context::RenderContext},
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::{InputEvent, Key, MouseButton},
+        types::{EventPayload, EventType},
+    },
+};
+
+/// Side of the track where tick marks are drawn, top or left depending on
+/// the orientation
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TickStyle {
+    None,
+    TopLeft,
+    #[default]
+    BottomRight,
+    Both,
+}
+
+/// Thumb sliding along a track to pick a value from a `RangeModel`. Vertical
+/// sliders have their minimum at the bottom, like the WinForms TrackBar.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Slider {
+    base: BaseComponent,
+    range: RangeModel,
+    orientation: Orientation,
+    tick_style: TickStyle,
+    // Distance between tick marks in value units, 0 hides them
+    tick_frequency: f64,
+    enabled: bool,
+    #[serde(default)]
+    style: SliderStyle,
+    #[serde(skip)]
+    hovered: bool,
+    // Distance from the pointer to the thumb center while it is dragged
+    #[serde(skip)]
+    drag: Option<i32>,
+    #[serde(skip)]
+    focused: bool,
+}
+
+pub type TrackBar = Slider;
+
+impl Slider {
+    pub fn new(base: BaseComponent) -> Self {
+        Self {
+            base,
+            range: RangeModel::default(),
+            orientation: Orientation::Horizontal,
+            tick_style: TickStyle::default(),
+            tick_frequency: 10.0,
+            enabled: true,
+            style: SliderStyle::default(),
+            hovered: false,
+            drag: None,
+            focused: false,
+        }
+    }
+
+    pub fn with_range(mut self, range: RangeModel) -> Self {
+        self.range = range;
+        self
+    }
+
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn with_tick_style(mut self, tick_style: TickStyle) -> Self {
+        self.tick_style = tick_style;
+        self
+    }
+
+    pub fn with_tick_frequency(mut self, tick_frequency: f64) -> Self {
+        self.tick_frequency = tick_frequency.abs();
+        self
+    }
+
+    pub fn with_style(mut self, style: SliderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    // Setters
+    pub fn set_value(&mut self, value: f64) {
+        if self.range.set_value(value) {
+            self.emit_value();
+        }
+    }
+
+    /// Replaces the range model, emitting if that moves the value
+    pub fn set_range(&mut self, range: RangeModel) {
+        let changed = range.value() != self.range.value();
+        self.range = range;
+        if changed {
+            self.emit_value();
+        }
+    }
+
+    pub fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    pub fn set_tick_style(&mut self, tick_style: TickStyle) {
+        self.tick_style = tick_style;
+    }
+
+    pub fn set_tick_frequency(&mut self, tick_frequency: f64) {
+        self.tick_frequency = tick_frequency.abs();
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.drag = None;
+            self.hovered = false;
+        }
+    }
+
+    pub fn set_style(&mut self, style: SliderStyle) {
+        self.style = style;
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn value(&self) -> f64 {
+        self.range.value()
+    }
+
+    pub fn range(&self) -> &RangeModel {
+        &self.range
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    pub fn tick_style(&self) -> TickStyle {
+        self.tick_style
+    }
+
+    pub fn tick_frequency(&self) -> f64 {
+        self.tick_frequency
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn style(&self) -> &SliderStyle {
+        &self.style
+    }
+
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn state(&self) -> ActionState {
+        if !self.enabled {
+            ActionState::Disabled
+        } else if self.drag.is_some() {
+            ActionState::Pressed
+        } else if self.hovered {
+            ActionState::Hovered
+        } else {
+            ActionState::Normal
+        }
+    }
+
+    /// Distance the thumb center can travel
+    fn travel(&self) -> u16 {
+        let content = self.base.content_frame();
+        let length = match self.orientation {
+            Orientation::Horizontal => content.width(),
+            Orientation::Vertical => content.height(),
+        };
+        length.saturating_sub(self.style.thumb_length)
+    }
+
+    // Thumb center along the main axis for a value
+    fn offset_of(&self, value: f64) -> u16 {
+        let content = self.base.content_frame();
+        let fraction = self.range.fraction_of(value);
+        let half = self.style.thumb_length / 2;
+        let along = (fraction * self.travel() as f64).round() as u16;
+        match self.orientation {
+            Orientation::Horizontal => content.top_left().x() + half + along,
+            Orientation::Vertical => content.top_left().y() + half + self.travel() - along,
+        }
+    }
+
+    // Value whose thumb center sits at a coordinate of the main axis
+    fn value_at_offset(&self, offset: i32) -> f64 {
+        let content = self.base.content_frame();
+        let half = (self.style.thumb_length / 2) as i32;
+        let travel = self.travel().max(1) as f64;
+        let fraction = match self.orientation {
+            Orientation::Horizontal => {
+                (offset - content.top_left().x() as i32 - half) as f64 / travel
+            }
+            Orientation::Vertical => {
+                1.0 - (offset - content.top_left().y() as i32 - half) as f64 / travel
+            }
+        };
+        self.range.value_at(fraction)
+    }
+
+    fn along(&self, point: Position) -> i32 {
+        match self.orientation {
+            Orientation::Horizontal => point.x() as i32,
+            Orientation::Vertical => point.y() as i32,
+        }
+    }
+
+    /// Center of the content frame across the main axis
+    fn cross_center(&self) -> u16 {
+        let content = self.base.content_frame();
+        match self.orientation {
+            Orientation::Horizontal => content.top_left().y() + content.height() / 2,
+            Orientation::Vertical => content.top_left().x() + content.width() / 2,
+        }
+    }
+
+    pub fn track_bounds(&self) -> Boundaries {
+        let content = self.base.content_frame();
+        let half = self.style.thumb_length / 2;
+        let thickness = self.style.track_thickness;
+        let cross = self.cross_center().saturating_sub(thickness / 2);
+        match self.orientation {
+            Orientation::Horizontal => Boundaries::from_rect(
+                Position::new(content.top_left().x() + half, cross),
+                Size::new(thickness, self.travel()),
+            ),
+            Orientation::Vertical => Boundaries::from_rect(
+                Position::new(cross, content.top_left().y() + half),
+                Size::new(self.travel(), thickness),
+            ),
+        }
+    }
+
+    pub fn thumb_bounds(&self) -> Boundaries {
+        let center = self.offset_of(self.range.value());
+        let half = self.style.thumb_length / 2;
+        let cross = self
+            .cross_center()
+            .saturating_sub(self.style.thumb_thickness / 2);
+        match self.orientation {
+            Orientation::Horizontal => Boundaries::from_rect(
+                Position::new(center - half, cross),
+                Size::new(self.style.thumb_thickness, self.style.thumb_length),
+            ),
+            Orientation::Vertical => Boundaries::from_rect(
+                Position::new(cross, center - half),
+                Size::new(self.style.thumb_length, self.style.thumb_thickness),
+            ),
+        }
+    }
+
+    /// Positions of the tick marks along the main axis, from the minimum up
+    pub fn ticks(&self) -> Vec<u16> {
+        if self.tick_style == TickStyle::None || self.tick_frequency <= 0.0 {
+            return Vec::new();
+        }
+
+        let count = (self.range.span() / self.tick_frequency).floor() as usize;
+        let mut ticks: Vec<u16> = (0..=count)
+            .map(|i| self.offset_of(self.range.minimum() + i as f64 * self.tick_frequency))
+            .collect();
+        let last = self.offset_of(self.range.maximum());
+        if ticks.last() != Some(&last) {
+            ticks.push(last);
+        }
+        ticks
+    }
+
+    fn emit_value(&self) {
+        self.base.emit_with(
+            EventType::FieldValueChanged,
+            EventPayload::Number(self.range.value()),
+        );
+    }
+
+    fn update(&mut self, changed: bool) {
+        if changed {
+            self.emit_value();
+        }
+    }
+
+    fn handle_key(&mut self, key: Key) -> bool {
+        let changed = match key {
+            Key::Right | Key::Up => self.range.increment(1),
+            Key::Left | Key::Down => self.range.increment(-1),
+            Key::PageUp => self.range.page(1),
+            Key::PageDown => self.range.page(-1),
+            Key::Home => self.range.set_to_minimum(),
+            Key::End => self.range.set_to_maximum(),
+            _ => return false,
+        };
+        self.update(changed);
+        true
+    }
+}
+
+impl Interactive for Slider {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() {
+            return false;
+        }
+
+        match *input {
+            InputEvent::MouseMoved(position) => {
+                self.hovered = self.thumb_bounds().contains(position);
+                if let Some(grab) = self.drag {
+                    let value = self.value_at_offset(self.along(position) - grab);
+                    let changed = self.range.set_value(value);
+                    self.update(changed);
+                    return true;
+                }
+                self.hovered
+            }
+            InputEvent::MouseButtonPressed {
+                button: MouseButton::Left,
+                position,
+                ..
+            } if self.base.hit_test(position) => {
+                let thumb = self.thumb_bounds();
+                let center = self.offset_of(self.range.value()) as i32;
+                if thumb.contains(position) {
+                    self.drag = Some(self.along(position) - center);
+                } else {
+                    // Clicks on the track page toward the pointer
+                    let toward = self.along(position) > center;
+                    let forward = match self.orientation {
+                        Orientation::Horizontal => toward,
+                        Orientation::Vertical => !toward,
+                    };
+                    let changed = self.range.page(if forward { 1 } else { -1 });
+                    self.update(changed);
+                }
+                true
+            }
+            InputEvent::MouseButtonReleased {
+                button: MouseButton::Left,
+                ..
+            } => self.drag.take().is_some(),
+            InputEvent::MouseScrolled {
+                delta_y, position, ..
+            } if self.base.hit_test(position) => {
+                let changed = self.range.increment(delta_y as i32);
+                self.update(changed);
+                true
+            }
+            InputEvent::KeyPressed { key, modifiers } if self.focused && modifiers.is_empty() => {
+                self.handle_key(key)
+            }
+            _ => false,
+        }
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled && *self.base.visible()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        if !focused {
+            self.drag = None;
+        }
+    }
+}
+
+impl Renderable for Slider {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        let style = &self.style;
+        let track = self.track_bounds();
+        ctx.fill_rect(track, &style.track_background);
+        ctx.stroke_rect(track, &style.track_border);
+
+        // Ticks sit past the thumb on the chosen sides
+        let center = self.cross_center();
+        let reach = style.thumb_thickness / 2 + style.tick_spacing;
+        let sides: &[bool] = match self.tick_style {
+            TickStyle::None => &[],
+            TickStyle::TopLeft => &[true],
+            TickStyle::BottomRight => &[false],
+            TickStyle::Both => &[true, false],
+        };
+        for tick in self.ticks() {
+            for before in sides {
+                let (start, end) = if *before {
+                    let start = center.saturating_sub(reach + style.tick_length);
+                    (start, start + style.tick_length)
+                } else {
+                    (center + reach, center + reach + style.tick_length)
+                };
+                let (from, to) = match self.orientation {
+                    Orientation::Horizontal => {
+                        (Position::new(tick, start), Position::new(tick, end))
+                    }
+                    Orientation::Vertical => (Position::new(start, tick), Position::new(end, tick)),
+                };
+                ctx.draw_line(from, to, &style.tick_color, 1);
+            }
+        }
+
+        let thumb = self.thumb_bounds();
+        let fill = Background::new_solid(
+            style.thumb(&self.state()).clone(),
+            BackgroundShape::Rectangle,
+        );
+        ctx.fill_rect(thumb, &fill);
+        if self.focused {
+            ctx.stroke_rect(self.base.frame(), &style.focus_border);
+        }
+    }
+}
+
+impl Component for Slider {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
@@ -0,0 +1,469 @@
+use std::{borrow::Cow, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::{icon::Icon, progress_bar::ProgressBar},
+        layouts::container::Container,
+        properties::{
+            boundaries::Boundaries,
+            orientation::Orientation,
+            position::Position,
+            size::{Size, SizePolicy},
+        },
+        styles::status_strip_style::StatusStripStyle,
+    },
+    ui::{elements::theme::Theme, render::render_context::RenderContext},
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::input::InputEvent,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum StatusPanel {
+    Label {
+        text: String,
+        icon: Option<Icon>,
+        // Fixed width; without one the panel fits its content
+        width: Option<u16>,
+        // Takes the space the other panels leave, as `Spring` in WinForms
+        spring: bool,
+    },
+    Progress(Box<ProgressBar>),
+}
+
+/// Bar docked along the bottom of a window showing text panels and progress
+/// cells side by side, with a sizing grip in the corner. Panels are laid out
+/// left to right by `Container::arrange`; spring panels share the free space.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusStrip {
+    base: BaseComponent,
+    panels: Vec<StatusPanel>,
+    sizing_grip: bool,
+    #[serde(default)]
+    style: StatusStripStyle,
+    // Frame of every panel, filled in by `refresh`
+    #[serde(skip)]
+    bounds: Vec<Boundaries>,
+    // Frame of the strip when `bounds` was filled in
+    #[serde(skip)]
+    laid_out: Option<Boundaries>,
+}
+
+impl StatusStrip {
+    pub fn new(base: BaseComponent) -> Self {
+        Self {
+            base,
+            panels: Vec::new(),
+            sizing_grip: true,
+            style: StatusStripStyle::default(),
+            bounds: Vec::new(),
+            laid_out: None,
+        }
+    }
+
+    pub fn with_label(mut self, text: &str) -> Self {
+        self.add_label(text);
+        self
+    }
+
+    pub fn with_spring(mut self, text: &str) -> Self {
+        self.add_spring(text);
+        self
+    }
+
+    pub fn with_progress(mut self, bar: ProgressBar) -> Self {
+        self.add_progress(bar);
+        self
+    }
+
+    pub fn with_sizing_grip(mut self, sizing_grip: bool) -> Self {
+        self.set_sizing_grip(sizing_grip);
+        self
+    }
+
+    pub fn with_style(mut self, style: StatusStripStyle) -> Self {
+        self.set_style(style);
+        self
+    }
+
+    pub fn with_theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// Adds a panel sized to its text, returning its index
+    pub fn add_label(&mut self, text: &str) -> usize {
+        self.add_panel(StatusPanel::Label {
+            text: text.to_string(),
+            icon: None,
+            width: None,
+            spring: false,
+        })
+    }
+
+    /// Adds a panel that stretches over the space left by the others
+    pub fn add_spring(&mut self, text: &str) -> usize {
+        self.add_panel(StatusPanel::Label {
+            text: text.to_string(),
+            icon: None,
+            width: None,
+            spring: true,
+        })
+    }
+
+    /// Adds a progress cell as wide as the bar, which takes the strip's style
+    pub fn add_progress(&mut self, mut bar: ProgressBar) -> usize {
+        bar.set_style(self.style.progress.clone());
+        self.add_panel(StatusPanel::Progress(Box::new(bar)))
+    }
+
+    pub fn add_panel(&mut self, panel: StatusPanel) -> usize {
+        self.panels.push(panel);
+        self.refresh();
+        self.panels.len() - 1
+    }
+
+    pub fn remove_panel(&mut self, index: usize) -> Option<StatusPanel> {
+        if index >= self.panels.len() {
+            return None;
+        }
+        let removed = self.panels.remove(index);
+        self.refresh();
+        Some(removed)
+    }
+
+    // Setters
+    pub fn set_text(&mut self, index: usize, text: &str) {
+        if let Some(StatusPanel::Label { text: current, .. }) = self.panels.get_mut(index) {
+            *current = text.to_string();
+            self.refresh();
+        }
+    }
+
+    pub fn set_icon(&mut self, index: usize, icon: Option<Icon>) {
+        if let Some(StatusPanel::Label { icon: current, .. }) = self.panels.get_mut(index) {
+            *current = icon;
+            self.refresh();
+        }
+    }
+
+    pub fn set_width(&mut self, index: usize, width: Option<u16>) {
+        if let Some(StatusPanel::Label { width: current, .. }) = self.panels.get_mut(index) {
+            *current = width;
+            self.refresh();
+        }
+    }
+
+    /// Moves the progress cell at `index`
+    pub fn set_value(&mut self, index: usize, value: f64) {
+        if let Some(bar) = self.progress_mut(index) {
+            bar.set_value(value);
+        }
+    }
+
+    pub fn set_sizing_grip(&mut self, sizing_grip: bool) {
+        self.sizing_grip = sizing_grip;
+        self.refresh();
+    }
+
+    pub fn set_style(&mut self, style: StatusStripStyle) {
+        for panel in &mut self.panels {
+            if let StatusPanel::Progress(bar) = panel {
+                bar.set_style(style.progress.clone());
+            }
+        }
+        self.style = style;
+        self.refresh();
+    }
+
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.set_style(theme.status_strip.clone());
+    }
+
+    /// Stretches the strip along the bottom edge of `viewport`
+    pub fn dock(&mut self, viewport: Boundaries) {
+        let height = self.style.height.min(viewport.height());
+        self.base.set_position(Position::new(
+            viewport.top_left().x(),
+            viewport.bottom_right().y() - height,
+        ));
+        self.base.set_size(Size::new(height, viewport.width()));
+        self.refresh();
+    }
+
+    /// Re-lays out the panels. Rendering paints them where they would go
+    /// once the frame has changed
+    pub fn refresh(&mut self) {
+        self.laid_out = Some(self.base.frame());
+        self.bounds = self.panel_frames();
+        for (panel, bounds) in self.panels.iter_mut().zip(&self.bounds) {
+            if let StatusPanel::Progress(bar) = panel {
+                bar.base_mut().set_position(bounds.top_left());
+                bar.base_mut().set_size(bounds.size());
+            }
+        }
+    }
+
+    /// Advances indeterminate progress cells, returning whether any needs
+    /// repainting
+    pub fn tick(&mut self, elapsed: Duration) -> bool {
+        let mut changed = false;
+        for panel in &mut self.panels {
+            if let StatusPanel::Progress(bar) = panel {
+                changed |= bar.tick(elapsed);
+            }
+        }
+        changed
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn panels(&self) -> &[StatusPanel] {
+        &self.panels
+    }
+
+    pub fn panel(&self, index: usize) -> Option<&StatusPanel> {
+        self.panels.get(index)
+    }
+
+    pub fn text(&self, index: usize) -> Option<&str> {
+        match self.panels.get(index)? {
+            StatusPanel::Label { text, .. } => Some(text),
+            StatusPanel::Progress(_) => None,
+        }
+    }
+
+    pub fn progress(&self, index: usize) -> Option<&ProgressBar> {
+        match self.panels.get(index)? {
+            StatusPanel::Progress(bar) => Some(bar),
+            StatusPanel::Label { .. } => None,
+        }
+    }
+
+    pub fn progress_mut(&mut self, index: usize) -> Option<&mut ProgressBar> {
+        match self.panels.get_mut(index)? {
+            StatusPanel::Progress(bar) => Some(bar),
+            StatusPanel::Label { .. } => None,
+        }
+    }
+
+    pub fn sizing_grip(&self) -> bool {
+        self.sizing_grip
+    }
+
+    pub fn style(&self) -> &StatusStripStyle {
+        &self.style
+    }
+
+    pub fn panel_bounds(&self, index: usize) -> Option<Boundaries> {
+        self.bounds.get(index).copied()
+    }
+
+    /// Corner where the sizing grip is drawn, `None` when it is hidden
+    pub fn grip_bounds(&self) -> Option<Boundaries> {
+        if !self.sizing_grip {
+            return None;
+        }
+        let frame = self.base.frame();
+        let size = self.style.grip_size.min(frame.height()).min(frame.width());
+        Some(Boundaries::from_rect(
+            Position::new(
+                frame.bottom_right().x() - size,
+                frame.bottom_right().y() - size,
+            ),
+            Size::new(size, size),
+        ))
+    }
+
+    // Moved or resized since the last refresh, or just deserialized
+    fn stale(&self) -> bool {
+        self.laid_out != Some(self.base.frame())
+    }
+
+    // Frame below the top border and left of the sizing grip
+    fn panel_area(&self) -> Boundaries {
+        let frame = self.base.frame();
+        let border = self.style.border.width().ceil() as u16;
+        let grip = self.grip_bounds().map_or(0, |grip| grip.width());
+        Boundaries::from_rect(
+            Position::new(frame.top_left().x(), frame.top_left().y() + border),
+            Size::new(
+                frame.height().saturating_sub(border),
+                frame.width().saturating_sub(grip),
+            ),
+        )
+    }
+
+    // Frame of every panel at the current frame
+    fn panel_frames(&self) -> Vec<Boundaries> {
+        let items: Vec<(Size, SizePolicy)> = self
+            .panels
+            .iter()
+            .map(|panel| self.measure(panel))
+            .collect();
+        Container::arrange(
+            self.panel_area(),
+            Orientation::Horizontal,
+            self.style.spacing,
+            &items,
+        )
+    }
+
+    fn measure(&self, panel: &StatusPanel) -> (Size, SizePolicy) {
+        match panel {
+            StatusPanel::Label {
+                text,
+                icon,
+                width,
+                spring,
+            } => {
+                let width = width.unwrap_or_else(|| {
+                    let text_width = self.style.typography.measure_text(text).width();
+                    let icon_width = icon.as_ref().map_or(0, |icon| {
+                        icon.size.0.min(u16::MAX as u32) as u16 + self.style.panel_padding
+                    });
+                    text_width + icon_width + self.style.panel_padding * 2
+                });
+                let policy = if *spring {
+                    SizePolicy::Fill
+                } else {
+                    SizePolicy::Fit
+                };
+                (Size::new(0, width), policy)
+            }
+            StatusPanel::Progress(bar) => (
+                Size::new(self.style.progress_height, bar.base().size().width()),
+                SizePolicy::Fixed,
+            ),
+        }
+    }
+
+    fn render_label(
+        &self,
+        ctx: &mut RenderContext,
+        bounds: Boundaries,
+        text: &str,
+        icon: Option<&Icon>,
+    ) {
+        let style = &self.style;
+        let middle =
+            |height: u16| bounds.top_left().y() + bounds.height().saturating_sub(height) / 2;
+        ctx.push_clip(bounds);
+        let mut x = bounds.top_left().x() + style.panel_padding;
+        if let Some(icon) = icon {
+            let (width, height) = (
+                icon.size.0.min(u16::MAX as u32) as u16,
+                icon.size.1.min(u16::MAX as u32) as u16,
+            );
+            ctx.draw_icon(Position::new(x, middle(height)), icon);
+            x += width + style.panel_padding;
+        }
+        let text_height = style.typography.line_height();
+        ctx.draw_text(
+            Position::new(x, middle(text_height)),
+            text,
+            &style.typography,
+            &style.text_color,
+        );
+        ctx.pop_clip();
+        if let Some(border) = &style.panel_border {
+            ctx.stroke_rect(bounds, border);
+        }
+    }
+}
+
+impl Interactive for StatusStrip {
+    fn handle_input(&mut self, _input: &InputEvent) -> bool {
+        false
+    }
+}
+
+impl Renderable for StatusStrip {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+        let bounds = if self.stale() {
+            Cow::Owned(self.panel_frames())
+        } else {
+            Cow::Borrowed(self.bounds.as_slice())
+        };
+
+        let style = &self.style;
+        let frame = self.base.frame();
+        ctx.fill_rect(frame, &style.background);
+        ctx.draw_line(
+            frame.top_left(),
+            Position::new(
+                frame.bottom_right().x().saturating_sub(1),
+                frame.top_left().y(),
+            ),
+            style.border.color(),
+            style.border.width().ceil() as u16,
+        );
+
+        for (panel, bounds) in self.panels.iter().zip(bounds.iter()) {
+            match panel {
+                StatusPanel::Label { text, icon, .. } => {
+                    self.render_label(ctx, *bounds, text, icon.as_ref())
+                }
+                // A bar placed for another frame is shifted onto its panel
+                StatusPanel::Progress(bar) => {
+                    let placed = bar.base().position();
+                    ctx.push_translation(
+                        bounds.top_left().x() as i32 - placed.x() as i32,
+                        bounds.top_left().y() as i32 - placed.y() as i32,
+                    );
+                    bar.paint(ctx);
+                    ctx.pop_translation();
+                }
+            }
+        }
+
+        // Three diagonal ridges in the corner
+        if let Some(grip) = self.grip_bounds() {
+            let (right, bottom) = (
+                grip.bottom_right().x().saturating_sub(1),
+                grip.bottom_right().y().saturating_sub(1),
+            );
+            for step in 1..=3u16 {
+                let reach = grip.width() * step / 3;
+                ctx.draw_line(
+                    Position::new(right.saturating_sub(reach), bottom),
+                    Position::new(right, bottom.saturating_sub(reach)),
+                    &style.grip_color,
+                    1,
+                );
+            }
+        }
+    }
+}
+
+impl Component for StatusStrip {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    fn find_frame(&self, id: usize) -> Option<Boundaries> {
+        if *self.base.id() == id {
+            return Some(self.base.frame());
+        }
+        self.panels.iter().find_map(|panel| match panel {
+            StatusPanel::Progress(bar) => bar.find_frame(id),
+            StatusPanel::Label { .. } => None,
+        })
+    }
+}
@@ -0,0 +1,728 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        properties::{
+            boundaries::Boundaries, graphics::background::Background, position::Position,
+        },
+        styles::text_box_style::TextBoxStyle,
+    },
+    ui::render::render_context::RenderContext,
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::{InputEvent, Key, Modifiers, MouseButton},
+        types::{EventPayload, EventType},
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CharClass {
+    Whitespace,
+    Word,
+    Punctuation,
+}
+
+impl CharClass {
+    fn of(c: char) -> Self {
+        if c.is_whitespace() {
+            CharClass::Whitespace
+        } else if c.is_alphanumeric() || c == '_' {
+            CharClass::Word
+        } else {
+            CharClass::Punctuation
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "RawTextBox")]
+pub struct TextBox {
+    base: BaseComponent,
+    text: String,
+    placeholder: String,
+    multiline: bool,
+    read_only: bool,
+    enabled: bool,
+    max_length: Option<usize>,
+    password_char: Option<char>,
+    #[serde(default)]
+    style: TextBoxStyle,
+    // Caret and selection anchor are char indices into `text`
+    #[serde(skip)]
+    caret: usize,
+    #[serde(skip)]
+    anchor: Option<usize>,
+    #[serde(skip)]
+    focused: bool,
+    #[serde(skip)]
+    dragging: bool,
+    // Text passed to `with_text`, sanitized again by `with_multiline` and
+    // `with_max_length` so the builders can come in any order. Dropped once
+    // the text changes any other way
+    #[serde(skip)]
+    given_text: Option<String>,
+}
+
+// Serialized form of TextBox so deserialized text keeps to `multiline` and
+// `max_length` like text set through the builders
+#[derive(Deserialize)]
+struct RawTextBox {
+    base: BaseComponent,
+    text: String,
+    placeholder: String,
+    multiline: bool,
+    read_only: bool,
+    enabled: bool,
+    max_length: Option<usize>,
+    password_char: Option<char>,
+    #[serde(default)]
+    style: TextBoxStyle,
+}
+
+impl From<RawTextBox> for TextBox {
+    fn from(raw: RawTextBox) -> Self {
+        let mut text_box = Self::new(raw.base)
+            .with_multiline(raw.multiline)
+            .with_max_length(raw.max_length)
+            .with_text(&raw.text)
+            .with_placeholder(&raw.placeholder)
+            .with_read_only(raw.read_only)
+            .with_password_char(raw.password_char)
+            .with_style(raw.style);
+        text_box.enabled = raw.enabled;
+        text_box
+    }
+}
+
+impl TextBox {
+    pub fn new(base: BaseComponent) -> Self {
+        Self {
+            base,
+            text: String::new(),
+            placeholder: String::new(),
+            multiline: false,
+            read_only: false,
+            enabled: true,
+            max_length: None,
+            password_char: None,
+            style: TextBoxStyle::default(),
+            caret: 0,
+            anchor: None,
+            focused: false,
+            dragging: false,
+            given_text: None,
+        }
+    }
+
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.given_text = Some(text.to_string());
+        self.resanitize();
+        self
+    }
+
+    pub fn with_placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = placeholder.to_string();
+        self
+    }
+
+    pub fn with_multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self.resanitize();
+        self
+    }
+
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    pub fn with_max_length(mut self, max_length: Option<usize>) -> Self {
+        self.max_length = max_length;
+        self.resanitize();
+        self
+    }
+
+    pub fn with_password_char(mut self, password_char: Option<char>) -> Self {
+        self.password_char = password_char;
+        self
+    }
+
+    pub fn with_style(mut self, style: TextBoxStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    // Setters
+    pub fn set_text(&mut self, text: &str) {
+        let text = self.sanitize(text);
+        self.given_text = None;
+        if self.text != text {
+            self.text = text;
+            self.caret = self.char_count();
+            self.anchor = None;
+            self.notify_changed();
+        }
+    }
+
+    pub fn set_placeholder(&mut self, placeholder: &str) {
+        self.placeholder = placeholder.to_string();
+    }
+
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.dragging = false;
+        }
+    }
+
+    /// Text beyond the new limit is cut off
+    pub fn set_max_length(&mut self, max_length: Option<usize>) {
+        self.max_length = max_length;
+        let text = self.truncate(self.text.clone());
+        self.given_text = None;
+        if self.text != text {
+            self.text = text;
+            let count = self.char_count();
+            self.caret = self.caret.min(count);
+            self.anchor = self.anchor.map(|anchor| anchor.min(count));
+            self.notify_changed();
+        }
+    }
+
+    pub fn set_password_char(&mut self, password_char: Option<char>) {
+        self.password_char = password_char;
+    }
+
+    pub fn set_style(&mut self, style: TextBoxStyle) {
+        self.style = style;
+    }
+
+    pub fn select(&mut self, start: usize, end: usize) {
+        let count = self.char_count();
+        let (start, end) = (start.min(count), end.min(count));
+        self.anchor = (start != end).then_some(start);
+        self.caret = end;
+    }
+
+    pub fn select_all(&mut self) {
+        self.select(0, self.char_count());
+    }
+
+    /// Replaces the selection (or inserts at the caret) as if the user had typed `text`
+    pub fn insert_text(&mut self, text: &str) -> bool {
+        self.replace_selection(text)
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn placeholder(&self) -> &str {
+        &self.placeholder
+    }
+
+    pub fn multiline(&self) -> bool {
+        self.multiline
+    }
+
+    pub fn read_only(&self) -> bool {
+        self.read_only
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    pub fn password_char(&self) -> Option<char> {
+        self.password_char
+    }
+
+    pub fn style(&self) -> &TextBoxStyle {
+        &self.style
+    }
+
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    /// Selected char range as `(start, end)`, with `start < end`
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        self.anchor
+            .filter(|anchor| *anchor != self.caret)
+            .map(|anchor| (anchor.min(self.caret), anchor.max(self.caret)))
+    }
+
+    pub fn selected_text(&self) -> &str {
+        match self.selection() {
+            Some((start, end)) => &self.text[self.byte_index(start)..self.byte_index(end)],
+            None => "",
+        }
+    }
+
+    /// Text as shown on screen, with the password mask applied
+    pub fn display_text(&self) -> String {
+        match self.password_char {
+            Some(mask) => self
+                .text
+                .chars()
+                .map(|c| if c == '\n' { c } else { mask })
+                .collect(),
+            None => self.text.clone(),
+        }
+    }
+
+    // Text model helpers
+    fn char_count(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(char_index)
+            .map(|(byte, _)| byte)
+            .unwrap_or(self.text.len())
+    }
+
+    fn sanitize(&self, text: &str) -> String {
+        let text = text.replace('\r', "");
+        let text = if self.multiline {
+            text
+        } else {
+            // Single line boxes keep only the first line, like a paste in WinForms
+            text.split('\n').next().unwrap_or_default().to_string()
+        };
+        self.truncate(text)
+    }
+
+    // Applies the builders' rules to the text given to `with_text`, or to the
+    // current text when there is none
+    fn resanitize(&mut self) {
+        let text = self.given_text.as_deref().unwrap_or(&self.text);
+        self.text = self.sanitize(text);
+        self.caret = self.char_count();
+        self.anchor = None;
+    }
+
+    fn truncate(&self, text: String) -> String {
+        match self.max_length {
+            Some(max_length) if text.chars().count() > max_length => {
+                text.chars().take(max_length).collect()
+            }
+            _ => text,
+        }
+    }
+
+    // (start char index, length in chars) of every line
+    fn lines(&self) -> Vec<(usize, usize)> {
+        let mut lines = Vec::new();
+        let mut start = 0;
+        for line in self.text.split('\n') {
+            let len = line.chars().count();
+            lines.push((start, len));
+            start += len + 1;
+        }
+        lines
+    }
+
+    fn line_col(&self, index: usize) -> (usize, usize) {
+        let lines = self.lines();
+        for (row, (start, len)) in lines.iter().enumerate() {
+            if index <= start + len {
+                return (row, index.saturating_sub(*start));
+            }
+        }
+        let last = lines.len() - 1;
+        (last, lines[last].1)
+    }
+
+    fn index_at(&self, row: usize, col: usize) -> usize {
+        let lines = self.lines();
+        let (start, len) = lines[row.min(lines.len() - 1)];
+        start + col.min(len)
+    }
+
+    fn previous_word_start(&self, from: usize) -> usize {
+        if self.password_char.is_some() {
+            return 0;
+        }
+
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut index = from;
+        while index > 0 && CharClass::of(chars[index - 1]) == CharClass::Whitespace {
+            index -= 1;
+        }
+        if index > 0 {
+            let class = CharClass::of(chars[index - 1]);
+            while index > 0 && CharClass::of(chars[index - 1]) == class {
+                index -= 1;
+            }
+        }
+        index
+    }
+
+    fn next_word_start(&self, from: usize) -> usize {
+        let chars: Vec<char> = self.text.chars().collect();
+        if self.password_char.is_some() {
+            return chars.len();
+        }
+
+        let mut index = from;
+        if index < chars.len() {
+            let class = CharClass::of(chars[index]);
+            if class != CharClass::Whitespace {
+                while index < chars.len() && CharClass::of(chars[index]) == class {
+                    index += 1;
+                }
+            }
+        }
+        while index < chars.len() && CharClass::of(chars[index]) == CharClass::Whitespace {
+            index += 1;
+        }
+        index
+    }
+
+    // Editing
+    fn notify_changed(&self) {
+        self.base.emit_with(
+            EventType::FieldValueChanged,
+            EventPayload::Text(self.text.clone()),
+        );
+    }
+
+    fn replace_selection(&mut self, insert: &str) -> bool {
+        if self.read_only || !self.enabled {
+            return false;
+        }
+
+        let (start, end) = self.selection().unwrap_or((self.caret, self.caret));
+        let mut insert = self.sanitize(insert);
+        if let Some(max_length) = self.max_length {
+            let remaining = max_length.saturating_sub(self.char_count() - (end - start));
+            insert = insert.chars().take(remaining).collect();
+        }
+        if insert.is_empty() && start == end {
+            return false;
+        }
+
+        let (byte_start, byte_end) = (self.byte_index(start), self.byte_index(end));
+        self.text.replace_range(byte_start..byte_end, &insert);
+        self.given_text = None;
+        self.caret = start + insert.chars().count();
+        self.anchor = None;
+        self.notify_changed();
+        true
+    }
+
+    fn delete_backward(&mut self, word: bool) -> bool {
+        if self.selection().is_none() {
+            if self.caret == 0 {
+                return false;
+            }
+            let start = if word {
+                self.previous_word_start(self.caret)
+            } else {
+                self.caret - 1
+            };
+            self.anchor = Some(start);
+        }
+        self.replace_selection("")
+    }
+
+    fn delete_forward(&mut self, word: bool) -> bool {
+        if self.selection().is_none() {
+            if self.caret >= self.char_count() {
+                return false;
+            }
+            let end = if word {
+                self.next_word_start(self.caret)
+            } else {
+                self.caret + 1
+            };
+            self.anchor = Some(self.caret);
+            self.caret = end;
+        }
+        self.replace_selection("")
+    }
+
+    fn move_caret(&mut self, to: usize, extend: bool) {
+        if extend {
+            self.anchor.get_or_insert(self.caret);
+        } else {
+            self.anchor = None;
+        }
+        self.caret = to.min(self.char_count());
+    }
+
+    fn handle_navigation(&mut self, key: Key, modifiers: Modifiers) -> bool {
+        let extend = modifiers.shift;
+        let (row, col) = self.line_col(self.caret);
+
+        let target = match key {
+            Key::Left if !extend && !modifiers.ctrl && self.selection().is_some() => {
+                self.selection().map(|(start, _)| start).unwrap_or_default()
+            }
+            Key::Right if !extend && !modifiers.ctrl && self.selection().is_some() => {
+                self.selection().map(|(_, end)| end).unwrap_or_default()
+            }
+            Key::Left if modifiers.ctrl => self.previous_word_start(self.caret),
+            Key::Left => self.caret.saturating_sub(1),
+            Key::Right if modifiers.ctrl => self.next_word_start(self.caret),
+            Key::Right => self.caret + 1,
+            Key::Home if modifiers.ctrl => 0,
+            Key::Home => self.index_at(row, 0),
+            Key::End if modifiers.ctrl => self.char_count(),
+            Key::End => self.index_at(row, usize::MAX),
+            Key::Up if self.multiline => {
+                if row == 0 {
+                    0
+                } else {
+                    self.index_at(row - 1, col)
+                }
+            }
+            Key::Down if self.multiline => {
+                if row + 1 >= self.lines().len() {
+                    self.char_count()
+                } else {
+                    self.index_at(row + 1, col)
+                }
+            }
+            _ => return false,
+        };
+
+        self.move_caret(target, extend);
+        true
+    }
+
+    fn handle_key(&mut self, key: Key, modifiers: Modifiers) -> bool {
+        match key {
+            Key::Backspace => {
+                self.delete_backward(modifiers.ctrl);
+                true
+            }
+            Key::Delete => {
+                self.delete_forward(modifiers.ctrl);
+                true
+            }
+            Key::Enter if self.multiline => {
+                self.replace_selection("\n");
+                true
+            }
+            Key::Char(c) if modifiers.ctrl && c.eq_ignore_ascii_case(&'a') => {
+                self.select_all();
+                true
+            }
+            _ => self.handle_navigation(key, modifiers),
+        }
+    }
+
+    // Geometry
+    fn caret_index_at(&self, point: Position) -> usize {
+        let content = self.base.content_frame();
+        let typography = &self.style.typography;
+        let dx = point.x().saturating_sub(content.top_left().x());
+        let dy = point.y().saturating_sub(content.top_left().y());
+
+        let row = (dy / typography.line_height()) as usize;
+        let col = ((dx + typography.char_width() / 2) / typography.char_width()) as usize;
+        self.index_at(row, col)
+    }
+
+    /// Top-left corner of the caret for the given char index
+    pub fn position_of(&self, index: usize) -> Position {
+        let origin = self.base.content_frame().top_left();
+        let typography = &self.style.typography;
+        let (row, col) = self.line_col(index);
+
+        Position::new(
+            origin
+                .x()
+                .saturating_add((col as u16).saturating_mul(typography.char_width())),
+            origin
+                .y()
+                .saturating_add((row as u16).saturating_mul(typography.line_height())),
+        )
+    }
+
+    fn background(&self) -> &Background {
+        if !self.enabled {
+            &self.style.disabled_background
+        } else if self.read_only {
+            &self.style.read_only_background
+        } else {
+            &self.style.background
+        }
+    }
+}
+
+impl Interactive for TextBox {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() {
+            return false;
+        }
+
+        match *input {
+            InputEvent::MouseButtonPressed {
+                button: MouseButton::Left,
+                position,
+                modifiers,
+            } => {
+                if !self.base.hit_test(position) {
+                    return false;
+                }
+                self.set_focused(true);
+                let index = self.caret_index_at(position);
+                self.move_caret(index, modifiers.shift);
+                self.dragging = true;
+                true
+            }
+            InputEvent::MouseMoved(position) if self.dragging => {
+                let index = self.caret_index_at(position);
+                self.move_caret(index, true);
+                true
+            }
+            InputEvent::MouseButtonReleased {
+                button: MouseButton::Left,
+                ..
+            } if self.dragging => {
+                self.dragging = false;
+                true
+            }
+            InputEvent::KeyPressed { key, modifiers } if self.focused => {
+                self.handle_key(key, modifiers)
+            }
+            InputEvent::CharTyped(c) if self.focused => {
+                if !c.is_control() {
+                    self.replace_selection(&c.to_string());
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled && *self.base.visible()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        if self.focused == focused {
+            return;
+        }
+
+        self.focused = focused;
+        if focused {
+            self.base.emit(EventType::FieldFocused);
+        } else {
+            self.dragging = false;
+            self.base.emit_with(
+                EventType::FieldValidating,
+                EventPayload::Text(self.text.clone()),
+            );
+            self.base.emit(EventType::FieldFocusedLost);
+        }
+    }
+}
+
+impl Renderable for TextBox {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        let frame = self.base.frame();
+        let content = self.base.content_frame();
+        let typography = &self.style.typography;
+
+        ctx.fill_rect(frame, self.background());
+        if self.focused {
+            ctx.stroke_rect(frame, &self.style.focus_border);
+        } else {
+            ctx.stroke_rect(frame, &self.style.border);
+        }
+
+        ctx.push_clip(content);
+        if self.text.is_empty() {
+            if !self.placeholder.is_empty() {
+                ctx.draw_text(
+                    content.top_left(),
+                    &self.placeholder,
+                    typography,
+                    &self.style.placeholder_color,
+                );
+            }
+        } else {
+            if let Some((start, end)) = self.selection() {
+                for (line_start, len) in self.lines() {
+                    let from = start.max(line_start);
+                    let to = end.min(line_start + len);
+                    if from >= to {
+                        continue;
+                    }
+                    let top_left = self.position_of(from);
+                    let bottom_right = self.position_of(to);
+                    let size = typography
+                        .measure_text(" ")
+                        .with_width(bottom_right.x().saturating_sub(top_left.x()));
+                    ctx.fill_rect(
+                        Boundaries::from_rect(top_left, size),
+                        &self.style.selection_background,
+                    );
+                }
+            }
+
+            let color = if self.enabled {
+                &self.style.text_color
+            } else {
+                &self.style.disabled_text_color
+            };
+            let display = self.display_text();
+            let origin = content.top_left();
+            for (row, line) in display.split('\n').enumerate() {
+                let y = origin
+                    .y()
+                    .saturating_add((row as u16).saturating_mul(typography.line_height()));
+                ctx.draw_text(Position::new(origin.x(), y), line, typography, color);
+            }
+        }
+
+        if self.focused {
+            let top = self.position_of(self.caret);
+            let bottom = top.in_y(top.y().saturating_add(typography.line_height()));
+            ctx.draw_line(top, bottom, &self.style.caret_color, 1);
+        }
+        ctx.pop_clip();
+    }
+}
+
+impl Component for TextBox {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
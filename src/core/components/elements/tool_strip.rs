@@ -0,0 +1,697 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::{
+            menu_item::MenuItem,
+            menu_popup::{MenuAction, MenuPopup},
+            tool_strip_item::{ToolStripItem, ToolStripItemKind},
+        },
+        layouts::container::Container,
+        properties::{
+            boundaries::Boundaries,
+            graphics::color::Color,
+            orientation::Orientation,
+            position::Position,
+            size::{Size, SizePolicy},
+        },
+        styles::tool_strip_style::ToolStripStyle,
+    },
+    ui::{elements::theme::Theme, popup::PopupPlacement, render::render_context::RenderContext},
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::InputEvent,
+        types::{EventPayload, EventType},
+    },
+};
+
+/// Part of a tool bar under the pointer or with its menu open
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToolStripTarget {
+    Item(usize),
+    // Chevron listing the items that did not fit
+    Overflow,
+}
+
+/// Row of buttons, toggles, separators and drop-down buttons, laid out left
+/// to right by `Container::arrange`. Items that do not fit move, from the end,
+/// into a menu opened by a chevron on the right, as `ToolStrip` in WinForms.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolStrip {
+    base: BaseComponent,
+    items: Vec<ToolStripItem>,
+    enabled: bool,
+    #[serde(default)]
+    style: ToolStripStyle,
+    // Frames of the items shown on the bar; the rest overflow
+    #[serde(skip)]
+    bounds: Vec<Boundaries>,
+    // Frame of the strip when `bounds` was filled in
+    #[serde(skip)]
+    laid_out: Option<Boundaries>,
+    #[serde(skip)]
+    popup: MenuPopup,
+    // Built from the overflowing items while the chevron menu is open
+    #[serde(skip)]
+    overflow_menu: Option<MenuItem>,
+    #[serde(skip)]
+    open: Option<ToolStripTarget>,
+    #[serde(skip)]
+    hovered: Option<ToolStripTarget>,
+    #[serde(skip)]
+    pressed: Option<ToolStripTarget>,
+}
+
+impl ToolStrip {
+    pub fn new(base: BaseComponent) -> Self {
+        Self {
+            base,
+            items: Vec::new(),
+            enabled: true,
+            style: ToolStripStyle::default(),
+            bounds: Vec::new(),
+            laid_out: None,
+            popup: MenuPopup::default(),
+            overflow_menu: None,
+            open: None,
+            hovered: None,
+            pressed: None,
+        }
+    }
+
+    pub fn with_item(mut self, item: ToolStripItem) -> Self {
+        self.add_item(item);
+        self
+    }
+
+    pub fn with_style(mut self, style: ToolStripStyle) -> Self {
+        self.set_style(style);
+        self
+    }
+
+    pub fn with_theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    pub fn add_item(&mut self, item: ToolStripItem) -> usize {
+        self.items.push(item);
+        self.refresh();
+        self.items.len() - 1
+    }
+
+    pub fn remove_item(&mut self, index: usize) -> Option<ToolStripItem> {
+        if index >= self.items.len() {
+            return None;
+        }
+        self.close();
+        let removed = self.items.remove(index);
+        self.refresh();
+        Some(removed)
+    }
+
+    // Setters
+    pub fn set_text(&mut self, index: usize, text: &str) {
+        if let Some(item) = self.items.get_mut(index) {
+            item.set_text(text);
+            self.refresh();
+        }
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.close();
+            self.pressed = None;
+        }
+    }
+
+    pub fn set_style(&mut self, style: ToolStripStyle) {
+        self.style = style;
+        self.refresh();
+    }
+
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.set_style(theme.tool_strip.clone());
+    }
+
+    /// Area the drop-downs must stay inside, usually the window client area
+    pub fn set_viewport(&mut self, viewport: Boundaries) {
+        self.popup.set_viewport(viewport);
+    }
+
+    /// Checks or unchecks every toggle and menu item bound to a command
+    pub fn set_checked(&mut self, command: usize, checked: bool) {
+        for item in &mut self.items {
+            if item.command() == Some(command) {
+                item.set_checked(checked);
+            }
+            if let Some(entry) = item
+                .menu_mut()
+                .and_then(|menu| menu.find_command_mut(command))
+            {
+                entry.set_checked(checked);
+            }
+        }
+    }
+
+    pub fn set_item_enabled(&mut self, command: usize, enabled: bool) {
+        for item in &mut self.items {
+            if item.command() == Some(command) {
+                item.set_enabled(enabled);
+            }
+            if let Some(entry) = item
+                .menu_mut()
+                .and_then(|menu| menu.find_command_mut(command))
+            {
+                entry.set_enabled(enabled);
+            }
+        }
+    }
+
+    /// Runs a command as if its item was clicked: toggles flip and
+    /// `CommandInvoked` is emitted. Disabled or unknown commands do nothing.
+    pub fn invoke(&mut self, command: usize) -> bool {
+        if let Some(index) = self
+            .items
+            .iter()
+            .position(|item| item.command() == Some(command))
+        {
+            return self.activate(index);
+        }
+
+        let Some(entry) = self
+            .items
+            .iter_mut()
+            .find_map(|item| item.menu_mut()?.find_command_mut(command))
+        else {
+            return false;
+        };
+        if !entry.selectable() || entry.has_submenu() {
+            return false;
+        }
+        if entry.checkable() {
+            entry.set_checked(!entry.checked());
+        }
+        self.base
+            .emit_with(EventType::CommandInvoked, EventPayload::Index(command));
+        true
+    }
+
+    /// Opens the menu of a drop-down button or the overflow chevron
+    pub fn open_menu(&mut self, target: ToolStripTarget) {
+        let anchor = match target {
+            ToolStripTarget::Item(index) => self.item_bounds(index),
+            ToolStripTarget::Overflow => self.overflow_bounds(),
+        };
+        let Some(anchor) = anchor else {
+            return;
+        };
+
+        self.close();
+        if target == ToolStripTarget::Overflow {
+            let mut menu = MenuItem::new("");
+            for item in self.overflow_items() {
+                menu.add_item(item.to_menu_item());
+            }
+            self.overflow_menu = Some(menu);
+        }
+        let root = match target {
+            ToolStripTarget::Item(index) => self.items[index].menu(),
+            ToolStripTarget::Overflow => self.overflow_menu.as_ref(),
+        };
+        if let Some(root) = root.filter(|root| root.has_submenu()) {
+            self.popup
+                .open(root, &self.style.menu, anchor, PopupPlacement::Below, false);
+            self.open = Some(target);
+        }
+    }
+
+    pub fn close(&mut self) {
+        self.popup.close();
+        self.overflow_menu = None;
+        self.open = None;
+    }
+
+    /// Re-lays out the items, moving those that do not fit into the overflow
+    /// menu. Input does it on its own once the frame has changed, and
+    /// rendering paints the items where they would go
+    pub fn refresh(&mut self) {
+        self.laid_out = Some(self.base.frame());
+        self.bounds = self.item_frames();
+        if self.open.is_some() {
+            self.close();
+        }
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn items(&self) -> &[ToolStripItem] {
+        &self.items
+    }
+
+    pub fn item(&self, index: usize) -> Option<&ToolStripItem> {
+        self.items.get(index)
+    }
+
+    /// Items moved into the overflow menu for lack of space
+    pub fn overflow_items(&self) -> &[ToolStripItem] {
+        &self.items[self.bounds.len().min(self.items.len())..]
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn style(&self) -> &ToolStripStyle {
+        &self.style
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.popup.is_open()
+    }
+
+    pub fn open_target(&self) -> Option<ToolStripTarget> {
+        self.open
+    }
+
+    pub fn hovered(&self) -> Option<ToolStripTarget> {
+        self.hovered
+    }
+
+    /// Area of an item on the bar, `None` when it overflowed
+    pub fn item_bounds(&self, index: usize) -> Option<Boundaries> {
+        self.bounds.get(index).copied()
+    }
+
+    /// Area of the chevron, `None` while every item fits
+    pub fn overflow_bounds(&self) -> Option<Boundaries> {
+        self.chevron_frame(self.bounds.len())
+    }
+
+    /// Drop-down at `depth`, 0 being the one under the bar
+    pub fn popup_bounds(&self, depth: usize) -> Option<Boundaries> {
+        self.popup.bounds(depth)
+    }
+
+    /// Row of an entry inside the open drop-down at `depth`
+    pub fn menu_item_bounds(&self, depth: usize, index: usize) -> Option<Boundaries> {
+        let root = self.open_root()?;
+        self.popup.item_bounds(root, &self.style.menu, depth, index)
+    }
+
+    // Moved or resized since the last refresh, or just deserialized
+    fn stale(&self) -> bool {
+        self.laid_out != Some(self.base.frame())
+    }
+
+    // Frames of the items that fit on the bar at the current frame
+    fn item_frames(&self) -> Vec<Boundaries> {
+        let frame = self.base.frame();
+        let area = Boundaries::from_rect(
+            Position::new(
+                frame.top_left().x() + self.style.padding,
+                frame.top_left().y(),
+            ),
+            Size::new(
+                frame.height(),
+                frame.width().saturating_sub(self.style.padding * 2),
+            ),
+        );
+        let widths: Vec<u16> = self.items.iter().map(|item| self.measure(item)).collect();
+
+        let mut shown = widths.len();
+        if widths.iter().map(|width| *width as u32).sum::<u32>() > area.width() as u32 {
+            let room = area.width().saturating_sub(self.style.overflow_width) as u32;
+            let mut used = 0;
+            shown = widths
+                .iter()
+                .take_while(|width| {
+                    used += **width as u32;
+                    used <= room
+                })
+                .count();
+            // A separator ending the row has nothing left to separate
+            while shown > 0 && self.items[shown - 1].is_separator() {
+                shown -= 1;
+            }
+        }
+
+        let sizes: Vec<(Size, SizePolicy)> = widths[..shown]
+            .iter()
+            .map(|width| (Size::new(0, *width), SizePolicy::Fit))
+            .collect();
+        Container::arrange(area, Orientation::Horizontal, 0, &sizes)
+    }
+
+    // Chevron for a bar showing its first `shown` items
+    fn chevron_frame(&self, shown: usize) -> Option<Boundaries> {
+        if shown >= self.items.len() {
+            return None;
+        }
+        let frame = self.base.frame();
+        let width = self.style.overflow_width.min(frame.width());
+        Some(Boundaries::from_rect(
+            Position::new(
+                frame
+                    .bottom_right()
+                    .x()
+                    .saturating_sub(self.style.padding + width)
+                    .max(frame.top_left().x()),
+                frame.top_left().y(),
+            ),
+            Size::new(frame.height(), width),
+        ))
+    }
+
+    fn open_root(&self) -> Option<&MenuItem> {
+        match self.open? {
+            ToolStripTarget::Item(index) => self.items.get(index)?.menu(),
+            ToolStripTarget::Overflow => self.overflow_menu.as_ref(),
+        }
+    }
+
+    fn measure(&self, item: &ToolStripItem) -> u16 {
+        let style = &self.style;
+        if item.is_separator() {
+            return style.separator_width;
+        }
+
+        let text = style.typography.measure_text(item.text()).width();
+        let icon = item
+            .icon()
+            .map_or(0, |icon| icon.size.0.min(u16::MAX as u32) as u16);
+        let gap = if icon > 0 && text > 0 {
+            style.icon_spacing
+        } else {
+            0
+        };
+        let arrow = match item.kind() {
+            ToolStripItemKind::DropDown(_) => style.arrow_width,
+            _ => 0,
+        };
+        style.item_padding * 2 + icon + gap + text + arrow
+    }
+
+    fn target_at(&self, point: Position) -> Option<ToolStripTarget> {
+        if let Some(index) = self.bounds.iter().position(|bounds| bounds.contains(point)) {
+            return Some(ToolStripTarget::Item(index));
+        }
+        self.overflow_bounds()
+            .filter(|bounds| bounds.contains(point))
+            .map(|_| ToolStripTarget::Overflow)
+    }
+
+    // Clicks an item: toggles flip, then its command runs
+    fn activate(&mut self, index: usize) -> bool {
+        let Some(item) = self.items.get_mut(index) else {
+            return false;
+        };
+        if !item.selectable() || item.menu().is_some() {
+            return false;
+        }
+
+        if *item.kind() == ToolStripItemKind::Toggle {
+            item.set_checked(!item.checked());
+        }
+        if let Some(command) = item.command() {
+            self.base
+                .emit_with(EventType::CommandInvoked, EventPayload::Index(command));
+        }
+        true
+    }
+
+    // Runs the entry at `path` inside the menu that was open for `target`
+    fn invoke_path(&mut self, target: ToolStripTarget, path: &[usize]) {
+        let (index, path) = match target {
+            ToolStripTarget::Item(index) => (index, path),
+            // The overflow menu lists the overflowing items in order
+            ToolStripTarget::Overflow => {
+                let Some((first, rest)) = path.split_first() else {
+                    return;
+                };
+                if rest.is_empty() {
+                    self.activate(self.bounds.len() + first);
+                    return;
+                }
+                (self.bounds.len() + first, rest)
+            }
+        };
+        let command = self
+            .items
+            .get(index)
+            .and_then(ToolStripItem::menu)
+            .and_then(|menu| menu.item_at(path))
+            .and_then(MenuItem::command);
+        if let Some(command) = command {
+            self.invoke(command);
+        }
+    }
+
+    fn handle_open(&mut self, target: ToolStripTarget, input: &InputEvent) -> bool {
+        let root = match target {
+            ToolStripTarget::Item(index) => self.items.get(index).and_then(ToolStripItem::menu),
+            ToolStripTarget::Overflow => self.overflow_menu.as_ref(),
+        };
+        let Some(root) = root else {
+            self.close();
+            return false;
+        };
+
+        match self.popup.handle_input(root, &self.style.menu, input) {
+            MenuAction::Invoked(path) => {
+                self.close();
+                self.invoke_path(target, &path);
+                true
+            }
+            MenuAction::Dismissed => {
+                self.close();
+                if input.is_keyboard() {
+                    return true;
+                }
+                // Pressing the button that opened the menu only closes it
+                match input.mouse_position().and_then(|p| self.target_at(p)) {
+                    Some(pressed) if pressed == target => true,
+                    Some(_) => self.handle_input(input),
+                    None => false,
+                }
+            }
+            MenuAction::Handled | MenuAction::Next | MenuAction::Previous => true,
+            // Sliding over another drop-down button opens its menu instead
+            MenuAction::Ignored => match *input {
+                InputEvent::MouseMoved(position) => {
+                    self.hovered = self.target_at(position);
+                    if let Some(hovered) = self.hovered
+                        && hovered != target
+                        && self.opens_menu(hovered)
+                    {
+                        self.open_menu(hovered);
+                    }
+                    self.hovered.is_some()
+                }
+                _ => false,
+            },
+        }
+    }
+
+    fn opens_menu(&self, target: ToolStripTarget) -> bool {
+        match target {
+            ToolStripTarget::Item(index) => {
+                self.items[index].selectable() && self.items[index].menu().is_some()
+            }
+            ToolStripTarget::Overflow => true,
+        }
+    }
+
+    fn render_item(&self, ctx: &mut RenderContext, index: usize, bounds: Boundaries) {
+        let style = &self.style;
+        let item = &self.items[index];
+        if item.is_separator() {
+            let x = bounds.top_left().x() + bounds.width() / 2;
+            ctx.draw_line(
+                Position::new(x, bounds.top_left().y() + 4),
+                Position::new(x, bounds.bottom_right().y().saturating_sub(4)),
+                &style.separator_color,
+                1,
+            );
+            return;
+        }
+
+        let target = Some(ToolStripTarget::Item(index));
+        self.render_state(ctx, target, bounds, item.enabled() && item.checked());
+
+        let enabled = self.enabled && item.enabled();
+        let middle =
+            |height: u16| bounds.top_left().y() + bounds.height().saturating_sub(height) / 2;
+        let mut x = bounds.top_left().x() + style.item_padding;
+        if let Some(icon) = item.icon() {
+            let (width, height) = (
+                icon.size.0.min(u16::MAX as u32) as u16,
+                icon.size.1.min(u16::MAX as u32) as u16,
+            );
+            ctx.draw_icon(Position::new(x, middle(height)), icon);
+            x += width + style.icon_spacing;
+        }
+        if !item.text().is_empty() {
+            let color = if enabled {
+                &style.text_color
+            } else {
+                &style.disabled_text_color
+            };
+            ctx.draw_text(
+                Position::new(x, middle(style.typography.line_height())),
+                item.text(),
+                &style.typography,
+                color,
+            );
+        }
+        if item.menu().is_some() {
+            let center = bounds.bottom_right().x() - style.item_padding - style.arrow_width / 2;
+            render_arrow(ctx, center, middle(0), &style.glyph_color);
+        }
+    }
+
+    // Background and border for pressed, open, checked or hovered parts
+    fn render_state(
+        &self,
+        ctx: &mut RenderContext,
+        target: Option<ToolStripTarget>,
+        bounds: Boundaries,
+        checked: bool,
+    ) {
+        let style = &self.style;
+        if !self.enabled {
+            return;
+        }
+        let hovered = self.hovered == target;
+        if self.open == target || (self.pressed == target && hovered) {
+            ctx.fill_rect(bounds, &style.pressed_background);
+            ctx.stroke_rect(bounds, &style.hover_border);
+        } else if checked {
+            ctx.fill_rect(bounds, &style.checked_background);
+            ctx.stroke_rect(bounds, &style.checked_border);
+        } else if hovered {
+            ctx.fill_rect(bounds, &style.hover_background);
+            ctx.stroke_rect(bounds, &style.hover_border);
+        }
+    }
+}
+
+// Small chevron pointing down, centered on `x` and `y`
+fn render_arrow(ctx: &mut RenderContext, x: u16, y: u16, color: &Color) {
+    let left = Position::new(x.saturating_sub(3), y.saturating_sub(1));
+    let tip = Position::new(x, y + 2);
+    let right = Position::new(x + 3, y.saturating_sub(1));
+    ctx.draw_line(left, tip, color, 1);
+    ctx.draw_line(tip, right, color, 1);
+}
+
+impl Interactive for ToolStrip {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() {
+            return false;
+        }
+        if self.stale() {
+            self.refresh();
+        }
+
+        if let Some(target) = self.open
+            && self.popup.is_open()
+        {
+            return self.handle_open(target, input);
+        }
+
+        match *input {
+            InputEvent::MouseMoved(position) => {
+                self.hovered = self.target_at(position);
+                self.hovered.is_some()
+            }
+            InputEvent::MouseButtonPressed { position, .. } => {
+                let Some(target) = self.target_at(position) else {
+                    self.pressed = None;
+                    return false;
+                };
+                if self.opens_menu(target) {
+                    self.open_menu(target);
+                } else if let ToolStripTarget::Item(index) = target
+                    && self.items[index].selectable()
+                {
+                    self.pressed = Some(target);
+                }
+                true
+            }
+            // Clicks count when the button is released over the item pressed
+            InputEvent::MouseButtonReleased { position, .. } => {
+                let Some(pressed) = self.pressed.take() else {
+                    return false;
+                };
+                if self.target_at(position) == Some(pressed)
+                    && let ToolStripTarget::Item(index) = pressed
+                {
+                    self.activate(index);
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Renderable for ToolStrip {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+        let stale = self.stale();
+        let bounds = if stale {
+            Cow::Owned(self.item_frames())
+        } else {
+            Cow::Borrowed(self.bounds.as_slice())
+        };
+
+        let style = &self.style;
+        ctx.fill_rect(self.base.frame(), &style.background);
+        for (index, bounds) in bounds.iter().enumerate() {
+            self.render_item(ctx, index, *bounds);
+        }
+
+        // Chevron: a bar over a down arrow
+        if let Some(bounds) = self.chevron_frame(bounds.len()) {
+            self.render_state(ctx, Some(ToolStripTarget::Overflow), bounds, false);
+            let x = bounds.top_left().x() + bounds.width() / 2;
+            let y = bounds.top_left().y() + bounds.height() / 2;
+            ctx.draw_line(
+                Position::new(x.saturating_sub(3), y.saturating_sub(3)),
+                Position::new(x + 3, y.saturating_sub(3)),
+                &style.glyph_color,
+                1,
+            );
+            render_arrow(ctx, x, y + 1, &style.glyph_color);
+        }
+
+        // A menu opened for another frame closes on the next input
+        if !stale && let Some(root) = self.open_root() {
+            self.popup.render(ctx, root, &style.menu);
+        }
+    }
+}
+
+impl Component for ToolStrip {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
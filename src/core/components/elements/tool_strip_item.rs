@@ -0,0 +1,161 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::elements::{icon::Icon, menu_item::MenuItem};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ToolStripItemKind {
+    Button,
+    // Stays pressed while checked, flipping on every click
+    Toggle,
+    Separator,
+    // Opens the given menu below the button
+    DropDown(MenuItem),
+}
+
+/// Entry of a `ToolStrip`. Clicks run the item's command, reported through
+/// `CommandInvoked` like menu items.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolStripItem {
+    kind: ToolStripItemKind,
+    text: String,
+    icon: Option<Icon>,
+    command: Option<usize>,
+    checked: bool,
+    enabled: bool,
+}
+
+impl ToolStripItem {
+    pub fn button(text: &str) -> Self {
+        Self::with_kind(ToolStripItemKind::Button, text)
+    }
+
+    pub fn toggle(text: &str) -> Self {
+        Self::with_kind(ToolStripItemKind::Toggle, text)
+    }
+
+    pub fn separator() -> Self {
+        Self::with_kind(ToolStripItemKind::Separator, "")
+    }
+
+    /// Button opening `menu`, whose items are the entries of the drop-down
+    pub fn drop_down(text: &str, menu: MenuItem) -> Self {
+        Self::with_kind(ToolStripItemKind::DropDown(menu), text)
+    }
+
+    fn with_kind(kind: ToolStripItemKind, text: &str) -> Self {
+        Self {
+            kind,
+            text: text.to_string(),
+            icon: None,
+            command: None,
+            checked: false,
+            enabled: true,
+        }
+    }
+
+    pub fn with_icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn with_command(mut self, command: usize) -> Self {
+        self.command = Some(command);
+        self
+    }
+
+    pub fn with_checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    // Setters
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+    }
+
+    pub fn set_icon(&mut self, icon: Option<Icon>) {
+        self.icon = icon;
+    }
+
+    pub fn set_checked(&mut self, checked: bool) {
+        self.checked = checked;
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    // Getters
+    pub fn kind(&self) -> &ToolStripItemKind {
+        &self.kind
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn icon(&self) -> Option<&Icon> {
+        self.icon.as_ref()
+    }
+
+    pub fn command(&self) -> Option<usize> {
+        self.command
+    }
+
+    pub fn checked(&self) -> bool {
+        self.checked
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn is_separator(&self) -> bool {
+        self.kind == ToolStripItemKind::Separator
+    }
+
+    /// Items that can be clicked: enabled and not a separator
+    pub fn selectable(&self) -> bool {
+        self.enabled && !self.is_separator()
+    }
+
+    pub fn menu(&self) -> Option<&MenuItem> {
+        match &self.kind {
+            ToolStripItemKind::DropDown(menu) => Some(menu),
+            _ => None,
+        }
+    }
+
+    pub fn menu_mut(&mut self) -> Option<&mut MenuItem> {
+        match &mut self.kind {
+            ToolStripItemKind::DropDown(menu) => Some(menu),
+            _ => None,
+        }
+    }
+
+    /// Entry standing for this item in the overflow menu
+    pub fn to_menu_item(&self) -> MenuItem {
+        let item = match &self.kind {
+            ToolStripItemKind::Separator => return MenuItem::separator(),
+            ToolStripItemKind::Button => MenuItem::new(&self.text),
+            ToolStripItemKind::Toggle => MenuItem::new(&self.text)
+                .with_checkable(true)
+                .with_checked(self.checked),
+            ToolStripItemKind::DropDown(menu) => {
+                let mut item = menu.clone();
+                item.set_text(&self.text);
+                item
+            }
+        };
+        let item = item.with_enabled(self.enabled);
+        match self.command {
+            Some(command) => item.with_command(command),
+            None => item,
+        }
+    }
+}
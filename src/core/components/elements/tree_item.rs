@@ -0,0 +1,270 @@
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::{check_box::render_check_glyph, icon::Icon, tree_view::NodeId},
+        properties::{
+            boundaries::Boundaries,
+            check_state::CheckState,
+            graphics::{action_state::ActionState, color::Color},
+            position::Position,
+            size::Size,
+        },
+        styles::tree_view_style::TreeViewStyle,
+    },
+    ui::render::render_context::RenderContext,
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::input::InputEvent,
+};
+
+const ICON_SPACING: u16 = 4;
+
+/// On-screen row of a `TreeView`, rebound to other nodes as the tree scrolls.
+/// From left to right it shows the indent, the expander, the check box, the
+/// icon and the text.
+#[derive(Debug, Clone)]
+pub struct TreeItem {
+    base: BaseComponent,
+    node: NodeId,
+    depth: u16,
+    text: String,
+    icon: Option<Icon>,
+    // `None` for leaves, otherwise whether the node is expanded
+    expander: Option<bool>,
+    // `None` when the tree shows no check boxes
+    check_state: Option<CheckState>,
+    selected: bool,
+    caret: bool,
+    enabled: bool,
+    style: TreeViewStyle,
+}
+
+impl TreeItem {
+    pub fn new(base: BaseComponent, style: TreeViewStyle) -> Self {
+        Self {
+            base,
+            node: NodeId::default(),
+            depth: 0,
+            text: String::new(),
+            icon: None,
+            expander: None,
+            check_state: None,
+            selected: false,
+            caret: false,
+            enabled: true,
+            style,
+        }
+    }
+
+    /// Points this row at another node
+    pub fn bind(&mut self, node: NodeId, depth: u16, text: &str, icon: Option<&Icon>) {
+        self.node = node;
+        self.depth = depth;
+        self.text.clear();
+        self.text.push_str(text);
+        self.icon = icon.cloned();
+    }
+
+    pub fn set_expander(&mut self, expander: Option<bool>) {
+        self.expander = expander;
+    }
+
+    pub fn set_check_state(&mut self, check_state: Option<CheckState>) {
+        self.check_state = check_state;
+    }
+
+    pub fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+    }
+
+    pub fn set_caret(&mut self, caret: bool) {
+        self.caret = caret;
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    pub fn set_style(&mut self, style: &TreeViewStyle) {
+        if &self.style != style {
+            self.style = style.clone();
+        }
+    }
+
+    pub fn place(&mut self, position: Position, size: Size) {
+        self.base.set_position(position);
+        self.base.set_size(size);
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn node(&self) -> NodeId {
+        self.node
+    }
+
+    pub fn depth(&self) -> u16 {
+        self.depth
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn expander(&self) -> Option<bool> {
+        self.expander
+    }
+
+    pub fn check_state(&self) -> Option<CheckState> {
+        self.check_state
+    }
+
+    pub fn selected(&self) -> bool {
+        self.selected
+    }
+
+    pub fn caret(&self) -> bool {
+        self.caret
+    }
+
+    // Layout
+    fn indent_x(&self) -> u16 {
+        let frame = self.base.frame();
+        frame
+            .top_left()
+            .x()
+            .saturating_add(self.depth.saturating_mul(self.style.indent))
+    }
+
+    fn centered(&self, x: u16, size: u16) -> Boundaries {
+        let frame = self.base.frame();
+        let y = frame.top_left().y() + frame.height().saturating_sub(size) / 2;
+        Boundaries::from_rect(Position::new(x, y), Size::new(size, size))
+    }
+
+    /// Plus/minus box, centered in the expander column
+    pub fn expander_bounds(&self) -> Boundaries {
+        let size = self.style.expander_size;
+        let x = self.indent_x() + self.style.indent.saturating_sub(size) / 2;
+        self.centered(x, size)
+    }
+
+    pub fn check_bounds(&self) -> Option<Boundaries> {
+        self.check_state?;
+        Some(self.centered(
+            self.indent_x() + self.style.indent,
+            self.style.check_box.box_size,
+        ))
+    }
+
+    fn content_x(&self) -> u16 {
+        let mut x = self.indent_x() + self.style.indent;
+        if self.check_state.is_some() {
+            x += self.style.check_box.box_size + self.style.check_box.spacing;
+        }
+        x
+    }
+
+    fn text_color(&self) -> &Color {
+        let list = &self.style.list;
+        if !self.enabled {
+            &list.disabled_text_color
+        } else if self.selected {
+            &list.highlight_text_color
+        } else {
+            &list.text_color
+        }
+    }
+}
+
+// Rows take no input themselves, their list handles it
+impl Interactive for TreeItem {
+    fn handle_input(&mut self, _input: &InputEvent) -> bool {
+        false
+    }
+}
+
+impl Renderable for TreeItem {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        let style = &self.style;
+        let frame = self.base.frame();
+        ctx.push_clip(frame);
+
+        if let Some(expanded) = self.expander {
+            let bounds = self.expander_bounds();
+            let (x, y) = (bounds.top_left().x(), bounds.top_left().y());
+            let size = style.expander_size;
+            let color = style.expander_border.color();
+            ctx.stroke_rect(bounds, &style.expander_border);
+            ctx.draw_line(
+                Position::new(x + 2, y + size / 2),
+                Position::new(x + size.saturating_sub(2), y + size / 2),
+                color,
+                1,
+            );
+            if !expanded {
+                ctx.draw_line(
+                    Position::new(x + size / 2, y + 2),
+                    Position::new(x + size / 2, y + size.saturating_sub(2)),
+                    color,
+                    1,
+                );
+            }
+        }
+
+        if let (Some(bounds), Some(check_state)) = (self.check_bounds(), self.check_state) {
+            let state = if self.enabled {
+                ActionState::Normal
+            } else {
+                ActionState::Disabled
+            };
+            render_check_glyph(ctx, bounds, check_state, &style.check_box, &state);
+        }
+
+        let mut x = self.content_x();
+        if let Some(icon) = &self.icon {
+            let height = icon.size.1.min(u16::MAX as u32) as u16;
+            ctx.draw_icon(self.centered(x, height).top_left(), icon);
+            x += icon.size.0.min(u16::MAX as u32) as u16 + ICON_SPACING;
+        }
+
+        // Only the text is highlighted, as in WinForms
+        let text_size = style.list.typography.measure_text(&self.text);
+        let text_bounds = Boundaries::from_rect(
+            Position::new(
+                x,
+                frame.top_left().y() + frame.height().saturating_sub(text_size.height()) / 2,
+            ),
+            text_size.with_width(text_size.width() + style.list.item_padding * 2),
+        );
+        if self.selected {
+            ctx.fill_rect(text_bounds, &style.list.highlight_background);
+        }
+        ctx.draw_text(
+            text_bounds.top_left().in_x(x + style.list.item_padding),
+            &self.text,
+            &style.list.typography,
+            self.text_color(),
+        );
+        if self.caret {
+            ctx.stroke_rect(text_bounds, &style.list.focus_border);
+        }
+
+        ctx.pop_clip();
+    }
+}
+
+impl Component for TreeItem {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
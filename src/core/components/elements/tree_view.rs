@@ -0,0 +1,1023 @@
+use std::{borrow::Cow, cell::RefCell, collections::HashMap, fmt, rc::Rc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::{icon::Icon, tree_item::TreeItem},
+        properties::{
+            boundaries::Boundaries, check_state::CheckState, position::Position,
+            selection_mode::SelectionMode, size::Size,
+        },
+        styles::tree_view_style::TreeViewStyle,
+    },
+    ui::{
+        render::render_context::RenderContext, selection_model::SelectionModel,
+        type_ahead::TypeAhead, virtual_list::VirtualList,
+    },
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        event_system::EventSystem,
+        input::{InputEvent, Key, Modifiers, MouseButton},
+        types::{EventPayload, EventType},
+    },
+};
+
+/// Stable handle of a node inside its `TreeView`
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// Description of a node and its subtree, used to add nodes to a tree
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TreeNode {
+    pub text: String,
+    pub icon: Option<Icon>,
+    pub children: Vec<TreeNode>,
+    // Children are requested from the tree's loader on the first expand
+    pub lazy: bool,
+    pub expanded: bool,
+    pub check_state: CheckState,
+}
+
+impl TreeNode {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            icon: None,
+            children: Vec::new(),
+            lazy: false,
+            expanded: false,
+            check_state: CheckState::Unchecked,
+        }
+    }
+
+    pub fn with_icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn with_child(mut self, child: TreeNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn with_lazy_children(mut self) -> Self {
+        self.lazy = true;
+        self
+    }
+
+    pub fn with_expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    pub fn with_check_state(mut self, check_state: CheckState) -> Self {
+        self.check_state = check_state;
+        self
+    }
+}
+
+/// Supplies the children of lazy nodes the first time they are expanded.
+/// Closures taking the node id and text implement it.
+pub trait NodeLoader {
+    fn load_children(&mut self, node: NodeId, text: &str) -> Vec<TreeNode>;
+}
+
+impl<F> NodeLoader for F
+where
+    F: FnMut(NodeId, &str) -> Vec<TreeNode>,
+{
+    fn load_children(&mut self, node: NodeId, text: &str) -> Vec<TreeNode> {
+        self(node, text)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Node {
+    text: String,
+    icon: Option<Icon>,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+    depth: u16,
+    expanded: bool,
+    check_state: CheckState,
+    // Lazy node whose children were not requested yet
+    pending_load: bool,
+}
+
+/// Hierarchical list of nodes. Only the rows of expanded branches that are
+/// inside the viewport get a `TreeItem`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TreeView {
+    base: BaseComponent,
+    // Removed nodes leave a hole so ids stay stable
+    nodes: Vec<Option<Node>>,
+    roots: Vec<NodeId>,
+    check_boxes: bool,
+    enabled: bool,
+    #[serde(default)]
+    style: TreeViewStyle,
+    // Selection over positions in `flat`
+    #[serde(skip)]
+    selection: SelectionModel,
+    #[serde(skip)]
+    loader: Option<Rc<RefCell<dyn NodeLoader>>>,
+    // Nodes of expanded branches in display order
+    #[serde(skip)]
+    flat: Vec<NodeId>,
+    #[serde(skip)]
+    list: VirtualList,
+    #[serde(skip)]
+    rows: Vec<TreeItem>,
+    // Frame the rows were laid out for, `None` until the first refresh
+    #[serde(skip)]
+    laid_out: Option<Boundaries>,
+    #[serde(skip)]
+    type_ahead: TypeAhead,
+    #[serde(skip)]
+    focused: bool,
+}
+
+impl fmt::Debug for TreeView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TreeView")
+            .field("base", &self.base)
+            .field("nodes", &self.nodes)
+            .field("roots", &self.roots)
+            .field("check_boxes", &self.check_boxes)
+            .field("enabled", &self.enabled)
+            .field("selection", &self.selection)
+            .field("has_loader", &self.loader.is_some())
+            .finish()
+    }
+}
+
+impl TreeView {
+    pub fn new(base: BaseComponent) -> Self {
+        let mut tree_view = Self {
+            base,
+            nodes: Vec::new(),
+            roots: Vec::new(),
+            check_boxes: false,
+            enabled: true,
+            style: TreeViewStyle::default(),
+            selection: SelectionModel::new(SelectionMode::One),
+            loader: None,
+            flat: Vec::new(),
+            list: VirtualList::default(),
+            rows: Vec::new(),
+            laid_out: None,
+            type_ahead: TypeAhead::new(),
+            focused: false,
+        };
+        tree_view.refresh();
+        tree_view
+    }
+
+    pub fn with_node(mut self, node: TreeNode) -> Self {
+        self.add_root(node);
+        self
+    }
+
+    pub fn with_loader(mut self, loader: impl NodeLoader + 'static) -> Self {
+        self.set_loader(loader);
+        self
+    }
+
+    pub fn with_check_boxes(mut self, check_boxes: bool) -> Self {
+        self.set_check_boxes(check_boxes);
+        self
+    }
+
+    pub fn with_selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection.set_mode(mode);
+        self
+    }
+
+    pub fn with_style(mut self, style: TreeViewStyle) -> Self {
+        self.set_style(style);
+        self
+    }
+
+    // Nodes
+    pub fn add_root(&mut self, node: TreeNode) -> NodeId {
+        let id = self.insert(None, node);
+        self.roots.push(id);
+        self.rebuild();
+        id
+    }
+
+    pub fn add_child(&mut self, parent: NodeId, node: TreeNode) -> Option<NodeId> {
+        self.node(parent)?;
+        let id = self.insert(Some(parent), node);
+        if let Some(parent) = self.node_mut(parent) {
+            parent.children.push(id);
+        }
+        self.rebuild();
+        Some(id)
+    }
+
+    /// Removes a node together with its subtree
+    pub fn remove_node(&mut self, id: NodeId) -> bool {
+        let Some(node) = self.node(id) else {
+            return false;
+        };
+
+        match node.parent {
+            Some(parent) => {
+                if let Some(parent) = self.node_mut(parent) {
+                    parent.children.retain(|child| *child != id);
+                }
+            }
+            None => self.roots.retain(|root| *root != id),
+        }
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            if let Some(node) = self.nodes[id.0].take() {
+                stack.extend(node.children);
+            }
+        }
+        self.rebuild();
+        true
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.roots.clear();
+        self.rebuild();
+    }
+
+    // Setters
+    pub fn set_loader(&mut self, loader: impl NodeLoader + 'static) {
+        self.loader = Some(Rc::new(RefCell::new(loader)));
+    }
+
+    pub fn set_check_boxes(&mut self, check_boxes: bool) {
+        self.check_boxes = check_boxes;
+        self.refresh();
+    }
+
+    pub fn set_selection_mode(&mut self, mode: SelectionMode) {
+        if self.selection.set_mode(mode) {
+            self.emit_selection();
+        }
+        self.refresh();
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.refresh();
+    }
+
+    pub fn set_style(&mut self, style: TreeViewStyle) {
+        self.style = style;
+        self.refresh();
+    }
+
+    pub fn expand(&mut self, id: NodeId) {
+        let Some(node) = self.node(id) else {
+            return;
+        };
+        if node.expanded {
+            return;
+        }
+
+        if node.pending_load {
+            self.load_children(id);
+        }
+        let Some(node) = self.node_mut(id) else {
+            return;
+        };
+        if node.children.is_empty() {
+            // Nothing to show, the expander goes away
+            self.refresh();
+            return;
+        }
+        node.expanded = true;
+        self.rebuild();
+        self.base
+            .emit_with(EventType::ItemExpanded, EventPayload::Index(id.0));
+    }
+
+    /// Collapses a node; selected nodes it hides pass their selection to it
+    pub fn collapse(&mut self, id: NodeId) {
+        let Some(node) = self.node_mut(id) else {
+            return;
+        };
+        if !node.expanded {
+            return;
+        }
+
+        node.expanded = false;
+        self.rebuild();
+        self.base
+            .emit_with(EventType::ItemCollapsed, EventPayload::Index(id.0));
+    }
+
+    pub fn toggle_expanded(&mut self, id: NodeId) {
+        if self.is_expanded(id) {
+            self.collapse(id);
+        } else {
+            self.expand(id);
+        }
+    }
+
+    /// Expands every node, loading lazy ones on the way
+    pub fn expand_all(&mut self) {
+        let mut stack = self.roots.clone();
+        while let Some(id) = stack.pop() {
+            self.expand(id);
+            if let Some(node) = self.node(id) {
+                stack.extend(node.children.iter().copied());
+            }
+        }
+    }
+
+    pub fn collapse_all(&mut self) {
+        let expanded: Vec<NodeId> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.as_ref().is_some_and(|node| node.expanded))
+            .map(|(index, _)| NodeId(index))
+            .collect();
+        for id in expanded.into_iter().rev() {
+            self.collapse(id);
+        }
+    }
+
+    /// Checks or unchecks a node and its loaded subtree; ancestors become
+    /// indeterminate when their children disagree
+    pub fn set_check_state(&mut self, id: NodeId, check_state: CheckState) {
+        let Some(node) = self.node(id) else {
+            return;
+        };
+        if node.check_state == check_state {
+            return;
+        }
+
+        let mut stack = vec![id];
+        while let Some(current) = stack.pop() {
+            let Some(node) = self.node_mut(current) else {
+                continue;
+            };
+            node.check_state = check_state;
+            if check_state != CheckState::Indeterminate {
+                stack.extend(node.children.iter().copied());
+            }
+        }
+
+        let mut parent = self.node(id).and_then(|node| node.parent);
+        while let Some(current) = parent {
+            let states: Vec<CheckState> = self.nodes_of(current).map(|n| n.check_state).collect();
+            let state = if states.iter().all(|s| *s == CheckState::Checked) {
+                CheckState::Checked
+            } else if states.iter().all(|s| *s == CheckState::Unchecked) {
+                CheckState::Unchecked
+            } else {
+                CheckState::Indeterminate
+            };
+            let Some(node) = self.node_mut(current) else {
+                break;
+            };
+            node.check_state = state;
+            parent = node.parent;
+        }
+
+        self.refresh();
+        self.base.emit_with(
+            EventType::ItemChecked,
+            EventPayload::ItemCheckState(id.0, check_state),
+        );
+    }
+
+    /// Selects a single node, expanding its ancestors and scrolling it into view
+    pub fn select_node(&mut self, id: NodeId) {
+        if self.node(id).is_none() {
+            return;
+        }
+
+        self.reveal(id);
+        let Some(position) = self.position_of(id) else {
+            return;
+        };
+        let changed = self.selection.select_only(position);
+        self.list.ensure_visible(position);
+        self.refresh();
+        if changed {
+            self.emit_selection();
+        }
+    }
+
+    pub fn clear_selection(&mut self) {
+        if self.selection.clear() {
+            self.emit_selection();
+        }
+        self.refresh();
+    }
+
+    /// Expands the ancestors of a node and scrolls to it
+    pub fn ensure_visible(&mut self, id: NodeId) {
+        self.reveal(id);
+        if let Some(position) = self.position_of(id) {
+            self.list.ensure_visible(position);
+        }
+        self.refresh();
+    }
+
+    /// Lays the rows out again. Input does it on its own once the frame has
+    /// changed, and rendering paints the rows where they would go
+    pub fn refresh(&mut self) {
+        self.laid_out = Some(self.base.frame());
+        self.flat = self.shown_nodes().into_owned();
+        self.list = self.fitted_list(&self.flat);
+
+        let frames = self.row_frames(&self.flat, &self.list);
+        let mut rows = std::mem::take(&mut self.rows);
+        rows.truncate(frames.len());
+        while rows.len() < frames.len() {
+            let base = BaseComponent::new(
+                Size::new(0, 0),
+                self.viewport().top_left(),
+                Default::default(),
+                Default::default(),
+                self.base.event_system().clone(),
+            );
+            rows.push(TreeItem::new(base, self.style.clone()));
+        }
+        for (row, (position, id, frame)) in rows.iter_mut().zip(frames) {
+            row.place(frame.top_left(), frame.size());
+            self.bind_row(row, position, id);
+        }
+        self.rows = rows;
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn roots(&self) -> &[NodeId] {
+        &self.roots
+    }
+
+    pub fn text(&self, id: NodeId) -> Option<&str> {
+        self.node(id).map(|node| node.text.as_str())
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id)?.parent
+    }
+
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        self.node(id).map_or(&[], |node| node.children.as_slice())
+    }
+
+    pub fn is_expanded(&self, id: NodeId) -> bool {
+        self.node(id).is_some_and(|node| node.expanded)
+    }
+
+    pub fn check_state(&self, id: NodeId) -> Option<CheckState> {
+        self.node(id).map(|node| node.check_state)
+    }
+
+    /// Nodes with a checked box, in no particular order
+    pub fn checked_nodes(&self) -> Vec<NodeId> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| {
+                node.as_ref()
+                    .is_some_and(|node| node.check_state == CheckState::Checked)
+            })
+            .map(|(index, _)| NodeId(index))
+            .collect()
+    }
+
+    pub fn check_boxes(&self) -> bool {
+        self.check_boxes
+    }
+
+    pub fn selection_mode(&self) -> SelectionMode {
+        self.selection.mode()
+    }
+
+    /// Selected nodes in display order
+    pub fn selected_nodes(&self) -> Vec<NodeId> {
+        self.selection
+            .selected()
+            .into_iter()
+            .map(|position| self.flat[position])
+            .collect()
+    }
+
+    pub fn selected_node(&self) -> Option<NodeId> {
+        self.selection
+            .first_selected()
+            .map(|position| self.flat[position])
+    }
+
+    pub fn caret_node(&self) -> Option<NodeId> {
+        self.selection.caret().map(|position| self.flat[position])
+    }
+
+    /// Nodes of expanded branches in display order
+    pub fn visible_nodes(&self) -> &[NodeId] {
+        &self.flat
+    }
+
+    /// Rows currently backed by a component
+    pub fn visible_rows(&self) -> &[TreeItem] {
+        &self.rows
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn style(&self) -> &TreeViewStyle {
+        &self.style
+    }
+
+    // Internals
+    fn node(&self, id: NodeId) -> Option<&Node> {
+        self.nodes.get(id.0)?.as_ref()
+    }
+
+    fn node_mut(&mut self, id: NodeId) -> Option<&mut Node> {
+        self.nodes.get_mut(id.0)?.as_mut()
+    }
+
+    fn nodes_of(&self, parent: NodeId) -> impl Iterator<Item = &Node> {
+        self.children(parent)
+            .iter()
+            .filter_map(|child| self.node(*child))
+    }
+
+    fn insert(&mut self, parent: Option<NodeId>, node: TreeNode) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        let depth = parent
+            .and_then(|parent| self.node(parent))
+            .map_or(0, |parent| parent.depth + 1);
+        self.nodes.push(Some(Node {
+            text: node.text,
+            icon: node.icon,
+            parent,
+            children: Vec::new(),
+            depth,
+            expanded: node.expanded,
+            check_state: node.check_state,
+            pending_load: node.lazy && node.children.is_empty(),
+        }));
+
+        let children: Vec<NodeId> = node
+            .children
+            .into_iter()
+            .map(|child| self.insert(Some(id), child))
+            .collect();
+        if let Some(node) = self.node_mut(id) {
+            node.children = children;
+        }
+        id
+    }
+
+    fn load_children(&mut self, id: NodeId) {
+        let (Some(loader), Some(node)) = (self.loader.clone(), self.node(id)) else {
+            return;
+        };
+
+        let text = node.text.clone();
+        let inherited = node.check_state;
+        let children = loader.borrow_mut().load_children(id, &text);
+        let ids: Vec<NodeId> = children
+            .into_iter()
+            .map(|mut child| {
+                // A checked or unchecked parent decides for the children it receives
+                if inherited != CheckState::Indeterminate {
+                    child.check_state = inherited;
+                }
+                self.insert(Some(id), child)
+            })
+            .collect();
+        if let Some(node) = self.node_mut(id) {
+            node.pending_load = false;
+            node.children.extend(ids);
+        }
+    }
+
+    fn flatten(&self) -> Vec<NodeId> {
+        let mut flat = Vec::new();
+        let mut stack: Vec<NodeId> = self.roots.iter().rev().copied().collect();
+        while let Some(id) = stack.pop() {
+            let Some(node) = self.node(id) else {
+                continue;
+            };
+            flat.push(id);
+            if node.expanded {
+                stack.extend(node.children.iter().rev().copied());
+            }
+        }
+        flat
+    }
+
+    fn position_of(&self, id: NodeId) -> Option<usize> {
+        self.flat.iter().position(|node| *node == id)
+    }
+
+    // Expands every ancestor of a node
+    fn reveal(&mut self, id: NodeId) {
+        let mut ancestors = Vec::new();
+        let mut parent = self.parent(id);
+        while let Some(current) = parent {
+            ancestors.push(current);
+            parent = self.parent(current);
+        }
+        for ancestor in ancestors.into_iter().rev() {
+            self.expand(ancestor);
+        }
+    }
+
+    // Recomputes the visible nodes after the shape of the tree changed,
+    // carrying the selection over to the same nodes or their visible ancestors
+    fn rebuild(&mut self) {
+        let selected = self.selected_nodes_in(&self.flat);
+        let caret = self
+            .selection
+            .caret()
+            .and_then(|position| self.flat.get(position).copied());
+
+        self.flat = self.flatten();
+        let positions: HashMap<NodeId, usize> = self
+            .flat
+            .iter()
+            .enumerate()
+            .map(|(position, id)| (*id, position))
+            .collect();
+        let visible = |mut id: NodeId| loop {
+            if let Some(position) = positions.get(&id) {
+                return Some(*position);
+            }
+            id = self.node(id)?.parent?;
+        };
+
+        let mut selection = SelectionModel::new(self.selection.mode());
+        for position in selected.iter().filter_map(|id| visible(*id)) {
+            selection.set_selected(position, true);
+        }
+        selection.set_caret(caret.and_then(visible));
+        self.selection = selection;
+
+        self.refresh();
+        if self.selected_nodes_in(&self.flat) != selected {
+            self.emit_selection();
+        }
+    }
+
+    fn selected_nodes_in(&self, flat: &[NodeId]) -> Vec<NodeId> {
+        let mut nodes: Vec<NodeId> = self
+            .selection
+            .selected()
+            .into_iter()
+            .filter_map(|position| flat.get(position).copied())
+            .collect();
+        nodes.sort();
+        nodes
+    }
+
+    fn emit_selection(&self) {
+        let mut nodes: Vec<usize> = self.selected_nodes().iter().map(|id| id.0).collect();
+        nodes.sort();
+        self.base
+            .emit_with(EventType::FieldValueChanged, EventPayload::Indices(nodes));
+    }
+
+    // Layout
+    // Moved or resized since the last refresh, or just deserialized
+    fn stale(&self) -> bool {
+        self.laid_out != Some(self.base.frame())
+    }
+
+    fn viewport(&self) -> Boundaries {
+        let frame = self.base.frame();
+        Boundaries::from_rect(
+            Position::new(frame.top_left().x() + 1, frame.top_left().y() + 1),
+            Size::new(
+                frame.height().saturating_sub(2),
+                frame.width().saturating_sub(2),
+            ),
+        )
+    }
+
+    fn row_width(&self, list: &VirtualList, viewport: Boundaries) -> u16 {
+        if list.thumb(viewport.height()).is_some() {
+            viewport
+                .width()
+                .saturating_sub(self.style.list.scrollbar_width)
+        } else {
+            viewport.width()
+        }
+    }
+
+    // Nodes in display order; deserialized trees have not flattened theirs yet
+    fn shown_nodes(&self) -> Cow<'_, [NodeId]> {
+        if self.flat.is_empty() && !self.roots.is_empty() {
+            Cow::Owned(self.flatten())
+        } else {
+            Cow::Borrowed(self.flat.as_slice())
+        }
+    }
+
+    // Scroll state fitted to the current frame and nodes
+    fn fitted_list(&self, flat: &[NodeId]) -> VirtualList {
+        let mut list = self.list;
+        list.set_item_count(flat.len());
+        list.set_row_height(self.style.list.item_height);
+        list.set_viewport_height(self.viewport().height());
+        list
+    }
+
+    // Display position, node and frame of every row `list` shows
+    fn row_frames(&self, flat: &[NodeId], list: &VirtualList) -> Vec<(usize, NodeId, Boundaries)> {
+        let viewport = self.viewport();
+        let size = Size::new(self.style.list.item_height, self.row_width(list, viewport));
+        list.visible_range()
+            .filter(|position| self.nodes[flat[*position].0].is_some())
+            .map(|position| {
+                let top = list.row_top(position).unwrap_or_default();
+                let at = Position::new(viewport.top_left().x(), viewport.top_left().y() + top);
+                (position, flat[position], Boundaries::from_rect(at, size))
+            })
+            .collect()
+    }
+
+    fn bind_row(&self, row: &mut TreeItem, position: usize, id: NodeId) {
+        let Some(node) = self.nodes[id.0].as_ref() else {
+            return;
+        };
+        let has_children = !node.children.is_empty() || node.pending_load;
+        row.bind(id, node.depth, &node.text, node.icon.as_ref());
+        row.set_expander(has_children.then_some(node.expanded));
+        row.set_check_state(self.check_boxes.then_some(node.check_state));
+        row.set_style(&self.style);
+        row.set_enabled(self.enabled);
+        row.set_selected(self.selection.is_selected(position));
+        row.set_caret(self.focused && self.selection.caret() == Some(position));
+    }
+
+    // Rows for a frame the tree was not laid out for. They report to a private
+    // event system, so painting them takes no ids and emits nothing
+    fn detached_rows(&self, flat: &[NodeId], list: &VirtualList) -> Vec<TreeItem> {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        self.row_frames(flat, list)
+            .into_iter()
+            .map(|(position, id, frame)| {
+                let base = BaseComponent::new(
+                    frame.size(),
+                    frame.top_left(),
+                    Default::default(),
+                    Default::default(),
+                    event_system.clone(),
+                );
+                let mut row = TreeItem::new(base, self.style.clone());
+                self.bind_row(&mut row, position, id);
+                row
+            })
+            .collect()
+    }
+
+    fn row_at(&self, point: Position) -> Option<&TreeItem> {
+        self.rows
+            .iter()
+            .find(|row| row.base().frame().contains(point))
+    }
+
+    fn toggle_check(&mut self, id: NodeId) {
+        let next = match self.check_state(id) {
+            Some(CheckState::Checked) => CheckState::Unchecked,
+            Some(_) => CheckState::Checked,
+            None => return,
+        };
+        self.set_check_state(id, next);
+    }
+
+    fn navigate(&mut self, position: usize, modifiers: Modifiers) {
+        self.list.ensure_visible(position);
+        if self.selection.navigate(position, modifiers) {
+            self.emit_selection();
+        }
+    }
+
+    fn handle_key(&mut self, key: Key, modifiers: Modifiers) -> bool {
+        let Some(last) = self.flat.len().checked_sub(1) else {
+            return false;
+        };
+        let caret = self.selection.caret();
+        let caret_node = caret.map(|position| self.flat[position]);
+        let page = self.list.page_size();
+
+        match (key, caret, caret_node) {
+            (Key::Char('a') | Key::Char('A'), _, _) if modifiers.ctrl => {
+                if self.selection.select_all(self.flat.len()) {
+                    self.emit_selection();
+                }
+            }
+            (Key::Space, _, Some(id)) if self.check_boxes => self.toggle_check(id),
+            (Key::Space, _, _) => {
+                if self.selection.toggle_caret() {
+                    self.emit_selection();
+                }
+            }
+            // Right expands a collapsed node, then steps into its first child
+            (Key::Right, Some(position), Some(id)) => {
+                if !self.is_expanded(id) {
+                    self.expand(id);
+                } else if !self.children(id).is_empty() {
+                    self.navigate(position + 1, modifiers);
+                }
+            }
+            // Left collapses an expanded node, then steps out to its parent
+            (Key::Left, _, Some(id)) => {
+                if self.is_expanded(id) {
+                    self.collapse(id);
+                } else if let Some(parent) = self.parent(id).and_then(|p| self.position_of(p)) {
+                    self.navigate(parent, modifiers);
+                }
+            }
+            (Key::Up, Some(position), _) => self.navigate(position.saturating_sub(1), modifiers),
+            (Key::Down, Some(position), _) => self.navigate((position + 1).min(last), modifiers),
+            (Key::PageUp, Some(position), _) => {
+                self.navigate(position.saturating_sub(page - 1), modifiers)
+            }
+            (Key::PageDown, Some(position), _) => {
+                self.navigate((position + page - 1).min(last), modifiers)
+            }
+            (Key::Up | Key::Down | Key::PageUp | Key::PageDown | Key::Home, _, _) => {
+                self.navigate(0, modifiers)
+            }
+            (Key::End, _, _) => self.navigate(last, modifiers),
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl Interactive for TreeView {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() {
+            return false;
+        }
+        if self.stale() {
+            self.refresh();
+        }
+
+        let consumed = match *input {
+            InputEvent::MouseButtonPressed {
+                button: MouseButton::Left,
+                position,
+                modifiers,
+            } if self.base.hit_test(position) => {
+                self.set_focused(true);
+                let hit = self.row_at(position).map(|row| {
+                    let on_expander =
+                        row.expander().is_some() && row.expander_bounds().contains(position);
+                    let on_check = row
+                        .check_bounds()
+                        .is_some_and(|bounds| bounds.contains(position));
+                    (row.node(), on_expander, on_check)
+                });
+                match hit {
+                    Some((id, true, _)) => self.toggle_expanded(id),
+                    Some((id, _, true)) => self.toggle_check(id),
+                    Some((id, _, _)) => {
+                        if let Some(position) = self.position_of(id)
+                            && self.selection.click(position, modifiers)
+                        {
+                            self.emit_selection();
+                        }
+                    }
+                    None => {}
+                }
+                true
+            }
+            InputEvent::MouseScrolled {
+                delta_y, position, ..
+            } if self.base.hit_test(position) => {
+                self.list.scroll_by(-(delta_y as isize) * 3);
+                true
+            }
+            InputEvent::KeyPressed { key, modifiers } if self.focused => {
+                self.handle_key(key, modifiers)
+            }
+            InputEvent::CharTyped(c) if self.focused && !c.is_control() => {
+                let nodes = &self.nodes;
+                let texts: Vec<&str> = self
+                    .flat
+                    .iter()
+                    .filter_map(|id| nodes[id.0].as_ref().map(|node| node.text.as_str()))
+                    .collect();
+                if let Some(position) = self.type_ahead.search(c, &texts, self.selection.caret()) {
+                    self.navigate(position, Modifiers::none());
+                }
+                true
+            }
+            _ => false,
+        };
+
+        if consumed {
+            self.refresh();
+        }
+        consumed
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled && *self.base.visible()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        if self.focused == focused {
+            return;
+        }
+
+        self.focused = focused;
+        if focused {
+            if self.selection.caret().is_none() && !self.flat.is_empty() {
+                let caret = self.selection.first_selected().unwrap_or(0);
+                self.selection.set_caret(Some(caret));
+            }
+            self.base.emit(EventType::FieldFocused);
+        } else {
+            self.type_ahead.reset();
+            self.base.emit(EventType::FieldFocusedLost);
+        }
+        self.refresh();
+    }
+}
+
+impl Renderable for TreeView {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+        let flat = self.shown_nodes();
+        let list = self.fitted_list(&flat);
+        let rows = if self.stale() {
+            Cow::Owned(self.detached_rows(&flat, &list))
+        } else {
+            Cow::Borrowed(self.rows.as_slice())
+        };
+
+        let style = &self.style.list;
+        let frame = self.base.frame();
+        let viewport = self.viewport();
+        ctx.fill_rect(frame, &style.background);
+
+        ctx.push_clip(viewport);
+        for row in rows.iter() {
+            row.paint(ctx);
+        }
+        if let Some((offset, length)) = list.thumb(viewport.height()) {
+            let thumb = Boundaries::from_rect(
+                Position::new(
+                    viewport.top_left().x() + self.row_width(&list, viewport),
+                    viewport.top_left().y() + offset,
+                ),
+                Size::new(length, style.scrollbar_width),
+            );
+            ctx.fill_rect(thumb, &style.scrollbar_thumb);
+        }
+        ctx.pop_clip();
+
+        if self.focused {
+            ctx.stroke_rect(frame, &style.focus_border);
+        } else {
+            ctx.stroke_rect(frame, &style.border);
+        }
+    }
+}
+
+impl Component for TreeView {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
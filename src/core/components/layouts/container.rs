@@ -1,14 +1,479 @@
+use std::fmt;
+
 use crate::core::{
     components::{
         base_component::BaseComponent,
-        properties::{overflow::Overflow, size::SizePolicy},
+        properties::{
+            boundaries::Boundaries,
+            orientation::Orientation,
+            overflow::Overflow,
+            position::Position,
+            size::{Size, SizePolicy},
+        },
     },
-    utils::traits::renderable::Renderable,
+    ui::render::render_context::RenderContext,
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{input::InputEvent, types::EventType},
 };
 
+/// Groups components into a subtree. Children are painted in insertion order,
+/// so later children sit on top and get pointer input first; keyboard input
+/// only reaches the child holding the focus.
+///
+/// Children keep the position they were given unless a flow is set, in which
+/// case `layout` lines them up one after another along it.
 pub struct Container {
     base: BaseComponent,
-    children: Vec<Box<dyn Renderable>>,
+    children: Vec<Box<dyn Component>>,
+    // How each child is sized when laid out, parallel to `children`
+    policies: Vec<SizePolicy>,
     overflow: Overflow,
     sizing_policy: SizePolicy,
+    flow: Option<Orientation>,
+    spacing: u16,
+    focus_index: Option<usize>,
+    focused: bool,
+    // Child under the pointer, reported with HoverEntered and HoverExited
+    hovered: Option<usize>,
+}
+
+impl fmt::Debug for Container {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Container")
+            .field("base", &self.base)
+            .field("children", &self.children.len())
+            .field("overflow", &self.overflow)
+            .field("sizing_policy", &self.sizing_policy)
+            .field("flow", &self.flow)
+            .field("spacing", &self.spacing)
+            .field("focus_index", &self.focus_index)
+            .finish()
+    }
+}
+
+impl Container {
+    pub fn new(base: BaseComponent) -> Self {
+        Self {
+            base,
+            children: Vec::new(),
+            policies: Vec::new(),
+            overflow: Overflow::default(),
+            sizing_policy: SizePolicy::default(),
+            flow: None,
+            spacing: 0,
+            focus_index: None,
+            focused: false,
+            hovered: None,
+        }
+    }
+
+    pub fn with_child<C: Component + 'static>(mut self, child: C) -> Self {
+        self.add_child(child);
+        self
+    }
+
+    pub fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    pub fn with_sizing_policy(mut self, sizing_policy: SizePolicy) -> Self {
+        self.sizing_policy = sizing_policy;
+        self
+    }
+
+    pub fn with_flow(mut self, flow: Orientation) -> Self {
+        self.flow = Some(flow);
+        self
+    }
+
+    pub fn with_spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    pub fn add_child<C: Component + 'static>(&mut self, child: C) {
+        self.add_child_with_policy(child, SizePolicy::Fixed);
+    }
+
+    /// Adds a child sized by `policy` whenever the container lays out
+    pub fn add_child_with_policy<C: Component + 'static>(&mut self, child: C, policy: SizePolicy) {
+        self.children.push(Box::new(child));
+        self.policies.push(policy);
+        self.base.emit(EventType::ComponentAdded);
+    }
+
+    pub fn remove_child(&mut self, index: usize) -> Option<Box<dyn Component>> {
+        if index >= self.children.len() {
+            return None;
+        }
+
+        let mut removed = self.children.remove(index);
+        self.policies.remove(index);
+        removed.set_focused(false);
+        self.focus_index = match self.focus_index {
+            Some(focus) if focus == index => None,
+            Some(focus) if focus > index => Some(focus - 1),
+            other => other,
+        };
+        self.hovered = match self.hovered {
+            Some(hovered) if hovered == index => None,
+            Some(hovered) if hovered > index => Some(hovered - 1),
+            other => other,
+        };
+        self.base.emit(EventType::ComponentRemoved);
+        Some(removed)
+    }
+
+    pub fn clear(&mut self) {
+        while !self.children.is_empty() {
+            self.remove_child(self.children.len() - 1);
+        }
+    }
+
+    pub fn set_overflow(&mut self, overflow: Overflow) {
+        self.overflow = overflow;
+    }
+
+    pub fn set_sizing_policy(&mut self, sizing_policy: SizePolicy) {
+        self.sizing_policy = sizing_policy;
+    }
+
+    pub fn set_flow(&mut self, flow: Option<Orientation>) {
+        self.flow = flow;
+    }
+
+    pub fn set_spacing(&mut self, spacing: u16) {
+        self.spacing = spacing;
+    }
+
+    pub fn set_child_policy(&mut self, index: usize, policy: SizePolicy) {
+        if let Some(current) = self.policies.get_mut(index) {
+            *current = policy;
+        }
+    }
+
+    /// Lines the visible children up along the flow; without one, children
+    /// stay where they are. Call it after adding children or resizing.
+    pub fn layout(&mut self) {
+        let Some(flow) = self.flow else {
+            return;
+        };
+
+        let visible: Vec<usize> = (0..self.children.len())
+            .filter(|index| *self.children[*index].base().visible())
+            .collect();
+        let items: Vec<(Size, SizePolicy)> = visible
+            .iter()
+            .map(|index| (*self.children[*index].base().size(), self.policies[*index]))
+            .collect();
+        let frames = Self::arrange(self.base.frame(), flow, self.spacing, &items);
+        for (index, frame) in visible.into_iter().zip(frames) {
+            let child = self.children[index].base_mut();
+            child.set_position(frame.top_left());
+            child.set_size(frame.size());
+        }
+    }
+
+    /// Places items one after another inside `frame` along `flow`, `spacing`
+    /// apart. `Fixed` items keep their size and are centered across the flow,
+    /// `Fit` items keep their length and stretch across it, and `Fill` items
+    /// stretch too while sharing the length nobody else takes. Items that do
+    /// not fit run past the end of the frame.
+    pub fn arrange(
+        frame: Boundaries,
+        flow: Orientation,
+        spacing: u16,
+        items: &[(Size, SizePolicy)],
+    ) -> Vec<Boundaries> {
+        let horizontal = flow == Orientation::Horizontal;
+        let along = |size: &Size| {
+            if horizontal {
+                size.width()
+            } else {
+                size.height()
+            }
+        };
+        let across = |size: &Size| {
+            if horizontal {
+                size.height()
+            } else {
+                size.width()
+            }
+        };
+
+        let gaps = spacing as u32 * items.len().saturating_sub(1) as u32;
+        let taken: u32 = items
+            .iter()
+            .filter(|(_, policy)| *policy != SizePolicy::Fill)
+            .map(|(size, _)| along(size) as u32)
+            .sum();
+        let fills = items
+            .iter()
+            .filter(|(_, policy)| *policy == SizePolicy::Fill)
+            .count() as u32;
+        let free = (along(&frame.size()) as u32).saturating_sub(taken + gaps);
+        // Extra pixels go to the first fill items
+        let (share, mut extra) = match fills {
+            0 => (0, 0),
+            _ => (free / fills, free % fills),
+        };
+
+        let start = if horizontal {
+            frame.top_left().x()
+        } else {
+            frame.top_left().y()
+        };
+        let cross_start = if horizontal {
+            frame.top_left().y()
+        } else {
+            frame.top_left().x()
+        };
+        let cross_length = across(&frame.size());
+        let mut offset = start as u32;
+        items
+            .iter()
+            .map(|(size, policy)| {
+                let length = match policy {
+                    SizePolicy::Fill => {
+                        let length = share + u32::from(extra > 0);
+                        extra = extra.saturating_sub(1);
+                        length.min(u16::MAX as u32) as u16
+                    }
+                    _ => along(size),
+                };
+                let (cross, thickness) = match policy {
+                    SizePolicy::Fixed => {
+                        let thickness = across(size);
+                        (
+                            cross_start + cross_length.saturating_sub(thickness) / 2,
+                            thickness,
+                        )
+                    }
+                    _ => (cross_start, cross_length),
+                };
+                let at = offset.min(u16::MAX as u32) as u16;
+                offset += length as u32 + spacing as u32;
+                if horizontal {
+                    Boundaries::from_rect(Position::new(at, cross), Size::new(thickness, length))
+                } else {
+                    Boundaries::from_rect(Position::new(cross, at), Size::new(length, thickness))
+                }
+            })
+            .collect()
+    }
+
+    /// Hides or shows the whole subtree; a hidden container drops its focus
+    pub fn set_visible(&mut self, visible: bool) {
+        self.base.set_visible(visible);
+        if !visible {
+            self.set_focused(false);
+        }
+    }
+
+    /// Gives the focus to a child, taking it from the previous one
+    pub fn focus_child(&mut self, index: Option<usize>) {
+        let index = index.filter(|index| {
+            self.children
+                .get(*index)
+                .is_some_and(|child| child.focusable())
+        });
+        if self.focus_index == index {
+            return;
+        }
+
+        if let Some(previous) = self.focus_index.and_then(|i| self.children.get_mut(i)) {
+            previous.set_focused(false);
+        }
+        self.focus_index = index;
+        if let Some(child) = index.and_then(|i| self.children.get_mut(i)) {
+            child.set_focused(true);
+        }
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn children(&self) -> &[Box<dyn Component>] {
+        &self.children
+    }
+
+    pub fn child(&self, index: usize) -> Option<&dyn Component> {
+        self.children.get(index).map(|child| child.as_ref())
+    }
+
+    pub fn child_mut(&mut self, index: usize) -> Option<&mut (dyn Component + 'static)> {
+        self.children.get_mut(index).map(|child| child.as_mut())
+    }
+
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    pub fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    pub fn sizing_policy(&self) -> SizePolicy {
+        self.sizing_policy
+    }
+
+    pub fn flow(&self) -> Option<Orientation> {
+        self.flow
+    }
+
+    pub fn spacing(&self) -> u16 {
+        self.spacing
+    }
+
+    pub fn child_policy(&self, index: usize) -> Option<SizePolicy> {
+        self.policies.get(index).copied()
+    }
+
+    pub fn focused_child(&self) -> Option<usize> {
+        self.focus_index
+    }
+
+    // Topmost visible child under a point
+    fn child_at(&self, input: &InputEvent) -> Option<usize> {
+        let position = input.mouse_position()?;
+        self.children
+            .iter()
+            .rposition(|child| child.base().hit_test(position))
+    }
+
+    // Presses and wheel go to the focused child first, so open drop-downs see
+    // clicks outside their frame, then to the child under the pointer
+    fn dispatch_pointer(&mut self, input: &InputEvent) -> Option<usize> {
+        if let Some(focus) = self.focus_index
+            && self.children[focus].handle_input(input)
+        {
+            return Some(focus);
+        }
+
+        let target = self
+            .child_at(input)
+            .filter(|i| Some(*i) != self.focus_index)?;
+        self.children[target].handle_input(input).then_some(target)
+    }
+
+    // Reports the pointer entering and leaving children for them, so
+    // tooltips work on components that do not track hover themselves
+    fn update_hover(&mut self, input: &InputEvent) {
+        let hovered = self.child_at(input);
+        if hovered == self.hovered {
+            return;
+        }
+        if let Some(index) = self.hovered {
+            self.children[index].base().emit(EventType::HoverExited);
+        }
+        if let Some(index) = hovered {
+            self.children[index].base().emit(EventType::HoverEntered);
+        }
+        self.hovered = hovered;
+    }
+}
+
+impl Interactive for Container {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.base.visible() {
+            return false;
+        }
+
+        if input.is_keyboard() {
+            return match self.focus_index {
+                Some(focus) => self.children[focus].handle_input(input),
+                None => false,
+            };
+        }
+
+        match input {
+            InputEvent::MouseButtonPressed { .. } => match self.dispatch_pointer(input) {
+                Some(target) => {
+                    if self.children[target].focusable() {
+                        self.focused = true;
+                        self.focus_child(Some(target));
+                    }
+                    true
+                }
+                None => false,
+            },
+            InputEvent::MouseScrolled { .. } => self.dispatch_pointer(input).is_some(),
+            // Moves and releases reach every child so hover and drags can end
+            _ => {
+                if let InputEvent::MouseMoved(_) = input {
+                    self.update_hover(input);
+                }
+                let mut consumed = false;
+                for child in &mut self.children {
+                    consumed |= child.handle_input(input);
+                }
+                consumed
+            }
+        }
+    }
+
+    fn focusable(&self) -> bool {
+        *self.base.visible() && self.children.iter().any(|child| child.focusable())
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        if self.focused == focused {
+            return;
+        }
+
+        self.focused = focused;
+        if focused {
+            let first = self.children.iter().position(|child| child.focusable());
+            self.focus_child(self.focus_index.or(first));
+        } else {
+            self.focus_child(None);
+        }
+    }
+}
+
+impl Renderable for Container {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        let clip = self.overflow != Overflow::Visible;
+        if clip {
+            ctx.push_clip(self.base.frame());
+        }
+        for child in &self.children {
+            child.paint(ctx);
+        }
+        if clip {
+            ctx.pop_clip();
+        }
+    }
+}
+
+impl Component for Container {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    fn find_frame(&self, id: usize) -> Option<Boundaries> {
+        if *self.base.id() == id {
+            return Some(self.base.frame());
+        }
+        self.children.iter().find_map(|child| child.find_frame(id))
+    }
 }
@@ -0,0 +1,251 @@
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        layouts::container::Container,
+        properties::{boundaries::Boundaries, overflow::Overflow, position::Position, size::Size},
+        styles::group_box_style::GroupBoxStyle,
+    },
+    ui::render::render_context::RenderContext,
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::input::InputEvent,
+};
+
+/// Frame with a title set into its top border, grouping related components
+/// such as a set of radio buttons. Children are placed inside
+/// `display_rectangle`; disabling the group disables everything in it.
+#[derive(Debug)]
+pub struct GroupBox {
+    // Shares the group's base, so its events carry the group's id
+    content: Container,
+    text: String,
+    enabled: bool,
+    style: GroupBoxStyle,
+}
+
+impl GroupBox {
+    pub fn new(base: BaseComponent, text: &str) -> Self {
+        Self {
+            content: Container::new(base).with_overflow(Overflow::Hidden),
+            text: text.to_string(),
+            enabled: true,
+            style: GroupBoxStyle::default(),
+        }
+    }
+
+    pub fn with_child<C: Component + 'static>(mut self, child: C) -> Self {
+        self.add_child(child);
+        self
+    }
+
+    pub fn with_style(mut self, style: GroupBoxStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn add_child<C: Component + 'static>(&mut self, child: C) {
+        self.content.add_child(child);
+    }
+
+    pub fn remove_child(&mut self, index: usize) -> Option<Box<dyn Component>> {
+        self.content.remove_child(index)
+    }
+
+    // Setters
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.content.set_focused(false);
+        }
+    }
+
+    pub fn set_style(&mut self, style: GroupBoxStyle) {
+        self.style = style;
+    }
+
+    pub fn set_visible(&mut self, visible: bool) {
+        self.content.set_visible(visible);
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        self.content.base()
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        self.content.base_mut()
+    }
+
+    pub fn content(&self) -> &Container {
+        &self.content
+    }
+
+    pub fn content_mut(&mut self) -> &mut Container {
+        &mut self.content
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn style(&self) -> &GroupBoxStyle {
+        &self.style
+    }
+
+    /// Area inside the border and below the title where children go
+    pub fn display_rectangle(&self) -> Boundaries {
+        let frame = self.base().frame();
+        let inset = self.border_width() + self.style.padding;
+        let top = self.style.typography.line_height() + self.style.padding;
+        Boundaries::from_rect(
+            Position::new(frame.top_left().x() + inset, frame.top_left().y() + top),
+            Size::new(
+                frame.height().saturating_sub(top + inset),
+                frame.width().saturating_sub(inset * 2),
+            ),
+        )
+    }
+
+    /// Where the title is written, over the top border
+    pub fn title_bounds(&self) -> Boundaries {
+        let frame = self.base().frame();
+        let size = self.style.typography.measure_text(&self.text);
+        let x = frame.top_left().x() + self.style.title_indent + self.style.title_spacing;
+        let available = frame
+            .width()
+            .saturating_sub(self.style.title_indent * 2 + self.style.title_spacing * 2);
+        Boundaries::from_rect(
+            Position::new(x, frame.top_left().y()),
+            Size::new(size.height(), size.width().min(available)),
+        )
+    }
+
+    fn border_width(&self) -> u16 {
+        self.style.border.width().ceil() as u16
+    }
+}
+
+impl Interactive for GroupBox {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        self.enabled && self.content.handle_input(input)
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled && self.content.focusable()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.content.set_focused(focused);
+    }
+}
+
+impl GroupBox {
+    // Background, border and title, everything but the content
+    fn render_frame(&self, ctx: &mut RenderContext) {
+        let style = &self.style;
+        let frame = self.base().frame();
+        ctx.fill_rect(frame, &style.background);
+
+        // The border starts halfway down the title, with a gap for it
+        let title = self.title_bounds();
+        let (left, right) = (
+            frame.top_left().x(),
+            frame.bottom_right().x().saturating_sub(1),
+        );
+        let top = frame.top_left().y() + title.height() / 2;
+        let bottom = frame.bottom_right().y().saturating_sub(1);
+        let color = style.border.color();
+        let width = self.border_width();
+        if self.text.is_empty() {
+            ctx.draw_line(
+                Position::new(left, top),
+                Position::new(right, top),
+                color,
+                width,
+            );
+        } else {
+            let gap_start = title.top_left().x() - style.title_spacing;
+            let gap_end = title.bottom_right().x() + style.title_spacing;
+            ctx.draw_line(
+                Position::new(left, top),
+                Position::new(gap_start, top),
+                color,
+                width,
+            );
+            ctx.draw_line(
+                Position::new(gap_end, top),
+                Position::new(right, top),
+                color,
+                width,
+            );
+        }
+        ctx.draw_line(
+            Position::new(left, top),
+            Position::new(left, bottom),
+            color,
+            width,
+        );
+        ctx.draw_line(
+            Position::new(right, top),
+            Position::new(right, bottom),
+            color,
+            width,
+        );
+        ctx.draw_line(
+            Position::new(left, bottom),
+            Position::new(right, bottom),
+            color,
+            width,
+        );
+
+        if !self.text.is_empty() {
+            let text_color = if self.enabled {
+                &style.text_color
+            } else {
+                &style.disabled_text_color
+            };
+            ctx.push_clip(title);
+            ctx.draw_text(title.top_left(), &self.text, &style.typography, text_color);
+            ctx.pop_clip();
+        }
+    }
+}
+
+impl Renderable for GroupBox {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base().visible() {
+            return;
+        }
+
+        // The content shares this base and fades itself when painted, so
+        // only the frame takes the opacity here
+        ctx.with_opacity(self.base().opacity(), |ctx| self.render_frame(ctx));
+        self.content.paint(ctx);
+    }
+}
+
+impl Component for GroupBox {
+    fn base(&self) -> &BaseComponent {
+        self.content.base()
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        self.content.base_mut()
+    }
+
+    fn find_frame(&self, id: usize) -> Option<Boundaries> {
+        self.content.find_frame(id)
+    }
+
+    // `render` already applies the opacity, to the frame and the content
+    fn paint(&self, ctx: &mut RenderContext) {
+        self.render(ctx);
+    }
+}
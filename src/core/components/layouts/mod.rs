@@ -1 +1,7 @@
 pub mod container;
+pub mod group_box;
+pub mod panel;
+pub mod radio_group;
+pub mod scroll_viewer;
+pub mod split_container;
+pub mod tab_control;
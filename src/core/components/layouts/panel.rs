@@ -0,0 +1,171 @@
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        layouts::{container::Container, scroll_viewer::ScrollViewer},
+        properties::{boundaries::Boundaries, overflow::Overflow, position::Position},
+        styles::panel_style::PanelStyle,
+    },
+    ui::render::render_context::RenderContext,
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::input::InputEvent,
+};
+
+/// Plain surface for grouping components, with a background and an optional
+/// border. Children are clipped to the frame; with auto scroll on, scrollbars
+/// appear when they extend past it, as `Panel.AutoScroll` in WinForms.
+#[derive(Debug)]
+pub struct Panel {
+    // Shares the panel's base, so its events carry the panel's id
+    viewer: ScrollViewer,
+    auto_scroll: bool,
+    style: PanelStyle,
+}
+
+impl Panel {
+    pub fn new(base: BaseComponent) -> Self {
+        let style = PanelStyle::default();
+        Self {
+            viewer: ScrollViewer::new(base)
+                .with_overflow(Overflow::Hidden)
+                .with_style(style.scroll_bar.clone()),
+            auto_scroll: false,
+            style,
+        }
+    }
+
+    pub fn with_child<C: Component + 'static>(mut self, child: C) -> Self {
+        self.add_child(child);
+        self
+    }
+
+    pub fn with_auto_scroll(mut self, auto_scroll: bool) -> Self {
+        self.set_auto_scroll(auto_scroll);
+        self
+    }
+
+    pub fn with_style(mut self, style: PanelStyle) -> Self {
+        self.set_style(style);
+        self
+    }
+
+    pub fn add_child<C: Component + 'static>(&mut self, child: C) {
+        self.viewer.add_child(child);
+    }
+
+    pub fn remove_child(&mut self, index: usize) -> Option<Box<dyn Component>> {
+        self.viewer.remove_child(index)
+    }
+
+    // Setters
+    pub fn set_auto_scroll(&mut self, auto_scroll: bool) {
+        self.auto_scroll = auto_scroll;
+        self.viewer.set_overflow(if auto_scroll {
+            Overflow::Auto
+        } else {
+            Overflow::Hidden
+        });
+    }
+
+    pub fn set_style(&mut self, style: PanelStyle) {
+        self.viewer.set_style(style.scroll_bar.clone());
+        self.style = style;
+    }
+
+    pub fn set_visible(&mut self, visible: bool) {
+        self.viewer.set_visible(visible);
+    }
+
+    pub fn scroll_to(&mut self, x: u16, y: u16) {
+        self.viewer.scroll_to(x, y);
+    }
+
+    pub fn scroll_into_view(&mut self, component_id: usize) -> bool {
+        self.viewer.scroll_into_view(component_id)
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        self.viewer.base()
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        self.viewer.base_mut()
+    }
+
+    pub fn content(&self) -> &Container {
+        self.viewer.content()
+    }
+
+    pub fn content_mut(&mut self) -> &mut Container {
+        self.viewer.content_mut()
+    }
+
+    pub fn auto_scroll(&self) -> bool {
+        self.auto_scroll
+    }
+
+    pub fn style(&self) -> &PanelStyle {
+        &self.style
+    }
+
+    pub fn scroll_offset(&self) -> Position {
+        self.viewer.scroll_offset()
+    }
+
+    /// Part of the frame showing children, without the scrollbars
+    pub fn viewport(&self) -> Boundaries {
+        self.viewer.viewport()
+    }
+}
+
+impl Interactive for Panel {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        self.viewer.handle_input(input)
+    }
+
+    fn focusable(&self) -> bool {
+        self.viewer.focusable()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.viewer.set_focused(focused);
+    }
+}
+
+impl Renderable for Panel {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base().visible() {
+            return;
+        }
+
+        // The viewer shares this base and fades itself when painted, so only
+        // the background and the border take the opacity here
+        let opacity = self.base().opacity();
+        let frame = self.base().frame();
+        ctx.with_opacity(opacity, |ctx| ctx.fill_rect(frame, &self.style.background));
+        self.viewer.paint(ctx);
+        // Painted last so children reaching the edge do not cover it
+        if let Some(border) = &self.style.border {
+            ctx.with_opacity(opacity, |ctx| ctx.stroke_rect(frame, border));
+        }
+    }
+}
+
+impl Component for Panel {
+    fn base(&self) -> &BaseComponent {
+        self.viewer.base()
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        self.viewer.base_mut()
+    }
+
+    fn find_frame(&self, id: usize) -> Option<Boundaries> {
+        self.viewer.find_frame(id)
+    }
+
+    // `render` already applies the opacity, to the panel and the viewer
+    fn paint(&self, ctx: &mut RenderContext) {
+        self.render(ctx);
+    }
+}
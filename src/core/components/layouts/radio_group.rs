@@ -0,0 +1,250 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::core::{
+    components::{base_component::BaseComponent, elements::radio_button::RadioButton},
+    ui::render::render_context::RenderContext,
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::{InputEvent, Key},
+        types::{EventPayload, EventType},
+    },
+};
+
+/// Keeps its radio buttons mutually exclusive and moves the selection with the arrow keys
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RadioGroup {
+    base: BaseComponent,
+    #[serde(deserialize_with = "exclusive_buttons")]
+    buttons: Vec<RadioButton>,
+    #[serde(skip)]
+    focused: bool,
+    #[serde(skip)]
+    focus_index: Option<usize>,
+}
+
+impl RadioGroup {
+    pub fn new(base: BaseComponent) -> Self {
+        Self {
+            base,
+            buttons: Vec::new(),
+            focused: false,
+            focus_index: None,
+        }
+    }
+
+    pub fn with_button(mut self, button: RadioButton) -> Self {
+        self.add_button(button);
+        self
+    }
+
+    pub fn add_button(&mut self, button: RadioButton) {
+        let checked = button.checked();
+        self.buttons.push(button);
+        if checked {
+            self.uncheck_others(self.buttons.len() - 1);
+        }
+    }
+
+    pub fn remove_button(&mut self, index: usize) -> Option<RadioButton> {
+        if index >= self.buttons.len() {
+            return None;
+        }
+
+        let mut removed = self.buttons.remove(index);
+        removed.set_focused(false);
+        self.focus_index = match self.focus_index {
+            Some(focus) if focus == index => None,
+            Some(focus) if focus > index => Some(focus - 1),
+            other => other,
+        };
+        if self.focused {
+            self.move_focus(self.focus_target());
+        }
+        Some(removed)
+    }
+
+    /// Checks the button at `index` and unchecks every other one
+    pub fn select(&mut self, index: usize) {
+        let Some(button) = self.buttons.get_mut(index) else {
+            return;
+        };
+
+        let changed = !button.checked();
+        button.set_checked(true);
+        self.uncheck_others(index);
+        if changed {
+            self.base
+                .emit_with(EventType::FieldValueChanged, EventPayload::Index(index));
+        }
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn buttons(&self) -> &[RadioButton] {
+        &self.buttons
+    }
+
+    pub fn button_mut(&mut self, index: usize) -> Option<&mut RadioButton> {
+        self.buttons.get_mut(index)
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        self.buttons.iter().position(|button| button.checked())
+    }
+
+    pub fn selected(&self) -> Option<&RadioButton> {
+        self.buttons.iter().find(|button| button.checked())
+    }
+
+    pub fn focus_index(&self) -> Option<usize> {
+        self.focus_index
+    }
+
+    fn uncheck_others(&mut self, index: usize) {
+        for (i, button) in self.buttons.iter_mut().enumerate() {
+            if i != index {
+                button.set_checked(false);
+            }
+        }
+    }
+
+    // Where focus lands when entering the group: the checked button, else the first usable one
+    fn focus_target(&self) -> Option<usize> {
+        self.selected_index()
+            .filter(|index| self.buttons[*index].focusable())
+            .or_else(|| self.buttons.iter().position(|button| button.focusable()))
+    }
+
+    fn move_focus(&mut self, target: Option<usize>) {
+        if let Some(previous) = self.focus_index.and_then(|i| self.buttons.get_mut(i)) {
+            previous.set_focused(false);
+        }
+        self.focus_index = target;
+        if let Some(next) = target.and_then(|i| self.buttons.get_mut(i)) {
+            next.set_focused(true);
+        }
+    }
+
+    // Next focusable button in the given direction, wrapping around
+    fn step(&self, from: usize, forward: bool) -> Option<usize> {
+        let len = self.buttons.len();
+        (1..len)
+            .map(|offset| {
+                if forward {
+                    (from + offset) % len
+                } else {
+                    (from + len - offset) % len
+                }
+            })
+            .find(|index| self.buttons[*index].focusable())
+    }
+}
+
+impl Interactive for RadioGroup {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.base.visible() {
+            return false;
+        }
+
+        if self.focused
+            && let InputEvent::KeyPressed { key, .. } = *input
+        {
+            let forward = match key {
+                Key::Down | Key::Right => Some(true),
+                Key::Up | Key::Left => Some(false),
+                _ => None,
+            };
+            if let (Some(forward), Some(from)) = (forward, self.focus_index)
+                && let Some(target) = self.step(from, forward)
+            {
+                self.move_focus(Some(target));
+                self.select(target);
+                return true;
+            }
+        }
+
+        let mut consumed = false;
+        let mut clicked = None;
+        for (index, button) in self.buttons.iter_mut().enumerate() {
+            let was_checked = button.checked();
+            // Keyboard input only reaches the focused button
+            if input.is_keyboard() && !button.focused() {
+                continue;
+            }
+            consumed |= button.handle_input(input);
+            if !was_checked && button.checked() {
+                clicked = Some(index);
+            }
+        }
+
+        if let Some(index) = clicked {
+            self.uncheck_others(index);
+            self.base
+                .emit_with(EventType::FieldValueChanged, EventPayload::Index(index));
+            if self.focused {
+                self.move_focus(Some(index));
+            }
+        }
+        consumed
+    }
+
+    fn focusable(&self) -> bool {
+        *self.base.visible() && self.buttons.iter().any(|button| button.focusable())
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        if self.focused == focused {
+            return;
+        }
+
+        self.focused = focused;
+        if focused {
+            self.move_focus(self.focus_target());
+        } else {
+            self.move_focus(None);
+        }
+    }
+}
+
+impl Renderable for RadioGroup {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        for button in &self.buttons {
+            button.paint(ctx);
+        }
+    }
+}
+
+impl Component for RadioGroup {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+}
+
+// Saved data may check several buttons; the first checked one stays selected
+fn exclusive_buttons<'de, D>(deserializer: D) -> Result<Vec<RadioButton>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut buttons = Vec::<RadioButton>::deserialize(deserializer)?;
+    if let Some(first) = buttons.iter().position(|button| button.checked()) {
+        for button in buttons.iter_mut().skip(first + 1) {
+            button.set_checked(false);
+        }
+    }
+    Ok(buttons)
+}
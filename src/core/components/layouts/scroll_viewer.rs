@@ -0,0 +1,698 @@
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        layouts::container::Container,
+        properties::{
+            boundaries::Boundaries, orientation::Orientation, overflow::Overflow,
+            position::Position, size::Size,
+        },
+        styles::scroll_bar_style::ScrollBarStyle,
+    },
+    ui::render::render_context::RenderContext,
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::{InputEvent, Key, MouseButton},
+        types::EventType,
+    },
+};
+
+// Pointer travel before a press on the content starts panning
+const PAN_THRESHOLD: u16 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScrollDrag {
+    // Thumb grabbed `grab` pixels from its start
+    Thumb {
+        orientation: Orientation,
+        grab: u16,
+    },
+    // Content dragged from `start` while the offset was `origin`
+    Pan {
+        start: Position,
+        origin: Position,
+        moving: bool,
+    },
+}
+
+/// Container whose children may extend past its frame. Children are placed
+/// as if nothing was scrolled; the viewer shifts them while painting and
+/// shifts the pointer back before handing them input.
+///
+/// `Overflow::Scroll` always shows both scrollbars, `Overflow::Auto` only the
+/// ones that are needed and `Overflow::Hidden` none, leaving scrolling to
+/// code. `Overflow::Visible` does not scroll or clip at all.
+#[derive(Debug)]
+pub struct ScrollViewer {
+    base: BaseComponent,
+    // Has a copy of the viewer's base, so its events carry the viewer's id.
+    // The frame setters and input keep the copy in step with the viewer
+    content: Container,
+    overflow: Overflow,
+    style: ScrollBarStyle,
+    offset: Position,
+    drag: Option<ScrollDrag>,
+    hovered_thumb: Option<Orientation>,
+    focused: bool,
+}
+
+impl ScrollViewer {
+    pub fn new(base: BaseComponent) -> Self {
+        Self {
+            content: Container::new(content_base(&base)),
+            base,
+            overflow: Overflow::Auto,
+            style: ScrollBarStyle::default(),
+            offset: Position::new(0, 0),
+            drag: None,
+            hovered_thumb: None,
+            focused: false,
+        }
+    }
+
+    pub fn with_child<C: Component + 'static>(mut self, child: C) -> Self {
+        self.add_child(child);
+        self
+    }
+
+    pub fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.set_overflow(overflow);
+        self
+    }
+
+    pub fn with_style(mut self, style: ScrollBarStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn add_child<C: Component + 'static>(&mut self, child: C) {
+        self.content.add_child(child);
+    }
+
+    pub fn remove_child(&mut self, index: usize) -> Option<Box<dyn Component>> {
+        let removed = self.content.remove_child(index);
+        self.clamp_offset();
+        removed
+    }
+
+    // Setters
+    pub fn set_overflow(&mut self, overflow: Overflow) {
+        self.overflow = overflow;
+        self.clamp_offset();
+    }
+
+    pub fn set_style(&mut self, style: ScrollBarStyle) {
+        self.style = style;
+        self.clamp_offset();
+    }
+
+    pub fn set_visible(&mut self, visible: bool) {
+        self.base.set_visible(visible);
+        self.sync_content();
+        if !visible {
+            self.set_focused(false);
+        }
+    }
+
+    pub fn set_position(&mut self, position: Position) {
+        self.base.set_position(position);
+        self.sync_content();
+    }
+
+    pub fn set_size(&mut self, size: Size) {
+        self.base.set_size(size);
+        self.sync_content();
+        self.clamp_offset();
+    }
+
+    /// Scrolls so the content point `(x, y)` sits at the top-left corner of
+    /// the viewport, as far as the content allows
+    pub fn scroll_to(&mut self, x: u16, y: u16) {
+        let max = self.max_offset();
+        let offset = Position::new(x.min(max.x()), y.min(max.y()));
+        if !self.scrollable() || offset == self.offset {
+            return;
+        }
+
+        self.offset = offset;
+        self.base.emit(EventType::ComponentScrolled);
+    }
+
+    pub fn scroll_by(&mut self, dx: i32, dy: i32) {
+        let shift =
+            |value: u16, delta: i32| (value as i32 + delta).clamp(0, u16::MAX as i32) as u16;
+        self.scroll_to(shift(self.offset.x(), dx), shift(self.offset.y(), dy));
+    }
+
+    /// Scrolls the minimum needed for a component inside the viewer to be
+    /// visible, returning `false` when no such component exists
+    pub fn scroll_into_view(&mut self, component_id: usize) -> bool {
+        let Some(frame) = self
+            .content
+            .children()
+            .iter()
+            .find_map(|child| child.find_frame(component_id))
+        else {
+            return false;
+        };
+
+        let viewport = self.viewport();
+        let origin = viewport.top_left();
+        let axis = |start: u16, end: u16, offset: u16, length: u16| {
+            if start < offset {
+                start
+            } else if end > offset + length {
+                // Items larger than the viewport keep their start in view
+                (end - length).min(start)
+            } else {
+                offset
+            }
+        };
+        let x = axis(
+            frame.top_left().x().saturating_sub(origin.x()),
+            frame.bottom_right().x().saturating_sub(origin.x()),
+            self.offset.x(),
+            viewport.width(),
+        );
+        let y = axis(
+            frame.top_left().y().saturating_sub(origin.y()),
+            frame.bottom_right().y().saturating_sub(origin.y()),
+            self.offset.y(),
+            viewport.height(),
+        );
+        self.scroll_to(x, y);
+        true
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn content(&self) -> &Container {
+        &self.content
+    }
+
+    pub fn content_mut(&mut self) -> &mut Container {
+        &mut self.content
+    }
+
+    pub fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    pub fn style(&self) -> &ScrollBarStyle {
+        &self.style
+    }
+
+    pub fn scroll_offset(&self) -> Position {
+        self.offset
+    }
+
+    /// Largest offset on each axis
+    pub fn max_offset(&self) -> Position {
+        let content = self.content_size();
+        let viewport = self.viewport();
+        Position::new(
+            content.width().saturating_sub(viewport.width()),
+            content.height().saturating_sub(viewport.height()),
+        )
+    }
+
+    /// Extent of the visible children measured from the top-left corner of
+    /// the viewport
+    pub fn content_size(&self) -> Size {
+        let origin = self.base.content_frame().top_left();
+        let (width, height) = self
+            .content
+            .children()
+            .iter()
+            .filter(|child| *child.base().visible())
+            .map(|child| child.base().frame().bottom_right())
+            .fold((0, 0), |(width, height), corner| {
+                (
+                    width.max(corner.x().saturating_sub(origin.x())),
+                    height.max(corner.y().saturating_sub(origin.y())),
+                )
+            });
+        Size::new(height, width)
+    }
+
+    /// Part of the frame showing content, without the scrollbars
+    pub fn viewport(&self) -> Boundaries {
+        let frame = self.base.content_frame();
+        let (horizontal, vertical) = self.bars_shown();
+        let bar = |shown: bool| if shown { self.style.width } else { 0 };
+        Boundaries::from_rect(
+            frame.top_left(),
+            Size::new(
+                frame.height().saturating_sub(bar(horizontal)),
+                frame.width().saturating_sub(bar(vertical)),
+            ),
+        )
+    }
+
+    /// Track of a scrollbar, if it is shown
+    pub fn scroll_bar_bounds(&self, orientation: Orientation) -> Option<Boundaries> {
+        let (horizontal, vertical) = self.bars_shown();
+        let frame = self.base.content_frame();
+        let viewport = self.viewport();
+        match orientation {
+            Orientation::Horizontal if horizontal => Some(Boundaries::from_rect(
+                Position::new(frame.top_left().x(), viewport.bottom_right().y()),
+                Size::new(self.style.width, viewport.width()),
+            )),
+            Orientation::Vertical if vertical => Some(Boundaries::from_rect(
+                Position::new(viewport.bottom_right().x(), frame.top_left().y()),
+                Size::new(viewport.height(), self.style.width),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Thumb of a scrollbar, `None` when the bar is hidden or there is
+    /// nothing to scroll along it
+    pub fn thumb_bounds(&self, orientation: Orientation) -> Option<Boundaries> {
+        let track = self.scroll_bar_bounds(orientation)?;
+        let content = self.content_size();
+        let viewport = self.viewport();
+        let (track_length, content_length, viewport_length, offset, max) = match orientation {
+            Orientation::Horizontal => (
+                track.width(),
+                content.width(),
+                viewport.width(),
+                self.offset.x(),
+                self.max_offset().x(),
+            ),
+            Orientation::Vertical => (
+                track.height(),
+                content.height(),
+                viewport.height(),
+                self.offset.y(),
+                self.max_offset().y(),
+            ),
+        };
+        if content_length <= viewport_length || track_length == 0 {
+            return None;
+        }
+
+        let length = ((track_length as u32 * viewport_length as u32 / content_length as u32)
+            as u16)
+            .max(self.style.min_thumb_length)
+            .min(track_length);
+        let travel = track_length - length;
+        let start = (travel as u32 * offset as u32 / max.max(1) as u32) as u16;
+        let top_left = track.top_left();
+        Some(match orientation {
+            Orientation::Horizontal => Boundaries::from_rect(
+                Position::new(top_left.x() + start, top_left.y()),
+                Size::new(track.height(), length),
+            ),
+            Orientation::Vertical => Boundaries::from_rect(
+                Position::new(top_left.x(), top_left.y() + start),
+                Size::new(length, track.width()),
+            ),
+        })
+    }
+
+    // Copies the viewer's frame into the content without emitting anything,
+    // the viewer has already reported the change
+    fn sync_content(&mut self) {
+        let base = content_base(&self.base);
+        if *self.content.base() != base {
+            *self.content.base_mut() = base;
+        }
+    }
+
+    fn scrollable(&self) -> bool {
+        self.overflow != Overflow::Visible
+    }
+
+    // Which bars are shown, horizontal first. A bar takes room from the other
+    // axis, so showing one can make the other necessary
+    fn bars_shown(&self) -> (bool, bool) {
+        match self.overflow {
+            Overflow::Visible | Overflow::Hidden => (false, false),
+            Overflow::Scroll => (true, true),
+            Overflow::Auto => {
+                let frame = self.base.content_frame();
+                let content = self.content_size();
+                let mut horizontal = content.width() > frame.width();
+                let mut vertical = content.height() > frame.height();
+                if horizontal && !vertical {
+                    vertical = content.height() > frame.height().saturating_sub(self.style.width);
+                }
+                if vertical && !horizontal {
+                    horizontal = content.width() > frame.width().saturating_sub(self.style.width);
+                }
+                (horizontal, vertical)
+            }
+        }
+    }
+
+    fn clamp_offset(&mut self) {
+        let max = self.max_offset();
+        let offset = if self.scrollable() {
+            Position::new(self.offset.x().min(max.x()), self.offset.y().min(max.y()))
+        } else {
+            Position::new(0, 0)
+        };
+        if offset != self.offset {
+            self.offset = offset;
+            self.base.emit(EventType::ComponentScrolled);
+        }
+    }
+
+    // Screen position to content position
+    fn to_content(&self, input: &InputEvent) -> InputEvent {
+        match input.mouse_position() {
+            Some(position) => input.with_mouse_position(Position::new(
+                position.x().saturating_add(self.offset.x()),
+                position.y().saturating_add(self.offset.y()),
+            )),
+            None => *input,
+        }
+    }
+
+    fn thumb_at(&self, point: Position) -> Option<Orientation> {
+        [Orientation::Vertical, Orientation::Horizontal]
+            .into_iter()
+            .find(|orientation| {
+                self.thumb_bounds(*orientation)
+                    .is_some_and(|thumb| thumb.contains(point))
+            })
+    }
+
+    fn bar_at(&self, point: Position) -> Option<Orientation> {
+        [Orientation::Vertical, Orientation::Horizontal]
+            .into_iter()
+            .find(|orientation| {
+                self.scroll_bar_bounds(*orientation)
+                    .is_some_and(|bar| bar.contains(point))
+            })
+    }
+
+    fn press_bar(&mut self, orientation: Orientation, point: Position) {
+        if let Some(thumb) = self.thumb_bounds(orientation) {
+            if thumb.contains(point) {
+                let grab = match orientation {
+                    Orientation::Horizontal => point.x() - thumb.top_left().x(),
+                    Orientation::Vertical => point.y() - thumb.top_left().y(),
+                };
+                self.drag = Some(ScrollDrag::Thumb { orientation, grab });
+                return;
+            }
+
+            // A press on the track pages toward the pointer
+            let viewport = self.viewport();
+            match orientation {
+                Orientation::Horizontal => {
+                    let page = viewport.width() as i32;
+                    let before = point.x() < thumb.top_left().x();
+                    self.scroll_by(if before { -page } else { page }, 0);
+                }
+                Orientation::Vertical => {
+                    let page = viewport.height() as i32;
+                    let before = point.y() < thumb.top_left().y();
+                    self.scroll_by(0, if before { -page } else { page });
+                }
+            }
+        }
+    }
+
+    fn drag_to(&mut self, drag: ScrollDrag, point: Position) {
+        match drag {
+            ScrollDrag::Thumb { orientation, grab } => {
+                let (Some(track), Some(thumb)) = (
+                    self.scroll_bar_bounds(orientation),
+                    self.thumb_bounds(orientation),
+                ) else {
+                    return;
+                };
+                let max = self.max_offset();
+                match orientation {
+                    Orientation::Horizontal => {
+                        let travel = track.width() - thumb.width();
+                        let start = point
+                            .x()
+                            .saturating_sub(track.top_left().x() + grab)
+                            .min(travel);
+                        let x = (start as u32 * max.x() as u32 / travel.max(1) as u32) as u16;
+                        self.scroll_to(x, self.offset.y());
+                    }
+                    Orientation::Vertical => {
+                        let travel = track.height() - thumb.height();
+                        let start = point
+                            .y()
+                            .saturating_sub(track.top_left().y() + grab)
+                            .min(travel);
+                        let y = (start as u32 * max.y() as u32 / travel.max(1) as u32) as u16;
+                        self.scroll_to(self.offset.x(), y);
+                    }
+                }
+            }
+            ScrollDrag::Pan {
+                start,
+                origin,
+                moving,
+            } => {
+                let dx = point.x() as i32 - start.x() as i32;
+                let dy = point.y() as i32 - start.y() as i32;
+                if !moving && dx.unsigned_abs().max(dy.unsigned_abs()) < PAN_THRESHOLD as u32 {
+                    return;
+                }
+                self.drag = Some(ScrollDrag::Pan {
+                    start,
+                    origin,
+                    moving: true,
+                });
+                let shift = |value: u16, delta: i32| {
+                    (value as i32 - delta).clamp(0, u16::MAX as i32) as u16
+                };
+                self.scroll_to(shift(origin.x(), dx), shift(origin.y(), dy));
+            }
+        }
+    }
+
+    fn handle_key(&mut self, key: Key) -> bool {
+        let line = self.style.line_step as i32;
+        let viewport = self.viewport();
+        match key {
+            Key::Up => self.scroll_by(0, -line),
+            Key::Down => self.scroll_by(0, line),
+            Key::Left => self.scroll_by(-line, 0),
+            Key::Right => self.scroll_by(line, 0),
+            Key::PageUp => self.scroll_by(0, -(viewport.height() as i32)),
+            Key::PageDown => self.scroll_by(0, viewport.height() as i32),
+            Key::Home => self.scroll_to(self.offset.x(), 0),
+            Key::End => self.scroll_to(self.offset.x(), u16::MAX),
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl Interactive for ScrollViewer {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.base.visible() {
+            return false;
+        }
+        // The frame may have changed through `base_mut`
+        self.sync_content();
+        if !self.scrollable() {
+            return self.content.handle_input(input);
+        }
+
+        if input.is_keyboard() {
+            if self.content.handle_input(input) {
+                return true;
+            }
+            return match *input {
+                InputEvent::KeyPressed { key, modifiers }
+                    if self.focused && modifiers.is_empty() =>
+                {
+                    self.handle_key(key)
+                }
+                _ => false,
+            };
+        }
+
+        let viewport = self.viewport();
+        match *input {
+            InputEvent::MouseMoved(position) => {
+                if let Some(drag) = self.drag {
+                    self.drag_to(drag, position);
+                    if matches!(
+                        self.drag,
+                        Some(ScrollDrag::Thumb { .. } | ScrollDrag::Pan { moving: true, .. })
+                    ) {
+                        return true;
+                    }
+                }
+                self.hovered_thumb = self.thumb_at(position);
+                self.content.handle_input(&self.to_content(input)) || self.hovered_thumb.is_some()
+            }
+            InputEvent::MouseButtonPressed {
+                button, position, ..
+            } => {
+                if let Some(orientation) = self.bar_at(position) {
+                    if button == MouseButton::Left {
+                        self.press_bar(orientation, position);
+                    }
+                    return true;
+                }
+                // The focused child sees presses anywhere so its drop-downs
+                // can close, the rest only inside the viewport
+                let inside = viewport.contains(position);
+                if (inside || self.content.focused_child().is_some())
+                    && self.content.handle_input(&self.to_content(input))
+                {
+                    return true;
+                }
+                if inside && button == MouseButton::Left && self.overflow != Overflow::Hidden {
+                    self.drag = Some(ScrollDrag::Pan {
+                        start: position,
+                        origin: self.offset,
+                        moving: false,
+                    });
+                    return true;
+                }
+                false
+            }
+            InputEvent::MouseButtonReleased { .. } => {
+                let dragging = self.drag.take().is_some_and(|drag| {
+                    matches!(
+                        drag,
+                        ScrollDrag::Thumb { .. } | ScrollDrag::Pan { moving: true, .. }
+                    )
+                });
+                self.content.handle_input(&self.to_content(input)) || dragging
+            }
+            InputEvent::MouseScrolled {
+                delta_x,
+                delta_y,
+                position,
+            } => {
+                if !self.base.hit_test(position) {
+                    return false;
+                }
+                if viewport.contains(position) && self.content.handle_input(&self.to_content(input))
+                {
+                    return true;
+                }
+                if self.overflow == Overflow::Hidden {
+                    return false;
+                }
+                let step = self.style.wheel_step as i32;
+                let before = self.offset;
+                self.scroll_by(-(delta_x as i32) * step, -(delta_y as i32) * step);
+                self.offset != before
+            }
+            _ => false,
+        }
+    }
+
+    fn focusable(&self) -> bool {
+        self.content.focusable()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        self.content.set_focused(focused);
+    }
+}
+
+// Base of the content: the viewer's own, fully opaque since the viewer's
+// opacity is applied when the viewer itself is painted
+fn content_base(base: &BaseComponent) -> BaseComponent {
+    let mut base = base.clone();
+    base.set_opacity(1.0);
+    base
+}
+
+impl Renderable for ScrollViewer {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+        if !self.scrollable() {
+            self.content.paint(ctx);
+            return;
+        }
+
+        ctx.push_clip(self.viewport());
+        ctx.push_translation(-(self.offset.x() as i32), -(self.offset.y() as i32));
+        self.content.paint(ctx);
+        ctx.pop_translation();
+        ctx.pop_clip();
+
+        let style = &self.style;
+        for orientation in [Orientation::Horizontal, Orientation::Vertical] {
+            let Some(track) = self.scroll_bar_bounds(orientation) else {
+                continue;
+            };
+            ctx.fill_rect(track, &style.track_background);
+            if let Some(thumb) = self.thumb_bounds(orientation) {
+                let background = match self.drag {
+                    Some(ScrollDrag::Thumb {
+                        orientation: dragged,
+                        ..
+                    }) if dragged == orientation => &style.pressed_thumb_background,
+                    _ if self.hovered_thumb == Some(orientation) => &style.hovered_thumb_background,
+                    _ => &style.thumb_background,
+                };
+                ctx.fill_rect(thumb, background);
+            }
+        }
+        if let (Some(horizontal), Some(vertical)) = (
+            self.scroll_bar_bounds(Orientation::Horizontal),
+            self.scroll_bar_bounds(Orientation::Vertical),
+        ) {
+            let corner = Boundaries::from_rect(
+                Position::new(vertical.top_left().x(), horizontal.top_left().y()),
+                Size::new(horizontal.height(), vertical.width()),
+            );
+            ctx.fill_rect(corner, &style.corner_background);
+        }
+    }
+}
+
+impl Component for ScrollViewer {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    // Frames of the content are reported where they are shown on screen
+    fn find_frame(&self, id: usize) -> Option<Boundaries> {
+        if *self.base.id() == id {
+            return Some(self.base.frame());
+        }
+        let frame = self
+            .content
+            .children()
+            .iter()
+            .find_map(|child| child.find_frame(id))?;
+        let shift = |position: Position| {
+            Position::new(
+                position.x().saturating_sub(self.offset.x()),
+                position.y().saturating_sub(self.offset.y()),
+            )
+        };
+        let top_left = shift(frame.top_left());
+        let bottom_right = shift(frame.bottom_right());
+        Some(Boundaries::from_rect(
+            top_left,
+            Size::new(
+                bottom_right.y() - top_left.y(),
+                bottom_right.x() - top_left.x(),
+            ),
+        ))
+    }
+}
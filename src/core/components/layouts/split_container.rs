@@ -0,0 +1,558 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        layouts::panel::Panel,
+        properties::{
+            boundaries::Boundaries, orientation::Orientation, position::Position, size::Size,
+        },
+        styles::split_container_style::SplitContainerStyle,
+    },
+    ui::render::render_context::RenderContext,
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::{InputEvent, Key, MouseButton},
+        types::{EventPayload, EventType},
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SplitPane {
+    // Left or top
+    First,
+    // Right or bottom
+    Second,
+}
+
+impl SplitPane {
+    fn index(&self) -> usize {
+        match self {
+            SplitPane::First => 0,
+            SplitPane::Second => 1,
+        }
+    }
+}
+
+/// Splitter position to save with the user settings and restore later.
+/// Storing the ratio rather than pixels keeps the layout when the window
+/// opens at another size
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SplitterState {
+    pub ratio: f32,
+    pub collapsed: Option<SplitPane>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SplitFocus {
+    Splitter,
+    Pane(SplitPane),
+}
+
+/// Two panels separated by a splitter the user can drag, as the WinForms
+/// `SplitContainer`. With `Orientation::Vertical` the splitter is vertical
+/// and the panels sit side by side, with `Orientation::Horizontal` they are
+/// stacked.
+///
+/// The splitter keeps its position as a ratio of the space, so resizing the
+/// container with `set_size` scales both panels.
+/// Panels never shrink below their minimum size, but dragging the
+/// splitter more than halfway into a minimum collapses that panel against the
+/// edge, and dragging it back out restores it. Moving the splitter emits
+/// `FieldValueChanged` with the new ratio.
+#[derive(Debug)]
+pub struct SplitContainer {
+    base: BaseComponent,
+    orientation: Orientation,
+    panels: [Panel; 2],
+    // Share of the space besides the splitter given to the first panel
+    ratio: f32,
+    min_sizes: [u16; 2],
+    collapsed: Option<SplitPane>,
+    style: SplitContainerStyle,
+    // Pointer offset from the start of the splitter while it is dragged, and
+    // the state when the drag started
+    drag: Option<(u16, SplitterState)>,
+    hovered: bool,
+    focus: Option<SplitFocus>,
+    focused: bool,
+}
+
+impl SplitContainer {
+    pub fn new(base: BaseComponent) -> Self {
+        let style = SplitContainerStyle::default();
+        let panel = || {
+            Panel::new(BaseComponent::new(
+                Size::new(0, 0),
+                Position::new(0, 0),
+                Default::default(),
+                Default::default(),
+                Rc::clone(base.event_system()),
+            ))
+            .with_style(style.panel.clone())
+        };
+        let mut split = Self {
+            panels: [panel(), panel()],
+            base,
+            // Side by side, as in WinForms
+            orientation: Orientation::Vertical,
+            ratio: 0.5,
+            min_sizes: [25, 25],
+            collapsed: None,
+            style,
+            drag: None,
+            hovered: false,
+            focus: None,
+            focused: false,
+        };
+        split.refresh();
+        split
+    }
+
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.set_orientation(orientation);
+        self
+    }
+
+    pub fn with_splitter_ratio(mut self, ratio: f32) -> Self {
+        self.set_splitter_ratio(ratio);
+        self
+    }
+
+    pub fn with_panel_min_size(mut self, pane: SplitPane, min_size: u16) -> Self {
+        self.set_panel_min_size(pane, min_size);
+        self
+    }
+
+    pub fn with_style(mut self, style: SplitContainerStyle) -> Self {
+        self.set_style(style);
+        self
+    }
+
+    // Setters
+    pub fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+        self.refresh();
+    }
+
+    pub fn set_splitter_ratio(&mut self, ratio: f32) {
+        self.restore(SplitterState {
+            ratio,
+            collapsed: self.collapsed,
+        });
+    }
+
+    /// Places the splitter `distance` pixels from the left or top edge,
+    /// within the minimum sizes of the panels
+    pub fn set_splitter_distance(&mut self, distance: u16) {
+        let distance = self.clamp_distance(distance as i32);
+        self.apply(SplitterState {
+            ratio: self.ratio_of(distance),
+            collapsed: self.collapsed,
+        });
+    }
+
+    pub fn set_panel_min_size(&mut self, pane: SplitPane, min_size: u16) {
+        self.min_sizes[pane.index()] = min_size;
+        self.refresh();
+    }
+
+    /// Collapses a panel against its edge, or restores both with `None`
+    pub fn set_collapsed(&mut self, collapsed: Option<SplitPane>) {
+        self.apply(SplitterState {
+            ratio: self.ratio,
+            collapsed,
+        });
+    }
+
+    pub fn set_style(&mut self, style: SplitContainerStyle) {
+        for panel in &mut self.panels {
+            panel.set_style(style.panel.clone());
+        }
+        self.style = style;
+        self.refresh();
+    }
+
+    pub fn set_visible(&mut self, visible: bool) {
+        self.base.set_visible(visible);
+        if !visible {
+            self.set_focused(false);
+        }
+    }
+
+    /// Moves the container, taking the panels along
+    pub fn set_position(&mut self, position: Position) {
+        self.base.set_position(position);
+        self.refresh();
+    }
+
+    /// Resizes the container and scales both panels to it
+    pub fn set_size(&mut self, size: Size) {
+        self.base.set_size(size);
+        self.refresh();
+    }
+
+    /// Puts the splitter back where a saved state had it
+    pub fn restore(&mut self, state: SplitterState) {
+        let ratio = if state.ratio.is_finite() {
+            state.ratio.clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        self.apply(SplitterState { ratio, ..state });
+    }
+
+    /// Re-lays out the panels. `set_position` and `set_size` do it on their
+    /// own; a frame changed through `base_mut` needs a call to this
+    pub fn refresh(&mut self) {
+        for pane in [SplitPane::First, SplitPane::Second] {
+            let bounds = self.panel_bounds(pane);
+            let visible = self.collapsed != Some(pane);
+            let panel = &mut self.panels[pane.index()];
+            panel.base_mut().set_position(bounds.top_left());
+            panel.base_mut().set_size(bounds.size());
+            panel.set_visible(visible);
+        }
+        if let Some(pane) = self.collapsed
+            && self.focus == Some(SplitFocus::Pane(pane))
+        {
+            self.set_focus(Some(SplitFocus::Splitter));
+        }
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn panel(&self, pane: SplitPane) -> &Panel {
+        &self.panels[pane.index()]
+    }
+
+    pub fn panel_mut(&mut self, pane: SplitPane) -> &mut Panel {
+        &mut self.panels[pane.index()]
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    pub fn splitter_ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    pub fn panel_min_size(&self, pane: SplitPane) -> u16 {
+        self.min_sizes[pane.index()]
+    }
+
+    pub fn collapsed(&self) -> Option<SplitPane> {
+        self.collapsed
+    }
+
+    pub fn style(&self) -> &SplitContainerStyle {
+        &self.style
+    }
+
+    pub fn state(&self) -> SplitterState {
+        SplitterState {
+            ratio: self.ratio,
+            collapsed: self.collapsed,
+        }
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Pixels from the left or top edge to the splitter
+    pub fn splitter_distance(&self) -> u16 {
+        let available = self.available();
+        match self.collapsed {
+            Some(SplitPane::First) => 0,
+            Some(SplitPane::Second) => available,
+            None => self.clamp_distance((self.ratio * available as f32).round() as i32),
+        }
+    }
+
+    pub fn splitter_bounds(&self) -> Boundaries {
+        let width = self.style.splitter_width.min(self.length());
+        self.band(self.splitter_distance(), width)
+    }
+
+    /// Area given to a panel, empty when it is collapsed
+    pub fn panel_bounds(&self, pane: SplitPane) -> Boundaries {
+        let distance = self.splitter_distance();
+        match pane {
+            SplitPane::First => self.band(0, distance),
+            SplitPane::Second => {
+                let start = distance + self.style.splitter_width.min(self.length());
+                self.band(start, self.length().saturating_sub(start))
+            }
+        }
+    }
+
+    // Length of the container along the axis the splitter moves on
+    fn length(&self) -> u16 {
+        let frame = self.base.frame();
+        match self.orientation {
+            Orientation::Vertical => frame.width(),
+            Orientation::Horizontal => frame.height(),
+        }
+    }
+
+    // Space shared by the panels
+    fn available(&self) -> u16 {
+        self.length().saturating_sub(self.style.splitter_width)
+    }
+
+    // Strip of the frame across the axis, `start` pixels from its edge
+    fn band(&self, start: u16, length: u16) -> Boundaries {
+        let frame = self.base.frame();
+        let origin = frame.top_left();
+        match self.orientation {
+            Orientation::Vertical => Boundaries::from_rect(
+                Position::new(origin.x() + start, origin.y()),
+                Size::new(frame.height(), length),
+            ),
+            Orientation::Horizontal => Boundaries::from_rect(
+                Position::new(origin.x(), origin.y() + start),
+                Size::new(length, frame.width()),
+            ),
+        }
+    }
+
+    fn clamp_distance(&self, distance: i32) -> u16 {
+        let available = self.available() as i32;
+        let min = (self.min_sizes[0] as i32).min(available);
+        let max = (available - self.min_sizes[1] as i32).max(min);
+        distance.clamp(min, max) as u16
+    }
+
+    fn ratio_of(&self, distance: u16) -> f32 {
+        match self.available() {
+            0 => self.ratio,
+            available => distance as f32 / available as f32,
+        }
+    }
+
+    fn apply(&mut self, state: SplitterState) {
+        if state == self.state() {
+            return;
+        }
+        self.ratio = state.ratio;
+        self.collapsed = state.collapsed;
+        self.refresh();
+        if self.drag.is_none() {
+            self.emit_moved();
+        }
+    }
+
+    fn emit_moved(&self) {
+        self.base.emit_with(
+            EventType::FieldValueChanged,
+            EventPayload::Number(self.ratio as f64),
+        );
+    }
+
+    // Where the pointer wants the splitter, collapsing a panel when it is
+    // dragged more than halfway into its minimum size
+    fn drag_to(&mut self, requested: i32) {
+        let available = self.available() as i32;
+        let state = if requested < self.min_sizes[0] as i32 / 2 {
+            SplitterState {
+                ratio: self.ratio,
+                collapsed: Some(SplitPane::First),
+            }
+        } else if requested > available - self.min_sizes[1] as i32 / 2 {
+            SplitterState {
+                ratio: self.ratio,
+                collapsed: Some(SplitPane::Second),
+            }
+        } else {
+            let distance = self.clamp_distance(requested);
+            SplitterState {
+                ratio: self.ratio_of(distance),
+                collapsed: None,
+            }
+        };
+        self.apply(state);
+    }
+
+    // Position of a point along the axis, from the edge of the container
+    fn along(&self, point: Position) -> i32 {
+        let origin = self.base.frame().top_left();
+        match self.orientation {
+            Orientation::Vertical => point.x() as i32 - origin.x() as i32,
+            Orientation::Horizontal => point.y() as i32 - origin.y() as i32,
+        }
+    }
+
+    fn set_focus(&mut self, focus: Option<SplitFocus>) {
+        self.focus = focus;
+        for pane in [SplitPane::First, SplitPane::Second] {
+            let focused = self.focused && focus == Some(SplitFocus::Pane(pane));
+            self.panels[pane.index()].set_focused(focused);
+        }
+    }
+
+    fn handle_key(&mut self, key: Key) -> bool {
+        let step = self.style.splitter_increment as i32;
+        let delta = match (self.orientation, key) {
+            (Orientation::Vertical, Key::Left) | (Orientation::Horizontal, Key::Up) => -step,
+            (Orientation::Vertical, Key::Right) | (Orientation::Horizontal, Key::Down) => step,
+            _ => return false,
+        };
+        // The arrows only move the splitter, collapsing is left to the mouse
+        let distance = self.clamp_distance(self.splitter_distance() as i32 + delta);
+        self.apply(SplitterState {
+            ratio: self.ratio_of(distance),
+            collapsed: None,
+        });
+        true
+    }
+
+    fn forward(&mut self, input: &InputEvent) -> Option<SplitPane> {
+        let mut target = None;
+        for pane in [SplitPane::First, SplitPane::Second] {
+            if self.panels[pane.index()].handle_input(input) {
+                target = target.or(Some(pane));
+            }
+        }
+        target
+    }
+}
+
+impl Interactive for SplitContainer {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.base.visible() {
+            return false;
+        }
+        if input.is_keyboard() {
+            return match (self.focus, *input) {
+                (Some(SplitFocus::Splitter), InputEvent::KeyPressed { key, modifiers })
+                    if modifiers.is_empty() =>
+                {
+                    self.handle_key(key)
+                }
+                (Some(SplitFocus::Pane(pane)), _) => self.panels[pane.index()].handle_input(input),
+                _ => false,
+            };
+        }
+
+        match *input {
+            InputEvent::MouseButtonPressed {
+                button: MouseButton::Left,
+                position,
+                ..
+            } if self.splitter_bounds().contains(position) => {
+                let grab = self.along(position) - self.splitter_distance() as i32;
+                self.drag = Some((grab.max(0) as u16, self.state()));
+                self.focused = true;
+                self.set_focus(Some(SplitFocus::Splitter));
+                true
+            }
+            InputEvent::MouseButtonPressed { .. } => match self.forward(input) {
+                Some(pane) => {
+                    if self.panels[pane.index()].focusable() {
+                        self.focused = true;
+                        self.set_focus(Some(SplitFocus::Pane(pane)));
+                    }
+                    true
+                }
+                None => false,
+            },
+            InputEvent::MouseMoved(position) => {
+                if let Some((grab, _)) = self.drag {
+                    self.drag_to(self.along(position) - grab as i32);
+                    return true;
+                }
+                self.hovered = self.splitter_bounds().contains(position);
+                self.forward(input).is_some()
+            }
+            InputEvent::MouseButtonReleased {
+                button: MouseButton::Left,
+                ..
+            } if self.drag.is_some() => {
+                if let Some((_, start)) = self.drag.take()
+                    && start != self.state()
+                {
+                    self.emit_moved();
+                }
+                self.forward(input);
+                true
+            }
+            _ => self.forward(input).is_some(),
+        }
+    }
+
+    fn focusable(&self) -> bool {
+        *self.base.visible()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        if self.focused == focused {
+            return;
+        }
+
+        self.focused = focused;
+        if focused {
+            let pane = [SplitPane::First, SplitPane::Second]
+                .into_iter()
+                .find(|pane| self.panels[pane.index()].focusable())
+                .map(SplitFocus::Pane);
+            self.set_focus(self.focus.or(pane).or(Some(SplitFocus::Splitter)));
+        } else {
+            self.drag = None;
+            self.set_focus(None);
+        }
+    }
+}
+
+impl Renderable for SplitContainer {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        for panel in &self.panels {
+            panel.paint(ctx);
+        }
+
+        let style = &self.style;
+        let splitter = self.splitter_bounds();
+        let background = if self.drag.is_some() {
+            &style.dragging_splitter_background
+        } else if self.hovered {
+            &style.hovered_splitter_background
+        } else {
+            &style.splitter_background
+        };
+        ctx.fill_rect(splitter, background);
+        if self.focused && self.focus == Some(SplitFocus::Splitter) {
+            ctx.stroke_rect(splitter, &style.focus_border);
+        }
+    }
+}
+
+impl Component for SplitContainer {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    fn find_frame(&self, id: usize) -> Option<Boundaries> {
+        if *self.base.id() == id {
+            return Some(self.base.frame());
+        }
+        self.panels.iter().find_map(|panel| panel.find_frame(id))
+    }
+}
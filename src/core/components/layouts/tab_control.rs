@@ -0,0 +1,799 @@
+use std::{fmt, rc::Rc};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        layouts::container::Container,
+        properties::{boundaries::Boundaries, position::Position, size::Size},
+        styles::tab_control_style::TabControlStyle,
+    },
+    ui::render::render_context::RenderContext,
+    utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+    window::events::{
+        input::{InputEvent, Key, MouseButton},
+        types::{EventPayload, EventType},
+    },
+};
+
+// Pointer travel before a pressed tab starts moving
+const DRAG_THRESHOLD: u16 = 4;
+
+type PageBuilder = Box<dyn FnOnce(&mut Container)>;
+
+/// One tab: its title and the page shown while it is selected. The page is
+/// filled by its builder the first time the tab is selected.
+struct TabPage {
+    title: String,
+    page: Container,
+    builder: Option<PageBuilder>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TabPress {
+    Close(usize),
+    Tab {
+        index: usize,
+        start_x: u16,
+        moving: bool,
+    },
+}
+
+/// Strip of tabs over a stack of `Container` pages. Only the selected page is
+/// visible; the others stay in the tree with `set_visible(false)`.
+pub struct TabControl {
+    base: BaseComponent,
+    tabs: Vec<TabPage>,
+    selected: Option<usize>,
+    closable: bool,
+    reorderable: bool,
+    enabled: bool,
+    style: TabControlStyle,
+    // Horizontal scroll of the tab strip when the tabs do not fit
+    header_offset: u16,
+    hovered: Option<usize>,
+    hovered_close: Option<usize>,
+    press: Option<TabPress>,
+    focused: bool,
+    // Whether the focus is on the tab strip rather than inside the page
+    header_focused: bool,
+}
+
+impl fmt::Debug for TabControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TabControl")
+            .field("base", &self.base)
+            .field(
+                "tabs",
+                &self.tabs.iter().map(|tab| &tab.title).collect::<Vec<_>>(),
+            )
+            .field("selected", &self.selected)
+            .field("closable", &self.closable)
+            .field("reorderable", &self.reorderable)
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+impl TabControl {
+    pub fn new(base: BaseComponent) -> Self {
+        Self {
+            base,
+            tabs: Vec::new(),
+            selected: None,
+            closable: false,
+            reorderable: true,
+            enabled: true,
+            style: TabControlStyle::default(),
+            header_offset: 0,
+            hovered: None,
+            hovered_close: None,
+            press: None,
+            focused: false,
+            header_focused: false,
+        }
+    }
+
+    pub fn with_page(
+        mut self,
+        title: &str,
+        builder: impl FnOnce(&mut Container) + 'static,
+    ) -> Self {
+        self.add_page(title, builder);
+        self
+    }
+
+    pub fn with_closable_tabs(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    pub fn with_reorderable_tabs(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    pub fn with_style(mut self, style: TabControlStyle) -> Self {
+        self.set_style(style);
+        self
+    }
+
+    /// Adds a tab whose page is built when it is first selected. The first tab
+    /// added is selected right away.
+    pub fn add_page(
+        &mut self,
+        title: &str,
+        builder: impl FnOnce(&mut Container) + 'static,
+    ) -> usize {
+        let bounds = self.page_bounds();
+        let mut page = Container::new(BaseComponent::new(
+            bounds.size(),
+            bounds.top_left(),
+            Default::default(),
+            Default::default(),
+            Rc::clone(self.base.event_system()),
+        ));
+        page.set_visible(false);
+
+        self.tabs.push(TabPage {
+            title: title.to_string(),
+            page,
+            builder: Some(Box::new(builder)),
+        });
+        let index = self.tabs.len() - 1;
+        if self.selected.is_none() {
+            self.select(index);
+        }
+        index
+    }
+
+    /// Removes a tab, selecting its right neighbour (or the left one when it
+    /// was the last), and hands back its page
+    pub fn close_tab(&mut self, index: usize) -> Option<Container> {
+        if index >= self.tabs.len() {
+            return None;
+        }
+
+        let was_selected = self.selected == Some(index);
+        let mut removed = self.tabs.remove(index);
+        removed.page.set_visible(false);
+        self.hovered = None;
+        self.hovered_close = None;
+        self.press = None;
+        self.base
+            .emit_with(EventType::ItemClosed, EventPayload::Index(index));
+
+        match self.selected {
+            Some(selected) if selected > index => self.selected = Some(selected - 1),
+            _ if was_selected => {
+                self.selected = None;
+                if !self.tabs.is_empty() {
+                    self.select(index.min(self.tabs.len() - 1));
+                }
+            }
+            _ => {}
+        }
+        self.clamp_header_offset();
+        Some(removed.page)
+    }
+
+    pub fn move_tab(&mut self, from: usize, to: usize) {
+        if from >= self.tabs.len() || to >= self.tabs.len() || from == to {
+            return;
+        }
+
+        let tab = self.tabs.remove(from);
+        self.tabs.insert(to, tab);
+        let remap = |index: usize| {
+            if index == from {
+                to
+            } else if from < index && index <= to {
+                index - 1
+            } else if to <= index && index < from {
+                index + 1
+            } else {
+                index
+            }
+        };
+        self.selected = self.selected.map(remap);
+        self.hovered = self.hovered.map(remap);
+        self.hovered_close = None;
+        self.base
+            .emit_with(EventType::ItemMoved, EventPayload::Moved(from, to));
+    }
+
+    /// Shows the page of a tab, building it first if needed
+    pub fn select(&mut self, index: usize) {
+        if index >= self.tabs.len() || self.selected == Some(index) {
+            return;
+        }
+
+        let page_focused = self.focused && !self.header_focused;
+        if let Some(previous) = self.selected.and_then(|i| self.tabs.get_mut(i)) {
+            previous.page.set_visible(false);
+        }
+
+        let tab = &mut self.tabs[index];
+        if let Some(builder) = tab.builder.take() {
+            builder(&mut tab.page);
+        }
+        tab.page.set_visible(true);
+        // Focus inside the old page follows into the new one when it can
+        if page_focused {
+            if tab.page.focusable() {
+                tab.page.set_focused(true);
+            } else {
+                self.header_focused = true;
+            }
+        }
+        self.selected = Some(index);
+        self.ensure_tab_visible(index);
+        self.base
+            .emit_with(EventType::FieldValueChanged, EventPayload::Index(index));
+    }
+
+    pub fn set_title(&mut self, index: usize, title: &str) {
+        if let Some(tab) = self.tabs.get_mut(index) {
+            tab.title = title.to_string();
+        }
+    }
+
+    pub fn set_closable_tabs(&mut self, closable: bool) {
+        self.closable = closable;
+    }
+
+    pub fn set_reorderable_tabs(&mut self, reorderable: bool) {
+        self.reorderable = reorderable;
+    }
+
+    /// Moves the control, taking the pages along
+    pub fn set_position(&mut self, position: Position) {
+        self.base.set_position(position);
+        self.refresh();
+    }
+
+    /// Resizes the control and fits the pages to the new page area
+    pub fn set_size(&mut self, size: Size) {
+        self.base.set_size(size);
+        self.refresh();
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.press = None;
+            self.hovered = None;
+            self.hovered_close = None;
+        }
+    }
+
+    pub fn set_style(&mut self, style: TabControlStyle) {
+        self.style = style;
+        self.refresh();
+    }
+
+    /// Re-lays out the pages. `set_position` and `set_size` do it on their
+    /// own; a frame changed through `base_mut` needs a call to this
+    pub fn refresh(&mut self) {
+        let bounds = self.page_bounds();
+        for tab in &mut self.tabs {
+            tab.page.base_mut().set_position(bounds.top_left());
+            tab.page.base_mut().set_size(bounds.size());
+        }
+        self.clamp_header_offset();
+    }
+
+    // Getters
+    pub fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    pub fn tab_count(&self) -> usize {
+        self.tabs.len()
+    }
+
+    pub fn title(&self, index: usize) -> Option<&str> {
+        self.tabs.get(index).map(|tab| tab.title.as_str())
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        self.selected
+    }
+
+    pub fn page(&self, index: usize) -> Option<&Container> {
+        self.tabs.get(index).map(|tab| &tab.page)
+    }
+
+    pub fn page_mut(&mut self, index: usize) -> Option<&mut Container> {
+        self.tabs.get_mut(index).map(|tab| &mut tab.page)
+    }
+
+    /// Whether the page of a tab has been built already
+    pub fn is_built(&self, index: usize) -> bool {
+        self.tabs
+            .get(index)
+            .is_some_and(|tab| tab.builder.is_none())
+    }
+
+    pub fn closable_tabs(&self) -> bool {
+        self.closable
+    }
+
+    pub fn reorderable_tabs(&self) -> bool {
+        self.reorderable
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn style(&self) -> &TabControlStyle {
+        &self.style
+    }
+
+    /// Area below the tab strip where the pages are placed
+    pub fn page_bounds(&self) -> Boundaries {
+        let frame = self.base.frame();
+        let header = self.style.header_height.min(frame.height());
+        Boundaries::from_rect(
+            Position::new(frame.top_left().x() + 1, frame.top_left().y() + header + 1),
+            Size::new(
+                frame.height().saturating_sub(header + 2),
+                frame.width().saturating_sub(2),
+            ),
+        )
+    }
+
+    /// Bounds of a tab in the strip, before clipping to the control
+    pub fn tab_bounds(&self, index: usize) -> Option<Boundaries> {
+        if index >= self.tabs.len() {
+            return None;
+        }
+
+        let frame = self.base.frame();
+        let left: u16 = (0..index)
+            .map(|i| self.tab_width(i))
+            .fold(0, u16::saturating_add);
+        let x = (frame.top_left().x() + left).saturating_sub(self.header_offset);
+        Some(Boundaries::from_rect(
+            Position::new(x, frame.top_left().y()),
+            Size::new(
+                self.style.header_height.min(frame.height()),
+                self.tab_width(index),
+            ),
+        ))
+    }
+
+    /// Close button of a tab, when tabs are closable
+    pub fn close_bounds(&self, index: usize) -> Option<Boundaries> {
+        if !self.closable {
+            return None;
+        }
+
+        let tab = self.tab_bounds(index)?;
+        let size = self.style.close_button_size;
+        Some(Boundaries::from_rect(
+            Position::new(
+                (tab.bottom_right().x()).saturating_sub(self.style.tab_padding / 2 + size),
+                tab.top_left().y() + tab.height().saturating_sub(size) / 2,
+            ),
+            Size::new(size, size),
+        ))
+    }
+
+    // Layout
+
+    fn tab_width(&self, index: usize) -> u16 {
+        let style = &self.style;
+        let mut width = style
+            .typography
+            .measure_text(&self.tabs[index].title)
+            .width()
+            + style.tab_padding * 2;
+        if self.closable {
+            width += style.close_button_size;
+        }
+        width.max(style.min_tab_width)
+    }
+
+    fn strip_width(&self) -> u16 {
+        (0..self.tabs.len())
+            .map(|i| self.tab_width(i))
+            .fold(0, u16::saturating_add)
+    }
+
+    fn header_bounds(&self) -> Boundaries {
+        let frame = self.base.frame();
+        Boundaries::from_rect(
+            frame.top_left(),
+            frame
+                .size()
+                .with_height(self.style.header_height.min(frame.height())),
+        )
+    }
+
+    fn clamp_header_offset(&mut self) {
+        let max = self.strip_width().saturating_sub(self.base.frame().width());
+        self.header_offset = self.header_offset.min(max);
+    }
+
+    fn ensure_tab_visible(&mut self, index: usize) {
+        let left: u16 = (0..index)
+            .map(|i| self.tab_width(i))
+            .fold(0, u16::saturating_add);
+        let right = left.saturating_add(self.tab_width(index));
+        let width = self.base.frame().width();
+        if left < self.header_offset {
+            self.header_offset = left;
+        } else if right > self.header_offset.saturating_add(width) {
+            self.header_offset = right - width;
+        }
+        self.clamp_header_offset();
+    }
+
+    fn tab_at(&self, point: Position) -> Option<usize> {
+        if !self.header_bounds().contains(point) {
+            return None;
+        }
+        (0..self.tabs.len()).find(|i| {
+            self.tab_bounds(*i)
+                .is_some_and(|bounds| bounds.contains(point))
+        })
+    }
+
+    fn close_at(&self, point: Position) -> Option<usize> {
+        let index = self.tab_at(point)?;
+        self.close_bounds(index)?.contains(point).then_some(index)
+    }
+
+    fn selected_page_mut(&mut self) -> Option<&mut Container> {
+        let selected = self.selected?;
+        self.tabs.get_mut(selected).map(|tab| &mut tab.page)
+    }
+
+    // Moves the focus between the strip and the selected page
+    fn focus_header(&mut self, header: bool) {
+        self.header_focused = header;
+        if let Some(page) = self.selected_page_mut() {
+            page.set_focused(!header);
+        }
+    }
+
+    fn select_relative(&mut self, step: isize) {
+        let count = self.tabs.len() as isize;
+        if count == 0 {
+            return;
+        }
+        let current = self.selected.map_or(0, |i| i as isize);
+        self.select((current + step).rem_euclid(count) as usize);
+    }
+
+    fn handle_key(&mut self, key: Key, shift: bool, ctrl: bool) -> bool {
+        // Ctrl+Tab and Ctrl+PageUp/PageDown switch tabs from anywhere inside
+        match key {
+            Key::Tab if ctrl => {
+                self.select_relative(if shift { -1 } else { 1 });
+                return true;
+            }
+            Key::PageDown if ctrl => {
+                self.select_relative(1);
+                return true;
+            }
+            Key::PageUp if ctrl => {
+                self.select_relative(-1);
+                return true;
+            }
+            _ => {}
+        }
+
+        if !self.header_focused {
+            return false;
+        }
+        // Unlike Ctrl+Tab the arrows stop at both ends of the strip
+        let last = self.tabs.len().saturating_sub(1);
+        let current = self.selected.unwrap_or_default();
+        match key {
+            Key::Left => self.select(current.saturating_sub(1)),
+            Key::Right => self.select((current + 1).min(last)),
+            Key::Home => self.select(0),
+            Key::End => self.select(last),
+            // Tab or Down step from the strip into the page
+            Key::Tab | Key::Down => {
+                let focusable = self.selected.is_some_and(|i| self.tabs[i].page.focusable());
+                if !focusable {
+                    return false;
+                }
+                self.focus_header(false);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn handle_press(&mut self, position: Position) -> bool {
+        if let Some(index) = self.close_at(position) {
+            self.press = Some(TabPress::Close(index));
+            return true;
+        }
+        if let Some(index) = self.tab_at(position) {
+            self.focused = true;
+            self.focus_header(true);
+            self.select(index);
+            self.press = Some(TabPress::Tab {
+                index,
+                start_x: position.x(),
+                moving: false,
+            });
+            return true;
+        }
+        false
+    }
+
+    fn handle_pointer_move(&mut self, position: Position) -> bool {
+        self.hovered = self.tab_at(position);
+        self.hovered_close = self.close_at(position);
+
+        let Some(TabPress::Tab {
+            index,
+            start_x,
+            moving,
+        }) = self.press
+        else {
+            return false;
+        };
+        if !self.reorderable {
+            return true;
+        }
+        let moving = moving || position.x().abs_diff(start_x) > DRAG_THRESHOLD;
+        let mut index = index;
+        if moving {
+            // The dragged tab swaps places as the pointer crosses its neighbours
+            let header = self.header_bounds();
+            let probe = Position::new(position.x(), header.top_left().y());
+            let target = (0..self.tabs.len())
+                .find(|i| self.tab_bounds(*i).is_some_and(|b| b.contains(probe)))
+                .or_else(|| {
+                    let first = self.tab_bounds(0)?;
+                    Some(if position.x() < first.top_left().x() {
+                        0
+                    } else {
+                        self.tabs.len() - 1
+                    })
+                });
+            if let Some(target) = target
+                && target != index
+            {
+                self.move_tab(index, target);
+                index = target;
+            }
+        }
+        self.press = Some(TabPress::Tab {
+            index,
+            start_x,
+            moving,
+        });
+        true
+    }
+
+    fn handle_release(&mut self, position: Position) -> bool {
+        match self.press.take() {
+            Some(TabPress::Close(index)) => {
+                if self.close_at(position) == Some(index) {
+                    self.close_tab(index);
+                }
+                true
+            }
+            Some(TabPress::Tab { .. }) => true,
+            None => false,
+        }
+    }
+}
+
+impl Interactive for TabControl {
+    fn handle_input(&mut self, input: &InputEvent) -> bool {
+        if !self.enabled || !self.base.visible() {
+            return false;
+        }
+        match *input {
+            InputEvent::KeyPressed { key, modifiers } if self.focused => {
+                if self.handle_key(key, modifiers.shift, modifiers.ctrl) {
+                    return true;
+                }
+                !self.header_focused
+                    && self
+                        .selected_page_mut()
+                        .is_some_and(|page| page.handle_input(input))
+            }
+            InputEvent::MouseButtonPressed {
+                button: MouseButton::Left,
+                position,
+                ..
+            } if self.handle_press(position) => true,
+            InputEvent::MouseMoved(position) => {
+                let consumed = self.handle_pointer_move(position);
+                let page = self
+                    .selected_page_mut()
+                    .is_some_and(|page| page.handle_input(input));
+                consumed || page
+            }
+            InputEvent::MouseButtonReleased {
+                button: MouseButton::Left,
+                position,
+                ..
+            } if self.press.is_some() => self.handle_release(position),
+            _ if input.is_keyboard() && (!self.focused || self.header_focused) => false,
+            _ => {
+                let consumed = self
+                    .selected_page_mut()
+                    .is_some_and(|page| page.handle_input(input));
+                // A click that lands on a page control takes the focus off the strip
+                if consumed && matches!(input, InputEvent::MouseButtonPressed { .. }) {
+                    self.focused = true;
+                    self.header_focused = false;
+                }
+                consumed
+            }
+        }
+    }
+
+    fn focusable(&self) -> bool {
+        self.enabled && *self.base.visible() && !self.tabs.is_empty()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        if self.focused == focused {
+            return;
+        }
+
+        self.focused = focused;
+        if focused {
+            self.focus_header(true);
+            self.base.emit(EventType::FieldFocused);
+        } else {
+            self.press = None;
+            if let Some(page) = self.selected_page_mut() {
+                page.set_focused(false);
+            }
+            self.base.emit(EventType::FieldFocusedLost);
+        }
+    }
+}
+
+impl TabControl {
+    fn render_tab(&self, ctx: &mut RenderContext, index: usize) {
+        let Some(bounds) = self.tab_bounds(index) else {
+            return;
+        };
+        let style = &self.style;
+        let selected = self.selected == Some(index);
+        // Unselected tabs sit two pixels lower, as in WinForms
+        let bounds = if selected {
+            bounds
+        } else {
+            Boundaries::from_rect(
+                Position::new(bounds.top_left().x(), bounds.top_left().y() + 2),
+                bounds.size().with_height(bounds.height().saturating_sub(2)),
+            )
+        };
+
+        let background = if selected {
+            &style.selected_tab_background
+        } else if self.hovered == Some(index) && self.enabled {
+            &style.hovered_tab_background
+        } else {
+            &style.tab_background
+        };
+        ctx.fill_rect(bounds, background);
+        ctx.stroke_rect(bounds, &style.border);
+
+        let title = &self.tabs[index].title;
+        let text_size = style.typography.measure_text(title);
+        let text_position = Position::new(
+            bounds.top_left().x() + style.tab_padding,
+            bounds.top_left().y() + bounds.height().saturating_sub(text_size.height()) / 2,
+        );
+        let color = if self.enabled {
+            &style.text_color
+        } else {
+            &style.disabled_text_color
+        };
+        ctx.draw_text(text_position, title, &style.typography, color);
+
+        if selected && self.focused && self.header_focused {
+            ctx.stroke_rect(
+                Boundaries::from_rect(text_position, text_size),
+                &style.focus_border,
+            );
+        }
+
+        if let Some(close) = self.close_bounds(index) {
+            if self.hovered_close == Some(index) {
+                ctx.fill_rect(close, &style.close_hovered_background);
+            }
+            let (x0, y0) = (close.top_left().x() + 3, close.top_left().y() + 3);
+            let (x1, y1) = (
+                close.bottom_right().x().saturating_sub(4),
+                close.bottom_right().y().saturating_sub(4),
+            );
+            ctx.draw_line(
+                Position::new(x0, y0),
+                Position::new(x1, y1),
+                &style.close_glyph_color,
+                1,
+            );
+            ctx.draw_line(
+                Position::new(x1, y0),
+                Position::new(x0, y1),
+                &style.close_glyph_color,
+                1,
+            );
+        }
+    }
+}
+
+impl Renderable for TabControl {
+    fn render(&self, ctx: &mut RenderContext) {
+        if !self.base.visible() {
+            return;
+        }
+
+        let style = &self.style;
+        let frame = self.base.frame();
+        let header = self.header_bounds();
+        let body = Boundaries::from_rect(
+            Position::new(frame.top_left().x(), header.bottom_right().y()),
+            Size::new(frame.height() - header.height(), frame.width()),
+        );
+        ctx.fill_rect(body, &style.page_background);
+        ctx.stroke_rect(body, &style.border);
+
+        // The selected tab is drawn last so it overlaps its neighbours
+        ctx.push_clip(header);
+        for index in (0..self.tabs.len()).filter(|i| Some(*i) != self.selected) {
+            self.render_tab(ctx, index);
+        }
+        if let Some(selected) = self.selected {
+            self.render_tab(ctx, selected);
+        }
+        ctx.pop_clip();
+
+        // Open the border line below the selected tab into its page
+        if let Some(tab) = self.selected.and_then(|i| self.tab_bounds(i)) {
+            let gap = Boundaries::from_rect(
+                Position::new(tab.top_left().x() + 1, header.bottom_right().y()),
+                Size::new(1, tab.width().saturating_sub(2)),
+            );
+            ctx.fill_rect(gap, &style.page_background);
+        }
+
+        if let Some(selected) = self.selected {
+            ctx.push_clip(self.page_bounds());
+            self.tabs[selected].page.paint(ctx);
+            ctx.pop_clip();
+        }
+    }
+}
+
+impl Component for TabControl {
+    fn base(&self) -> &BaseComponent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseComponent {
+        &mut self.base
+    }
+
+    fn find_frame(&self, id: usize) -> Option<Boundaries> {
+        if *self.base.id() == id {
+            return Some(self.base.frame());
+        }
+        self.tabs.iter().find_map(|tab| tab.page.find_frame(id))
+    }
+}
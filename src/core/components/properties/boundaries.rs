@@ -1,4 +1,7 @@
-use crate::core::{components::properties::position::Position, ui::cursor_tracker::CursorTracker};
+use crate::core::{
+    components::properties::{position::Position, size::Size},
+    ui::cursor_tracker::CursorTracker,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Boundaries {
@@ -18,12 +21,53 @@ impl Boundaries {
         Self { p0, p1, p2, p3 }
     }
 
+    pub fn from_rect(position: Position, size: Size) -> Self {
+        let right = position.x().saturating_add(size.width());
+        let bottom = position.y().saturating_add(size.height());
+
+        Self {
+            p0: position,
+            p1: Position::new(right, position.y()),
+            p2: Position::new(right, bottom),
+            p3: Position::new(position.x(), bottom),
+        }
+    }
+
+    /// Inclusive of all four edges, unlike `contains`
     pub fn inside_boundary(&self, cursor: CursorTracker) -> bool {
-        let cursor_pos = cursor.current_position();
+        let point = cursor.current_position();
+        point.x() >= self.p0.x()
+            && point.x() <= self.p2.x()
+            && point.y() >= self.p0.y()
+            && point.y() <= self.p2.y()
+    }
+
+    /// Half-open: the right and bottom edges belong to the next rectangle,
+    /// so adjacent rectangles never both hit-test a point
+    pub fn contains(&self, point: Position) -> bool {
+        point.x() >= self.p0.x()
+            && point.x() < self.p2.x()
+            && point.y() >= self.p0.y()
+            && point.y() < self.p2.y()
+    }
+
+    pub fn top_left(&self) -> Position {
+        self.p0
+    }
+
+    pub fn bottom_right(&self) -> Position {
+        self.p2
+    }
+
+    pub fn width(&self) -> u16 {
+        self.p2.x().saturating_sub(self.p0.x())
+    }
+
+    pub fn height(&self) -> u16 {
+        self.p2.y().saturating_sub(self.p0.y())
+    }
 
-        cursor_pos.x() >= self.p0.x()
-            && cursor_pos.x() <= self.p2.x()
-            && cursor_pos.y() >= self.p0.y()
-            && cursor_pos.y() <= self.p2.y()
+    pub fn size(&self) -> Size {
+        Size::new(self.height(), self.width())
     }
 }
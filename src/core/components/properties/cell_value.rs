@@ -0,0 +1,81 @@
+use std::{cmp::Ordering, fmt};
+
+use serde::{Deserialize, Serialize};
+
+/// Value of a single cell in a tabular data source
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CellValue {
+    #[default]
+    Empty,
+    Text(String),
+    Number(f64),
+    Bool(bool),
+}
+
+impl CellValue {
+    pub fn is_empty(&self) -> bool {
+        matches!(self, CellValue::Empty)
+    }
+
+    pub fn as_bool(&self) -> bool {
+        matches!(self, CellValue::Bool(true))
+    }
+
+    /// Ordering used when sorting a column: empty cells first, numbers by
+    /// value, text case-insensitively and mixed kinds by kind
+    pub fn sort_cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (CellValue::Number(a), CellValue::Number(b)) => a.total_cmp(b),
+            (CellValue::Text(a), CellValue::Text(b)) => a
+                .to_lowercase()
+                .cmp(&b.to_lowercase())
+                .then_with(|| a.cmp(b)),
+            (CellValue::Bool(a), CellValue::Bool(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            CellValue::Empty => 0,
+            CellValue::Bool(_) => 1,
+            CellValue::Number(_) => 2,
+            CellValue::Text(_) => 3,
+        }
+    }
+}
+
+impl fmt::Display for CellValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CellValue::Empty => Ok(()),
+            CellValue::Text(text) => f.write_str(text),
+            CellValue::Number(number) => write!(f, "{number}"),
+            CellValue::Bool(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<&str> for CellValue {
+    fn from(text: &str) -> Self {
+        CellValue::Text(text.to_string())
+    }
+}
+
+impl From<String> for CellValue {
+    fn from(text: String) -> Self {
+        CellValue::Text(text)
+    }
+}
+
+impl From<f64> for CellValue {
+    fn from(number: f64) -> Self {
+        CellValue::Number(number)
+    }
+}
+
+impl From<bool> for CellValue {
+    fn from(value: bool) -> Self {
+        CellValue::Bool(value)
+    }
+}
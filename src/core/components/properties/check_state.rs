@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Checked,
+    Indeterminate,
+}
+
+impl CheckState {
+    pub fn is_checked(&self) -> bool {
+        matches!(self, CheckState::Checked)
+    }
+
+    pub fn is_indeterminate(&self) -> bool {
+        matches!(self, CheckState::Indeterminate)
+    }
+
+    /// Next state in the click cycle: Unchecked -> Checked -> Indeterminate -> Unchecked
+    pub fn next(&self, three_state: bool) -> Self {
+        match self {
+            CheckState::Unchecked => CheckState::Checked,
+            CheckState::Checked if three_state => CheckState::Indeterminate,
+            CheckState::Checked | CheckState::Indeterminate => CheckState::Unchecked,
+        }
+    }
+}
+
+impl From<bool> for CheckState {
+    fn from(checked: bool) -> Self {
+        if checked {
+            CheckState::Checked
+        } else {
+            CheckState::Unchecked
+        }
+    }
+}
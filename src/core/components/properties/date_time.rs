@@ -0,0 +1,316 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// Position in the week counting from Monday as 0
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index % 7]
+    }
+}
+
+/// Day of the proleptic Gregorian calendar, between the years 1 and 9999.
+/// Dates order chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "RawDate")]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+// Unchecked form of Date so deserialized values go through `Date::new`
+#[derive(Deserialize)]
+struct RawDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl TryFrom<RawDate> for Date {
+    type Error = String;
+
+    fn try_from(raw: RawDate) -> Result<Self, Self::Error> {
+        Date::new(raw.year, raw.month, raw.day).ok_or_else(|| {
+            format!(
+                "invalid date {:04}-{:02}-{:02}",
+                raw.year, raw.month, raw.day
+            )
+        })
+    }
+}
+
+impl Date {
+    pub const MIN: Date = Date {
+        year: 1,
+        month: 1,
+        day: 1,
+    };
+    pub const MAX: Date = Date {
+        year: 9999,
+        month: 12,
+        day: 31,
+    };
+
+    /// `None` when the day does not exist, such as February 30
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        ((1..=9999).contains(&year)
+            && (1..=12).contains(&month)
+            && day >= 1
+            && day <= days_in_month(year, month))
+        .then_some(Self { year, month, day })
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::from_index((self.days_since_epoch() + 3).rem_euclid(7) as usize)
+    }
+
+    pub fn first_of_month(&self) -> Self {
+        Self { day: 1, ..*self }
+    }
+
+    pub fn last_of_month(&self) -> Self {
+        Self {
+            day: days_in_month(self.year, self.month),
+            ..*self
+        }
+    }
+
+    /// Moves by a number of days, stopping at `MIN` and `MAX`
+    pub fn add_days(&self, days: i64) -> Self {
+        let days = (self.days_since_epoch() + days)
+            .clamp(Self::MIN.days_since_epoch(), Self::MAX.days_since_epoch());
+        Self::from_days_since_epoch(days)
+    }
+
+    /// Moves by a number of months, keeping the day where the month allows
+    /// it and taking its last day otherwise, so January 31 plus one month is
+    /// the end of February
+    pub fn add_months(&self, months: i32) -> Self {
+        let total =
+            (self.year as i32 * 12 + self.month as i32 - 1 + months).clamp(12, 9999 * 12 + 11);
+        let (year, month) = ((total / 12) as u16, (total % 12 + 1) as u8);
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    pub fn add_years(&self, years: i32) -> Self {
+        self.add_months(years.saturating_mul(12))
+    }
+
+    /// Days since 1970-01-01, negative before it
+    pub fn days_since_epoch(&self) -> i64 {
+        // Howard Hinnant's days_from_civil, with years starting in March
+        let year = self.year as i64 - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = self.month as i64;
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        } as u8;
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Self {
+            year: year.clamp(1, 9999) as u16,
+            month,
+            day,
+        }
+    }
+}
+
+/// Time of day with second precision
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(try_from = "RawTime")]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+// Unchecked form of Time so deserialized values go through `Time::new`
+#[derive(Deserialize)]
+struct RawTime {
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl TryFrom<RawTime> for Time {
+    type Error = String;
+
+    fn try_from(raw: RawTime) -> Result<Self, Self::Error> {
+        Time::new(raw.hour, raw.minute, raw.second).ok_or_else(|| {
+            format!(
+                "invalid time {:02}:{:02}:{:02}",
+                raw.hour, raw.minute, raw.second
+            )
+        })
+    }
+}
+
+impl Time {
+    pub const MIDNIGHT: Time = Time {
+        hour: 0,
+        minute: 0,
+        second: 0,
+    };
+
+    pub fn new(hour: u8, minute: u8, second: u8) -> Option<Self> {
+        (hour < 24 && minute < 60 && second < 60).then_some(Self {
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    pub fn with_hour(&self, hour: u8) -> Self {
+        Self {
+            hour: hour % 24,
+            ..*self
+        }
+    }
+
+    pub fn with_minute(&self, minute: u8) -> Self {
+        Self {
+            minute: minute % 60,
+            ..*self
+        }
+    }
+}
+
+/// Date plus time of day, without a time zone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct DateTime {
+    pub date: Date,
+    pub time: Time,
+}
+
+impl DateTime {
+    pub const MIN: DateTime = DateTime {
+        date: Date::MIN,
+        time: Time::MIDNIGHT,
+    };
+    pub const MAX: DateTime = DateTime {
+        date: Date::MAX,
+        time: Time {
+            hour: 23,
+            minute: 59,
+            second: 59,
+        },
+    };
+
+    pub fn new(date: Date, time: Time) -> Self {
+        Self { date, time }
+    }
+
+    /// Midnight at the start of `date`
+    pub fn from_date(date: Date) -> Self {
+        Self::new(date, Time::MIDNIGHT)
+    }
+
+    /// Current time in UTC, as there is no time zone data to convert it
+    pub fn now() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs() as i64);
+        let of_day = seconds.rem_euclid(86_400);
+        Self {
+            date: Date::from_days_since_epoch(seconds.div_euclid(86_400)),
+            time: Time {
+                hour: (of_day / 3600) as u8,
+                minute: (of_day / 60 % 60) as u8,
+                second: (of_day % 60) as u8,
+            },
+        }
+    }
+}
+
+impl From<Date> for DateTime {
+    fn from(date: Date) -> Self {
+        Self::from_date(date)
+    }
+}
+
+pub fn is_leap_year(year: u16) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+pub fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
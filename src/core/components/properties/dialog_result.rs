@@ -0,0 +1,30 @@
+/// How a dialog was closed, as in WinForms
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DialogResult {
+    // Still open, or closed without an answer
+    #[default]
+    None,
+    Ok,
+    Cancel,
+    Abort,
+    Retry,
+    Ignore,
+    Yes,
+    No,
+}
+
+impl DialogResult {
+    /// Text of the button that gives this result
+    pub fn label(&self) -> &'static str {
+        match self {
+            DialogResult::None => "",
+            DialogResult::Ok => "OK",
+            DialogResult::Cancel => "Cancel",
+            DialogResult::Abort => "Abort",
+            DialogResult::Retry => "Retry",
+            DialogResult::Ignore => "Ignore",
+            DialogResult::Yes => "Yes",
+            DialogResult::No => "No",
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use crate::core::components::properties::graphics::{color::Color, gradient::Gradient};
+use crate::core::components::properties::graphics::{
+    blend_mode::BlendMode, color::Color, gradient::Gradient,
+};
 use std::hash::Hash;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -37,6 +39,8 @@ impl Hash for BackgroundShape {
 pub struct Background {
     color: BackgroundColor,
     shape: BackgroundShape,
+    #[serde(default, skip_serializing_if = "BlendMode::is_normal")]
+    blend_mode: BlendMode,
 }
 
 impl Background {
@@ -44,6 +48,7 @@ impl Background {
         Self {
             color: BackgroundColor::Solid(color),
             shape,
+            blend_mode: BlendMode::default(),
         }
     }
 
@@ -51,9 +56,21 @@ impl Background {
         Self {
             color: BackgroundColor::Gradient(gradient),
             shape,
+            blend_mode: BlendMode::default(),
         }
     }
 
+    pub fn with_blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    // Setters
+    pub fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        self.blend_mode = blend_mode;
+    }
+
+    // Getters
     pub fn color(&self) -> &BackgroundColor {
         &self.color
     }
@@ -61,4 +78,32 @@ impl Background {
     pub fn shape(&self) -> &BackgroundShape {
         &self.shape
     }
+
+    pub fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    pub fn solid_color(&self) -> Option<&Color> {
+        match &self.color {
+            BackgroundColor::Solid(color) => Some(color),
+            BackgroundColor::Gradient(_) => None,
+        }
+    }
+
+    pub fn gradient(&self) -> Option<&Gradient> {
+        match &self.color {
+            BackgroundColor::Solid(_) => None,
+            BackgroundColor::Gradient(gradient) => Some(gradient),
+        }
+    }
+
+    /// Colors the background paints: its solid color or every gradient stop
+    pub fn colors(&self) -> Vec<&Color> {
+        match &self.color {
+            BackgroundColor::Solid(color) => vec![color],
+            BackgroundColor::Gradient(gradient) => {
+                gradient.stops.iter().map(|(color, _)| color).collect()
+            }
+        }
+    }
 }
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+/// How a background's colors mix with what is already painted under it,
+/// before the result is composited over it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BlendMode {
+    // The background covers what is below
+    #[default]
+    Normal,
+    // Darkens: white leaves the backdrop as is, black gives black
+    Multiply,
+    // Lightens: black leaves the backdrop as is, white gives white
+    Screen,
+    // Multiply on the backdrop's dark tones and screen on its light ones,
+    // raising contrast
+    Overlay,
+}
+
+impl BlendMode {
+    pub fn is_normal(&self) -> bool {
+        matches!(self, BlendMode::Normal)
+    }
+
+    /// Mixes a source channel over a backdrop channel, both straight and in
+    /// 0..=1
+    pub fn blend(self, backdrop: f32, source: f32) -> f32 {
+        match self {
+            BlendMode::Normal => source,
+            BlendMode::Multiply => backdrop * source,
+            BlendMode::Screen => backdrop + source - backdrop * source,
+            BlendMode::Overlay => {
+                if backdrop <= 0.5 {
+                    BlendMode::Multiply.blend(2.0 * backdrop, source)
+                } else {
+                    BlendMode::Screen.blend(2.0 * backdrop - 1.0, source)
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,50 @@
+use std::{fmt::Display, str::FromStr, sync::LazyLock};
+
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 
+use crate::core::{
+    components::properties::graphics::{
+        color_space::{ACHROMATIC, ColorSpace, LinearRgb, OkLab, OkLch},
+        named_colors::named_color,
+    },
+    utils::functions::lerp,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColorParseError {
+    Empty,
+    // Hex digits not in the `#RGB`, `#RRGGBB` or `#RRGGBBAA` forms
+    InvalidHex(String),
+    // Functional notation without its closing parenthesis
+    Syntax(String),
+    UnknownFunction(String),
+    ArgumentCount { function: String, found: usize },
+    // Argument that is not a number or is out of range
+    InvalidArgument(String),
+    UnknownName(String),
+}
+
+impl Display for ColorParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorParseError::Empty => write!(f, "empty color"),
+            ColorParseError::InvalidHex(value) => write!(f, "invalid hex color: {value}"),
+            ColorParseError::Syntax(value) => write!(f, "missing closing parenthesis: {value}"),
+            ColorParseError::UnknownFunction(name) => write!(f, "unknown color function: {name}"),
+            ColorParseError::ArgumentCount { function, found } => {
+                write!(f, "{function}() takes 3 or 4 arguments, found {found}")
+            }
+            ColorParseError::InvalidArgument(value) => {
+                write!(f, "invalid color argument: {value}")
+            }
+            ColorParseError::UnknownName(name) => write!(f, "unknown color name: {name}"),
+        }
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct RGBA {
     pub r: u8,
@@ -17,6 +61,27 @@ impl RGBA {
     pub fn from((r, g, b, a): (u8, u8, u8, u8)) -> Self {
         RGBA { r, g, b, a }
     }
+
+    /// Drops the alpha channel
+    pub fn to_rgb(self) -> RGB {
+        RGB::new(self.r, self.g, self.b)
+    }
+
+    /// Drops the alpha channel, which HSV does not hold
+    pub fn to_hsv(self) -> HSV {
+        self.to_rgb().to_hsv()
+    }
+
+    /// Six digits when opaque, eight with the alpha otherwise
+    pub fn to_hex(self) -> HEX {
+        let RGBA { r, g, b, a } = self;
+        let value = if a == 255 {
+            format!("#{r:02X}{g:02X}{b:02X}")
+        } else {
+            format!("#{r:02X}{g:02X}{b:02X}{a:02X}")
+        };
+        HEX { value }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -37,8 +102,21 @@ impl RGB {
     pub fn to_rgba(self, a: u8) -> RGBA {
         RGBA::new(self.r, self.g, self.b, a)
     }
+
+    pub fn to_hsv(self) -> HSV {
+        let (hue, saturation, value) = rgb_to_hsv(self);
+        HSV::from_degrees(hue, saturation, value)
+    }
+
+    pub fn to_hex(self) -> HEX {
+        self.to_rgba(255).to_hex()
+    }
 }
 
+/// Hue in half degrees (0-179, the way OpenCV stores it) so a full turn fits
+/// a byte, with saturation and value in 0-255. `from_degrees` and
+/// `to_degrees` convert from and to the usual degrees and fractions; hues from
+/// 180 up are past a full turn and wrap around
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct HSV {
     pub h: u8,
@@ -54,27 +132,115 @@ impl HSV {
     pub fn from((h, s, v): (u8, u8, u8)) -> Self {
         HSV { h, s, v }
     }
+
+    /// Hue in degrees with saturation and value in 0..=1, rounded to the
+    /// nearest value HSV can hold
+    pub fn from_degrees(hue: f32, saturation: f32, value: f32) -> Self {
+        HSV::new(
+            ((hue.rem_euclid(360.0) / 2.0).round() as u16 % 180) as u8,
+            (saturation.clamp(0.0, 1.0) * 255.0).round() as u8,
+            (value.clamp(0.0, 1.0) * 255.0).round() as u8,
+        )
+    }
+
+    /// Hue in degrees (0-358), saturation and value in 0..=1
+    pub fn to_degrees(self) -> (f32, f32, f32) {
+        (
+            (self.h % 180) as f32 * 2.0,
+            self.s as f32 / 255.0,
+            self.v as f32 / 255.0,
+        )
+    }
+
+    pub fn to_rgb(self) -> RGB {
+        let (hue, saturation, value) = self.to_degrees();
+        hsv_to_rgb(hue, saturation, value)
+    }
+
+    /// Opaque, as HSV holds no alpha
+    pub fn to_rgba(self) -> RGBA {
+        self.to_rgb().to_rgba(255)
+    }
+
+    pub fn to_hex(self) -> HEX {
+        self.to_rgba().to_hex()
+    }
 }
 
+// "#RGB", "#RRGGBB" or "#RRGGBBAA", with a "#", "0x" or no prefix; compiled
+// once on first use
+static HEX_FORMAT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(#|0x)?([0-9a-fA-F]{3}|[0-9a-fA-F]{6}|[0-9a-fA-F]{8})$").unwrap()
+});
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "RawHex")]
 pub struct HEX {
-    pub value: String,
+    // Always in one of the forms `parse` accepts
+    value: String,
+}
+
+// Unchecked form of HEX so deserialized values go through `HEX::parse`
+#[derive(Deserialize)]
+struct RawHex {
+    value: String,
+}
+
+impl TryFrom<RawHex> for HEX {
+    type Error = ColorParseError;
+
+    fn try_from(raw: RawHex) -> Result<Self, Self::Error> {
+        HEX::parse(&raw.value)
+    }
 }
 
 impl HEX {
+    /// Panics when the value is not a valid hex color; use `parse` for
+    /// values that come from the user or from files
     pub fn new(value: &'static str) -> Self {
+        match Self::parse(value) {
+            Ok(hex) => hex,
+            Err(_) => panic!("Invalid HEX color format: {}", value),
+        }
+    }
+
+    /// Checks the format and keeps the value as written
+    pub fn parse(value: &str) -> Result<Self, ColorParseError> {
         if !Self::validate_format(value) {
-            panic!("Invalid HEX color format: {}", value);
+            return Err(ColorParseError::InvalidHex(value.to_string()));
         }
-        HEX {
+        Ok(HEX {
             value: value.to_string(),
+        })
+    }
+
+    // Getters
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Channels of `#RGB`, `#RRGGBB` or `#RRGGBBAA`, with a `#`, `0x` or no
+    /// prefix. Three digits are doubled and six are opaque
+    pub fn to_rgba(&self) -> RGBA {
+        let digits = self
+            .value
+            .strip_prefix('#')
+            .or_else(|| self.value.strip_prefix("0x"))
+            .unwrap_or(&self.value);
+        // The format was checked when the value was parsed
+        let channel = |at: usize, len: usize| {
+            let n = u8::from_str_radix(&digits[at..at + len], 16).unwrap_or(0);
+            if len == 1 { n * 17 } else { n }
+        };
+        match digits.len() {
+            3 => RGBA::new(channel(0, 1), channel(1, 1), channel(2, 1), 255),
+            6 => RGBA::new(channel(0, 2), channel(2, 2), channel(4, 2), 255),
+            _ => RGBA::new(channel(0, 2), channel(2, 2), channel(4, 2), channel(6, 2)),
         }
     }
 
-    fn validate_format(value: &'static str) -> bool {
-        // Simple validation for hex format (e.g., "#RRGGBB" or "#RGB" or "#RRGGBBAA" or "0xRRGGBB" or "0xRGB" or "0xRRGGBBAA")
-        let re = Regex::new(r"^(#|0x)?([0-9a-fA-F]{3}|[0-9a-fA-F]{6}|[0-9a-fA-F]{8})$").unwrap();
-        re.is_match(value)
+    fn validate_format(value: &str) -> bool {
+        HEX_FORMAT.is_match(value)
     }
 }
 
@@ -95,6 +261,7 @@ impl Color {
         Color::RGB(RGB::new(r, g, b))
     }
 
+    /// Hue in half degrees, saturation and value in 0-255, as `HSV` holds them
     pub fn hsv(h: u8, s: u8, v: u8) -> Self {
         Color::HSV(HSV::new(h, s, v))
     }
@@ -102,4 +269,435 @@ impl Color {
     pub fn hex(value: &'static str) -> Self {
         Color::HEX(HEX::new(value))
     }
+
+    /// Channels of the color
+    pub fn to_rgba(&self) -> RGBA {
+        match self {
+            Color::RGBA(rgba) => *rgba,
+            Color::RGB(rgb) => rgb.to_rgba(255),
+            Color::HSV(hsv) => hsv.to_rgba(),
+            Color::HEX(hex) => hex.to_rgba(),
+        }
+    }
+
+    /// Hue, saturation and value of the color, without its alpha
+    pub fn to_hsv(&self) -> HSV {
+        match self {
+            Color::HSV(hsv) => *hsv,
+            color => color.to_rgba().to_hsv(),
+        }
+    }
+
+    /// HEX colors are returned as written, the rest with six or eight digits
+    pub fn to_hex(&self) -> HEX {
+        match self {
+            Color::HEX(hex) => hex.clone(),
+            color => color.to_rgba().to_hex(),
+        }
+    }
+}
+
+// Manipulation
+impl Color {
+    /// Alpha of the color, opaque for representations without one
+    pub fn alpha(&self) -> u8 {
+        self.to_rgba().a
+    }
+
+    pub fn with_alpha(&self, alpha: u8) -> Color {
+        Color::RGBA(RGBA {
+            a: alpha,
+            ..self.to_rgba()
+        })
+    }
+
+    /// Multiplies the alpha by `factor`, in 0..=1
+    pub fn fade(&self, factor: f32) -> Color {
+        let alpha = self.alpha() as f32 * factor.clamp(0.0, 1.0);
+        self.with_alpha(alpha.round() as u8)
+    }
+
+    pub fn to_linear_rgb(&self) -> LinearRgb {
+        LinearRgb::from_rgb(self.to_rgba().to_rgb())
+    }
+
+    pub fn to_oklab(&self) -> OkLab {
+        OkLab::from_rgb(self.to_rgba().to_rgb())
+    }
+
+    pub fn to_oklch(&self) -> OkLch {
+        OkLch::from_rgb(self.to_rgba().to_rgb())
+    }
+
+    /// Raises the perceptual lightness by `amount`, in 0..=1, keeping the hue
+    /// and the alpha
+    pub fn lighten(&self, amount: f32) -> Color {
+        self.map_oklch(|lch| OkLch::new(lch.l + amount, lch.c, lch.h))
+    }
+
+    pub fn darken(&self, amount: f32) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Scales the chroma by `1 + amount`, so 1 doubles it and -1 leaves a
+    /// grey of the same lightness
+    pub fn saturate(&self, amount: f32) -> Color {
+        self.map_oklch(|lch| OkLch::new(lch.l, lch.c * (1.0 + amount).max(0.0), lch.h))
+    }
+
+    pub fn desaturate(&self, amount: f32) -> Color {
+        self.saturate(-amount)
+    }
+
+    /// Blends in `weight` of `other`, in 0..=1, in OkLab so the midpoints
+    /// do not turn grey or darker the way sRGB ones do
+    pub fn mix(&self, other: &Color, weight: f32) -> Color {
+        self.interpolate(other, weight, ColorSpace::OkLab)
+    }
+
+    /// Color at `t`, in 0..=1, of the way from this color to `other`.
+    /// Channels are weighted by alpha, so fading to a transparent color does
+    /// not pass through its hidden channels
+    pub fn interpolate(&self, other: &Color, t: f32, space: ColorSpace) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let (from, to) = (self.to_rgba(), other.to_rgba());
+        let alpha = lerp(from.a as f32, to.a as f32, t);
+
+        let from_weight = from.a as f32 * (1.0 - t);
+        let to_weight = to.a as f32 * t;
+        let weight = if from_weight + to_weight == 0.0 {
+            t
+        } else {
+            to_weight / (from_weight + to_weight)
+        };
+
+        let (start, end) = (from.to_rgb(), to.to_rgb());
+        let rgb = match space {
+            ColorSpace::Srgb => {
+                let channel = |a: u8, b: u8| lerp(a as f32, b as f32, weight).round() as u8;
+                RGB::new(
+                    channel(start.r, end.r),
+                    channel(start.g, end.g),
+                    channel(start.b, end.b),
+                )
+            }
+            ColorSpace::LinearRgb => {
+                lerp(LinearRgb::from_rgb(start), LinearRgb::from_rgb(end), weight).to_rgb()
+            }
+            ColorSpace::OkLab => {
+                lerp(OkLab::from_rgb(start), OkLab::from_rgb(end), weight).to_rgb()
+            }
+            ColorSpace::OkLch => {
+                let (start, end) = (OkLch::from_rgb(start), OkLch::from_rgb(end));
+                // Greys have no hue of their own and take the other one's
+                let (start_hue, end_hue) = match (start.c < ACHROMATIC, end.c < ACHROMATIC) {
+                    (true, false) => (end.h, end.h),
+                    (false, true) => (start.h, start.h),
+                    _ => (start.h, end.h),
+                };
+                // The shorter way around the wheel
+                let turn = (end_hue - start_hue + 180.0).rem_euclid(360.0) - 180.0;
+                OkLch::new(
+                    lerp(start.l, end.l, weight),
+                    lerp(start.c, end.c, weight),
+                    (start_hue + turn * weight).rem_euclid(360.0),
+                )
+                .to_rgb()
+            }
+        };
+        Color::RGBA(rgb.to_rgba(alpha.round() as u8))
+    }
+
+    fn map_oklch(&self, change: impl FnOnce(OkLch) -> OkLch) -> Color {
+        let rgba = self.to_rgba();
+        Color::RGBA(
+            change(OkLch::from_rgb(rgba.to_rgb()))
+                .to_rgb()
+                .to_rgba(rgba.a),
+        )
+    }
+}
+
+/// WCAG 2 conformance level for text contrast
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WcagLevel {
+    #[default]
+    Aa,
+    Aaa,
+}
+
+impl WcagLevel {
+    /// Contrast ratio text must reach. Large text is at least 24px, or 19px
+    /// when bold
+    pub fn minimum_ratio(self, large_text: bool) -> f32 {
+        match (self, large_text) {
+            (WcagLevel::Aa, false) => 4.5,
+            (WcagLevel::Aa, true) => 3.0,
+            (WcagLevel::Aaa, false) => 7.0,
+            (WcagLevel::Aaa, true) => 4.5,
+        }
+    }
+}
+
+// Contrast
+impl Color {
+    /// Color seen when this one is painted over `backdrop`, which is taken
+    /// as opaque
+    pub fn over(&self, backdrop: &Color) -> Color {
+        let alpha = self.alpha() as f32 / 255.0;
+        backdrop
+            .with_alpha(255)
+            .interpolate(&self.with_alpha(255), alpha, ColorSpace::Srgb)
+    }
+
+    /// WCAG relative luminance, from 0 for black to 1 for white. Alpha is
+    /// ignored
+    pub fn relative_luminance(&self) -> f32 {
+        let linear = self.to_linear_rgb();
+        0.2126 * linear.r + 0.7152 * linear.g + 0.0722 * linear.b
+    }
+
+    /// WCAG contrast ratio, from 1 to 21, of this color as text over
+    /// `background`. A translucent text color is blended into the background
+    /// first
+    pub fn contrast_ratio(&self, background: &Color) -> f32 {
+        let background = background.with_alpha(255);
+        let lighter = self.over(&background).relative_luminance();
+        let darker = background.relative_luminance();
+        let (lighter, darker) = (lighter.max(darker), lighter.min(darker));
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    pub fn meets_contrast(&self, background: &Color, level: WcagLevel, large_text: bool) -> bool {
+        self.contrast_ratio(background) >= level.minimum_ratio(large_text)
+    }
+
+    /// The closest color, changing only the perceptual lightness, that reaches
+    /// `level` over `background`. When no lightness is enough, which can
+    /// happen for AAA over mid greys, the most contrasting one is returned
+    pub fn ensure_contrast(&self, background: &Color, level: WcagLevel, large_text: bool) -> Color {
+        let target = level.minimum_ratio(large_text);
+        if self.contrast_ratio(background) >= target {
+            return self.clone();
+        }
+        let start = self.to_oklch().l;
+        let at = |lightness: f32| self.lighten(lightness - start);
+
+        // Search each direction for the smallest change that passes
+        let mut best: Option<(f32, Color)> = None;
+        for end in [0.0, 1.0] {
+            if at(end).contrast_ratio(background) < target {
+                continue;
+            }
+            let (mut near, mut far) = (start, end);
+            for _ in 0..20 {
+                let middle = (near + far) / 2.0;
+                if at(middle).contrast_ratio(background) >= target {
+                    far = middle;
+                } else {
+                    near = middle;
+                }
+            }
+            let change = (far - start).abs();
+            if best.as_ref().is_none_or(|(smallest, _)| change < *smallest) {
+                best = Some((change, at(far)));
+            }
+        }
+        best.map(|(_, color)| color).unwrap_or_else(|| {
+            let (black, white) = (at(0.0), at(1.0));
+            if black.contrast_ratio(background) >= white.contrast_ratio(background) {
+                black
+            } else {
+                white
+            }
+        })
+    }
+}
+
+impl Color {
+    /// Reads a hex color (`#RGB`, `#RRGGBB` or `#RRGGBBAA`, with a `#`, `0x`
+    /// or no prefix), the `rgb()`, `rgba()`, `hsl()`, `hsla()`, `hsv()` and
+    /// `hsva()` functions or a CSS color name, ignoring case
+    pub fn parse(text: &str) -> Result<Self, ColorParseError> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(ColorParseError::Empty);
+        }
+        if text.starts_with('#') || text.starts_with("0x") {
+            return HEX::parse(text).map(Color::HEX);
+        }
+        if let Some(open) = text.find('(') {
+            return parse_function(&text[..open], &text[open + 1..]);
+        }
+        if text.eq_ignore_ascii_case("transparent") {
+            return Ok(Color::rgba(0, 0, 0, 0));
+        }
+        if let Some(rgb) = named_color(text) {
+            return Ok(Color::RGB(rgb));
+        }
+        HEX::parse(text)
+            .map(Color::HEX)
+            .map_err(|_| ColorParseError::UnknownName(text.to_string()))
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Color::parse(text)
+    }
+}
+
+// Arguments may be separated by commas, spaces or a `/` before the alpha
+fn parse_function(name: &str, rest: &str) -> Result<Color, ColorParseError> {
+    let function = name.trim().to_ascii_lowercase();
+    if !matches!(
+        function.as_str(),
+        "rgb" | "rgba" | "hsl" | "hsla" | "hsv" | "hsva"
+    ) {
+        return Err(ColorParseError::UnknownFunction(function));
+    }
+    let Some(body) = rest.trim_end().strip_suffix(')') else {
+        return Err(ColorParseError::Syntax(format!("{name}({rest}")));
+    };
+    let args: Vec<&str> = body
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|arg| !arg.is_empty())
+        .collect();
+    let alpha = match args.len() {
+        3 => None,
+        4 => Some(parse_alpha(args[3])?),
+        found => return Err(ColorParseError::ArgumentCount { function, found }),
+    };
+
+    match function.as_str() {
+        "rgb" | "rgba" => {
+            let rgb = RGB::new(
+                parse_channel(args[0])?,
+                parse_channel(args[1])?,
+                parse_channel(args[2])?,
+            );
+            Ok(match alpha {
+                None if function == "rgb" => Color::RGB(rgb),
+                alpha => Color::RGBA(rgb.to_rgba(alpha.unwrap_or(255))),
+            })
+        }
+        "hsv" | "hsva" => {
+            let (hue, saturation, value) = (
+                parse_hue(args[0])?,
+                parse_fraction(args[1])?,
+                parse_fraction(args[2])?,
+            );
+            Ok(match alpha {
+                None => Color::HSV(HSV::from_degrees(hue, saturation, value)),
+                Some(alpha) => Color::RGBA(hsv_to_rgb(hue, saturation, value).to_rgba(alpha)),
+            })
+        }
+        _ => {
+            let (hue, saturation, lightness) = (
+                parse_hue(args[0])?,
+                parse_fraction(args[1])?,
+                parse_fraction(args[2])?,
+            );
+            // HSL to HSV keeps the hue and only reshapes the other two
+            let value = lightness + saturation * lightness.min(1.0 - lightness);
+            let saturation = if value == 0.0 {
+                0.0
+            } else {
+                2.0 * (1.0 - lightness / value)
+            };
+            let rgb = hsv_to_rgb(hue, saturation, value);
+            Ok(match alpha {
+                None => Color::RGB(rgb),
+                Some(alpha) => Color::RGBA(rgb.to_rgba(alpha)),
+            })
+        }
+    }
+}
+
+// Number of an argument and whether it was a percentage
+fn parse_number(arg: &str) -> Result<(f32, bool), ColorParseError> {
+    let (digits, percent) = match arg.strip_suffix('%') {
+        Some(digits) => (digits, true),
+        None => (arg, false),
+    };
+    match digits.parse::<f32>() {
+        Ok(number) if number.is_finite() => Ok((number, percent)),
+        _ => Err(ColorParseError::InvalidArgument(arg.to_string())),
+    }
+}
+
+// 0-255 or a percentage
+fn parse_channel(arg: &str) -> Result<u8, ColorParseError> {
+    let (number, percent) = parse_number(arg)?;
+    let level = if percent {
+        number * 255.0 / 100.0
+    } else {
+        number
+    };
+    if !(0.0..=255.0).contains(&level) {
+        return Err(ColorParseError::InvalidArgument(arg.to_string()));
+    }
+    Ok(level.round() as u8)
+}
+
+// 0-1 or a percentage
+fn parse_alpha(arg: &str) -> Result<u8, ColorParseError> {
+    let (number, percent) = parse_number(arg)?;
+    let alpha = if percent { number / 100.0 } else { number };
+    if !(0.0..=1.0).contains(&alpha) {
+        return Err(ColorParseError::InvalidArgument(arg.to_string()));
+    }
+    Ok((alpha * 255.0).round() as u8)
+}
+
+// Degrees, with or without the `deg` unit, wrapped into 0..360
+fn parse_hue(arg: &str) -> Result<f32, ColorParseError> {
+    let digits = arg.strip_suffix("deg").unwrap_or(arg);
+    match digits.parse::<f32>() {
+        Ok(degrees) if degrees.is_finite() => Ok(degrees.rem_euclid(360.0)),
+        _ => Err(ColorParseError::InvalidArgument(arg.to_string())),
+    }
+}
+
+// Saturation, lightness and value are percentages, the `%` being optional
+fn parse_fraction(arg: &str) -> Result<f32, ColorParseError> {
+    let (number, _) = parse_number(arg)?;
+    if !(0.0..=100.0).contains(&number) {
+        return Err(ColorParseError::InvalidArgument(arg.to_string()));
+    }
+    Ok(number / 100.0)
+}
+
+/// Channels of a hue in degrees with saturation and value in 0..=1
+pub fn hsv_to_rgb(hue: f32, saturation: f32, value: f32) -> RGB {
+    let channel = |n: f32| {
+        let k = (n + hue / 60.0).rem_euclid(6.0);
+        let level = value - value * saturation * k.min(4.0 - k).clamp(0.0, 1.0);
+        (level * 255.0).round() as u8
+    };
+    RGB::new(channel(5.0), channel(3.0), channel(1.0))
+}
+
+/// Hue in degrees, saturation and value in 0..=1. Greys have a hue of 0
+pub fn rgb_to_hsv(rgb: RGB) -> (f32, f32, f32) {
+    let (r, g, b) = (
+        rgb.r as f32 / 255.0,
+        rgb.g as f32 / 255.0,
+        rgb.b as f32 / 255.0,
+    );
+    let max = r.max(g).max(b);
+    let delta = max - r.min(g).min(b);
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    let saturation = if max == 0.0 { 0.0 } else { delta / max };
+    (hue, saturation, max)
 }
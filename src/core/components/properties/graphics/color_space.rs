@@ -0,0 +1,240 @@
+use std::ops::{Add, Mul, Sub};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::graphics::color::RGB;
+
+/// Space two colors are blended in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ColorSpace {
+    // Channels as stored, the way most toolkits blend
+    Srgb,
+    // Physically correct light mixing, brighter midpoints than sRGB
+    LinearRgb,
+    // Perceptually even steps without hue shifts
+    #[default]
+    OkLab,
+    // OkLab around the hue wheel, keeps midpoints saturated
+    OkLch,
+}
+
+/// sRGB channels without the gamma curve, in 0..=1
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct LinearRgb {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl LinearRgb {
+    pub fn new(r: f32, g: f32, b: f32) -> Self {
+        LinearRgb { r, g, b }
+    }
+
+    pub fn from_rgb(rgb: RGB) -> Self {
+        LinearRgb::new(
+            decode_gamma(rgb.r),
+            decode_gamma(rgb.g),
+            decode_gamma(rgb.b),
+        )
+    }
+
+    /// Channels outside 0..=1 are clipped
+    pub fn to_rgb(self) -> RGB {
+        RGB::new(
+            encode_gamma(self.r),
+            encode_gamma(self.g),
+            encode_gamma(self.b),
+        )
+    }
+
+    pub fn to_oklab(self) -> OkLab {
+        let l = (0.412_221_46 * self.r + 0.536_332_55 * self.g + 0.051_445_99 * self.b).cbrt();
+        let m = (0.211_903_5 * self.r + 0.680_699_5 * self.g + 0.107_396_96 * self.b).cbrt();
+        let s = (0.088_302_46 * self.r + 0.281_718_85 * self.g + 0.629_978_7 * self.b).cbrt();
+        OkLab::new(
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        )
+    }
+
+    /// Whether the color can be shown without clipping, allowing for rounding
+    pub fn in_gamut(self) -> bool {
+        const EPSILON: f32 = 0.0001;
+        [self.r, self.g, self.b]
+            .iter()
+            .all(|channel| (-EPSILON..=1.0 + EPSILON).contains(channel))
+    }
+}
+
+impl Add for LinearRgb {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        LinearRgb::new(self.r + other.r, self.g + other.g, self.b + other.b)
+    }
+}
+
+impl Sub for LinearRgb {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        LinearRgb::new(self.r - other.r, self.g - other.g, self.b - other.b)
+    }
+}
+
+impl Mul<f32> for LinearRgb {
+    type Output = Self;
+
+    fn mul(self, factor: f32) -> Self {
+        LinearRgb::new(self.r * factor, self.g * factor, self.b * factor)
+    }
+}
+
+/// Perceptual lightness in 0..=1 and two opponent axes, green-red and
+/// blue-yellow, roughly in -0.4..=0.4
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct OkLab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl OkLab {
+    pub fn new(l: f32, a: f32, b: f32) -> Self {
+        OkLab { l, a, b }
+    }
+
+    pub fn from_rgb(rgb: RGB) -> Self {
+        LinearRgb::from_rgb(rgb).to_oklab()
+    }
+
+    pub fn to_linear_rgb(self) -> LinearRgb {
+        let l = (self.l + 0.396_337_78 * self.a + 0.215_803_76 * self.b).powi(3);
+        let m = (self.l - 0.105_561_346 * self.a - 0.063_854_17 * self.b).powi(3);
+        let s = (self.l - 0.089_484_18 * self.a - 1.291_485_5 * self.b).powi(3);
+        LinearRgb::new(
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        )
+    }
+
+    /// Out of gamut colors are clipped, see `OkLch::to_rgb` to keep the hue
+    pub fn to_rgb(self) -> RGB {
+        self.to_linear_rgb().to_rgb()
+    }
+
+    pub fn to_oklch(self) -> OkLch {
+        let chroma = self.a.hypot(self.b);
+        let hue = if chroma < ACHROMATIC {
+            0.0
+        } else {
+            self.b.atan2(self.a).to_degrees().rem_euclid(360.0)
+        };
+        OkLch::new(self.l, chroma, hue)
+    }
+}
+
+impl Add for OkLab {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        OkLab::new(self.l + other.l, self.a + other.a, self.b + other.b)
+    }
+}
+
+impl Sub for OkLab {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        OkLab::new(self.l - other.l, self.a - other.a, self.b - other.b)
+    }
+}
+
+impl Mul<f32> for OkLab {
+    type Output = Self;
+
+    fn mul(self, factor: f32) -> Self {
+        OkLab::new(self.l * factor, self.a * factor, self.b * factor)
+    }
+}
+
+/// OkLab in polar form: lightness, chroma (0 for greys, about 0.37 for the
+/// most vivid sRGB colors) and hue in degrees
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct OkLch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+impl OkLch {
+    pub fn new(l: f32, c: f32, h: f32) -> Self {
+        OkLch { l, c, h }
+    }
+
+    pub fn from_rgb(rgb: RGB) -> Self {
+        OkLab::from_rgb(rgb).to_oklch()
+    }
+
+    pub fn to_oklab(self) -> OkLab {
+        let (sin, cos) = self.h.to_radians().sin_cos();
+        OkLab::new(self.l, self.c * cos, self.c * sin)
+    }
+
+    /// Colors outside sRGB lose chroma until they fit, so lightness and hue
+    /// are kept instead of shifting when the channels are clipped
+    pub fn to_rgb(self) -> RGB {
+        let l = self.l.clamp(0.0, 1.0);
+        // The ends of the lightness axis have room for no chroma at all
+        let c = if l == 0.0 || l == 1.0 {
+            0.0
+        } else {
+            self.c.max(0.0)
+        };
+        let fits = |chroma: f32| {
+            OkLch::new(l, chroma, self.h)
+                .to_oklab()
+                .to_linear_rgb()
+                .in_gamut()
+        };
+        if fits(c) {
+            return OkLch::new(l, c, self.h).to_oklab().to_rgb();
+        }
+        let (mut low, mut high) = (0.0, c);
+        for _ in 0..16 {
+            let middle = (low + high) / 2.0;
+            if fits(middle) {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        OkLch::new(l, low, self.h).to_oklab().to_rgb()
+    }
+}
+
+// Chroma below which a color is a grey and its hue meaningless
+pub(crate) const ACHROMATIC: f32 = 0.000_1;
+
+// sRGB transfer function, from a stored channel to linear light
+fn decode_gamma(channel: u8) -> f32 {
+    let c = channel as f32 / 255.0;
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn encode_gamma(linear: f32) -> u8 {
+    let c = linear.clamp(0.0, 1.0);
+    let c = if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
+}
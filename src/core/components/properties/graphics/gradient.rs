@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::core::components::properties::graphics::color::Color;
+use crate::core::components::properties::graphics::{color::Color, color_space::ColorSpace};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum GradientType {
@@ -48,4 +48,26 @@ impl Gradient {
             stops,
         }
     }
+
+    /// Color at `position`, in the 0-100 range of the stops, blending the
+    /// two stops around it in `space`. Before the first stop and after the
+    /// last one their colors are kept; `None` when there are no stops
+    pub fn color_at(&self, position: f32, space: ColorSpace) -> Option<Color> {
+        let (first, last) = (self.stops.first()?, self.stops.last()?);
+        if position <= first.1 as f32 {
+            return Some(first.0.clone());
+        }
+        self.stops
+            .windows(2)
+            .find(|pair| position <= pair[1].1 as f32)
+            .map(|pair| {
+                let ((start, from), (end, to)) = (&pair[0], &pair[1]);
+                let span = to.saturating_sub(*from) as f32;
+                if span == 0.0 {
+                    return end.clone();
+                }
+                start.interpolate(end, (position - *from as f32) / span, space)
+            })
+            .or_else(|| Some(last.0.clone()))
+    }
 }
@@ -1,5 +1,8 @@
 pub mod action_state;
 pub mod background;
+pub mod blend_mode;
 pub mod border;
 pub mod color;
+pub mod color_space;
 pub mod gradient;
+pub mod named_colors;
@@ -1,8 +1,14 @@
 pub mod boundaries;
+pub mod cell_value;
+pub mod check_state;
+pub mod date_time;
+pub mod dialog_result;
 pub mod graphics;
 pub mod margin;
+pub mod orientation;
 pub mod overflow;
 pub mod padding;
 pub mod position;
+pub mod selection_mode;
 pub mod size;
 pub mod typography;
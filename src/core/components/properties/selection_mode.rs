@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SelectionMode {
+    None,
+    #[default]
+    One,
+    // Every click toggles the clicked item
+    MultiSimple,
+    // Click selects, Ctrl+click toggles and Shift+click selects a range
+    MultiExtended,
+}
+
+impl SelectionMode {
+    pub fn is_multiple(&self) -> bool {
+        matches!(
+            self,
+            SelectionMode::MultiSimple | SelectionMode::MultiExtended
+        )
+    }
+}
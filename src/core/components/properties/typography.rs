@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+use crate::core::components::properties::size::Size;
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum FontWeight {
     THIN = 100,
     EXTRA_LIGHT = 200,
@@ -14,9 +16,58 @@ pub enum FontWeight {
     BLACK = 900,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct TypoGraphy {
     font_size: u32,
     font_family: String,
     font_type: FontWeight,
 }
+
+impl Default for TypoGraphy {
+    fn default() -> Self {
+        Self {
+            font_size: 14,
+            font_family: String::from("Segoe UI"),
+            font_type: FontWeight::default(),
+        }
+    }
+}
+
+impl TypoGraphy {
+    pub fn new(font_size: u32, font_family: &str, font_type: FontWeight) -> Self {
+        Self {
+            font_size,
+            font_family: font_family.to_string(),
+            font_type,
+        }
+    }
+
+    pub fn font_size(&self) -> u32 {
+        self.font_size
+    }
+
+    pub fn font_family(&self) -> &str {
+        &self.font_family
+    }
+
+    pub fn font_type(&self) -> &FontWeight {
+        &self.font_type
+    }
+
+    // Until a font backend exists text is measured with a fixed advance per glyph
+    pub fn char_width(&self) -> u16 {
+        (self.font_size * 3 / 5).max(1) as u16
+    }
+
+    pub fn line_height(&self) -> u16 {
+        (self.font_size * 4 / 3).max(1) as u16
+    }
+
+    pub fn measure_text(&self, text: &str) -> Size {
+        let columns = text.chars().count() as u16;
+        Size::new(
+            self.line_height(),
+            columns.saturating_mul(self.char_width()),
+        )
+    }
+}
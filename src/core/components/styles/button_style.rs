@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::{
+    graphics::{
+        action_state::ActionState,
+        background::{Background, BackgroundShape},
+        border::{Border, BorderType},
+        color::Color,
+    },
+    typography::TypoGraphy,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ButtonStyle {
+    pub normal: Background,
+    pub hovered: Background,
+    pub pressed: Background,
+    pub disabled: Background,
+    pub border: Border,
+    pub focus_border: Border,
+    pub text_color: Color,
+    pub disabled_text_color: Color,
+    pub typography: TypoGraphy,
+}
+
+impl Default for ButtonStyle {
+    fn default() -> Self {
+        Self {
+            normal: Background::new_solid(Color::rgb(225, 225, 225), BackgroundShape::Rectangle),
+            hovered: Background::new_solid(Color::rgb(229, 241, 251), BackgroundShape::Rectangle),
+            pressed: Background::new_solid(Color::rgb(204, 228, 247), BackgroundShape::Rectangle),
+            disabled: Background::new_solid(Color::rgb(204, 204, 204), BackgroundShape::Rectangle),
+            border: Border::new(Color::rgb(173, 173, 173), 1.0, BorderType::Solid),
+            focus_border: Border::new(Color::rgb(0, 120, 215), 2.0, BorderType::Solid),
+            text_color: Color::rgb(0, 0, 0),
+            disabled_text_color: Color::rgb(160, 160, 160),
+            typography: TypoGraphy::default(),
+        }
+    }
+}
+
+impl ButtonStyle {
+    pub fn background(&self, state: &ActionState) -> &Background {
+        match state {
+            ActionState::Normal => &self.normal,
+            ActionState::Hovered => &self.hovered,
+            ActionState::Pressed => &self.pressed,
+            ActionState::Disabled => &self.disabled,
+        }
+    }
+
+    pub fn text_color(&self, state: &ActionState) -> &Color {
+        if state.is_disabled() {
+            &self.disabled_text_color
+        } else {
+            &self.text_color
+        }
+    }
+}
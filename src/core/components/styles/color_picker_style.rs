@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::{
+    graphics::{
+        background::{Background, BackgroundShape},
+        border::{Border, BorderType},
+        color::Color,
+    },
+    typography::TypoGraphy,
+};
+
+/// Look of color pickers: a saturation/value square with hue and alpha bars
+/// beside it, and the preview, hex field and recent colors below
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ColorPickerStyle {
+    pub background: Background,
+    pub border: Border,
+    pub focus_border: Border,
+    // Frame around the square, the bars and the preview
+    pub area_border: Border,
+    pub marker_border: Border,
+    pub thumb_border: Border,
+    pub field_background: Background,
+    pub field_border: Border,
+    pub swatch_border: Border,
+    pub hovered_swatch_border: Border,
+    pub typography: TypoGraphy,
+    pub padding: u16,
+    pub spacing: u16,
+    pub square_size: u16,
+    pub slider_width: u16,
+    pub preview_width: u16,
+    pub field_height: u16,
+    pub swatch_size: u16,
+    pub swatch_spacing: u16,
+}
+
+impl Default for ColorPickerStyle {
+    fn default() -> Self {
+        Self {
+            background: Background::new_solid(
+                Color::rgb(240, 240, 240),
+                BackgroundShape::Rectangle,
+            ),
+            border: Border::new(Color::rgb(160, 160, 160), 1.0, BorderType::Solid),
+            focus_border: Border::new(Color::rgb(0, 120, 215), 1.0, BorderType::Solid),
+            area_border: Border::new(Color::rgb(122, 122, 122), 1.0, BorderType::Solid),
+            marker_border: Border::new(Color::rgb(255, 255, 255), 2.0, BorderType::Solid),
+            thumb_border: Border::new(Color::rgb(0, 0, 0), 1.0, BorderType::Solid),
+            field_background: Background::new_solid(
+                Color::rgb(255, 255, 255),
+                BackgroundShape::Rectangle,
+            ),
+            field_border: Border::new(Color::rgb(122, 122, 122), 1.0, BorderType::Solid),
+            swatch_border: Border::new(Color::rgb(160, 160, 160), 1.0, BorderType::Solid),
+            hovered_swatch_border: Border::new(Color::rgb(0, 120, 215), 1.0, BorderType::Solid),
+            typography: TypoGraphy::default(),
+            padding: 8,
+            spacing: 8,
+            square_size: 160,
+            slider_width: 16,
+            preview_width: 40,
+            field_height: 23,
+            swatch_size: 16,
+            swatch_spacing: 4,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::{
+    properties::{
+        graphics::{
+            background::{Background, BackgroundShape},
+            border::{Border, BorderType},
+            color::Color,
+        },
+        typography::TypoGraphy,
+    },
+    styles::list_style::ListStyle,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ComboBoxStyle {
+    pub background: Background,
+    pub disabled_background: Background,
+    pub button_background: Background,
+    pub border: Border,
+    pub focus_border: Border,
+    pub arrow_color: Color,
+    pub text_color: Color,
+    pub disabled_text_color: Color,
+    pub button_width: u16,
+    pub typography: TypoGraphy,
+    pub list: ListStyle,
+}
+
+impl Default for ComboBoxStyle {
+    fn default() -> Self {
+        Self {
+            background: Background::new_solid(
+                Color::rgb(255, 255, 255),
+                BackgroundShape::Rectangle,
+            ),
+            disabled_background: Background::new_solid(
+                Color::rgb(240, 240, 240),
+                BackgroundShape::Rectangle,
+            ),
+            button_background: Background::new_solid(
+                Color::rgb(225, 225, 225),
+                BackgroundShape::Rectangle,
+            ),
+            border: Border::new(Color::rgb(122, 122, 122), 1.0, BorderType::Solid),
+            focus_border: Border::new(Color::rgb(0, 120, 215), 1.0, BorderType::Solid),
+            arrow_color: Color::rgb(96, 96, 96),
+            text_color: Color::rgb(0, 0, 0),
+            disabled_text_color: Color::rgb(160, 160, 160),
+            button_width: 17,
+            typography: TypoGraphy::default(),
+            list: ListStyle::default(),
+        }
+    }
+}
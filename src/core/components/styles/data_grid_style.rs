@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::{
+    properties::graphics::{
+        background::{Background, BackgroundShape},
+        border::{Border, BorderType},
+        color::Color,
+    },
+    styles::{list_style::ListStyle, toggle_style::ToggleStyle},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DataGridStyle {
+    // Cell colors, row height, typography and scrollbar
+    pub list: ListStyle,
+    pub header_background: Background,
+    pub header_text_color: Color,
+    pub header_height: u16,
+    pub grid_line: Border,
+    pub current_cell_border: Border,
+    // Line between frozen and scrolling columns
+    pub frozen_divider: Border,
+    pub sort_glyph_color: Color,
+    pub check_box: ToggleStyle,
+    // Distance from a header edge that still grabs it for resizing
+    pub resize_grip: u16,
+    pub min_column_width: u16,
+}
+
+impl Default for DataGridStyle {
+    fn default() -> Self {
+        Self {
+            list: ListStyle {
+                item_height: 22,
+                ..ListStyle::default()
+            },
+            header_background: Background::new_solid(
+                Color::rgb(240, 240, 240),
+                BackgroundShape::Rectangle,
+            ),
+            header_text_color: Color::rgb(0, 0, 0),
+            header_height: 23,
+            grid_line: Border::new(Color::rgb(160, 160, 160), 1.0, BorderType::Solid),
+            current_cell_border: Border::new(Color::rgb(0, 0, 0), 1.0, BorderType::Dotted),
+            frozen_divider: Border::new(Color::rgb(105, 105, 105), 1.0, BorderType::Solid),
+            sort_glyph_color: Color::rgb(96, 96, 96),
+            check_box: ToggleStyle::default(),
+            resize_grip: 3,
+            min_column_width: 20,
+        }
+    }
+}
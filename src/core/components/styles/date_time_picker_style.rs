@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::{
+    graphics::{
+        background::{Background, BackgroundShape},
+        border::{Border, BorderType},
+        color::Color,
+    },
+    typography::{FontWeight, TypoGraphy},
+};
+
+/// Look of date pickers, their text field and the calendar they open
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DateTimePickerStyle {
+    pub background: Background,
+    pub disabled_background: Background,
+    pub button_background: Background,
+    pub border: Border,
+    pub focus_border: Border,
+    pub arrow_color: Color,
+    pub button_width: u16,
+    pub typography: TypoGraphy,
+    pub calendar_background: Background,
+    pub calendar_border: Border,
+    pub title_typography: TypoGraphy,
+    pub title_color: Color,
+    pub day_name_color: Color,
+    pub day_color: Color,
+    // Days outside the allowed range or disabled
+    pub disabled_day_color: Color,
+    pub hover_background: Background,
+    pub selected_background: Background,
+    pub selected_color: Color,
+    // Outline of the day the keyboard sits on
+    pub highlight_border: Border,
+    pub time_background: Background,
+    pub time_border: Border,
+    pub cell_width: u16,
+    pub cell_height: u16,
+    pub header_height: u16,
+    pub calendar_padding: u16,
+}
+
+impl Default for DateTimePickerStyle {
+    fn default() -> Self {
+        Self {
+            background: Background::new_solid(
+                Color::rgb(255, 255, 255),
+                BackgroundShape::Rectangle,
+            ),
+            disabled_background: Background::new_solid(
+                Color::rgb(240, 240, 240),
+                BackgroundShape::Rectangle,
+            ),
+            button_background: Background::new_solid(
+                Color::rgb(225, 225, 225),
+                BackgroundShape::Rectangle,
+            ),
+            border: Border::new(Color::rgb(122, 122, 122), 1.0, BorderType::Solid),
+            focus_border: Border::new(Color::rgb(0, 120, 215), 1.0, BorderType::Solid),
+            arrow_color: Color::rgb(96, 96, 96),
+            button_width: 17,
+            typography: TypoGraphy::default(),
+            calendar_background: Background::new_solid(
+                Color::rgb(255, 255, 255),
+                BackgroundShape::Rectangle,
+            ),
+            calendar_border: Border::new(Color::rgb(204, 204, 204), 1.0, BorderType::Solid),
+            title_typography: TypoGraphy::new(14, "Segoe UI", FontWeight::SEMI_BOLD),
+            title_color: Color::rgb(0, 0, 0),
+            day_name_color: Color::rgb(96, 96, 96),
+            day_color: Color::rgb(0, 0, 0),
+            disabled_day_color: Color::rgb(190, 190, 190),
+            hover_background: Background::new_solid(
+                Color::rgb(229, 243, 255),
+                BackgroundShape::Rectangle,
+            ),
+            selected_background: Background::new_solid(
+                Color::rgb(0, 120, 215),
+                BackgroundShape::Rectangle,
+            ),
+            selected_color: Color::rgb(255, 255, 255),
+            highlight_border: Border::new(Color::rgb(0, 120, 215), 1.0, BorderType::Dotted),
+            time_background: Background::new_solid(
+                Color::rgb(255, 255, 255),
+                BackgroundShape::Rectangle,
+            ),
+            time_border: Border::new(Color::rgb(122, 122, 122), 1.0, BorderType::Solid),
+            cell_width: 28,
+            cell_height: 20,
+            header_height: 24,
+            calendar_padding: 4,
+        }
+    }
+}
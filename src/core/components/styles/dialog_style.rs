@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::{
+    elements::icon::Icon,
+    properties::{
+        graphics::{
+            background::{Background, BackgroundShape},
+            border::{Border, BorderType},
+            color::Color,
+        },
+        size::Size,
+        typography::{FontWeight, TypoGraphy},
+    },
+    styles::{button_style::ButtonStyle, text_box_style::TextBoxStyle},
+};
+
+/// Look of modal dialogs, message boxes included, and of the controls in them
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DialogStyle {
+    pub background: Background,
+    pub border: Border,
+    pub title_background: Background,
+    pub title_color: Color,
+    pub title_typography: TypoGraphy,
+    pub text_color: Color,
+    pub typography: TypoGraphy,
+    // Painted over the window while a dialog is open
+    pub overlay: Background,
+    pub button: ButtonStyle,
+    pub text_box: TextBoxStyle,
+    // Stock message box icons
+    pub information_icon: Icon,
+    pub warning_icon: Icon,
+    pub error_icon: Icon,
+    pub question_icon: Icon,
+    pub title_height: u16,
+    // Margin below the title bar, around the message and the icon, and
+    // between the buttons and the window edge
+    pub padding: u16,
+    // Space between the icon, the text, the editor and the buttons
+    pub spacing: u16,
+    pub button_size: Size,
+    pub editor_height: u16,
+    pub min_width: u16,
+}
+
+impl Default for DialogStyle {
+    fn default() -> Self {
+        Self {
+            background: Background::new_solid(
+                Color::rgb(240, 240, 240),
+                BackgroundShape::Rectangle,
+            ),
+            border: Border::new(Color::rgb(100, 100, 100), 1.0, BorderType::Solid),
+            title_background: Background::new_solid(
+                Color::rgb(255, 255, 255),
+                BackgroundShape::Rectangle,
+            ),
+            title_color: Color::rgb(0, 0, 0),
+            title_typography: TypoGraphy::new(14, "Segoe UI", FontWeight::SEMI_BOLD),
+            text_color: Color::rgb(0, 0, 0),
+            typography: TypoGraphy::default(),
+            overlay: Background::new_solid(Color::rgba(0, 0, 0, 64), BackgroundShape::Rectangle),
+            button: ButtonStyle::default(),
+            text_box: TextBoxStyle::default(),
+            information_icon: Icon::new("icons/information.ico", (32, 32)),
+            warning_icon: Icon::new("icons/warning.ico", (32, 32)),
+            error_icon: Icon::new("icons/error.ico", (32, 32)),
+            question_icon: Icon::new("icons/question.ico", (32, 32)),
+            title_height: 30,
+            padding: 12,
+            spacing: 8,
+            button_size: Size::new(23, 75),
+            editor_height: 23,
+            min_width: 240,
+        }
+    }
+}
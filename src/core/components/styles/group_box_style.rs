@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::{
+    graphics::{
+        background::{Background, BackgroundShape},
+        border::{Border, BorderType},
+        color::Color,
+    },
+    typography::TypoGraphy,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GroupBoxStyle {
+    pub background: Background,
+    pub border: Border,
+    pub text_color: Color,
+    pub disabled_text_color: Color,
+    pub typography: TypoGraphy,
+    // Distance from the left edge of the frame to the title
+    pub title_indent: u16,
+    // Gap left in the border on each side of the title
+    pub title_spacing: u16,
+    // Space between the border and the children
+    pub padding: u16,
+}
+
+impl Default for GroupBoxStyle {
+    fn default() -> Self {
+        Self {
+            background: Background::new_solid(Color::rgba(0, 0, 0, 0), BackgroundShape::Rectangle),
+            border: Border::new(Color::rgb(220, 220, 220), 1.0, BorderType::Solid),
+            text_color: Color::rgb(0, 0, 0),
+            disabled_text_color: Color::rgb(109, 109, 109),
+            typography: TypoGraphy::default(),
+            title_indent: 8,
+            title_spacing: 2,
+            padding: 3,
+        }
+    }
+}
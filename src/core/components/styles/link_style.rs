@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::{
+    graphics::{
+        action_state::ActionState,
+        border::{Border, BorderType},
+        color::Color,
+    },
+    typography::TypoGraphy,
+};
+
+/// When the text of a link is underlined, as `LinkBehavior` in WinForms
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LinkUnderline {
+    Always,
+    #[default]
+    Hover,
+    Never,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LinkStyle {
+    pub link_color: Color,
+    // Color while the link is being pressed
+    pub active_color: Color,
+    pub visited_color: Color,
+    pub disabled_color: Color,
+    pub underline: LinkUnderline,
+    pub focus_border: Border,
+    pub typography: TypoGraphy,
+}
+
+impl Default for LinkStyle {
+    fn default() -> Self {
+        Self {
+            link_color: Color::rgb(0, 0, 255),
+            active_color: Color::rgb(255, 0, 0),
+            visited_color: Color::rgb(128, 0, 128),
+            disabled_color: Color::rgb(133, 133, 133),
+            underline: LinkUnderline::default(),
+            focus_border: Border::new(Color::rgb(0, 0, 0), 1.0, BorderType::Dotted),
+            typography: TypoGraphy::default(),
+        }
+    }
+}
+
+impl LinkStyle {
+    pub fn text_color(&self, state: &ActionState, visited: bool) -> &Color {
+        match state {
+            ActionState::Disabled => &self.disabled_color,
+            ActionState::Pressed => &self.active_color,
+            _ if visited => &self.visited_color,
+            _ => &self.link_color,
+        }
+    }
+
+    pub fn underlined(&self, state: &ActionState) -> bool {
+        match self.underline {
+            LinkUnderline::Always => true,
+            LinkUnderline::Hover => matches!(state, ActionState::Hovered | ActionState::Pressed),
+            LinkUnderline::Never => false,
+        }
+    }
+}
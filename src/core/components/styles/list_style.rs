@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::{
+    graphics::{
+        background::{Background, BackgroundShape},
+        border::{Border, BorderType},
+        color::Color,
+    },
+    typography::TypoGraphy,
+};
+
+/// Look of item lists, shared by list boxes and drop-down popups
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ListStyle {
+    pub background: Background,
+    pub border: Border,
+    pub focus_border: Border,
+    pub highlight_background: Background,
+    pub highlight_text_color: Color,
+    pub text_color: Color,
+    pub disabled_text_color: Color,
+    pub scrollbar_thumb: Background,
+    pub scrollbar_width: u16,
+    pub item_height: u16,
+    pub item_padding: u16,
+    pub typography: TypoGraphy,
+}
+
+impl Default for ListStyle {
+    fn default() -> Self {
+        Self {
+            background: Background::new_solid(
+                Color::rgb(255, 255, 255),
+                BackgroundShape::Rectangle,
+            ),
+            border: Border::new(Color::rgb(130, 135, 144), 1.0, BorderType::Solid),
+            focus_border: Border::new(Color::rgb(0, 120, 215), 1.0, BorderType::Solid),
+            highlight_background: Background::new_solid(
+                Color::rgb(0, 120, 215),
+                BackgroundShape::Rectangle,
+            ),
+            highlight_text_color: Color::rgb(255, 255, 255),
+            text_color: Color::rgb(0, 0, 0),
+            disabled_text_color: Color::rgb(160, 160, 160),
+            scrollbar_thumb: Background::new_solid(
+                Color::rgb(205, 205, 205),
+                BackgroundShape::Rectangle,
+            ),
+            scrollbar_width: 6,
+            item_height: 18,
+            item_padding: 3,
+            typography: TypoGraphy::default(),
+        }
+    }
+}
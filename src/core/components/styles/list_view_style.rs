@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::{
+    properties::graphics::{
+        background::{Background, BackgroundShape},
+        border::{Border, BorderType},
+        color::Color,
+    },
+    styles::list_style::ListStyle,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ListViewStyle {
+    pub list: ListStyle,
+    pub header_background: Background,
+    pub header_border: Border,
+    pub header_text_color: Color,
+    pub header_height: u16,
+    // Cell of each item in icon view
+    pub tile_width: u16,
+    pub tile_height: u16,
+    // Width of each column of items in list view
+    pub list_column_width: u16,
+}
+
+impl Default for ListViewStyle {
+    fn default() -> Self {
+        Self {
+            list: ListStyle::default(),
+            header_background: Background::new_solid(
+                Color::rgb(255, 255, 255),
+                BackgroundShape::Rectangle,
+            ),
+            header_border: Border::new(Color::rgb(229, 229, 229), 1.0, BorderType::Solid),
+            header_text_color: Color::rgb(76, 96, 122),
+            header_height: 24,
+            tile_width: 76,
+            tile_height: 70,
+            list_column_width: 120,
+        }
+    }
+}
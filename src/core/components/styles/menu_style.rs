@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::{
+    graphics::{
+        background::{Background, BackgroundShape},
+        border::{Border, BorderType},
+        color::Color,
+    },
+    typography::TypoGraphy,
+};
+
+/// Look of menu bars and the drop-down and context menus they open
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MenuStyle {
+    pub bar_background: Background,
+    pub bar_highlight_background: Background,
+    pub bar_highlight_border: Border,
+    pub popup_background: Background,
+    pub popup_border: Border,
+    pub highlight_background: Background,
+    pub highlight_border: Border,
+    pub text_color: Color,
+    pub disabled_text_color: Color,
+    pub glyph_color: Color,
+    pub separator_color: Color,
+    // Space around the text of each bar item
+    pub bar_item_padding: u16,
+    pub item_height: u16,
+    pub separator_height: u16,
+    // Column on the left of popup items where check marks go
+    pub gutter_width: u16,
+    // Gap between the text and the shortcut label
+    pub shortcut_spacing: u16,
+    // Column on the right of popup items where submenu arrows go
+    pub arrow_width: u16,
+    pub typography: TypoGraphy,
+}
+
+impl Default for MenuStyle {
+    fn default() -> Self {
+        Self {
+            bar_background: Background::new_solid(
+                Color::rgb(255, 255, 255),
+                BackgroundShape::Rectangle,
+            ),
+            bar_highlight_background: Background::new_solid(
+                Color::rgb(204, 232, 255),
+                BackgroundShape::Rectangle,
+            ),
+            bar_highlight_border: Border::new(Color::rgb(153, 209, 255), 1.0, BorderType::Solid),
+            popup_background: Background::new_solid(
+                Color::rgb(242, 242, 242),
+                BackgroundShape::Rectangle,
+            ),
+            popup_border: Border::new(Color::rgb(204, 204, 204), 1.0, BorderType::Solid),
+            highlight_background: Background::new_solid(
+                Color::rgb(145, 201, 247),
+                BackgroundShape::Rectangle,
+            ),
+            highlight_border: Border::new(Color::rgb(204, 232, 255), 1.0, BorderType::Solid),
+            text_color: Color::rgb(0, 0, 0),
+            disabled_text_color: Color::rgb(109, 109, 109),
+            glyph_color: Color::rgb(0, 0, 0),
+            separator_color: Color::rgb(215, 215, 215),
+            bar_item_padding: 7,
+            item_height: 22,
+            separator_height: 7,
+            gutter_width: 28,
+            shortcut_spacing: 24,
+            arrow_width: 18,
+            typography: TypoGraphy::default(),
+        }
+    }
+}
@@ -1 +1,26 @@
-
+pub mod button_style;
+pub mod color_picker_style;
+pub mod combo_box_style;
+pub mod data_grid_style;
+pub mod date_time_picker_style;
+pub mod dialog_style;
+pub mod group_box_style;
+pub mod link_style;
+pub mod list_style;
+pub mod list_view_style;
+pub mod menu_style;
+pub mod numeric_up_down_style;
+pub mod panel_style;
+pub mod picture_box_style;
+pub mod progress_bar_style;
+pub mod scroll_bar_style;
+pub mod slider_style;
+pub mod split_container_style;
+pub mod status_strip_style;
+pub mod tab_control_style;
+pub mod text_box_style;
+pub mod toast_style;
+pub mod toggle_style;
+pub mod tool_strip_style;
+pub mod tooltip_style;
+pub mod tree_view_style;
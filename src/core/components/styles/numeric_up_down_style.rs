@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::{
+    graphics::{
+        action_state::ActionState,
+        background::{Background, BackgroundShape},
+        border::{Border, BorderType},
+        color::Color,
+    },
+    typography::TypoGraphy,
+};
+
+/// Look of numeric up-downs: a text field with a pair of spin buttons
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NumericUpDownStyle {
+    pub background: Background,
+    pub disabled_background: Background,
+    pub border: Border,
+    pub focus_border: Border,
+    pub button_normal: Color,
+    pub button_hovered: Color,
+    pub button_pressed: Color,
+    pub button_disabled: Color,
+    pub arrow_color: Color,
+    pub disabled_arrow_color: Color,
+    pub text_color: Color,
+    pub disabled_text_color: Color,
+    pub button_width: u16,
+    pub typography: TypoGraphy,
+}
+
+impl Default for NumericUpDownStyle {
+    fn default() -> Self {
+        Self {
+            background: Background::new_solid(
+                Color::rgb(255, 255, 255),
+                BackgroundShape::Rectangle,
+            ),
+            disabled_background: Background::new_solid(
+                Color::rgb(240, 240, 240),
+                BackgroundShape::Rectangle,
+            ),
+            border: Border::new(Color::rgb(122, 122, 122), 1.0, BorderType::Solid),
+            focus_border: Border::new(Color::rgb(0, 120, 215), 1.0, BorderType::Solid),
+            button_normal: Color::rgb(240, 240, 240),
+            button_hovered: Color::rgb(229, 241, 251),
+            button_pressed: Color::rgb(204, 228, 247),
+            button_disabled: Color::rgb(240, 240, 240),
+            arrow_color: Color::rgb(0, 0, 0),
+            disabled_arrow_color: Color::rgb(160, 160, 160),
+            text_color: Color::rgb(0, 0, 0),
+            disabled_text_color: Color::rgb(109, 109, 109),
+            button_width: 17,
+            typography: TypoGraphy::default(),
+        }
+    }
+}
+
+impl NumericUpDownStyle {
+    pub fn button(&self, state: &ActionState) -> &Color {
+        match state {
+            ActionState::Normal => &self.button_normal,
+            ActionState::Hovered => &self.button_hovered,
+            ActionState::Pressed => &self.button_pressed,
+            ActionState::Disabled => &self.button_disabled,
+        }
+    }
+
+    pub fn arrow(&self, state: &ActionState) -> &Color {
+        if state.is_disabled() {
+            &self.disabled_arrow_color
+        } else {
+            &self.arrow_color
+        }
+    }
+}
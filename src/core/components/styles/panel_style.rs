@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::{
+    properties::graphics::{
+        background::{Background, BackgroundShape},
+        border::Border,
+        color::Color,
+    },
+    styles::scroll_bar_style::ScrollBarStyle,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PanelStyle {
+    // Transparent by default so the parent shows through
+    pub background: Background,
+    // No border by default, as `BorderStyle.None` in WinForms
+    pub border: Option<Border>,
+    pub scroll_bar: ScrollBarStyle,
+}
+
+impl Default for PanelStyle {
+    fn default() -> Self {
+        Self {
+            background: Background::new_solid(Color::rgba(0, 0, 0, 0), BackgroundShape::Rectangle),
+            border: None,
+            scroll_bar: ScrollBarStyle::default(),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::graphics::{
+    background::{Background, BackgroundShape},
+    border::Border,
+    color::Color,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PictureBoxStyle {
+    // Shows through transparent pixels and around the image
+    pub background: Background,
+    // No border by default, as `BorderStyle.None` in WinForms
+    pub border: Option<Border>,
+}
+
+impl Default for PictureBoxStyle {
+    fn default() -> Self {
+        Self {
+            background: Background::new_solid(Color::rgba(0, 0, 0, 0), BackgroundShape::Rectangle),
+            border: None,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::graphics::{
+    background::{Background, BackgroundShape},
+    border::{Border, BorderType},
+    color::Color,
+};
+
+/// Look of progress bars and speed of their indeterminate animation
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ProgressBarStyle {
+    pub background: Background,
+    pub border: Border,
+    pub fill: Background,
+    // Length of the block sliding across an indeterminate bar
+    pub marquee_length: u16,
+    // Pixels per second travelled by the block
+    pub marquee_speed: u16,
+}
+
+impl Default for ProgressBarStyle {
+    fn default() -> Self {
+        Self {
+            background: Background::new_solid(
+                Color::rgb(230, 230, 230),
+                BackgroundShape::Rectangle,
+            ),
+            border: Border::new(Color::rgb(188, 188, 188), 1.0, BorderType::Solid),
+            fill: Background::new_solid(Color::rgb(6, 176, 37), BackgroundShape::Rectangle),
+            marquee_length: 60,
+            marquee_speed: 120,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::graphics::{
+    background::{Background, BackgroundShape},
+    color::Color,
+};
+
+/// Look and step sizes of the scrollbars drawn by scroll viewers
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ScrollBarStyle {
+    pub track_background: Background,
+    pub thumb_background: Background,
+    pub hovered_thumb_background: Background,
+    pub pressed_thumb_background: Background,
+    // Square left between the two bars when both are shown
+    pub corner_background: Background,
+    pub width: u16,
+    pub min_thumb_length: u16,
+    // Pixels scrolled by one wheel notch and by the arrow keys
+    pub wheel_step: u16,
+    pub line_step: u16,
+}
+
+impl Default for ScrollBarStyle {
+    fn default() -> Self {
+        Self {
+            track_background: Background::new_solid(
+                Color::rgb(240, 240, 240),
+                BackgroundShape::Rectangle,
+            ),
+            thumb_background: Background::new_solid(
+                Color::rgb(205, 205, 205),
+                BackgroundShape::Rectangle,
+            ),
+            hovered_thumb_background: Background::new_solid(
+                Color::rgb(166, 166, 166),
+                BackgroundShape::Rectangle,
+            ),
+            pressed_thumb_background: Background::new_solid(
+                Color::rgb(96, 96, 96),
+                BackgroundShape::Rectangle,
+            ),
+            corner_background: Background::new_solid(
+                Color::rgb(240, 240, 240),
+                BackgroundShape::Rectangle,
+            ),
+            width: 17,
+            min_thumb_length: 17,
+            wheel_step: 48,
+            line_step: 16,
+        }
+    }
+}
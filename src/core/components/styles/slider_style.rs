@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::graphics::{
+    action_state::ActionState,
+    background::{Background, BackgroundShape},
+    border::{Border, BorderType},
+    color::Color,
+};
+
+/// Look of sliders: a thin track, a thumb and optional tick marks
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SliderStyle {
+    pub track_background: Background,
+    pub track_border: Border,
+    pub thumb_normal: Color,
+    pub thumb_hovered: Color,
+    pub thumb_pressed: Color,
+    pub thumb_disabled: Color,
+    pub tick_color: Color,
+    pub focus_border: Border,
+    pub track_thickness: u16,
+    // Size of the thumb along the track and across it
+    pub thumb_length: u16,
+    pub thumb_thickness: u16,
+    pub tick_length: u16,
+    // Gap between the thumb and the tick marks
+    pub tick_spacing: u16,
+}
+
+impl Default for SliderStyle {
+    fn default() -> Self {
+        Self {
+            track_background: Background::new_solid(
+                Color::rgb(231, 234, 234),
+                BackgroundShape::Rectangle,
+            ),
+            track_border: Border::new(Color::rgb(214, 214, 214), 1.0, BorderType::Solid),
+            thumb_normal: Color::rgb(0, 122, 217),
+            thumb_hovered: Color::rgb(23, 23, 23),
+            thumb_pressed: Color::rgb(204, 204, 204),
+            thumb_disabled: Color::rgb(204, 204, 204),
+            tick_color: Color::rgb(196, 196, 196),
+            focus_border: Border::new(Color::rgb(0, 120, 215), 1.0, BorderType::Dotted),
+            track_thickness: 4,
+            thumb_length: 11,
+            thumb_thickness: 21,
+            tick_length: 4,
+            tick_spacing: 2,
+        }
+    }
+}
+
+impl SliderStyle {
+    pub fn thumb(&self, state: &ActionState) -> &Color {
+        match state {
+            ActionState::Normal => &self.thumb_normal,
+            ActionState::Hovered => &self.thumb_hovered,
+            ActionState::Pressed => &self.thumb_pressed,
+            ActionState::Disabled => &self.thumb_disabled,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::{
+    properties::graphics::{
+        background::{Background, BackgroundShape},
+        border::{Border, BorderType},
+        color::Color,
+    },
+    styles::panel_style::PanelStyle,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SplitContainerStyle {
+    pub splitter_background: Background,
+    pub hovered_splitter_background: Background,
+    pub dragging_splitter_background: Background,
+    // Drawn around the splitter while it has the keyboard focus
+    pub focus_border: Border,
+    pub panel: PanelStyle,
+    pub splitter_width: u16,
+    // Pixels the arrow keys move the splitter
+    pub splitter_increment: u16,
+}
+
+impl Default for SplitContainerStyle {
+    fn default() -> Self {
+        Self {
+            splitter_background: Background::new_solid(
+                Color::rgb(240, 240, 240),
+                BackgroundShape::Rectangle,
+            ),
+            hovered_splitter_background: Background::new_solid(
+                Color::rgb(218, 218, 218),
+                BackgroundShape::Rectangle,
+            ),
+            dragging_splitter_background: Background::new_solid(
+                Color::rgb(160, 160, 160),
+                BackgroundShape::Rectangle,
+            ),
+            focus_border: Border::new(Color::rgb(0, 0, 0), 1.0, BorderType::Dotted),
+            panel: PanelStyle::default(),
+            splitter_width: 4,
+            splitter_increment: 1,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::{
+    properties::{
+        graphics::{
+            background::{Background, BackgroundShape},
+            border::{Border, BorderType},
+            color::Color,
+        },
+        typography::TypoGraphy,
+    },
+    styles::progress_bar_style::ProgressBarStyle,
+};
+
+/// Look of the status bar along the bottom of a window and of its panels
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct StatusStripStyle {
+    pub background: Background,
+    // Line along the top edge, separating the bar from the window content
+    pub border: Border,
+    // Drawn around every panel when set, as `BorderSides` in WinForms
+    pub panel_border: Option<Border>,
+    pub text_color: Color,
+    pub grip_color: Color,
+    pub typography: TypoGraphy,
+    pub progress: ProgressBarStyle,
+    pub height: u16,
+    // Space around the text of each panel
+    pub panel_padding: u16,
+    // Gap between panels
+    pub spacing: u16,
+    pub progress_height: u16,
+    // Square in the bottom right corner holding the sizing grip
+    pub grip_size: u16,
+}
+
+impl Default for StatusStripStyle {
+    fn default() -> Self {
+        Self {
+            background: Background::new_solid(
+                Color::rgb(240, 240, 240),
+                BackgroundShape::Rectangle,
+            ),
+            border: Border::new(Color::rgb(215, 215, 215), 1.0, BorderType::Solid),
+            panel_border: None,
+            text_color: Color::rgb(0, 0, 0),
+            grip_color: Color::rgb(160, 160, 160),
+            typography: TypoGraphy::default(),
+            progress: ProgressBarStyle::default(),
+            height: 22,
+            panel_padding: 4,
+            spacing: 2,
+            progress_height: 16,
+            grip_size: 12,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::{
+    graphics::{
+        background::{Background, BackgroundShape},
+        border::{Border, BorderType},
+        color::Color,
+    },
+    typography::TypoGraphy,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TabControlStyle {
+    pub page_background: Background,
+    pub border: Border,
+    pub tab_background: Background,
+    pub hovered_tab_background: Background,
+    pub selected_tab_background: Background,
+    pub close_hovered_background: Background,
+    pub close_glyph_color: Color,
+    pub text_color: Color,
+    pub disabled_text_color: Color,
+    pub focus_border: Border,
+    pub header_height: u16,
+    // Horizontal space around the title, and between it and the close button
+    pub tab_padding: u16,
+    pub min_tab_width: u16,
+    pub close_button_size: u16,
+    pub typography: TypoGraphy,
+}
+
+impl Default for TabControlStyle {
+    fn default() -> Self {
+        Self {
+            page_background: Background::new_solid(
+                Color::rgb(255, 255, 255),
+                BackgroundShape::Rectangle,
+            ),
+            border: Border::new(Color::rgb(172, 172, 172), 1.0, BorderType::Solid),
+            tab_background: Background::new_solid(
+                Color::rgb(240, 240, 240),
+                BackgroundShape::Rectangle,
+            ),
+            hovered_tab_background: Background::new_solid(
+                Color::rgb(216, 234, 249),
+                BackgroundShape::Rectangle,
+            ),
+            selected_tab_background: Background::new_solid(
+                Color::rgb(255, 255, 255),
+                BackgroundShape::Rectangle,
+            ),
+            close_hovered_background: Background::new_solid(
+                Color::rgb(199, 224, 244),
+                BackgroundShape::Rectangle,
+            ),
+            close_glyph_color: Color::rgb(96, 96, 96),
+            text_color: Color::rgb(0, 0, 0),
+            disabled_text_color: Color::rgb(160, 160, 160),
+            focus_border: Border::new(Color::rgb(0, 0, 0), 1.0, BorderType::Dotted),
+            header_height: 22,
+            tab_padding: 8,
+            min_tab_width: 40,
+            close_button_size: 12,
+            typography: TypoGraphy::default(),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::{
+    graphics::{
+        background::{Background, BackgroundShape},
+        border::{Border, BorderType},
+        color::Color,
+    },
+    typography::TypoGraphy,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TextBoxStyle {
+    pub background: Background,
+    pub read_only_background: Background,
+    pub disabled_background: Background,
+    pub selection_background: Background,
+    pub border: Border,
+    pub focus_border: Border,
+    pub text_color: Color,
+    pub placeholder_color: Color,
+    pub disabled_text_color: Color,
+    pub caret_color: Color,
+    pub typography: TypoGraphy,
+}
+
+impl Default for TextBoxStyle {
+    fn default() -> Self {
+        Self {
+            background: Background::new_solid(
+                Color::rgb(255, 255, 255),
+                BackgroundShape::Rectangle,
+            ),
+            read_only_background: Background::new_solid(
+                Color::rgb(240, 240, 240),
+                BackgroundShape::Rectangle,
+            ),
+            disabled_background: Background::new_solid(
+                Color::rgb(240, 240, 240),
+                BackgroundShape::Rectangle,
+            ),
+            selection_background: Background::new_solid(
+                Color::rgb(0, 120, 215),
+                BackgroundShape::Rectangle,
+            ),
+            border: Border::new(Color::rgb(122, 122, 122), 1.0, BorderType::Solid),
+            focus_border: Border::new(Color::rgb(0, 120, 215), 1.0, BorderType::Solid),
+            text_color: Color::rgb(0, 0, 0),
+            placeholder_color: Color::rgb(128, 128, 128),
+            disabled_text_color: Color::rgb(160, 160, 160),
+            caret_color: Color::rgb(0, 0, 0),
+            typography: TypoGraphy::default(),
+        }
+    }
+}
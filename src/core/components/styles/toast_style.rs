@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::properties::{
+        graphics::{
+            background::{Background, BackgroundShape},
+            border::{Border, BorderType},
+            color::Color,
+        },
+        typography::{FontWeight, TypoGraphy},
+    },
+    ui::notification_service::Severity,
+};
+
+/// Look of toast notifications: a card with a stripe in the severity color,
+/// a bold title, the message, a close button and optional action buttons
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ToastStyle {
+    pub background: Background,
+    pub border: Border,
+    pub title_typography: TypoGraphy,
+    pub typography: TypoGraphy,
+    pub text_color: Color,
+    pub info_color: Color,
+    pub success_color: Color,
+    pub warning_color: Color,
+    pub error_color: Color,
+    pub close_color: Color,
+    pub hovered_background: Background,
+    pub button_background: Background,
+    pub button_border: Border,
+    pub button_text_color: Color,
+    pub width: u16,
+    // Inset of the title, message and buttons from the card edges, measured
+    // from the accent stripe on the left
+    pub padding: u16,
+    // Space between the title, the message and the buttons
+    pub spacing: u16,
+    // Space between the toasts and from the viewport edge
+    pub margin: u16,
+    pub accent_width: u16,
+    pub close_size: u16,
+    pub button_height: u16,
+    pub button_padding: u16,
+}
+
+impl Default for ToastStyle {
+    fn default() -> Self {
+        Self {
+            background: Background::new_solid(
+                Color::rgb(255, 255, 255),
+                BackgroundShape::Rectangle,
+            ),
+            border: Border::new(Color::rgb(204, 204, 204), 1.0, BorderType::Solid),
+            title_typography: TypoGraphy::new(14, "Segoe UI", FontWeight::SEMI_BOLD),
+            typography: TypoGraphy::default(),
+            text_color: Color::rgb(0, 0, 0),
+            info_color: Color::rgb(0, 120, 215),
+            success_color: Color::rgb(16, 124, 16),
+            warning_color: Color::rgb(255, 185, 0),
+            error_color: Color::rgb(232, 17, 35),
+            close_color: Color::rgb(96, 96, 96),
+            hovered_background: Background::new_solid(
+                Color::rgb(229, 241, 251),
+                BackgroundShape::Rectangle,
+            ),
+            button_background: Background::new_solid(
+                Color::rgb(225, 225, 225),
+                BackgroundShape::Rectangle,
+            ),
+            button_border: Border::new(Color::rgb(173, 173, 173), 1.0, BorderType::Solid),
+            button_text_color: Color::rgb(0, 0, 0),
+            width: 320,
+            padding: 10,
+            spacing: 6,
+            margin: 12,
+            accent_width: 4,
+            close_size: 16,
+            button_height: 23,
+            button_padding: 10,
+        }
+    }
+}
+
+impl ToastStyle {
+    pub fn accent(&self, severity: Severity) -> &Color {
+        match severity {
+            Severity::Info => &self.info_color,
+            Severity::Success => &self.success_color,
+            Severity::Warning => &self.warning_color,
+            Severity::Error => &self.error_color,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::{
+    graphics::{
+        action_state::ActionState,
+        border::{Border, BorderType},
+        color::Color,
+    },
+    typography::TypoGraphy,
+};
+
+/// Shared look of check boxes and radio buttons
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ToggleStyle {
+    pub box_size: u16,
+    pub spacing: u16,
+    pub normal: Color,
+    pub hovered: Color,
+    pub pressed: Color,
+    pub disabled: Color,
+    pub mark_color: Color,
+    pub disabled_mark_color: Color,
+    pub border: Border,
+    pub focus_border: Border,
+    pub text_color: Color,
+    pub disabled_text_color: Color,
+    pub typography: TypoGraphy,
+}
+
+impl Default for ToggleStyle {
+    fn default() -> Self {
+        Self {
+            box_size: 13,
+            spacing: 4,
+            normal: Color::rgb(255, 255, 255),
+            hovered: Color::rgb(229, 241, 251),
+            pressed: Color::rgb(204, 228, 247),
+            disabled: Color::rgb(240, 240, 240),
+            mark_color: Color::rgb(0, 0, 0),
+            disabled_mark_color: Color::rgb(160, 160, 160),
+            border: Border::new(Color::rgb(51, 51, 51), 1.0, BorderType::Solid),
+            focus_border: Border::new(Color::rgb(0, 120, 215), 1.0, BorderType::Dotted),
+            text_color: Color::rgb(0, 0, 0),
+            disabled_text_color: Color::rgb(160, 160, 160),
+            typography: TypoGraphy::default(),
+        }
+    }
+}
+
+impl ToggleStyle {
+    pub fn fill(&self, state: &ActionState) -> &Color {
+        match state {
+            ActionState::Normal => &self.normal,
+            ActionState::Hovered => &self.hovered,
+            ActionState::Pressed => &self.pressed,
+            ActionState::Disabled => &self.disabled,
+        }
+    }
+
+    pub fn mark(&self, state: &ActionState) -> &Color {
+        if state.is_disabled() {
+            &self.disabled_mark_color
+        } else {
+            &self.mark_color
+        }
+    }
+
+    pub fn text_color(&self, state: &ActionState) -> &Color {
+        if state.is_disabled() {
+            &self.disabled_text_color
+        } else {
+            &self.text_color
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::{
+    properties::{
+        graphics::{
+            background::{Background, BackgroundShape},
+            border::{Border, BorderType},
+            color::Color,
+        },
+        typography::TypoGraphy,
+    },
+    styles::menu_style::MenuStyle,
+};
+
+/// Look of tool bars, their buttons and the menus their drop-down buttons and
+/// overflow chevron open
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ToolStripStyle {
+    pub background: Background,
+    pub hover_background: Background,
+    pub hover_border: Border,
+    pub pressed_background: Background,
+    // Toggles that are on
+    pub checked_background: Background,
+    pub checked_border: Border,
+    pub text_color: Color,
+    pub disabled_text_color: Color,
+    pub glyph_color: Color,
+    pub separator_color: Color,
+    pub typography: TypoGraphy,
+    pub menu: MenuStyle,
+    pub height: u16,
+    // Space between the edges of the bar and the first and last items
+    pub padding: u16,
+    // Space around the content of each button
+    pub item_padding: u16,
+    // Gap between the icon and the text of a button
+    pub icon_spacing: u16,
+    pub separator_width: u16,
+    // Part of a drop-down button holding its arrow
+    pub arrow_width: u16,
+    pub overflow_width: u16,
+}
+
+impl Default for ToolStripStyle {
+    fn default() -> Self {
+        Self {
+            background: Background::new_solid(
+                Color::rgb(248, 248, 248),
+                BackgroundShape::Rectangle,
+            ),
+            hover_background: Background::new_solid(
+                Color::rgb(204, 232, 255),
+                BackgroundShape::Rectangle,
+            ),
+            hover_border: Border::new(Color::rgb(153, 209, 255), 1.0, BorderType::Solid),
+            pressed_background: Background::new_solid(
+                Color::rgb(153, 209, 255),
+                BackgroundShape::Rectangle,
+            ),
+            checked_background: Background::new_solid(
+                Color::rgb(204, 232, 255),
+                BackgroundShape::Rectangle,
+            ),
+            checked_border: Border::new(Color::rgb(0, 120, 215), 1.0, BorderType::Solid),
+            text_color: Color::rgb(0, 0, 0),
+            disabled_text_color: Color::rgb(109, 109, 109),
+            glyph_color: Color::rgb(0, 0, 0),
+            separator_color: Color::rgb(189, 189, 189),
+            typography: TypoGraphy::default(),
+            menu: MenuStyle::default(),
+            height: 25,
+            padding: 2,
+            item_padding: 4,
+            icon_spacing: 4,
+            separator_width: 6,
+            arrow_width: 11,
+            overflow_width: 12,
+        }
+    }
+}
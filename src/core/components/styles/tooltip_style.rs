@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::{
+    graphics::{
+        background::{Background, BackgroundShape},
+        border::{Border, BorderType},
+        color::Color,
+    },
+    typography::{FontWeight, TypoGraphy},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TooltipStyle {
+    pub background: Background,
+    pub border: Border,
+    pub text_color: Color,
+    pub typography: TypoGraphy,
+    // Title of rich tooltips
+    pub title_typography: TypoGraphy,
+    // Gap between the text and the one pixel frame, on every side
+    pub padding: u16,
+    // Space between the icon, the title and the text
+    pub spacing: u16,
+    // Height of the mouse cursor, which the tooltip is placed under
+    pub cursor_height: u16,
+}
+
+impl Default for TooltipStyle {
+    fn default() -> Self {
+        Self {
+            background: Background::new_solid(
+                Color::rgb(255, 255, 225),
+                BackgroundShape::Rectangle,
+            ),
+            border: Border::new(Color::rgb(118, 118, 118), 1.0, BorderType::Solid),
+            text_color: Color::rgb(0, 0, 0),
+            typography: TypoGraphy::default(),
+            title_typography: TypoGraphy::new(14, "Segoe UI", FontWeight::BOLD),
+            padding: 4,
+            spacing: 4,
+            cursor_height: 20,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::{
+    properties::graphics::{
+        border::{Border, BorderType},
+        color::Color,
+    },
+    styles::{list_style::ListStyle, toggle_style::ToggleStyle},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TreeViewStyle {
+    pub list: ListStyle,
+    // Horizontal offset of each level, also the width of the expander column
+    pub indent: u16,
+    pub expander_size: u16,
+    pub expander_border: Border,
+    pub check_box: ToggleStyle,
+}
+
+impl Default for TreeViewStyle {
+    fn default() -> Self {
+        Self {
+            list: ListStyle::default(),
+            indent: 19,
+            expander_size: 9,
+            expander_border: Border::new(Color::rgb(145, 145, 145), 1.0, BorderType::Solid),
+            check_box: ToggleStyle::default(),
+        }
+    }
+}
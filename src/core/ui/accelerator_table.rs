@@ -0,0 +1,105 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use crate::core::{
+    components::elements::menu_item::MenuItem,
+    utils::traits::event_listener::EventListener,
+    window::events::{
+        event_system::EventSystem,
+        input::{InputEvent, KeyCombination},
+        types::{EventPayload, EventType},
+    },
+};
+
+/// Global table of keyboard shortcuts mapping key combinations to commands.
+///
+/// Subscribed to `KeyCombinationPressed`, it queues the command bound to each
+/// combination it hears. Listeners cannot emit while the event system is
+/// dispatching, so the owner drains the queue with `take_pending` and runs
+/// the commands, usually through `MenuStrip::invoke`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AcceleratorTable {
+    accelerators: HashMap<KeyCombination, usize>,
+    pending: Vec<usize>,
+}
+
+impl AcceleratorTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds a combination to a command, returning the command it replaced
+    pub fn register(&mut self, combination: KeyCombination, command: usize) -> Option<usize> {
+        let combination = KeyCombination::new(combination.key, combination.modifiers);
+        self.accelerators.insert(combination, command)
+    }
+
+    /// Binds the shortcut of every item of a menu tree that has a command
+    pub fn register_menu(&mut self, menu: &MenuItem) {
+        for item in menu.items() {
+            if let (Some(shortcut), Some(command)) = (item.shortcut(), item.command()) {
+                self.register(shortcut, command);
+            }
+            self.register_menu(item);
+        }
+    }
+
+    pub fn unregister(&mut self, combination: KeyCombination) -> Option<usize> {
+        let combination = KeyCombination::new(combination.key, combination.modifiers);
+        self.accelerators.remove(&combination)
+    }
+
+    pub fn clear(&mut self) {
+        self.accelerators.clear();
+        self.pending.clear();
+    }
+
+    /// Commands triggered since the last call, oldest first
+    pub fn take_pending(&mut self) -> Vec<usize> {
+        std::mem::take(&mut self.pending)
+    }
+
+    // Getters
+    pub fn command(&self, combination: KeyCombination) -> Option<usize> {
+        let combination = KeyCombination::new(combination.key, combination.modifiers);
+        self.accelerators.get(&combination).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.accelerators.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accelerators.is_empty()
+    }
+}
+
+impl EventListener for AcceleratorTable {
+    fn on_event(&mut self, _event: &EventType, _caller_id: usize) {}
+
+    fn on_event_with(&mut self, event: &EventType, _caller_id: usize, payload: &EventPayload) {
+        if *event == EventType::KeyCombinationPressed
+            && let EventPayload::KeyCombination(combination) = payload
+            && let Some(command) = self.command(*combination)
+        {
+            self.pending.push(command);
+        }
+    }
+}
+
+/// Emits `KeyCombinationPressed` for key presses that can trigger a shortcut,
+/// returning whether one was emitted
+pub fn emit_key_combination(
+    event_system: &Rc<RefCell<EventSystem>>,
+    caller_id: usize,
+    input: &InputEvent,
+) -> bool {
+    let Some(combination) = KeyCombination::from_input(input) else {
+        return false;
+    };
+    event_system.borrow_mut().emit_with(
+        EventType::KeyCombinationPressed,
+        caller_id,
+        EventPayload::KeyCombination(combination),
+    );
+    true
+}
@@ -0,0 +1,120 @@
+use crate::core::{
+    components::properties::{graphics::action_state::ActionState, position::Position},
+    window::events::input::{InputEvent, Key, MouseButton},
+};
+
+/// Result of feeding one input event to a `ClickTracker`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ClickResponse {
+    pub consumed: bool,
+    pub clicked: bool,
+    // Some(true) when the pointer entered, Some(false) when it left
+    pub hover_changed: Option<bool>,
+}
+
+/// Hover/press state machine shared by clickable components.
+///
+/// A click is only reported when the press and the release both land inside
+/// the component, or when Space is pressed and released while focused.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ClickTracker {
+    hovered: bool,
+    captured: bool,
+    key_pressed: bool,
+}
+
+impl ClickTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn handle_input(
+        &mut self,
+        input: &InputEvent,
+        hit_test: impl Fn(Position) -> bool,
+        focused: bool,
+    ) -> ClickResponse {
+        let mut response = ClickResponse::default();
+
+        match *input {
+            InputEvent::MouseMoved(position) => {
+                response.hover_changed = self.update_hover(hit_test(position));
+                response.consumed = self.hovered || self.captured;
+            }
+            InputEvent::MouseButtonPressed {
+                button: MouseButton::Left,
+                position,
+                ..
+            } => {
+                response.hover_changed = self.update_hover(hit_test(position));
+                if self.hovered {
+                    self.captured = true;
+                    response.consumed = true;
+                }
+            }
+            InputEvent::MouseButtonReleased {
+                button: MouseButton::Left,
+                position,
+                ..
+            } if self.captured => {
+                self.captured = false;
+                response.hover_changed = self.update_hover(hit_test(position));
+                response.clicked = self.hovered;
+                response.consumed = true;
+            }
+            InputEvent::KeyPressed {
+                key: Key::Space, ..
+            } if focused => {
+                self.key_pressed = true;
+                response.consumed = true;
+            }
+            InputEvent::KeyReleased {
+                key: Key::Space, ..
+            } if focused && self.key_pressed => {
+                self.key_pressed = false;
+                response.clicked = true;
+                response.consumed = true;
+            }
+            InputEvent::KeyPressed {
+                key: Key::Escape, ..
+            } if focused && self.key_pressed => {
+                self.key_pressed = false;
+                response.consumed = true;
+            }
+            _ => {}
+        }
+
+        response
+    }
+
+    pub fn state(&self) -> ActionState {
+        if self.key_pressed || (self.captured && self.hovered) {
+            ActionState::Pressed
+        } else if self.hovered && !self.captured {
+            ActionState::Hovered
+        } else {
+            ActionState::Normal
+        }
+    }
+
+    pub fn hovered(&self) -> bool {
+        self.hovered
+    }
+
+    /// Drops a pending Space press, e.g. when the component loses focus
+    pub fn release_key(&mut self) {
+        self.key_pressed = false;
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn update_hover(&mut self, inside: bool) -> Option<bool> {
+        if inside == self.hovered {
+            return None;
+        }
+        self.hovered = inside;
+        Some(inside)
+    }
+}
@@ -0,0 +1,379 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::date_time::{Date, DateTime, Time, Weekday};
+
+/// How a date picker shows its value, as `DateTimePickerFormat` in WinForms
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DateTimeFormat {
+    Long,
+    #[default]
+    Short,
+    Time,
+    // Pattern with the tokens understood by `DateLocale::format`
+    Custom(String),
+}
+
+/// Names and patterns for showing and reading dates in one culture.
+///
+/// Patterns use .NET style tokens: `yyyy` `yy` for the year, `M` `MM` `MMM`
+/// `MMMM` for the month as a number, abbreviation or name, `d` `dd` for the
+/// day and `ddd` `dddd` for the weekday, `H` `HH` or `h` `hh` plus `tt` for
+/// 24 or 12 hour clocks, `mm` and `ss`. Text in single quotes is literal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DateLocale {
+    pub month_names: [String; 12],
+    pub month_abbreviations: [String; 12],
+    // Indexed by `Weekday::index`, Monday first
+    pub day_names: [String; 7],
+    // Short names heading the calendar columns
+    pub day_abbreviations: [String; 7],
+    pub first_day_of_week: Weekday,
+    pub long_date: String,
+    pub short_date: String,
+    pub short_time: String,
+    pub am: String,
+    pub pm: String,
+}
+
+impl Default for DateLocale {
+    fn default() -> Self {
+        Self::en_us()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Field(char, usize),
+    Literal(String),
+}
+
+impl DateLocale {
+    pub fn en_us() -> Self {
+        Self {
+            month_names: names(&[
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ]),
+            month_abbreviations: names(&[
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+            ]),
+            day_names: names(&[
+                "Monday",
+                "Tuesday",
+                "Wednesday",
+                "Thursday",
+                "Friday",
+                "Saturday",
+                "Sunday",
+            ]),
+            day_abbreviations: names(&["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"]),
+            first_day_of_week: Weekday::Sunday,
+            long_date: "dddd, MMMM d, yyyy".to_string(),
+            short_date: "M/d/yyyy".to_string(),
+            short_time: "h:mm tt".to_string(),
+            am: "AM".to_string(),
+            pm: "PM".to_string(),
+        }
+    }
+
+    pub fn es_es() -> Self {
+        Self {
+            month_names: names(&[
+                "enero",
+                "febrero",
+                "marzo",
+                "abril",
+                "mayo",
+                "junio",
+                "julio",
+                "agosto",
+                "septiembre",
+                "octubre",
+                "noviembre",
+                "diciembre",
+            ]),
+            month_abbreviations: names(&[
+                "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+            ]),
+            day_names: names(&[
+                "lunes",
+                "martes",
+                "miércoles",
+                "jueves",
+                "viernes",
+                "sábado",
+                "domingo",
+            ]),
+            day_abbreviations: names(&["lu", "ma", "mi", "ju", "vi", "sá", "do"]),
+            first_day_of_week: Weekday::Monday,
+            long_date: "dddd, d 'de' MMMM 'de' yyyy".to_string(),
+            short_date: "dd/MM/yyyy".to_string(),
+            short_time: "H:mm".to_string(),
+            am: "a. m.".to_string(),
+            pm: "p. m.".to_string(),
+        }
+    }
+
+    pub fn de_de() -> Self {
+        Self {
+            month_names: names(&[
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ]),
+            month_abbreviations: names(&[
+                "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+            ]),
+            day_names: names(&[
+                "Montag",
+                "Dienstag",
+                "Mittwoch",
+                "Donnerstag",
+                "Freitag",
+                "Samstag",
+                "Sonntag",
+            ]),
+            day_abbreviations: names(&["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"]),
+            first_day_of_week: Weekday::Monday,
+            long_date: "dddd, d. MMMM yyyy".to_string(),
+            short_date: "dd.MM.yyyy".to_string(),
+            short_time: "HH:mm".to_string(),
+            am: String::new(),
+            pm: String::new(),
+        }
+    }
+
+    /// Pattern of a display format, with the time appended to the date
+    /// patterns when `with_time` is set
+    pub fn pattern(&self, format: &DateTimeFormat, with_time: bool) -> String {
+        let date = match format {
+            DateTimeFormat::Long => &self.long_date,
+            DateTimeFormat::Short => &self.short_date,
+            DateTimeFormat::Time => return self.short_time.clone(),
+            DateTimeFormat::Custom(pattern) => return pattern.clone(),
+        };
+        if with_time {
+            format!("{date} {}", self.short_time)
+        } else {
+            date.clone()
+        }
+    }
+
+    pub fn format(&self, value: &DateTime, pattern: &str) -> String {
+        let (date, time) = (&value.date, &value.time);
+        let mut text = String::new();
+        for token in tokenize(pattern) {
+            match token {
+                Token::Literal(literal) => text.push_str(&literal),
+                Token::Field('y', count) if count <= 2 => {
+                    text.push_str(&format!("{:02}", date.year() % 100))
+                }
+                Token::Field('y', count) => text.push_str(&format!("{:0count$}", date.year())),
+                Token::Field('M', 1) => text.push_str(&date.month().to_string()),
+                Token::Field('M', 2) => text.push_str(&format!("{:02}", date.month())),
+                Token::Field('M', 3) => {
+                    text.push_str(&self.month_abbreviations[date.month() as usize - 1])
+                }
+                Token::Field('M', _) => text.push_str(&self.month_names[date.month() as usize - 1]),
+                Token::Field('d', 1) => text.push_str(&date.day().to_string()),
+                Token::Field('d', 2) => text.push_str(&format!("{:02}", date.day())),
+                Token::Field('d', 3) => {
+                    text.push_str(&self.day_abbreviations[date.weekday().index()])
+                }
+                Token::Field('d', _) => text.push_str(&self.day_names[date.weekday().index()]),
+                Token::Field('H', count) => text.push_str(&pad(time.hour(), count)),
+                Token::Field('h', count) => {
+                    let hour = match time.hour() % 12 {
+                        0 => 12,
+                        hour => hour,
+                    };
+                    text.push_str(&pad(hour, count))
+                }
+                Token::Field('m', count) => text.push_str(&pad(time.minute(), count)),
+                Token::Field('s', count) => text.push_str(&pad(time.second(), count)),
+                Token::Field(_, count) => {
+                    // Only `t` is left: the AM or PM designator
+                    let designator = if time.hour() < 12 { &self.am } else { &self.pm };
+                    let designator: String = match count {
+                        1 => designator.chars().take(1).collect(),
+                        _ => designator.clone(),
+                    };
+                    text.push_str(&designator)
+                }
+            }
+        }
+        text.trim_end().to_string()
+    }
+
+    /// Reads text written in `pattern`, taking the parts the pattern lacks
+    /// from `base`. Names match without regard to case and runs of spaces
+    /// match any spacing; `None` when the text does not fit the pattern or
+    /// names a day that does not exist.
+    pub fn parse(&self, text: &str, pattern: &str, base: &DateTime) -> Option<DateTime> {
+        let mut rest = text.trim();
+        let (mut year, mut month, mut day) = (base.date.year(), base.date.month(), base.date.day());
+        let (mut hour, mut minute, mut second) =
+            (base.time.hour(), base.time.minute(), base.time.second());
+        let mut pm = None;
+        let mut twelve_hour = false;
+
+        for token in tokenize(pattern) {
+            match token {
+                Token::Literal(literal) => {
+                    for expected in literal.chars() {
+                        if expected.is_whitespace() {
+                            rest = rest.trim_start();
+                            continue;
+                        }
+                        let mut chars = rest.chars();
+                        let found = chars.next()?;
+                        if !found.to_lowercase().eq(expected.to_lowercase()) {
+                            return None;
+                        }
+                        rest = chars.as_str();
+                    }
+                }
+                Token::Field('y', count) => {
+                    let (digits, value) = take_number(&mut rest, 4)?;
+                    year = if count <= 2 && digits <= 2 {
+                        2000 + value
+                    } else {
+                        value
+                    };
+                }
+                Token::Field('M', count) if count <= 2 => {
+                    month = take_number(&mut rest, 2)?.1 as u8;
+                }
+                Token::Field('M', _) => {
+                    let names = self.month_names.iter().chain(&self.month_abbreviations);
+                    month = (take_name(&mut rest, names)? % 12 + 1) as u8;
+                }
+                Token::Field('d', count) if count <= 2 => {
+                    day = take_number(&mut rest, 2)?.1 as u8;
+                }
+                // Weekday names are read and ignored, the date decides them
+                Token::Field('d', _) => {
+                    take_name(
+                        &mut rest,
+                        self.day_names.iter().chain(&self.day_abbreviations),
+                    )?;
+                }
+                Token::Field(field @ ('H' | 'h'), _) => {
+                    hour = take_number(&mut rest, 2)?.1 as u8;
+                    twelve_hour = field == 'h';
+                }
+                Token::Field('m', _) => minute = take_number(&mut rest, 2)?.1 as u8,
+                Token::Field('s', _) => second = take_number(&mut rest, 2)?.1 as u8,
+                Token::Field(_, _) => {
+                    if !self.am.is_empty() || !self.pm.is_empty() {
+                        pm = Some(take_name(&mut rest, [&self.am, &self.pm].into_iter())? == 1);
+                    }
+                }
+            }
+        }
+        if !rest.trim().is_empty() {
+            return None;
+        }
+
+        if twelve_hour {
+            if !(1..=12).contains(&hour) {
+                return None;
+            }
+            hour = hour % 12 + if pm == Some(true) { 12 } else { 0 };
+        }
+        Some(DateTime::new(
+            Date::new(year, month, day)?,
+            Time::new(hour, minute, second)?,
+        ))
+    }
+}
+
+fn names<const N: usize>(values: &[&str; N]) -> [String; N] {
+    std::array::from_fn(|index| values[index].to_string())
+}
+
+fn pad(value: u8, count: usize) -> String {
+    format!("{value:0count$}")
+}
+
+// Splits a pattern into fields, runs of one token letter, and literal text
+fn tokenize(pattern: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut literal = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            literal.extend(chars.by_ref().take_while(|c| *c != '\''));
+        } else if "yMdHhmst".contains(c) {
+            let mut count = 1;
+            while chars.next_if_eq(&c).is_some() {
+                count += 1;
+            }
+            if !literal.is_empty() {
+                tokens.push(Token::Literal(std::mem::take(&mut literal)));
+            }
+            tokens.push(Token::Field(c, count));
+        } else {
+            literal.push(c);
+        }
+    }
+    if !literal.is_empty() {
+        tokens.push(Token::Literal(literal));
+    }
+    tokens
+}
+
+// Reads between one and `max_digits` digits, returning how many were read
+fn take_number(rest: &mut &str, max_digits: usize) -> Option<(usize, u16)> {
+    let digits = rest
+        .chars()
+        .take(max_digits)
+        .take_while(char::is_ascii_digit)
+        .count();
+    if digits == 0 {
+        return None;
+    }
+    let value = rest[..digits].parse().ok()?;
+    *rest = &rest[digits..];
+    Some((digits, value))
+}
+
+// Reads the longest of `names` starting the text, returning its position
+fn take_name<'a>(rest: &mut &str, names: impl Iterator<Item = &'a String>) -> Option<usize> {
+    let (index, length) = names
+        .enumerate()
+        .filter(|(_, name)| !name.is_empty())
+        .filter_map(|(index, name)| {
+            let mut chars = rest.char_indices();
+            for expected in name.chars() {
+                let (_, found) = chars.next()?;
+                if !found.to_lowercase().eq(expected.to_lowercase()) {
+                    return None;
+                }
+            }
+            let length = chars.next().map_or(rest.len(), |(at, _)| at);
+            Some((index, length))
+        })
+        .max_by_key(|(_, length)| *length)?;
+    *rest = &rest[length..];
+    Some(index)
+}
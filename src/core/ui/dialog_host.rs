@@ -0,0 +1,126 @@
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::dialog::Dialog,
+        properties::{
+            boundaries::Boundaries, dialog_result::DialogResult, margin::Margin, padding::Padding,
+            position::Position, size::Size,
+        },
+        styles::dialog_style::DialogStyle,
+    },
+    utils::traits::interactive::Interactive,
+    window::events::{event_system::EventSystem, input::InputEvent},
+};
+
+type InputSource = Box<dyn FnMut() -> Option<InputEvent>>;
+type Presenter = Box<dyn FnMut(&Dialog)>;
+
+/// Runs dialogs modally, like `Form.ShowDialog` in WinForms.
+///
+/// `show_dialog` opens a dialog and feeds it input from the window until it
+/// closes, as a nested message loop. The input source blocks until the window
+/// has input and returns `None` once the window is gone, which closes the
+/// dialog with `DialogResult::None`. Tests drive it from a list of inputs.
+pub struct DialogHost {
+    event_system: Rc<RefCell<EventSystem>>,
+    viewport: Boundaries,
+    style: DialogStyle,
+    input: InputSource,
+    // Repaints the window with the dialog on top after every input
+    presenter: Option<Presenter>,
+}
+
+impl Debug for DialogHost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DialogHost")
+            .field("viewport", &self.viewport)
+            .field("style", &self.style)
+            .finish()
+    }
+}
+
+impl DialogHost {
+    pub fn new(
+        event_system: Rc<RefCell<EventSystem>>,
+        viewport: Boundaries,
+        input: impl FnMut() -> Option<InputEvent> + 'static,
+    ) -> Self {
+        Self {
+            event_system,
+            viewport,
+            style: DialogStyle::default(),
+            input: Box::new(input),
+            presenter: None,
+        }
+    }
+
+    pub fn with_style(mut self, style: DialogStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_presenter(mut self, presenter: impl FnMut(&Dialog) + 'static) -> Self {
+        self.presenter = Some(Box::new(presenter));
+        self
+    }
+
+    // Setters
+    /// Area dialogs are centered in, usually the window client area
+    pub fn set_viewport(&mut self, viewport: Boundaries) {
+        self.viewport = viewport;
+    }
+
+    pub fn set_style(&mut self, style: DialogStyle) {
+        self.style = style;
+    }
+
+    /// A dialog with the host style, ready to be filled and shown
+    pub fn create_dialog(&self, title: &str) -> Dialog {
+        let base = BaseComponent::new(
+            Size::new(0, 0),
+            Position::new(0, 0),
+            Margin::default(),
+            Padding::default(),
+            self.event_system.clone(),
+        );
+        Dialog::new(base, title).with_style(self.style.clone())
+    }
+
+    /// Opens the dialog and returns once it is closed
+    pub fn show_dialog(&mut self, dialog: &mut Dialog) -> DialogResult {
+        dialog.open(self.viewport);
+        self.present(dialog);
+
+        while dialog.is_open() {
+            match (self.input)() {
+                Some(input) => {
+                    dialog.handle_input(&input);
+                    self.present(dialog);
+                }
+                None => dialog.close(DialogResult::None),
+            }
+        }
+        dialog.result()
+    }
+
+    // Getters
+    pub fn event_system(&self) -> &Rc<RefCell<EventSystem>> {
+        &self.event_system
+    }
+
+    pub fn viewport(&self) -> Boundaries {
+        self.viewport
+    }
+
+    pub fn style(&self) -> &DialogStyle {
+        &self.style
+    }
+
+    fn present(&mut self, dialog: &Dialog) {
+        if let Some(presenter) = &mut self.presenter {
+            presenter(dialog);
+        }
+    }
+}
@@ -1,2 +1,14 @@
-#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
-pub struct Theme {}
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::styles::{
+    status_strip_style::StatusStripStyle, tool_strip_style::ToolStripStyle,
+};
+
+/// Styles shared across a window. Components that follow the theme take
+/// their look from it through `apply_theme`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub status_strip: StatusStripStyle,
+    pub tool_strip: ToolStripStyle,
+}
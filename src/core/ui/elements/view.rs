@@ -1,2 +1,45 @@
-#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
-pub struct View {}
+use std::{cell::RefCell, rc::Rc};
+
+use crate::core::{
+    components::properties::{boundaries::Boundaries, position::Position, size::Size},
+    ui::notification_service::NotificationService,
+    window::events::event_system::EventSystem,
+};
+
+/// Root of a window's content, owning the services shared by its components
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct View {
+    notifications: NotificationService,
+}
+
+// A view of its own, on an event system nothing else shares
+impl Default for View {
+    fn default() -> Self {
+        Self::new(
+            Rc::new(RefCell::new(EventSystem::default())),
+            Boundaries::from_rect(Position::default(), Size::default()),
+        )
+    }
+}
+
+impl View {
+    pub fn new(event_system: Rc<RefCell<EventSystem>>, viewport: Boundaries) -> Self {
+        Self {
+            notifications: NotificationService::new(event_system, viewport),
+        }
+    }
+
+    /// Resizes the area the view's overlays are placed in
+    pub fn set_viewport(&mut self, viewport: Boundaries) {
+        self.notifications.set_viewport(viewport);
+    }
+
+    // Getters
+    pub fn notifications(&self) -> &NotificationService {
+        &self.notifications
+    }
+
+    pub fn notifications_mut(&mut self) -> &mut NotificationService {
+        &mut self.notifications
+    }
+}
@@ -0,0 +1,183 @@
+use std::fmt::Debug;
+
+/// Decoded image as 8-bit RGBA pixels with straight alpha, rows from the top
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Bitmap {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Debug for Bitmap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The pixels are left out, they would flood any output
+        f.debug_struct("Bitmap")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish()
+    }
+}
+
+impl Bitmap {
+    /// Fully transparent bitmap
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![0; width as usize * height as usize * 4],
+        }
+    }
+
+    /// Wraps RGBA data, `None` when its length does not match the size
+    pub fn from_rgba(width: u32, height: u32, pixels: Vec<u8>) -> Option<Self> {
+        (pixels.len() == width as usize * height as usize * 4).then_some(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    // Setters
+    pub fn set_pixel(&mut self, x: u32, y: u32, rgba: [u8; 4]) {
+        if x < self.width && y < self.height {
+            let index = self.index(x, y);
+            self.pixels[index..index + 4].copy_from_slice(&rgba);
+        }
+    }
+
+    // Getters
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        (x < self.width && y < self.height).then(|| {
+            let index = self.index(x, y);
+            [
+                self.pixels[index],
+                self.pixels[index + 1],
+                self.pixels[index + 2],
+                self.pixels[index + 3],
+            ]
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Copy of a rectangle of the bitmap, cut at its edges
+    pub fn crop(&self, x: u32, y: u32, width: u32, height: u32) -> Bitmap {
+        let width = width.min(self.width.saturating_sub(x));
+        let height = height.min(self.height.saturating_sub(y));
+        let mut cropped = Bitmap::new(width, height);
+        let row = width as usize * 4;
+        for line in 0..height {
+            let from = self.index(x, y + line);
+            let to = cropped.index(0, line);
+            cropped.pixels[to..to + row].copy_from_slice(&self.pixels[from..from + row]);
+        }
+        cropped
+    }
+
+    /// Resamples the bitmap to a new size. Shrinking averages every source
+    /// pixel under a target pixel; growing interpolates between neighbours
+    pub fn scaled(&self, width: u32, height: u32) -> Bitmap {
+        if (width, height) == self.size() {
+            return self.clone();
+        }
+        let mut scaled = Bitmap::new(width, height);
+        if self.is_empty() || scaled.is_empty() {
+            return scaled;
+        }
+
+        let scale_x = self.width as f64 / width as f64;
+        let scale_y = self.height as f64 / height as f64;
+        for y in 0..height {
+            for x in 0..width {
+                let rgba = if scale_x > 1.0 || scale_y > 1.0 {
+                    self.average(x as f64 * scale_x, y as f64 * scale_y, scale_x, scale_y)
+                } else {
+                    self.interpolate(
+                        (x as f64 + 0.5) * scale_x - 0.5,
+                        (y as f64 + 0.5) * scale_y - 0.5,
+                    )
+                };
+                scaled.set_pixel(x, y, rgba);
+            }
+        }
+        scaled
+    }
+
+    fn index(&self, x: u32, y: u32) -> usize {
+        (y as usize * self.width as usize + x as usize) * 4
+    }
+
+    // Colors are weighted by alpha so transparent pixels do not bleed their color
+    fn blend(samples: impl Iterator<Item = ([u8; 4], f64)>) -> [u8; 4] {
+        let mut sum = [0.0; 4];
+        let mut total = 0.0;
+        for (rgba, weight) in samples {
+            let alpha = rgba[3] as f64 * weight;
+            for channel in 0..3 {
+                sum[channel] += rgba[channel] as f64 * alpha;
+            }
+            sum[3] += alpha;
+            total += weight;
+        }
+        if sum[3] == 0.0 || total == 0.0 {
+            return [0; 4];
+        }
+        [
+            (sum[0] / sum[3]).round() as u8,
+            (sum[1] / sum[3]).round() as u8,
+            (sum[2] / sum[3]).round() as u8,
+            (sum[3] / total).round() as u8,
+        ]
+    }
+
+    fn average(&self, left: f64, top: f64, width: f64, height: f64) -> [u8; 4] {
+        let x_end = ((left + width).ceil() as u32).min(self.width);
+        let y_end = ((top + height).ceil() as u32).min(self.height);
+        let samples = (top as u32..y_end).flat_map(|y| {
+            (left as u32..x_end).map(move |x| {
+                // Share of the source pixel covered by the target pixel
+                let cover_x = (x as f64 + 1.0).min(left + width) - (x as f64).max(left);
+                let cover_y = (y as f64 + 1.0).min(top + height) - (y as f64).max(top);
+                (x, y, cover_x.max(0.0) * cover_y.max(0.0))
+            })
+        });
+        Self::blend(samples.filter_map(|(x, y, weight)| Some((self.pixel(x, y)?, weight))))
+    }
+
+    fn interpolate(&self, x: f64, y: f64) -> [u8; 4] {
+        let x = x.clamp(0.0, (self.width - 1) as f64);
+        let y = y.clamp(0.0, (self.height - 1) as f64);
+        let (x0, y0) = (x.floor() as u32, y.floor() as u32);
+        let (x1, y1) = ((x0 + 1).min(self.width - 1), (y0 + 1).min(self.height - 1));
+        let (fx, fy) = (x - x0 as f64, y - y0 as f64);
+        let samples = [
+            (x0, y0, (1.0 - fx) * (1.0 - fy)),
+            (x1, y0, fx * (1.0 - fy)),
+            (x0, y1, (1.0 - fx) * fy),
+            (x1, y1, fx * fy),
+        ];
+        Self::blend(
+            samples
+                .into_iter()
+                .filter_map(|(x, y, weight)| Some((self.pixel(x, y)?, weight))),
+        )
+    }
+}
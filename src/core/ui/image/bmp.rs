@@ -0,0 +1,234 @@
+use crate::core::ui::image::{
+    bitmap::Bitmap,
+    decoder::{ImageError, Reader, check_size},
+};
+
+const FILE_HEADER_SIZE: usize = 14;
+const CORE_HEADER_SIZE: u32 = 12;
+const INFO_HEADER_SIZE: u32 = 40;
+
+// Compression values of the info header
+const BI_RGB: u32 = 0;
+const BI_RLE8: u32 = 1;
+const BI_RLE4: u32 = 2;
+const BI_BITFIELDS: u32 = 3;
+const BI_ALPHABITFIELDS: u32 = 6;
+
+pub fn decode(bytes: &[u8]) -> Result<Bitmap, ImageError> {
+    let reader = Reader::new(bytes);
+    if reader.slice(0, 2)? != b"BM" {
+        return Err(ImageError::Invalid("missing BMP signature"));
+    }
+    let pixel_offset = (reader.u32(10)? as usize)
+        .checked_sub(FILE_HEADER_SIZE)
+        .ok_or(ImageError::Invalid("bad pixel data offset"))?;
+    decode_dib(&bytes[FILE_HEADER_SIZE..], Some(pixel_offset), false)
+}
+
+/// Decodes a device independent bitmap, the body of BMP files and of most
+/// icons. Icons store twice the height, the second half being a 1-bit mask
+/// of transparent pixels
+pub(super) fn decode_dib(
+    bytes: &[u8],
+    pixel_offset: Option<usize>,
+    icon: bool,
+) -> Result<Bitmap, ImageError> {
+    let reader = Reader::new(bytes);
+    let header_size = reader.u32(0)?;
+    let (width, height, bpp, compression, colors_used, entry_size) =
+        if header_size == CORE_HEADER_SIZE {
+            (
+                reader.u16(4)? as i32,
+                reader.u16(6)? as i32,
+                reader.u16(10)?,
+                BI_RGB,
+                0,
+                3,
+            )
+        } else if header_size >= INFO_HEADER_SIZE {
+            (
+                reader.i32(4)?,
+                reader.i32(8)?,
+                reader.u16(14)?,
+                reader.u32(16)?,
+                reader.u32(32)?,
+                4,
+            )
+        } else {
+            return Err(ImageError::Invalid("unknown bitmap header"));
+        };
+
+    let height = if icon { height / 2 } else { height };
+    // Negative heights store rows from the top
+    let top_down = height < 0;
+    let (width, height) = (width.unsigned_abs(), height.unsigned_abs());
+    if width == 0 || height == 0 {
+        return Err(ImageError::Invalid("empty bitmap"));
+    }
+    check_size(width, height)?;
+
+    let masks = match compression {
+        BI_RGB => Masks::default_for(bpp),
+        BI_BITFIELDS | BI_ALPHABITFIELDS => {
+            // Masks follow an info header and are part of the larger ones, so
+            // they start right after the info fields either way
+            let offset = INFO_HEADER_SIZE as usize;
+            let alpha = if compression == BI_ALPHABITFIELDS || header_size >= 56 {
+                reader.u32(offset + 12)?
+            } else {
+                0
+            };
+            Masks {
+                red: reader.u32(offset)?,
+                green: reader.u32(offset + 4)?,
+                blue: reader.u32(offset + 8)?,
+                alpha,
+            }
+        }
+        BI_RLE8 | BI_RLE4 => return Err(ImageError::Unsupported("run-length encoded bitmaps")),
+        _ => return Err(ImageError::Unsupported("compressed bitmaps")),
+    };
+    if !matches!(bpp, 1 | 4 | 8 | 16 | 24 | 32) {
+        return Err(ImageError::Invalid("bad bitmap bit depth"));
+    }
+
+    let mut palette_offset = header_size as usize;
+    if header_size == INFO_HEADER_SIZE {
+        palette_offset += match compression {
+            BI_BITFIELDS => 12,
+            BI_ALPHABITFIELDS => 16,
+            _ => 0,
+        };
+    }
+    let palette = if bpp <= 8 {
+        let count = if colors_used == 0 || colors_used > 1 << bpp {
+            1 << bpp
+        } else {
+            colors_used as usize
+        };
+        (0..count)
+            .map(|i| {
+                let entry = reader.slice(palette_offset + i * entry_size, 3)?;
+                Ok([entry[2], entry[1], entry[0], 255])
+            })
+            .collect::<Result<Vec<_>, ImageError>>()?
+    } else {
+        Vec::new()
+    };
+    let pixel_offset = pixel_offset.unwrap_or(palette_offset + palette.len() * entry_size);
+
+    let stride = (width as usize * bpp as usize).div_ceil(32) * 4;
+    let row_at = |y: u32| {
+        let stored = if top_down { y } else { height - 1 - y };
+        reader.slice(pixel_offset + stored as usize * stride, stride)
+    };
+
+    let mut bitmap = Bitmap::new(width, height);
+    let mut has_alpha = false;
+    for y in 0..height {
+        let row = row_at(y)?;
+        for x in 0..width as usize {
+            let rgba = match bpp {
+                1 | 4 | 8 => {
+                    let bit = x * bpp as usize;
+                    let shift = 8 - bpp as usize - bit % 8;
+                    let index = (row[bit / 8] >> shift) & ((1u16 << bpp) - 1) as u8;
+                    palette
+                        .get(index as usize)
+                        .copied()
+                        .unwrap_or([0, 0, 0, 255])
+                }
+                16 => masks.apply(u16::from_le_bytes([row[x * 2], row[x * 2 + 1]]) as u32),
+                24 => [row[x * 3 + 2], row[x * 3 + 1], row[x * 3], 255],
+                _ => masks.apply(u32::from_le_bytes([
+                    row[x * 4],
+                    row[x * 4 + 1],
+                    row[x * 4 + 2],
+                    row[x * 4 + 3],
+                ])),
+            };
+            has_alpha |= masks.alpha != 0 && rgba[3] != 0;
+            bitmap.set_pixel(x as u32, y, rgba);
+        }
+    }
+
+    // An alpha channel left at zero everywhere is padding, not transparency
+    if masks.alpha != 0 && !has_alpha {
+        for y in 0..height {
+            for x in 0..width {
+                let [r, g, b, _] = bitmap.pixel(x, y).unwrap_or_default();
+                bitmap.set_pixel(x, y, [r, g, b, 255]);
+            }
+        }
+    }
+
+    if icon && !has_alpha {
+        let mask_offset = pixel_offset + stride * height as usize;
+        let mask_stride = (width as usize).div_ceil(32) * 4;
+        for y in 0..height {
+            let stored = if top_down { y } else { height - 1 - y };
+            // Some icons leave the mask out; they are then fully opaque
+            let Ok(row) = reader.slice(mask_offset + stored as usize * mask_stride, mask_stride)
+            else {
+                break;
+            };
+            for x in 0..width as usize {
+                if row[x / 8] & (0x80 >> (x % 8)) != 0 {
+                    bitmap.set_pixel(x as u32, y, [0; 4]);
+                }
+            }
+        }
+    }
+    Ok(bitmap)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Masks {
+    red: u32,
+    green: u32,
+    blue: u32,
+    alpha: u32,
+}
+
+impl Masks {
+    fn default_for(bpp: u16) -> Self {
+        match bpp {
+            // 5 bits per channel
+            16 => Self {
+                red: 0x7C00,
+                green: 0x03E0,
+                blue: 0x001F,
+                alpha: 0,
+            },
+            // Only 32-bit pixels have room for alpha
+            _ => Self {
+                red: 0x00FF_0000,
+                green: 0x0000_FF00,
+                blue: 0x0000_00FF,
+                alpha: if bpp == 32 { 0xFF00_0000 } else { 0 },
+            },
+        }
+    }
+
+    fn apply(&self, value: u32) -> [u8; 4] {
+        let channel = |mask: u32| {
+            if mask == 0 {
+                return 0;
+            }
+            let shifted = (value & mask) >> mask.trailing_zeros();
+            let max = mask >> mask.trailing_zeros();
+            (shifted as u64 * 255 / max as u64) as u8
+        };
+        let alpha = if self.alpha == 0 {
+            255
+        } else {
+            channel(self.alpha)
+        };
+        [
+            channel(self.red),
+            channel(self.green),
+            channel(self.blue),
+            alpha,
+        ]
+    }
+}
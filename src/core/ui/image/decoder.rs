@@ -0,0 +1,119 @@
+use std::{fmt::Display, fs, path::Path};
+
+use crate::core::ui::image::{bitmap::Bitmap, bmp, ico, png};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageError {
+    // The file could not be read
+    Io(String),
+    // The data is not a PNG, BMP or ICO image
+    UnknownFormat,
+    // The data ends before the image does
+    Truncated,
+    Invalid(&'static str),
+    // Valid image using a feature that is not decoded, such as RLE bitmaps
+    Unsupported(&'static str),
+}
+
+impl Display for ImageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImageError::Io(message) => write!(f, "could not read image: {message}"),
+            ImageError::UnknownFormat => write!(f, "unknown image format"),
+            ImageError::Truncated => write!(f, "image data is truncated"),
+            ImageError::Invalid(reason) => write!(f, "invalid image: {reason}"),
+            ImageError::Unsupported(feature) => write!(f, "unsupported image feature: {feature}"),
+        }
+    }
+}
+
+impl std::error::Error for ImageError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    Png,
+    Bmp,
+    Ico,
+}
+
+impl ImageFormat {
+    /// Recognizes the format from the first bytes of the data
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(png::SIGNATURE) {
+            Some(ImageFormat::Png)
+        } else if bytes.starts_with(b"BM") {
+            Some(ImageFormat::Bmp)
+        } else if matches!(bytes, [0, 0, 1 | 2, 0, ..]) {
+            // Icons and cursors share the container
+            Some(ImageFormat::Ico)
+        } else {
+            None
+        }
+    }
+}
+
+// Larger images are refused before allocating, headers are not trusted
+const MAX_PIXELS: u64 = 1 << 26;
+
+pub(super) fn check_size(width: u32, height: u32) -> Result<(), ImageError> {
+    if width as u64 * height as u64 > MAX_PIXELS {
+        return Err(ImageError::Unsupported("image too large"));
+    }
+    Ok(())
+}
+
+pub fn decode(bytes: &[u8]) -> Result<Bitmap, ImageError> {
+    decode_sized(bytes, None)
+}
+
+/// Decodes an image; for icons holding several images, the one that best
+/// fits `size` is picked. The result keeps the size stored in the file
+pub fn decode_sized(bytes: &[u8], size: Option<(u32, u32)>) -> Result<Bitmap, ImageError> {
+    match ImageFormat::detect(bytes) {
+        Some(ImageFormat::Png) => png::decode(bytes),
+        Some(ImageFormat::Bmp) => bmp::decode(bytes),
+        Some(ImageFormat::Ico) => ico::decode(bytes, size),
+        None => Err(ImageError::UnknownFormat),
+    }
+}
+
+pub fn load(path: impl AsRef<Path>, size: Option<(u32, u32)>) -> Result<Bitmap, ImageError> {
+    let bytes = fs::read(path).map_err(|error| ImageError::Io(error.to_string()))?;
+    decode_sized(&bytes, size)
+}
+
+/// Little-endian reader over image data that fails with `Truncated`
+pub(super) struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn slice(&self, offset: usize, length: usize) -> Result<&'a [u8], ImageError> {
+        offset
+            .checked_add(length)
+            .and_then(|end| self.bytes.get(offset..end))
+            .ok_or(ImageError::Truncated)
+    }
+
+    pub fn u8(&self, offset: usize) -> Result<u8, ImageError> {
+        Ok(self.slice(offset, 1)?[0])
+    }
+
+    pub fn u16(&self, offset: usize) -> Result<u16, ImageError> {
+        let bytes = self.slice(offset, 2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    pub fn u32(&self, offset: usize) -> Result<u32, ImageError> {
+        let bytes = self.slice(offset, 4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    pub fn i32(&self, offset: usize) -> Result<i32, ImageError> {
+        Ok(self.u32(offset)? as i32)
+    }
+}
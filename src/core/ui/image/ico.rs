@@ -0,0 +1,68 @@
+use crate::core::ui::image::{
+    bitmap::Bitmap,
+    bmp,
+    decoder::{ImageError, Reader},
+    png,
+};
+
+const HEADER_SIZE: usize = 6;
+const ENTRY_SIZE: usize = 16;
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    width: u32,
+    height: u32,
+    bpp: u16,
+    length: usize,
+    offset: usize,
+}
+
+/// Decodes the image of an icon or cursor closest to `size`: the exact size
+/// if present, else the smallest larger one, else the largest. Without a
+/// size the largest image is used. Ties go to the deepest color
+pub fn decode(bytes: &[u8], size: Option<(u32, u32)>) -> Result<Bitmap, ImageError> {
+    let reader = Reader::new(bytes);
+    let count = reader.u16(4)? as usize;
+    let entries = (0..count)
+        .map(|i| {
+            let at = HEADER_SIZE + i * ENTRY_SIZE;
+            // A stored size of 0 means 256
+            let dimension = |offset| -> Result<u32, ImageError> {
+                Ok(match reader.u8(at + offset)? {
+                    0 => 256,
+                    value => value as u32,
+                })
+            };
+            Ok(Entry {
+                width: dimension(0)?,
+                height: dimension(1)?,
+                bpp: reader.u16(at + 6)?,
+                length: reader.u32(at + 8)? as usize,
+                offset: reader.u32(at + 12)? as usize,
+            })
+        })
+        .collect::<Result<Vec<_>, ImageError>>()?;
+
+    let area = |entry: &Entry| entry.width as u64 * entry.height as u64;
+    let entry = match size {
+        Some((width, height)) => entries.iter().min_by_key(|entry| {
+            let exact = entry.width == width && entry.height == height;
+            let fits = entry.width >= width && entry.height >= height;
+            let distance = if fits {
+                area(entry)
+            } else {
+                u64::MAX - area(entry)
+            };
+            (!exact, !fits, distance, u16::MAX - entry.bpp)
+        }),
+        None => entries.iter().max_by_key(|entry| (area(entry), entry.bpp)),
+    }
+    .ok_or(ImageError::Invalid("icon without images"))?;
+
+    let data = reader.slice(entry.offset, entry.length)?;
+    if data.starts_with(png::SIGNATURE) {
+        png::decode(data)
+    } else {
+        bmp::decode_dib(data, None, true)
+    }
+}
@@ -0,0 +1,82 @@
+use std::{collections::HashMap, rc::Rc};
+
+use crate::core::ui::image::{
+    bitmap::Bitmap,
+    decoder::{self, ImageError},
+};
+
+// Path and requested size, `None` being the size in the file
+type CacheKey = (String, Option<(u32, u32)>);
+
+/// Decoded images by path, so each file is read once per size it is shown at
+#[derive(Debug, Clone, Default)]
+pub struct ImageCache {
+    bitmaps: HashMap<CacheKey, Rc<Bitmap>>,
+}
+
+impl ImageCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a bitmap under a path, e.g. for images embedded in the binary
+    pub fn insert(&mut self, path: &str, bitmap: Bitmap) {
+        // Sizes derived from a replaced image are stale
+        self.bitmaps.retain(|(cached, _), _| cached != path);
+        self.bitmaps
+            .insert((path.to_string(), None), Rc::new(bitmap));
+    }
+
+    /// The image at its own size
+    pub fn load(&mut self, path: &str) -> Result<Rc<Bitmap>, ImageError> {
+        let key = (path.to_string(), None);
+        if let Some(bitmap) = self.bitmaps.get(&key) {
+            return Ok(bitmap.clone());
+        }
+
+        let bitmap = Rc::new(decoder::load(path, None)?);
+        self.bitmaps.insert(key, bitmap.clone());
+        Ok(bitmap)
+    }
+
+    /// The image resampled to `size`. Icons are decoded from the entry that
+    /// best fits it
+    pub fn load_sized(&mut self, path: &str, size: (u32, u32)) -> Result<Rc<Bitmap>, ImageError> {
+        let key = (path.to_string(), Some(size));
+        if let Some(bitmap) = self.bitmaps.get(&key) {
+            return Ok(bitmap.clone());
+        }
+
+        let source = match self.bitmaps.get(&(path.to_string(), None)) {
+            Some(bitmap) => bitmap.clone(),
+            None => Rc::new(decoder::load(path, Some(size))?),
+        };
+        let bitmap = if source.size() == size {
+            source
+        } else {
+            Rc::new(source.scaled(size.0, size.1))
+        };
+        self.bitmaps.insert(key, bitmap.clone());
+        Ok(bitmap)
+    }
+
+    pub fn remove(&mut self, path: &str) {
+        self.bitmaps.retain(|(cached, _), _| cached != path);
+    }
+
+    pub fn clear(&mut self) {
+        self.bitmaps.clear();
+    }
+
+    pub fn contains(&self, path: &str, size: Option<(u32, u32)>) -> bool {
+        self.bitmaps.contains_key(&(path.to_string(), size))
+    }
+
+    pub fn len(&self) -> usize {
+        self.bitmaps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bitmaps.is_empty()
+    }
+}
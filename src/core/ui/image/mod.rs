@@ -0,0 +1,6 @@
+pub mod bitmap;
+pub mod bmp;
+pub mod decoder;
+pub mod ico;
+pub mod image_cache;
+pub mod png;
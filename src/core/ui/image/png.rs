@@ -0,0 +1,310 @@
+use crate::core::ui::image::{
+    bitmap::Bitmap,
+    decoder::{ImageError, check_size},
+};
+
+pub(super) const SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+// Origin and spacing of the seven Adam7 passes as (x, y, dx, dy)
+const ADAM7: [(u32, u32, u32, u32); 7] = [
+    (0, 0, 8, 8),
+    (4, 0, 8, 8),
+    (0, 4, 4, 8),
+    (2, 0, 4, 4),
+    (0, 2, 2, 4),
+    (1, 0, 2, 2),
+    (0, 1, 1, 2),
+];
+
+#[derive(Debug, Clone, Copy)]
+struct Header {
+    width: u32,
+    height: u32,
+    bit_depth: u8,
+    color_type: u8,
+    interlaced: bool,
+}
+
+impl Header {
+    fn channels(&self) -> u32 {
+        match self.color_type {
+            2 => 3,
+            4 => 2,
+            6 => 4,
+            _ => 1,
+        }
+    }
+
+    fn bits_per_pixel(&self) -> u32 {
+        self.channels() * self.bit_depth as u32
+    }
+}
+
+pub fn decode(bytes: &[u8]) -> Result<Bitmap, ImageError> {
+    if !bytes.starts_with(SIGNATURE) {
+        return Err(ImageError::Invalid("missing PNG signature"));
+    }
+
+    let mut header = None;
+    let mut palette: Vec<[u8; 3]> = Vec::new();
+    let mut transparency: Option<Vec<u8>> = None;
+    let mut data = Vec::new();
+    let mut offset = SIGNATURE.len();
+    loop {
+        let length = be_u32(bytes, offset)? as usize;
+        let chunk = slice(bytes, offset + 4, length + 4)?;
+        let (kind, body) = chunk.split_at(4);
+        if be_u32(bytes, offset + 8 + length)? != crc32(chunk) {
+            return Err(ImageError::Invalid("chunk checksum mismatch"));
+        }
+
+        match kind {
+            b"IHDR" => header = Some(parse_header(body)?),
+            b"PLTE" => {
+                palette = body
+                    .chunks_exact(3)
+                    .map(|rgb| [rgb[0], rgb[1], rgb[2]])
+                    .collect();
+            }
+            b"tRNS" => transparency = Some(body.to_vec()),
+            b"IDAT" => data.extend_from_slice(body),
+            b"IEND" => break,
+            // Ancillary chunks start with a lowercase letter and can be skipped
+            _ if kind[0].is_ascii_uppercase() => {
+                return Err(ImageError::Unsupported("unknown critical PNG chunk"));
+            }
+            _ => {}
+        }
+        offset += length + 12;
+    }
+
+    let header = header.ok_or(ImageError::Invalid("missing PNG header"))?;
+    if header.color_type == 3 && palette.is_empty() {
+        return Err(ImageError::Invalid("indexed PNG without palette"));
+    }
+    let data = miniz_oxide::inflate::decompress_to_vec_zlib(&data)
+        .map_err(|_| ImageError::Invalid("corrupt PNG data"))?;
+
+    let passes: &[(u32, u32, u32, u32)] = if header.interlaced {
+        &ADAM7
+    } else {
+        &[(0, 0, 1, 1)]
+    };
+    let converter = Converter {
+        header,
+        palette,
+        transparency,
+    };
+    let mut bitmap = Bitmap::new(header.width, header.height);
+    let mut rest = data.as_slice();
+    for &(x0, y0, dx, dy) in passes {
+        let width = header.width.saturating_sub(x0).div_ceil(dx);
+        let height = header.height.saturating_sub(y0).div_ceil(dy);
+        if width == 0 || height == 0 {
+            continue;
+        }
+
+        let stride = (width as usize * header.bits_per_pixel() as usize).div_ceil(8);
+        let length = (stride + 1) * height as usize;
+        if rest.len() < length {
+            return Err(ImageError::Truncated);
+        }
+        let (pass, next) = rest.split_at(length);
+        rest = next;
+
+        let rows = unfilter(pass, stride, header.bits_per_pixel().div_ceil(8) as usize)?;
+        for (y, row) in rows.chunks_exact(stride).enumerate() {
+            for x in 0..width {
+                let rgba = converter.pixel(row, x as usize);
+                bitmap.set_pixel(x0 + x * dx, y0 + y as u32 * dy, rgba);
+            }
+        }
+    }
+    Ok(bitmap)
+}
+
+fn parse_header(body: &[u8]) -> Result<Header, ImageError> {
+    // No interlacing or Adam7, the only two methods PNG defines
+    let interlaced = match *body.get(12).ok_or(ImageError::Truncated)? {
+        0 => false,
+        1 => true,
+        _ => return Err(ImageError::Invalid("bad PNG interlace method")),
+    };
+    let header = Header {
+        width: be_u32(body, 0)?,
+        height: be_u32(body, 4)?,
+        bit_depth: *body.get(8).ok_or(ImageError::Truncated)?,
+        color_type: *body.get(9).ok_or(ImageError::Truncated)?,
+        interlaced,
+    };
+    let valid = match header.color_type {
+        0 => matches!(header.bit_depth, 1 | 2 | 4 | 8 | 16),
+        3 => matches!(header.bit_depth, 1 | 2 | 4 | 8),
+        2 | 4 | 6 => matches!(header.bit_depth, 8 | 16),
+        _ => false,
+    };
+    if !valid {
+        return Err(ImageError::Invalid("bad PNG color type or bit depth"));
+    }
+    if header.width == 0 || header.height == 0 {
+        return Err(ImageError::Invalid("empty PNG"));
+    }
+    check_size(header.width, header.height)?;
+    Ok(header)
+}
+
+// Reverses the per-row filters, returning the rows without their filter byte
+fn unfilter(data: &[u8], stride: usize, bpp: usize) -> Result<Vec<u8>, ImageError> {
+    let rows = data.len() / (stride + 1);
+    let mut out = vec![0u8; rows * stride];
+    for row in 0..rows {
+        let filter = data[row * (stride + 1)];
+        let line = &data[row * (stride + 1) + 1..(row + 1) * (stride + 1)];
+        let (done, current) = out.split_at_mut(row * stride);
+        let previous = (row > 0).then(|| &done[(row - 1) * stride..]);
+        let current = &mut current[..stride];
+        for i in 0..stride {
+            let left = if i >= bpp { current[i - bpp] } else { 0 };
+            let up = previous.map_or(0, |previous| previous[i]);
+            let up_left = match previous {
+                Some(previous) if i >= bpp => previous[i - bpp],
+                _ => 0,
+            };
+            let predictor = match filter {
+                0 => 0,
+                1 => left,
+                2 => up,
+                3 => ((left as u16 + up as u16) / 2) as u8,
+                4 => paeth(left, up, up_left),
+                _ => return Err(ImageError::Invalid("unknown PNG filter")),
+            };
+            current[i] = line[i].wrapping_add(predictor);
+        }
+    }
+    Ok(out)
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = a as i16 + b as i16 - c as i16;
+    let (pa, pb, pc) = (
+        (p - a as i16).abs(),
+        (p - b as i16).abs(),
+        (p - c as i16).abs(),
+    );
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+struct Converter {
+    header: Header,
+    palette: Vec<[u8; 3]>,
+    transparency: Option<Vec<u8>>,
+}
+
+impl Converter {
+    // Raw sample at an index of the row, at the bit depth of the image
+    fn sample(&self, row: &[u8], index: usize) -> u16 {
+        let depth = self.header.bit_depth as usize;
+        match depth {
+            16 => u16::from_be_bytes([row[index * 2], row[index * 2 + 1]]),
+            8 => row[index] as u16,
+            _ => {
+                let bit = index * depth;
+                let shift = 8 - depth - bit % 8;
+                ((row[bit / 8] >> shift) & ((1 << depth) - 1) as u8) as u16
+            }
+        }
+    }
+
+    fn to_u8(&self, sample: u16) -> u8 {
+        match self.header.bit_depth {
+            16 => (sample >> 8) as u8,
+            depth => (sample as u32 * 255 / ((1u32 << depth) - 1)) as u8,
+        }
+    }
+
+    fn pixel(&self, row: &[u8], x: usize) -> [u8; 4] {
+        let channels = self.header.channels() as usize;
+        let sample = |channel: usize| self.sample(row, x * channels + channel);
+        // Color keys in tRNS are stored as 16-bit values at the image depth
+        let keyed = |values: &[u16]| {
+            self.transparency.as_ref().is_some_and(|key| {
+                values.iter().enumerate().all(|(i, value)| {
+                    key.get(i * 2..i * 2 + 2)
+                        .is_some_and(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]) == *value)
+                })
+            })
+        };
+
+        match self.header.color_type {
+            0 => {
+                let gray = sample(0);
+                let alpha = if keyed(&[gray]) { 0 } else { 255 };
+                let gray = self.to_u8(gray);
+                [gray, gray, gray, alpha]
+            }
+            2 => {
+                let rgb = [sample(0), sample(1), sample(2)];
+                let alpha = if keyed(&rgb) { 0 } else { 255 };
+                [
+                    self.to_u8(rgb[0]),
+                    self.to_u8(rgb[1]),
+                    self.to_u8(rgb[2]),
+                    alpha,
+                ]
+            }
+            3 => {
+                let index = sample(0) as usize;
+                let [r, g, b] = self.palette.get(index).copied().unwrap_or([0; 3]);
+                let alpha = self
+                    .transparency
+                    .as_ref()
+                    .and_then(|alphas| alphas.get(index).copied())
+                    .unwrap_or(255);
+                [r, g, b, alpha]
+            }
+            4 => {
+                let gray = self.to_u8(sample(0));
+                [gray, gray, gray, self.to_u8(sample(1))]
+            }
+            _ => [
+                self.to_u8(sample(0)),
+                self.to_u8(sample(1)),
+                self.to_u8(sample(2)),
+                self.to_u8(sample(3)),
+            ],
+        }
+    }
+}
+
+fn slice(bytes: &[u8], offset: usize, length: usize) -> Result<&[u8], ImageError> {
+    offset
+        .checked_add(length)
+        .and_then(|end| bytes.get(offset..end))
+        .ok_or(ImageError::Truncated)
+}
+
+fn be_u32(bytes: &[u8], offset: usize) -> Result<u32, ImageError> {
+    let bytes = slice(bytes, offset, 4)?;
+    Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
@@ -0,0 +1,133 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::core::{
+    components::{
+        base_component::BaseComponent,
+        elements::text_box::TextBox,
+        properties::{
+            boundaries::Boundaries,
+            graphics::{
+                border::{Border, BorderType},
+                color::Color,
+            },
+            typography::TypoGraphy,
+        },
+        styles::text_box_style::TextBoxStyle,
+    },
+    ui::render::render_context::RenderContext,
+    utils::traits::{component::Component, interactive::Interactive},
+    window::events::{event_system::EventSystem, input::InputEvent},
+};
+
+/// Borderless text box over the text area of a control that paints its own
+/// frame, such as the pickers, `NumericUpDown` or an editable `ComboBox`.
+///
+/// The text box is built on the first `place`, so a control only has to call
+/// it after being built or deserialized and again whenever its frame may have
+/// changed. `paint` draws a text box placed for an older frame over the
+/// current text area without moving it.
+#[derive(Debug, Clone, Default)]
+pub struct InlineEditor {
+    text_box: Option<TextBox>,
+}
+
+impl InlineEditor {
+    /// Moves the text box over `bounds`, building it first with `initial_text`
+    /// if there is none
+    pub fn place(
+        &mut self,
+        owner: &BaseComponent,
+        typography: &TypoGraphy,
+        bounds: Boundaries,
+        initial_text: &str,
+    ) {
+        let text_box = self.text_box.get_or_insert_with(|| {
+            let base = BaseComponent::new(
+                bounds.size(),
+                bounds.top_left(),
+                Default::default(),
+                *owner.padding(),
+                owner.event_system().clone(),
+            );
+            // The owner paints the frame, so the editor draws no border of its own
+            let no_border = Border::new(Color::rgba(0, 0, 0, 0), 0.0, BorderType::Solid);
+            let style = TextBoxStyle {
+                border: no_border.clone(),
+                focus_border: no_border,
+                typography: typography.clone(),
+                ..TextBoxStyle::default()
+            };
+            TextBox::new(base).with_text(initial_text).with_style(style)
+        });
+        text_box.base_mut().set_position(bounds.top_left());
+        text_box.base_mut().set_size(bounds.size());
+    }
+
+    /// Replaces the text unless the text box already shows it
+    pub fn show(&mut self, text: &str) {
+        if let Some(text_box) = &mut self.text_box
+            && text_box.text() != text
+        {
+            text_box.set_text(text);
+        }
+    }
+
+    /// Drops the text box, so the next `place` builds it again
+    pub fn clear(&mut self) {
+        self.text_box = None;
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if let Some(text_box) = &mut self.text_box {
+            text_box.set_enabled(enabled);
+        }
+    }
+
+    pub fn set_focused(&mut self, focused: bool) {
+        if let Some(text_box) = &mut self.text_box {
+            text_box.set_focused(focused);
+        }
+    }
+
+    // Getters
+    pub fn text_box(&self) -> Option<&TextBox> {
+        self.text_box.as_ref()
+    }
+
+    pub fn text_box_mut(&mut self) -> Option<&mut TextBox> {
+        self.text_box.as_mut()
+    }
+
+    /// Text being edited, empty while there is no text box
+    pub fn text(&self) -> &str {
+        self.text_box.as_ref().map_or("", TextBox::text)
+    }
+
+    pub fn handle_input(&mut self, input: &InputEvent) -> bool {
+        match &mut self.text_box {
+            Some(text_box) => text_box.handle_input(input),
+            None => false,
+        }
+    }
+
+    /// Paints the text box over `bounds`, the owner's text area for its
+    /// current frame
+    pub fn paint(&self, ctx: &mut RenderContext, bounds: Boundaries) {
+        let Some(text_box) = &self.text_box else {
+            return;
+        };
+        if text_box.base().frame() == bounds {
+            text_box.paint(ctx);
+            return;
+        }
+
+        // A copy reporting to a private event system, so placing it for the
+        // frame emits nothing
+        let mut text_box = text_box.clone();
+        let base = text_box.base_mut();
+        base.inyect_event_system(Rc::new(RefCell::new(EventSystem::default())));
+        base.set_position(bounds.top_left());
+        base.set_size(bounds.size());
+        text_box.paint(ctx);
+    }
+}
@@ -1,3 +1,17 @@
+pub mod accelerator_table;
+pub mod click_tracker;
 pub mod cursor_tracker;
+pub mod date_locale;
+pub mod dialog_host;
 pub mod elements;
+pub mod image;
+pub mod inline_editor;
+pub mod notification_service;
+pub mod popup;
+pub mod range_model;
+pub mod render;
+pub mod selection_model;
 pub mod systems;
+pub mod tooltip_manager;
+pub mod type_ahead;
+pub mod virtual_list;
@@ -0,0 +1,748 @@
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    hash::{Hash, Hasher},
+    rc::Rc,
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::{
+        properties::{
+            boundaries::Boundaries,
+            graphics::background::{Background, BackgroundShape},
+            position::Position,
+            size::Size,
+        },
+        styles::toast_style::ToastStyle,
+    },
+    ui::render::render_context::RenderContext,
+    window::events::{
+        event_system::EventSystem,
+        input::{InputEvent, MouseButton},
+        types::{EventPayload, EventType},
+    },
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Severity {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+/// Corner of the viewport toasts stack in, the newest one nearest to it
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToastCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+impl ToastCorner {
+    fn is_top(self) -> bool {
+        matches!(self, ToastCorner::TopLeft | ToastCorner::TopRight)
+    }
+
+    fn is_left(self) -> bool {
+        matches!(self, ToastCorner::TopLeft | ToastCorner::BottomLeft)
+    }
+}
+
+/// Button of a toast, which invokes a command and closes the toast
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ToastAction {
+    pub label: String,
+    pub command: usize,
+}
+
+/// Non-blocking notification with a title, a message and optional action
+/// buttons. It closes on its own after its duration unless it is persistent.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Toast {
+    severity: Severity,
+    title: String,
+    message: String,
+    duration: Option<Duration>,
+    actions: Vec<ToastAction>,
+}
+
+impl Toast {
+    pub fn new(severity: Severity, title: &str, message: &str) -> Self {
+        Self {
+            severity,
+            title: title.to_string(),
+            message: message.to_string(),
+            duration: Some(Duration::from_secs(5)),
+            actions: Vec::new(),
+        }
+    }
+
+    pub fn info(title: &str, message: &str) -> Self {
+        Self::new(Severity::Info, title, message)
+    }
+
+    pub fn success(title: &str, message: &str) -> Self {
+        Self::new(Severity::Success, title, message)
+    }
+
+    pub fn warning(title: &str, message: &str) -> Self {
+        Self::new(Severity::Warning, title, message)
+    }
+
+    pub fn error(title: &str, message: &str) -> Self {
+        Self::new(Severity::Error, title, message)
+    }
+
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Keeps the toast until it is closed or one of its actions is used
+    pub fn persistent(mut self) -> Self {
+        self.duration = None;
+        self
+    }
+
+    pub fn with_action(mut self, label: &str, command: usize) -> Self {
+        self.actions.push(ToastAction {
+            label: label.to_string(),
+            command,
+        });
+        self
+    }
+
+    // Getters
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration
+    }
+
+    pub fn actions(&self) -> &[ToastAction] {
+        &self.actions
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToastPart {
+    Body,
+    Close,
+    Action(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ToastPhase {
+    // Time since the toast started sliding in or out
+    Entering(Duration),
+    Shown,
+    Leaving(Duration),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ActiveToast {
+    id: usize,
+    toast: Toast,
+    phase: ToastPhase,
+    // Time left before it closes, paused while hovered
+    remaining: Option<Duration>,
+}
+
+/// In-window toast notifications, stacked in a corner of the viewport.
+///
+/// `show` queues a toast and returns its id. Toasts slide in from the edge
+/// of their corner, count down their duration while the pointer is not over
+/// them and slide out when they expire or are closed; toasts past
+/// `max_visible` wait until there is room. Using an action emits
+/// `CommandInvoked` with its command, and every toast emits `ItemClosed`
+/// with its id once it is gone. The owner feeds it input before the
+/// components, advances it with `tick` and renders it last.
+#[derive(Debug, Clone)]
+pub struct NotificationService {
+    event_system: Rc<RefCell<EventSystem>>,
+    // Id the events are emitted with
+    id: usize,
+    viewport: Boundaries,
+    corner: ToastCorner,
+    max_visible: usize,
+    animation_duration: Duration,
+    style: ToastStyle,
+    // Oldest first
+    active: Vec<ActiveToast>,
+    queue: VecDeque<(usize, Toast)>,
+    next_toast: usize,
+    hovered: Option<(usize, ToastPart)>,
+}
+
+// The event system is compared by identity, not by what it holds
+impl PartialEq for NotificationService {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.event_system, &other.event_system)
+            && self.id == other.id
+            && self.viewport == other.viewport
+            && self.corner == other.corner
+            && self.max_visible == other.max_visible
+            && self.animation_duration == other.animation_duration
+            && self.style == other.style
+            && self.active == other.active
+            && self.queue == other.queue
+            && self.next_toast == other.next_toast
+            && self.hovered == other.hovered
+    }
+}
+
+impl Eq for NotificationService {}
+
+impl Hash for NotificationService {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.event_system).hash(state);
+        self.id.hash(state);
+        self.viewport.hash(state);
+        self.corner.hash(state);
+        self.max_visible.hash(state);
+        self.animation_duration.hash(state);
+        self.style.hash(state);
+        self.active.hash(state);
+        self.queue.hash(state);
+        self.next_toast.hash(state);
+        self.hovered.hash(state);
+    }
+}
+
+impl NotificationService {
+    pub fn new(event_system: Rc<RefCell<EventSystem>>, viewport: Boundaries) -> Self {
+        let id = event_system.borrow_mut().get_next_id();
+        Self {
+            event_system,
+            id,
+            viewport,
+            corner: ToastCorner::default(),
+            max_visible: 5,
+            animation_duration: Duration::from_millis(200),
+            style: ToastStyle::default(),
+            active: Vec::new(),
+            queue: VecDeque::new(),
+            next_toast: 0,
+            hovered: None,
+        }
+    }
+
+    pub fn with_corner(mut self, corner: ToastCorner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    pub fn with_max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible.max(1);
+        self
+    }
+
+    pub fn with_style(mut self, style: ToastStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    // Setters
+    /// Area toasts are stacked in, usually the window client area
+    pub fn set_viewport(&mut self, viewport: Boundaries) {
+        self.viewport = viewport;
+    }
+
+    pub fn set_corner(&mut self, corner: ToastCorner) {
+        self.corner = corner;
+    }
+
+    pub fn set_max_visible(&mut self, max_visible: usize) {
+        self.max_visible = max_visible.max(1);
+        self.promote();
+    }
+
+    pub fn set_animation_duration(&mut self, duration: Duration) {
+        self.animation_duration = duration;
+        self.sweep();
+    }
+
+    pub fn set_style(&mut self, style: ToastStyle) {
+        self.style = style;
+    }
+
+    /// Queues a toast, returning the id it is reported with
+    pub fn show(&mut self, toast: Toast) -> usize {
+        let id = self.next_toast;
+        self.next_toast += 1;
+        self.queue.push_back((id, toast));
+        self.promote();
+        id
+    }
+
+    /// Slides a toast out, or drops it if it is still waiting
+    pub fn dismiss(&mut self, id: usize) -> bool {
+        if let Some(index) = self.queue.iter().position(|(queued, _)| *queued == id) {
+            self.queue.remove(index);
+            self.emit_closed(id);
+            return true;
+        }
+
+        let progress = self.progress(id);
+        let animation = self.animation_duration;
+        let Some(active) = self.active.iter_mut().find(|active| active.id == id) else {
+            return false;
+        };
+        if matches!(active.phase, ToastPhase::Leaving(_)) {
+            return false;
+        }
+        // Leaving halfway through entering starts from where it got to
+        let shown = animation.mul_f32(progress.unwrap_or(1.0));
+        active.phase = ToastPhase::Leaving(animation.saturating_sub(shown));
+        self.sweep();
+        true
+    }
+
+    pub fn dismiss_all(&mut self) {
+        for (id, _) in std::mem::take(&mut self.queue) {
+            self.emit_closed(id);
+        }
+        let ids: Vec<_> = self.active.iter().map(|active| active.id).collect();
+        for id in ids {
+            self.dismiss(id);
+        }
+    }
+
+    /// Closes toasts and runs actions under the pointer. Returns whether the
+    /// input was over a toast, in which case it is not for the components
+    pub fn handle_input(&mut self, input: &InputEvent) -> bool {
+        match *input {
+            InputEvent::MouseMoved(position) => {
+                self.hovered = self.part_at(position);
+                self.hovered.is_some()
+            }
+            InputEvent::MouseButtonPressed {
+                button: MouseButton::Left,
+                position,
+                ..
+            } => {
+                let Some((id, part)) = self.part_at(position) else {
+                    return false;
+                };
+                match part {
+                    ToastPart::Close => {
+                        self.dismiss(id);
+                    }
+                    ToastPart::Action(index) => {
+                        let command = self
+                            .toast(id)
+                            .and_then(|toast| toast.actions.get(index))
+                            .map(|action| action.command);
+                        if let Some(command) = command {
+                            self.event_system.borrow_mut().emit_with(
+                                EventType::CommandInvoked,
+                                self.id,
+                                EventPayload::Index(command),
+                            );
+                            self.dismiss(id);
+                        }
+                    }
+                    ToastPart::Body => {}
+                }
+                true
+            }
+            InputEvent::MouseButtonReleased { position, .. }
+            | InputEvent::MouseScrolled { position, .. } => self.part_at(position).is_some(),
+            _ => false,
+        }
+    }
+
+    /// Advances the animations and timers, returning whether anything moved
+    pub fn tick(&mut self, elapsed: Duration) -> bool {
+        let animation = self.animation_duration;
+        let hovered = self.hovered.map(|(id, _)| id);
+        let mut changed = false;
+        for active in &mut self.active {
+            match &mut active.phase {
+                ToastPhase::Entering(time) => {
+                    *time += elapsed;
+                    if *time >= animation {
+                        active.phase = ToastPhase::Shown;
+                    }
+                    changed = true;
+                }
+                ToastPhase::Shown if hovered != Some(active.id) => {
+                    if let Some(remaining) = &mut active.remaining {
+                        *remaining = remaining.saturating_sub(elapsed);
+                        if remaining.is_zero() {
+                            active.phase = ToastPhase::Leaving(Duration::ZERO);
+                            changed = true;
+                        }
+                    }
+                }
+                ToastPhase::Shown => {}
+                ToastPhase::Leaving(time) => {
+                    *time += elapsed;
+                    changed = true;
+                }
+            }
+        }
+        self.sweep();
+        changed
+    }
+
+    // Getters
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn viewport(&self) -> Boundaries {
+        self.viewport
+    }
+
+    pub fn corner(&self) -> ToastCorner {
+        self.corner
+    }
+
+    pub fn max_visible(&self) -> usize {
+        self.max_visible
+    }
+
+    pub fn animation_duration(&self) -> Duration {
+        self.animation_duration
+    }
+
+    pub fn style(&self) -> &ToastStyle {
+        &self.style
+    }
+
+    /// Ids of the toasts on screen, oldest first
+    pub fn visible_toasts(&self) -> Vec<usize> {
+        self.active.iter().map(|active| active.id).collect()
+    }
+
+    /// Number of toasts waiting for room
+    pub fn queued(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn toast(&self, id: usize) -> Option<&Toast> {
+        self.active
+            .iter()
+            .find(|active| active.id == id)
+            .map(|active| &active.toast)
+            .or_else(|| {
+                self.queue
+                    .iter()
+                    .find(|(queued, _)| *queued == id)
+                    .map(|(_, toast)| toast)
+            })
+    }
+
+    /// How far a toast is slid in, from 0 off screen to 1 in place
+    pub fn progress(&self, id: usize) -> Option<f32> {
+        let active = self.active.iter().find(|active| active.id == id)?;
+        let fraction = |time: Duration| {
+            if self.animation_duration.is_zero() {
+                1.0
+            } else {
+                (time.as_secs_f32() / self.animation_duration.as_secs_f32()).min(1.0)
+            }
+        };
+        Some(match active.phase {
+            ToastPhase::Entering(time) => fraction(time),
+            ToastPhase::Shown => 1.0,
+            ToastPhase::Leaving(time) => 1.0 - fraction(time),
+        })
+    }
+
+    /// Time left before a toast closes, `None` for persistent ones
+    pub fn remaining(&self, id: usize) -> Option<Duration> {
+        self.active
+            .iter()
+            .find(|active| active.id == id)
+            .and_then(|active| active.remaining)
+    }
+
+    /// Area of a toast, slid towards the edge while it enters or leaves
+    pub fn toast_bounds(&self, id: usize) -> Option<Boundaries> {
+        let (_, bounds) = self.layout().into_iter().find(|(toast, _)| *toast == id)?;
+        // Eased out, so toasts slow down as they settle
+        let eased = 1.0 - (1.0 - self.progress(id)?).powi(3);
+        let shift = ((1.0 - eased) * (bounds.width() + self.style.margin) as f32).round() as u16;
+        let x = if self.corner.is_left() {
+            bounds.top_left().x().saturating_sub(shift)
+        } else {
+            bounds.top_left().x() + shift
+        };
+        Some(Boundaries::from_rect(
+            Position::new(x, bounds.top_left().y()),
+            bounds.size(),
+        ))
+    }
+
+    pub fn part_bounds(&self, id: usize, part: ToastPart) -> Option<Boundaries> {
+        let bounds = self.toast_bounds(id)?;
+        let toast = self.toast(id)?;
+        let style = &self.style;
+        let right = bounds.bottom_right().x() - style.padding;
+        match part {
+            ToastPart::Body => Some(bounds),
+            ToastPart::Close => Some(Boundaries::from_rect(
+                Position::new(
+                    right - style.close_size,
+                    bounds.top_left().y() + style.padding,
+                ),
+                Size::new(style.close_size, style.close_size),
+            )),
+            ToastPart::Action(index) => {
+                toast.actions.get(index)?;
+                let y = bounds.bottom_right().y() - style.padding - style.button_height;
+                let mut x = bounds.top_left().x() + style.accent_width + style.padding;
+                for action in &toast.actions[..index] {
+                    x += self.button_width(action) + style.spacing;
+                }
+                Some(Boundaries::from_rect(
+                    Position::new(x, y),
+                    Size::new(
+                        style.button_height,
+                        self.button_width(&toast.actions[index]),
+                    ),
+                ))
+            }
+        }
+    }
+
+    /// Height of a toast at the style width
+    pub fn measure(&self, toast: &Toast) -> u16 {
+        let style = &self.style;
+        let title = style.title_typography.line_height().max(style.close_size);
+        let mut height = style.padding * 2 + title;
+        let lines = toast.message.lines().count() as u16;
+        if lines > 0 {
+            height += style.spacing + lines * style.typography.line_height();
+        }
+        if !toast.actions.is_empty() {
+            height += style.spacing + style.button_height;
+        }
+        height
+    }
+
+    // Resting place of every toast on screen, the newest nearest the corner
+    fn layout(&self) -> Vec<(usize, Boundaries)> {
+        let style = &self.style;
+        let viewport = self.viewport;
+        let x = if self.corner.is_left() {
+            viewport.top_left().x() + style.margin
+        } else {
+            viewport
+                .bottom_right()
+                .x()
+                .saturating_sub(style.margin + style.width)
+        };
+        let mut offset = style.margin;
+        self.active
+            .iter()
+            .rev()
+            .map(|active| {
+                let height = self.measure(&active.toast);
+                let y = if self.corner.is_top() {
+                    viewport.top_left().y() + offset
+                } else {
+                    viewport.bottom_right().y().saturating_sub(offset + height)
+                };
+                offset += height + style.spacing;
+                (
+                    active.id,
+                    Boundaries::from_rect(Position::new(x, y), Size::new(height, style.width)),
+                )
+            })
+            .collect()
+    }
+
+    fn part_at(&self, point: Position) -> Option<(usize, ToastPart)> {
+        self.active.iter().rev().find_map(|active| {
+            let id = active.id;
+            if !self.toast_bounds(id)?.contains(point) {
+                return None;
+            }
+            let part = (0..active.toast.actions.len())
+                .map(ToastPart::Action)
+                .chain([ToastPart::Close])
+                .find(|part| {
+                    self.part_bounds(id, *part)
+                        .is_some_and(|bounds| bounds.contains(point))
+                })
+                .unwrap_or(ToastPart::Body);
+            Some((id, part))
+        })
+    }
+
+    fn button_width(&self, action: &ToastAction) -> u16 {
+        self.style.typography.measure_text(&action.label).width() + self.style.button_padding * 2
+    }
+
+    // Drops toasts done leaving and brings in the waiting ones
+    fn sweep(&mut self) {
+        let animation = self.animation_duration;
+        let mut closed = Vec::new();
+        self.active.retain(|active| match active.phase {
+            ToastPhase::Leaving(time) if time >= animation => {
+                closed.push(active.id);
+                false
+            }
+            _ => true,
+        });
+        for id in closed {
+            if self.hovered.is_some_and(|(hovered, _)| hovered == id) {
+                self.hovered = None;
+            }
+            self.emit_closed(id);
+        }
+        for active in &mut self.active {
+            if matches!(active.phase, ToastPhase::Entering(time) if time >= animation) {
+                active.phase = ToastPhase::Shown;
+            }
+        }
+        self.promote();
+    }
+
+    fn promote(&mut self) {
+        while self.active.len() < self.max_visible {
+            let Some((id, toast)) = self.queue.pop_front() else {
+                break;
+            };
+            let phase = if self.animation_duration.is_zero() {
+                ToastPhase::Shown
+            } else {
+                ToastPhase::Entering(Duration::ZERO)
+            };
+            self.active.push(ActiveToast {
+                id,
+                remaining: toast.duration,
+                toast,
+                phase,
+            });
+        }
+    }
+
+    fn emit_closed(&self, id: usize) {
+        self.event_system.borrow_mut().emit_with(
+            EventType::ItemClosed,
+            self.id,
+            EventPayload::Index(id),
+        );
+    }
+
+    pub fn render(&self, ctx: &mut RenderContext) {
+        if self.active.is_empty() {
+            return;
+        }
+
+        let style = &self.style;
+        ctx.push_layer();
+        // Toasts sliding in or out are cut at the viewport edge
+        ctx.push_clip(self.viewport);
+        for active in &self.active {
+            let (id, toast) = (active.id, &active.toast);
+            let Some(bounds) = self.toast_bounds(id) else {
+                continue;
+            };
+            let (left, top) = (bounds.top_left().x(), bounds.top_left().y());
+            ctx.fill_rect(bounds, &style.background);
+            ctx.fill_rect(
+                Boundaries::from_rect(
+                    bounds.top_left(),
+                    Size::new(bounds.height(), style.accent_width),
+                ),
+                &Background::new_solid(
+                    style.accent(toast.severity).clone(),
+                    BackgroundShape::Rectangle,
+                ),
+            );
+            ctx.stroke_rect(bounds, &style.border);
+
+            let x = left + style.accent_width + style.padding;
+            let mut y = top + style.padding;
+            ctx.draw_text(
+                Position::new(x, y),
+                &toast.title,
+                &style.title_typography,
+                &style.text_color,
+            );
+            y += style.title_typography.line_height().max(style.close_size);
+
+            if let Some(close) = self.part_bounds(id, ToastPart::Close) {
+                if self.hovered == Some((id, ToastPart::Close)) {
+                    ctx.fill_rect(close, &style.hovered_background);
+                }
+                let (x0, y0) = (close.top_left().x() + 4, close.top_left().y() + 4);
+                let (x1, y1) = (
+                    close.bottom_right().x().saturating_sub(5),
+                    close.bottom_right().y().saturating_sub(5),
+                );
+                ctx.draw_line(
+                    Position::new(x0, y0),
+                    Position::new(x1, y1),
+                    &style.close_color,
+                    1,
+                );
+                ctx.draw_line(
+                    Position::new(x0, y1),
+                    Position::new(x1, y0),
+                    &style.close_color,
+                    1,
+                );
+            }
+
+            if !toast.message.is_empty() {
+                y += style.spacing;
+            }
+            for line in toast.message.lines() {
+                ctx.draw_text(
+                    Position::new(x, y),
+                    line,
+                    &style.typography,
+                    &style.text_color,
+                );
+                y += style.typography.line_height();
+            }
+
+            for (index, action) in toast.actions.iter().enumerate() {
+                let Some(button) = self.part_bounds(id, ToastPart::Action(index)) else {
+                    continue;
+                };
+                if self.hovered == Some((id, ToastPart::Action(index))) {
+                    ctx.fill_rect(button, &style.hovered_background);
+                } else {
+                    ctx.fill_rect(button, &style.button_background);
+                }
+                ctx.stroke_rect(button, &style.button_border);
+                let text = style.typography.measure_text(&action.label);
+                ctx.draw_text(
+                    Position::new(
+                        button.top_left().x() + style.button_padding,
+                        button.top_left().y() + button.height().saturating_sub(text.height()) / 2,
+                    ),
+                    &action.label,
+                    &style.typography,
+                    &style.button_text_color,
+                );
+            }
+        }
+        ctx.pop_clip();
+        ctx.pop_layer();
+    }
+}
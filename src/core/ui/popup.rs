@@ -0,0 +1,125 @@
+use crate::core::{
+    components::properties::{boundaries::Boundaries, position::Position, size::Size},
+    window::events::input::{InputEvent, Key},
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PopupPlacement {
+    #[default]
+    Below,
+    Above,
+    Right,
+    Left,
+}
+
+/// Placement and dismissal logic for a surface painted on an overlay layer.
+///
+/// The owner component keeps the popup, opens it against an anchor rectangle
+/// and renders its content between `push_layer` and `pop_layer`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Popup {
+    placement: PopupPlacement,
+    viewport: Boundaries,
+    bounds: Option<Boundaries>,
+}
+
+impl Default for Popup {
+    fn default() -> Self {
+        Self {
+            placement: PopupPlacement::default(),
+            viewport: Boundaries::from_rect(Position::default(), Size::new(u16::MAX, u16::MAX)),
+            bounds: None,
+        }
+    }
+}
+
+impl Popup {
+    pub fn new(placement: PopupPlacement) -> Self {
+        Self {
+            placement,
+            ..Self::default()
+        }
+    }
+
+    /// Area the popup must stay inside, usually the window client area
+    pub fn set_viewport(&mut self, viewport: Boundaries) {
+        self.viewport = viewport;
+    }
+
+    pub fn set_placement(&mut self, placement: PopupPlacement) {
+        self.placement = placement;
+    }
+
+    /// Opens the popup next to `anchor`, flipping to the opposite side when the
+    /// preferred one does not fit and clamping it inside the viewport
+    pub fn open(&mut self, anchor: Boundaries, size: Size) {
+        let viewport = self.viewport;
+        let (vx0, vy0) = (viewport.top_left().x(), viewport.top_left().y());
+        let (vx1, vy1) = (viewport.bottom_right().x(), viewport.bottom_right().y());
+        let (ax0, ay0) = (anchor.top_left().x(), anchor.top_left().y());
+        let (ax1, ay1) = (anchor.bottom_right().x(), anchor.bottom_right().y());
+        let (width, height) = (size.width(), size.height());
+
+        let fits_below = ay1.saturating_add(height) <= vy1;
+        let fits_above = ay0.saturating_sub(vy0) >= height;
+        let fits_right = ax1.saturating_add(width) <= vx1;
+        let fits_left = ax0.saturating_sub(vx0) >= width;
+
+        let placement = match self.placement {
+            PopupPlacement::Below if !fits_below && fits_above => PopupPlacement::Above,
+            PopupPlacement::Above if !fits_above && fits_below => PopupPlacement::Below,
+            PopupPlacement::Right if !fits_right && fits_left => PopupPlacement::Left,
+            PopupPlacement::Left if !fits_left && fits_right => PopupPlacement::Right,
+            placement => placement,
+        };
+
+        let (x, y) = match placement {
+            PopupPlacement::Below => (ax0, ay1),
+            PopupPlacement::Above => (ax0, ay0.saturating_sub(height)),
+            PopupPlacement::Right => (ax1, ay0),
+            PopupPlacement::Left => (ax0.saturating_sub(width), ay0),
+        };
+        let x = x.min(vx1.saturating_sub(width)).max(vx0);
+        let y = y.min(vy1.saturating_sub(height)).max(vy0);
+
+        self.bounds = Some(Boundaries::from_rect(Position::new(x, y), size));
+    }
+
+    pub fn close(&mut self) {
+        self.bounds = None;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.bounds.is_some()
+    }
+
+    pub fn bounds(&self) -> Option<Boundaries> {
+        self.bounds
+    }
+
+    pub fn placement(&self) -> PopupPlacement {
+        self.placement
+    }
+
+    pub fn contains(&self, point: Position) -> bool {
+        self.bounds.is_some_and(|bounds| bounds.contains(point))
+    }
+
+    /// Whether `input` should close the popup: Escape, or a press outside both
+    /// the popup and its anchor
+    pub fn is_dismissal(&self, input: &InputEvent, anchor: Boundaries) -> bool {
+        if !self.is_open() {
+            return false;
+        }
+
+        match *input {
+            InputEvent::KeyPressed {
+                key: Key::Escape, ..
+            } => true,
+            InputEvent::MouseButtonPressed { position, .. } => {
+                !self.contains(position) && !anchor.contains(position)
+            }
+            _ => false,
+        }
+    }
+}
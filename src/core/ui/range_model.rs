@@ -0,0 +1,235 @@
+use serde::{Deserialize, Serialize};
+
+/// Bounded numeric value shared by sliders, progress bars and numeric
+/// up-downs. The value always stays inside `minimum..=maximum` and, when a
+/// step is set, on `minimum + k * step`. Every mutator returns whether the
+/// value changed.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawRangeModel")]
+pub struct RangeModel {
+    minimum: f64,
+    maximum: f64,
+    value: f64,
+    // Change of the arrow keys, the wheel and spin buttons
+    small_change: f64,
+    // Change of Page Up and Page Down and of clicks on a slider track
+    large_change: f64,
+    // Values snap to multiples of it from the minimum, 0 disables snapping
+    step: f64,
+}
+
+// Unchecked form of RangeModel so deserialized values keep the invariants
+// the constructor and setters maintain
+#[derive(Deserialize)]
+struct RawRangeModel {
+    minimum: f64,
+    maximum: f64,
+    value: f64,
+    small_change: f64,
+    large_change: f64,
+    step: f64,
+}
+
+impl TryFrom<RawRangeModel> for RangeModel {
+    type Error = String;
+
+    fn try_from(raw: RawRangeModel) -> Result<Self, Self::Error> {
+        let fields = [
+            raw.minimum,
+            raw.maximum,
+            raw.value,
+            raw.small_change,
+            raw.large_change,
+            raw.step,
+        ];
+        if fields.iter().any(|field| field.is_nan()) {
+            return Err("range values must be numbers".to_string());
+        }
+        if raw.minimum > raw.maximum {
+            return Err(format!(
+                "range minimum {} is above its maximum {}",
+                raw.minimum, raw.maximum
+            ));
+        }
+        if raw.small_change < 0.0 || raw.large_change < 0.0 || raw.step < 0.0 {
+            return Err("range changes and step must not be negative".to_string());
+        }
+
+        let model = Self::new(raw.minimum, raw.maximum)
+            .with_small_change(raw.small_change)
+            .with_large_change(raw.large_change)
+            .with_step(raw.step);
+        if model.coerce(raw.value) != raw.value {
+            return Err(format!(
+                "range value {} is outside {}..={} or off its step",
+                raw.value, raw.minimum, raw.maximum
+            ));
+        }
+        Ok(Self {
+            value: raw.value,
+            ..model
+        })
+    }
+}
+
+impl Default for RangeModel {
+    fn default() -> Self {
+        Self::new(0.0, 100.0)
+    }
+}
+
+impl RangeModel {
+    pub fn new(minimum: f64, maximum: f64) -> Self {
+        let (minimum, maximum) = if minimum <= maximum {
+            (minimum, maximum)
+        } else {
+            (maximum, minimum)
+        };
+        Self {
+            minimum,
+            maximum,
+            value: minimum,
+            small_change: 1.0,
+            large_change: 10.0,
+            step: 0.0,
+        }
+    }
+
+    pub fn with_value(mut self, value: f64) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    pub fn with_small_change(mut self, small_change: f64) -> Self {
+        self.small_change = small_change.abs();
+        self
+    }
+
+    pub fn with_large_change(mut self, large_change: f64) -> Self {
+        self.large_change = large_change.abs();
+        self
+    }
+
+    pub fn with_step(mut self, step: f64) -> Self {
+        self.set_step(step);
+        self
+    }
+
+    // Setters
+    /// Changes the bounds, swapping them if reversed, and pulls the value inside
+    pub fn set_range(&mut self, minimum: f64, maximum: f64) -> bool {
+        let (minimum, maximum) = if minimum <= maximum {
+            (minimum, maximum)
+        } else {
+            (maximum, minimum)
+        };
+        self.minimum = minimum;
+        self.maximum = maximum;
+        self.set_value(self.value)
+    }
+
+    pub fn set_value(&mut self, value: f64) -> bool {
+        let value = self.coerce(value);
+        if value == self.value {
+            return false;
+        }
+        self.value = value;
+        true
+    }
+
+    pub fn set_small_change(&mut self, small_change: f64) {
+        self.small_change = small_change.abs();
+    }
+
+    pub fn set_large_change(&mut self, large_change: f64) {
+        self.large_change = large_change.abs();
+    }
+
+    pub fn set_step(&mut self, step: f64) -> bool {
+        self.step = step.abs();
+        self.set_value(self.value)
+    }
+
+    /// Moves the value by a number of small changes, negative to decrease
+    pub fn increment(&mut self, times: i32) -> bool {
+        self.set_value(self.value + self.small_change * times as f64)
+    }
+
+    /// Moves the value by a number of large changes, negative to decrease
+    pub fn page(&mut self, times: i32) -> bool {
+        self.set_value(self.value + self.large_change * times as f64)
+    }
+
+    pub fn set_to_minimum(&mut self) -> bool {
+        self.set_value(self.minimum)
+    }
+
+    pub fn set_to_maximum(&mut self) -> bool {
+        self.set_value(self.maximum)
+    }
+
+    /// Sets the value at a fraction of the range, 0 being the minimum
+    pub fn set_fraction(&mut self, fraction: f64) -> bool {
+        self.set_value(self.value_at(fraction))
+    }
+
+    // Getters
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn minimum(&self) -> f64 {
+        self.minimum
+    }
+
+    pub fn maximum(&self) -> f64 {
+        self.maximum
+    }
+
+    pub fn small_change(&self) -> f64 {
+        self.small_change
+    }
+
+    pub fn large_change(&self) -> f64 {
+        self.large_change
+    }
+
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    pub fn span(&self) -> f64 {
+        self.maximum - self.minimum
+    }
+
+    /// Position of the value in the range, from 0 to 1
+    pub fn fraction(&self) -> f64 {
+        self.fraction_of(self.value)
+    }
+
+    pub fn fraction_of(&self, value: f64) -> f64 {
+        if self.span() <= 0.0 {
+            return 0.0;
+        }
+        ((value - self.minimum) / self.span()).clamp(0.0, 1.0)
+    }
+
+    /// Value the model would take at a fraction of the range
+    pub fn value_at(&self, fraction: f64) -> f64 {
+        self.coerce(self.minimum + self.span() * fraction.clamp(0.0, 1.0))
+    }
+
+    // Clamps and snaps a value
+    fn coerce(&self, value: f64) -> f64 {
+        if value.is_nan() {
+            return self.value;
+        }
+        let value = value.clamp(self.minimum, self.maximum);
+        // The maximum stays reachable when the span is not a multiple of the step
+        if self.step <= 0.0 || value == self.maximum {
+            return value;
+        }
+        let steps = ((value - self.minimum) / self.step).round();
+        (self.minimum + steps * self.step).min(self.maximum)
+    }
+}
@@ -0,0 +1,53 @@
+use std::rc::Rc;
+
+use crate::core::{
+    components::{
+        elements::icon::Icon,
+        properties::{
+            boundaries::Boundaries,
+            graphics::{background::Background, border::Border, color::Color},
+            position::Position,
+            typography::TypoGraphy,
+        },
+    },
+    ui::image::bitmap::Bitmap,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DrawCommand {
+    FillRect {
+        bounds: Boundaries,
+        background: Background,
+    },
+    StrokeRect {
+        bounds: Boundaries,
+        border: Border,
+    },
+    Line {
+        from: Position,
+        to: Position,
+        color: Color,
+        width: u16,
+    },
+    Text {
+        position: Position,
+        text: String,
+        typography: TypoGraphy,
+        color: Color,
+    },
+    Icon {
+        position: Position,
+        icon: Icon,
+    },
+    // Decoded pixels drawn at their own size
+    Image {
+        position: Position,
+        bitmap: Rc<Bitmap>,
+    },
+    PushClip(Boundaries),
+    PopClip,
+    // Multiplies the alpha of each following command on its own, nested ones
+    // multiplying
+    PushOpacity(f32),
+    PopOpacity,
+}
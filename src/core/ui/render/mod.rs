@@ -0,0 +1,3 @@
+pub mod draw_command;
+pub mod rasterizer;
+pub mod render_context;
@@ -0,0 +1,349 @@
+use std::collections::BTreeSet;
+
+use crate::core::{
+    components::properties::{
+        boundaries::Boundaries,
+        graphics::{
+            background::{Background, BackgroundShape},
+            blend_mode::BlendMode,
+            border::Border,
+            color::{Color, RGBA},
+            color_space::ColorSpace,
+            gradient::{Gradient, GradientType},
+        },
+        position::Position,
+    },
+    ui::{
+        image::bitmap::Bitmap,
+        render::{draw_command::DrawCommand, render_context::RenderContext},
+    },
+};
+
+// Pixel rectangle as left, top, right and bottom, the last two exclusive
+type Area = (u32, u32, u32, u32);
+
+/// Paints draw commands into pixels. The canvas keeps premultiplied alpha and
+/// every command is composited source-over what is already painted, after
+/// mixing with it through the blend mode of its background.
+///
+/// Text and icons are left out until a font backend exists, and borders are
+/// painted solid whatever their type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rasterizer {
+    width: u32,
+    height: u32,
+    // Premultiplied RGBA in 0..=1
+    pixels: Vec<[f32; 4]>,
+    clips: Vec<Area>,
+    opacities: Vec<f32>,
+}
+
+impl Rasterizer {
+    /// Fully transparent canvas
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![[0.0; 4]; width as usize * height as usize],
+            clips: Vec::new(),
+            opacities: Vec::new(),
+        }
+    }
+
+    /// Replaces every pixel with `color`, without compositing
+    pub fn clear(&mut self, color: &Color) {
+        let [r, g, b, a] = straight(color.to_rgba());
+        self.pixels.fill([r * a, g * a, b * a, a]);
+    }
+
+    /// Paints every layer of a render pass, bottom first
+    pub fn paint(&mut self, ctx: &RenderContext) {
+        for command in ctx.paint_order() {
+            self.draw(command);
+        }
+    }
+
+    pub fn draw(&mut self, command: &DrawCommand) {
+        match command {
+            DrawCommand::FillRect { bounds, background } => self.fill_rect(bounds, background),
+            DrawCommand::StrokeRect { bounds, border } => self.stroke_rect(bounds, border),
+            DrawCommand::Line {
+                from,
+                to,
+                color,
+                width,
+            } => self.draw_line(*from, *to, color, *width),
+            DrawCommand::Image { position, bitmap } => {
+                for y in 0..bitmap.height() {
+                    for x in 0..bitmap.width() {
+                        let (Some([r, g, b, a]), Some((cx, cy))) = (
+                            bitmap.pixel(x, y),
+                            self.offset(*position, x as i64, y as i64),
+                        ) else {
+                            continue;
+                        };
+                        self.composite(cx, cy, straight(RGBA::new(r, g, b, a)), BlendMode::Normal);
+                    }
+                }
+            }
+            DrawCommand::Text { .. } | DrawCommand::Icon { .. } => {}
+            DrawCommand::PushClip(bounds) => {
+                let (left, top, right, bottom) = self.clip();
+                let (x0, y0, x1, y1) = area(bounds);
+                self.clips.push((
+                    left.max(x0),
+                    top.max(y0),
+                    right.min(x1).max(left.max(x0)),
+                    bottom.min(y1).max(top.max(y0)),
+                ));
+            }
+            DrawCommand::PopClip => {
+                self.clips.pop();
+            }
+            DrawCommand::PushOpacity(opacity) => {
+                let opacity = self.opacity() * opacity.clamp(0.0, 1.0);
+                self.opacities.push(opacity);
+            }
+            DrawCommand::PopOpacity => {
+                self.opacities.pop();
+            }
+        }
+    }
+
+    /// Source-over of a straight color on the pixel, with the current opacity
+    /// applied. The blend mode mixes the source with the backdrop first, in
+    /// proportion to how opaque the backdrop is
+    fn composite(&mut self, x: u32, y: u32, [r, g, b, a]: [f32; 4], blend_mode: BlendMode) {
+        let alpha = a * self.opacity();
+        if alpha <= 0.0 || !self.visible(x, y) {
+            return;
+        }
+        let index = (y * self.width + x) as usize;
+        let pixel = &mut self.pixels[index];
+        let backdrop_alpha = pixel[3];
+        for (channel, source) in [r, g, b].into_iter().enumerate() {
+            let source = if blend_mode.is_normal() || backdrop_alpha == 0.0 {
+                source
+            } else {
+                let backdrop = pixel[channel] / backdrop_alpha;
+                (1.0 - backdrop_alpha) * source
+                    + backdrop_alpha * blend_mode.blend(backdrop, source)
+            };
+            pixel[channel] = source * alpha + pixel[channel] * (1.0 - alpha);
+        }
+        pixel[3] = alpha + backdrop_alpha * (1.0 - alpha);
+    }
+
+    fn fill_rect(&mut self, bounds: &Boundaries, background: &Background) {
+        let (left, top, right, bottom) = self.visible_area(bounds);
+        let solid = background
+            .solid_color()
+            .map(|color| straight(color.to_rgba()));
+        for y in top..bottom {
+            for x in left..right {
+                let center = (x as f32 + 0.5, y as f32 + 0.5);
+                if !covers(bounds, background.shape(), center) {
+                    continue;
+                }
+                let color = match (solid, background.gradient()) {
+                    (Some(color), _) => color,
+                    (None, Some(gradient)) => match gradient_color(gradient, bounds, center) {
+                        Some(color) => color,
+                        None => continue,
+                    },
+                    (None, None) => continue,
+                };
+                self.composite(x, y, color, background.blend_mode());
+            }
+        }
+    }
+
+    fn stroke_rect(&mut self, bounds: &Boundaries, border: &Border) {
+        if border.width() <= 0.0 {
+            return;
+        }
+        let width = border.width().ceil() as u32;
+        let color = straight(border.color().to_rgba());
+        let (x0, y0, x1, y1) = area(bounds);
+        let (left, top, right, bottom) = self.visible_area(bounds);
+        for y in top..bottom {
+            for x in left..right {
+                let inset = (x - x0).min(y - y0).min(x1 - 1 - x).min(y1 - 1 - y);
+                if inset < width {
+                    self.composite(x, y, color, BlendMode::Normal);
+                }
+            }
+        }
+    }
+
+    // Squares of the line width stepped along it; each pixel is painted once
+    // so translucent lines keep an even tone
+    fn draw_line(&mut self, from: Position, to: Position, color: &Color, width: u16) {
+        let color = straight(color.to_rgba());
+        let width = width.max(1) as i64;
+        let (dx, dy) = (
+            to.x() as i64 - from.x() as i64,
+            to.y() as i64 - from.y() as i64,
+        );
+        let steps = dx.abs().max(dy.abs()).max(1);
+        let mut pixels = BTreeSet::new();
+        for step in 0..=steps {
+            let x = (dx * step) as f32 / steps as f32;
+            let y = (dy * step) as f32 / steps as f32;
+            let (x, y) = (
+                x.round() as i64 - (width - 1) / 2,
+                y.round() as i64 - (width - 1) / 2,
+            );
+            for oy in 0..width {
+                for ox in 0..width {
+                    if let Some(pixel) = self.offset(from, x + ox, y + oy) {
+                        pixels.insert(pixel);
+                    }
+                }
+            }
+        }
+        for (x, y) in pixels {
+            self.composite(x, y, color, BlendMode::Normal);
+        }
+    }
+
+    // Getters
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Straight color of a pixel, `None` outside the canvas
+    pub fn pixel(&self, x: u32, y: u32) -> Option<RGBA> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let [r, g, b, a] = unpremultiply(self.pixels[(y * self.width + x) as usize]);
+        Some(RGBA::new(r, g, b, a))
+    }
+
+    pub fn to_bitmap(&self) -> Bitmap {
+        let pixels = self
+            .pixels
+            .iter()
+            .flat_map(|pixel| unpremultiply(*pixel))
+            .collect();
+        Bitmap::from_rgba(self.width, self.height, pixels)
+            .unwrap_or_else(|| Bitmap::new(self.width, self.height))
+    }
+
+    fn opacity(&self) -> f32 {
+        self.opacities.last().copied().unwrap_or(1.0)
+    }
+
+    fn clip(&self) -> Area {
+        self.clips
+            .last()
+            .copied()
+            .unwrap_or((0, 0, self.width, self.height))
+    }
+
+    fn visible(&self, x: u32, y: u32) -> bool {
+        let (left, top, right, bottom) = self.clip();
+        x >= left && x < right && y >= top && y < bottom
+    }
+
+    fn visible_area(&self, bounds: &Boundaries) -> Area {
+        let (left, top, right, bottom) = self.clip();
+        let (x0, y0, x1, y1) = area(bounds);
+        let (left, top) = (left.max(x0), top.max(y0));
+        (left, top, right.min(x1).max(left), bottom.min(y1).max(top))
+    }
+
+    // Canvas pixel at an offset from a position, `None` when it falls outside
+    fn offset(&self, position: Position, dx: i64, dy: i64) -> Option<(u32, u32)> {
+        let x = position.x() as i64 + dx;
+        let y = position.y() as i64 + dy;
+        (x >= 0 && y >= 0 && x < self.width as i64 && y < self.height as i64)
+            .then_some((x as u32, y as u32))
+    }
+}
+
+fn area(bounds: &Boundaries) -> Area {
+    let (top_left, bottom_right) = (bounds.top_left(), bounds.bottom_right());
+    (
+        top_left.x() as u32,
+        top_left.y() as u32,
+        bottom_right.x() as u32,
+        bottom_right.y() as u32,
+    )
+}
+
+fn straight(rgba: RGBA) -> [f32; 4] {
+    [rgba.r, rgba.g, rgba.b, rgba.a].map(|channel| channel as f32 / 255.0)
+}
+
+fn unpremultiply([r, g, b, a]: [f32; 4]) -> [u8; 4] {
+    if a <= 0.0 {
+        return [0; 4];
+    }
+    let byte = |value: f32| (value * 255.0).round().clamp(0.0, 255.0) as u8;
+    [byte(r / a), byte(g / a), byte(b / a), byte(a)]
+}
+
+// Whether the shape includes the point, tested at pixel centers
+fn covers(bounds: &Boundaries, shape: &BackgroundShape, (x, y): (f32, f32)) -> bool {
+    let (left, top, right, bottom) = area(bounds);
+    let (left, top, right, bottom) = (left as f32, top as f32, right as f32, bottom as f32);
+    match *shape {
+        BackgroundShape::Rectangle => true,
+        BackgroundShape::RoundedRectangle { radius } => {
+            let radius = radius.min((right - left) / 2.0).min((bottom - top) / 2.0);
+            // Distance to the nearest corner circle center, zero along the edges
+            let cx = x.clamp(left + radius, right - radius);
+            let cy = y.clamp(top + radius, bottom - radius);
+            (x - cx).powi(2) + (y - cy).powi(2) <= radius * radius
+        }
+        BackgroundShape::Circle { radius } => {
+            let (cx, cy) = ((left + right) / 2.0, (top + bottom) / 2.0);
+            (x - cx).powi(2) + (y - cy).powi(2) <= radius * radius
+        }
+    }
+}
+
+// Linear angles follow CSS, 90 painting left to right and 180 top to bottom.
+// Radial centers are offsets from the top left corner and conic angles turn
+// clockwise from the top
+fn gradient_color(
+    gradient: &Gradient,
+    bounds: &Boundaries,
+    (x, y): (f32, f32),
+) -> Option<[f32; 4]> {
+    let (left, top, right, bottom) = area(bounds);
+    let (width, height) = ((right - left) as f32, (bottom - top) as f32);
+    let (dx, dy) = (x - left as f32 - width / 2.0, y - top as f32 - height / 2.0);
+    let position = match gradient.gradient_type {
+        GradientType::Linear(angle) => {
+            let (sin, cos) = (angle as f32).to_radians().sin_cos();
+            let length = (width * sin).abs() + (height * cos).abs();
+            if length == 0.0 {
+                0.0
+            } else {
+                ((dx * sin - dy * cos) / length + 0.5) * 100.0
+            }
+        }
+        GradientType::Radial((cx, cy), radius) => {
+            let distance = (x - left as f32 - cx as f32).hypot(y - top as f32 - cy as f32);
+            if radius <= 0 {
+                100.0
+            } else {
+                distance / radius as f32 * 100.0
+            }
+        }
+        GradientType::Conic(angle) => {
+            let turn = dx.atan2(-dy).to_degrees() - angle as f32;
+            turn.rem_euclid(360.0) / 360.0 * 100.0
+        }
+    };
+    gradient
+        .color_at(position, ColorSpace::default())
+        .map(|color| straight(color.to_rgba()))
+}
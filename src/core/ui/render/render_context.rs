@@ -0,0 +1,281 @@
+use std::rc::Rc;
+
+use crate::core::{
+    components::{
+        elements::icon::Icon,
+        properties::{
+            boundaries::Boundaries,
+            graphics::{background::Background, border::Border, color::Color},
+            position::Position,
+            size::Size,
+            typography::TypoGraphy,
+        },
+    },
+    ui::{image::bitmap::Bitmap, render::draw_command::DrawCommand},
+};
+
+/// Collects the draw commands produced by a render pass, in painting order.
+///
+/// Commands are grouped in layers: layer 0 holds the component tree and every
+/// `push_layer` opens a layer painted above it, which is how popups, menus and
+/// other overlays escape the clipping of the component that owns them.
+///
+/// `push_translation` shifts every following command, which is how scrolled
+/// content is painted without moving the components themselves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderContext {
+    layers: Vec<Vec<DrawCommand>>,
+    current: usize,
+    translations: Vec<(i32, i32)>,
+}
+
+impl Default for RenderContext {
+    fn default() -> Self {
+        Self {
+            layers: vec![Vec::new()],
+            current: 0,
+            translations: Vec::new(),
+        }
+    }
+}
+
+impl RenderContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn fill_rect(&mut self, bounds: Boundaries, background: &Background) {
+        self.push(DrawCommand::FillRect {
+            bounds,
+            background: background.clone(),
+        });
+    }
+
+    pub fn stroke_rect(&mut self, bounds: Boundaries, border: &Border) {
+        self.push(DrawCommand::StrokeRect {
+            bounds,
+            border: border.clone(),
+        });
+    }
+
+    pub fn draw_line(&mut self, from: Position, to: Position, color: &Color, width: u16) {
+        self.push(DrawCommand::Line {
+            from,
+            to,
+            color: color.clone(),
+            width,
+        });
+    }
+
+    pub fn draw_text(
+        &mut self,
+        position: Position,
+        text: &str,
+        typography: &TypoGraphy,
+        color: &Color,
+    ) {
+        self.push(DrawCommand::Text {
+            position,
+            text: text.to_string(),
+            typography: typography.clone(),
+            color: color.clone(),
+        });
+    }
+
+    pub fn draw_icon(&mut self, position: Position, icon: &Icon) {
+        self.push(DrawCommand::Icon {
+            position,
+            icon: icon.clone(),
+        });
+    }
+
+    pub fn draw_image(&mut self, position: Position, bitmap: &Rc<Bitmap>) {
+        self.push(DrawCommand::Image {
+            position,
+            bitmap: bitmap.clone(),
+        });
+    }
+
+    pub fn push_clip(&mut self, bounds: Boundaries) {
+        self.push(DrawCommand::PushClip(bounds));
+    }
+
+    pub fn pop_clip(&mut self) {
+        self.push(DrawCommand::PopClip);
+    }
+
+    pub fn push_opacity(&mut self, opacity: f32) {
+        self.push(DrawCommand::PushOpacity(opacity));
+    }
+
+    pub fn pop_opacity(&mut self) {
+        self.push(DrawCommand::PopOpacity);
+    }
+
+    /// Fades what `paint` draws by an opacity, leaving a fully opaque one out
+    pub fn with_opacity(&mut self, opacity: f32, paint: impl FnOnce(&mut Self)) {
+        if opacity >= 1.0 {
+            paint(self);
+            return;
+        }
+        self.push_opacity(opacity);
+        paint(self);
+        self.pop_opacity();
+    }
+
+    /// Sends the following commands to the layer above the current one
+    pub fn push_layer(&mut self) {
+        self.current += 1;
+        if self.layers.len() <= self.current {
+            self.layers.push(Vec::new());
+        }
+    }
+
+    pub fn pop_layer(&mut self) {
+        self.current = self.current.saturating_sub(1);
+    }
+
+    /// Shifts the following commands by an offset, added to any enclosing one
+    pub fn push_translation(&mut self, dx: i32, dy: i32) {
+        let (x, y) = self.translation();
+        self.translations.push((x + dx, y + dy));
+    }
+
+    pub fn pop_translation(&mut self) {
+        self.translations.pop();
+    }
+
+    pub fn translation(&self) -> (i32, i32) {
+        self.translations.last().copied().unwrap_or_default()
+    }
+
+    /// Commands of the content layer
+    pub fn commands(&self) -> &[DrawCommand] {
+        &self.layers[0]
+    }
+
+    pub fn layer(&self, index: usize) -> &[DrawCommand] {
+        self.layers
+            .get(index)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    pub fn layer_count(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Every command, bottom layer first
+    pub fn paint_order(&self) -> impl Iterator<Item = &DrawCommand> {
+        self.layers.iter().flatten()
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    fn push(&mut self, command: DrawCommand) {
+        let command = match self.translation() {
+            (0, 0) => Some(command),
+            (dx, dy) => Self::translate(command, dx, dy),
+        };
+        if let Some(command) = command {
+            self.layers[self.current].push(command);
+        }
+    }
+
+    // Commands pushed outside the drawable area are cut at its edge; text and
+    // icons starting there are dropped since they cannot be partially placed,
+    // while images lose the rows and columns left outside
+    fn translate(command: DrawCommand, dx: i32, dy: i32) -> Option<DrawCommand> {
+        let point = |position: Position| {
+            let x = position.x() as i32 + dx;
+            let y = position.y() as i32 + dy;
+            (x, y)
+        };
+        let clamp = |value: i32| value.clamp(0, u16::MAX as i32) as u16;
+        let clamp_point = |position: Position| {
+            let (x, y) = point(position);
+            Position::new(clamp(x), clamp(y))
+        };
+        let rect = |bounds: Boundaries| {
+            let top_left = clamp_point(bounds.top_left());
+            let bottom_right = clamp_point(bounds.bottom_right());
+            Boundaries::from_rect(
+                top_left,
+                Size::new(
+                    bottom_right.y() - top_left.y(),
+                    bottom_right.x() - top_left.x(),
+                ),
+            )
+        };
+        let placed = |position: Position| {
+            let (x, y) = point(position);
+            (x >= 0 && y >= 0).then(|| clamp_point(position))
+        };
+
+        Some(match command {
+            DrawCommand::FillRect { bounds, background } => {
+                let bounds = rect(bounds);
+                if bounds.width() == 0 || bounds.height() == 0 {
+                    return None;
+                }
+                DrawCommand::FillRect { bounds, background }
+            }
+            DrawCommand::StrokeRect { bounds, border } => {
+                let bounds = rect(bounds);
+                if bounds.width() == 0 || bounds.height() == 0 {
+                    return None;
+                }
+                DrawCommand::StrokeRect { bounds, border }
+            }
+            DrawCommand::Line {
+                from,
+                to,
+                color,
+                width,
+            } => DrawCommand::Line {
+                from: clamp_point(from),
+                to: clamp_point(to),
+                color,
+                width,
+            },
+            DrawCommand::Text {
+                position,
+                text,
+                typography,
+                color,
+            } => DrawCommand::Text {
+                position: placed(position)?,
+                text,
+                typography,
+                color,
+            },
+            DrawCommand::Icon { position, icon } => DrawCommand::Icon {
+                position: placed(position)?,
+                icon,
+            },
+            DrawCommand::Image { position, bitmap } => {
+                let (x, y) = point(position);
+                let (skip_x, skip_y) = ((-x).max(0) as u32, (-y).max(0) as u32);
+                if skip_x >= bitmap.width() || skip_y >= bitmap.height() {
+                    return None;
+                }
+                let bitmap = if skip_x > 0 || skip_y > 0 {
+                    Rc::new(bitmap.crop(skip_x, skip_y, bitmap.width(), bitmap.height()))
+                } else {
+                    bitmap
+                };
+                DrawCommand::Image {
+                    position: clamp_point(position),
+                    bitmap,
+                }
+            }
+            // Clips stay balanced even when they end up empty
+            DrawCommand::PushClip(bounds) => DrawCommand::PushClip(rect(bounds)),
+            DrawCommand::PopClip => DrawCommand::PopClip,
+            DrawCommand::PushOpacity(opacity) => DrawCommand::PushOpacity(opacity),
+            DrawCommand::PopOpacity => DrawCommand::PopOpacity,
+        })
+    }
+}
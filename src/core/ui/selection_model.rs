@@ -0,0 +1,193 @@
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    components::properties::selection_mode::SelectionMode, window::events::input::Modifiers,
+};
+
+/// Selection state of an indexed collection, following the WinForms rules for
+/// each `SelectionMode`. Every mutator returns whether the selected set changed.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SelectionModel {
+    mode: SelectionMode,
+    selected: BTreeSet<usize>,
+    // Item with the keyboard focus rectangle
+    #[serde(skip)]
+    caret: Option<usize>,
+    // Start of Shift ranges
+    #[serde(skip)]
+    anchor: Option<usize>,
+}
+
+impl SelectionModel {
+    pub fn new(mode: SelectionMode) -> Self {
+        Self {
+            mode,
+            ..Self::default()
+        }
+    }
+
+    pub fn mode(&self) -> SelectionMode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: SelectionMode) -> bool {
+        self.mode = mode;
+        match mode {
+            SelectionMode::None => self.replace(BTreeSet::new()),
+            SelectionMode::One => {
+                let keep = self.caret.filter(|caret| self.selected.contains(caret));
+                let keep = keep.or_else(|| self.selected.first().copied());
+                self.replace(keep.into_iter().collect())
+            }
+            _ => false,
+        }
+    }
+
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selected.contains(&index)
+    }
+
+    /// Selected indices in ascending order
+    pub fn selected(&self) -> Vec<usize> {
+        self.selected.iter().copied().collect()
+    }
+
+    pub fn first_selected(&self) -> Option<usize> {
+        self.selected.first().copied()
+    }
+
+    pub fn caret(&self) -> Option<usize> {
+        self.caret
+    }
+
+    pub fn set_caret(&mut self, caret: Option<usize>) {
+        self.caret = caret;
+    }
+
+    /// Drops indices past the end of a collection that shrank
+    pub fn set_item_count(&mut self, count: usize) -> bool {
+        self.caret = self.caret.filter(|caret| *caret < count);
+        self.anchor = self.anchor.filter(|anchor| *anchor < count);
+        let kept = self
+            .selected
+            .iter()
+            .copied()
+            .filter(|i| *i < count)
+            .collect();
+        self.replace(kept)
+    }
+
+    pub fn clear(&mut self) -> bool {
+        self.replace(BTreeSet::new())
+    }
+
+    pub fn select_only(&mut self, index: usize) -> bool {
+        self.caret = Some(index);
+        self.anchor = Some(index);
+        if self.mode == SelectionMode::None {
+            return false;
+        }
+        self.replace(BTreeSet::from([index]))
+    }
+
+    pub fn set_selected(&mut self, index: usize, selected: bool) -> bool {
+        match (self.mode, selected) {
+            (SelectionMode::None, _) => false,
+            (SelectionMode::One, true) => self.select_only(index),
+            (_, true) => self.selected.insert(index),
+            (_, false) => self.selected.remove(&index),
+        }
+    }
+
+    pub fn select_all(&mut self, count: usize) -> bool {
+        if !self.mode.is_multiple() {
+            return false;
+        }
+        self.replace((0..count).collect())
+    }
+
+    /// Mouse click on `index`
+    pub fn click(&mut self, index: usize, modifiers: Modifiers) -> bool {
+        match self.mode {
+            SelectionMode::None => {
+                self.caret = Some(index);
+                false
+            }
+            SelectionMode::One => self.select_only(index),
+            SelectionMode::MultiSimple => {
+                self.caret = Some(index);
+                self.toggle(index)
+            }
+            SelectionMode::MultiExtended => {
+                if modifiers.shift {
+                    self.caret = Some(index);
+                    self.select_range(index, modifiers.ctrl)
+                } else if modifiers.ctrl {
+                    self.caret = Some(index);
+                    self.anchor = Some(index);
+                    self.toggle(index)
+                } else {
+                    self.select_only(index)
+                }
+            }
+        }
+    }
+
+    /// Keyboard navigation that moved the caret to `index`
+    pub fn navigate(&mut self, index: usize, modifiers: Modifiers) -> bool {
+        match self.mode {
+            SelectionMode::One => self.select_only(index),
+            SelectionMode::MultiExtended if modifiers.shift => {
+                self.caret = Some(index);
+                self.select_range(index, modifiers.ctrl)
+            }
+            SelectionMode::MultiExtended if !modifiers.ctrl => self.select_only(index),
+            // MultiSimple and Ctrl+arrows only move the focus rectangle
+            _ => {
+                self.caret = Some(index);
+                false
+            }
+        }
+    }
+
+    /// Space on the caret item
+    pub fn toggle_caret(&mut self) -> bool {
+        match (self.mode, self.caret) {
+            (SelectionMode::MultiSimple | SelectionMode::MultiExtended, Some(caret)) => {
+                self.anchor = Some(caret);
+                self.toggle(caret)
+            }
+            (SelectionMode::One, Some(caret)) => self.select_only(caret),
+            _ => false,
+        }
+    }
+
+    fn toggle(&mut self, index: usize) -> bool {
+        if !self.selected.remove(&index) {
+            self.selected.insert(index);
+        }
+        true
+    }
+
+    fn select_range(&mut self, index: usize, keep_existing: bool) -> bool {
+        let anchor = *self.anchor.get_or_insert(index);
+        let range = anchor.min(index)..=anchor.max(index);
+        let mut selected = if keep_existing {
+            self.selected.clone()
+        } else {
+            BTreeSet::new()
+        };
+        selected.extend(range);
+        self.replace(selected)
+    }
+
+    fn replace(&mut self, selected: BTreeSet<usize>) -> bool {
+        if self.selected == selected {
+            return false;
+        }
+        self.selected = selected;
+        true
+    }
+}
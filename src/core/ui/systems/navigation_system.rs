@@ -1,2 +1,157 @@
-#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
-pub struct NavigationSystem {}
+use std::{
+    cell::RefCell,
+    hash::{Hash, Hasher},
+    rc::Weak,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::utils::traits::uri_handler::UriHandler;
+
+/// Where a link leads: an external URI or a route to a view of the application
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LinkTarget {
+    Uri(String),
+    Route(String),
+}
+
+impl LinkTarget {
+    /// Anything with a scheme (`https://…`, `mailto:…`) is a URI, the rest is a route
+    pub fn parse(target: &str) -> Self {
+        let scheme = target.split_once(':').map(|(scheme, _)| scheme);
+        match scheme {
+            // A single letter is a drive (`C:\…`), not a scheme
+            Some(scheme)
+                if scheme.len() > 1
+                    && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                    && scheme
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.')) =>
+            {
+                LinkTarget::Uri(target.to_string())
+            }
+            _ => LinkTarget::Route(target.to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            LinkTarget::Uri(uri) => uri,
+            LinkTarget::Route(route) => route,
+        }
+    }
+}
+
+/// Keeps the history of visited routes, with back and forward like a browser,
+/// and hands external URIs to a pluggable handler.
+#[derive(Debug, Clone, Default)]
+pub struct NavigationSystem {
+    history: Vec<String>,
+    // Index of the current route in the history
+    current: Option<usize>,
+    uri_handler: Option<Weak<RefCell<dyn UriHandler>>>,
+}
+
+// Handlers are compared by identity, not by what they do
+impl PartialEq for NavigationSystem {
+    fn eq(&self, other: &Self) -> bool {
+        self.history == other.history
+            && self.current == other.current
+            && match (&self.uri_handler, &other.uri_handler) {
+                (Some(a), Some(b)) => Weak::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+impl Eq for NavigationSystem {}
+
+impl Hash for NavigationSystem {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.history.hash(state);
+        self.current.hash(state);
+        self.uri_handler
+            .as_ref()
+            .map(|handler| handler.as_ptr() as *const ())
+            .hash(state);
+    }
+}
+
+impl NavigationSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_uri_handler<H: UriHandler + 'static>(&mut self, handler: Weak<RefCell<H>>) {
+        self.uri_handler = Some(handler as Weak<RefCell<dyn UriHandler>>);
+    }
+
+    /// Goes to a route, dropping the forward history. Returns false when the
+    /// route is already the current one
+    pub fn navigate(&mut self, route: &str) -> bool {
+        if self.current_route() == Some(route) {
+            return false;
+        }
+
+        let next = self.current.map_or(0, |current| current + 1);
+        self.history.truncate(next);
+        self.history.push(route.to_string());
+        self.current = Some(next);
+        true
+    }
+
+    pub fn back(&mut self) -> bool {
+        match self.current {
+            Some(current) if current > 0 => {
+                self.current = Some(current - 1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn forward(&mut self) -> bool {
+        match self.current {
+            Some(current) if current + 1 < self.history.len() => {
+                self.current = Some(current + 1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Routes internal targets and passes URIs to the handler, returning
+    /// whether the target was opened
+    pub fn open(&mut self, target: &LinkTarget) -> bool {
+        match target {
+            LinkTarget::Route(route) => {
+                self.navigate(route);
+                true
+            }
+            LinkTarget::Uri(uri) => self
+                .uri_handler
+                .as_ref()
+                .and_then(Weak::upgrade)
+                .is_some_and(|handler| handler.borrow_mut().open_uri(uri)),
+        }
+    }
+
+    // Getters
+    pub fn current_route(&self) -> Option<&str> {
+        self.current.map(|current| self.history[current].as_str())
+    }
+
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        self.current.is_some_and(|current| current > 0)
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        self.current
+            .is_some_and(|current| current + 1 < self.history.len())
+    }
+}
@@ -1,4 +1,33 @@
-use crate::core::ui::elements::theme::Theme;
+use crate::core::{
+    components::properties::{
+        boundaries::Boundaries,
+        graphics::{
+            blend_mode::BlendMode,
+            color::{Color, WcagLevel},
+        },
+        position::Position,
+        size::Size,
+        typography::{FontWeight, TypoGraphy},
+    },
+    ui::{
+        elements::theme::Theme,
+        render::{draw_command::DrawCommand, render_context::RenderContext},
+    },
+    utils::traits::component::Component,
+};
+
+/// Text drawn with too little contrast against what is painted behind it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastIssue {
+    pub text: String,
+    pub position: Position,
+    // Text color as painted, faded by the opacity of its ancestors
+    pub foreground: Color,
+    // Opaque color behind the text giving the lowest ratio
+    pub background: Color,
+    pub ratio: f32,
+    pub required: f32,
+}
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct ThemeSystem {}
@@ -11,4 +40,183 @@ impl ThemeSystem {
     fn default_theme() -> Theme {
         Theme::default()
     }
+
+    /// Paints `root` and checks every text against the fills painted under
+    /// its center, over a white window, reporting the ones below `level`.
+    /// Texts and fills are faded by the opacities they are painted with and
+    /// cut by their clips, and fills mix with what is below them by their
+    /// blend mode the way the rasterizer composites them. Gradients are
+    /// checked at each stop and the worst one is reported.
+    /// Disabled text is reported too, although WCAG does not require it to pass
+    pub fn lint_contrast(root: &dyn Component, level: WcagLevel) -> Vec<ContrastIssue> {
+        let mut ctx = RenderContext::new();
+        root.paint(&mut ctx);
+        let commands = painted(ctx.paint_order());
+
+        let mut issues = Vec::new();
+        for (index, (command, opacity, clip)) in commands.iter().enumerate() {
+            let DrawCommand::Text {
+                position,
+                text,
+                typography,
+                color,
+            } = command
+            else {
+                continue;
+            };
+            if text.trim().is_empty() {
+                continue;
+            }
+            let size = typography.measure_text(text);
+            let center = Position::new(
+                position.x().saturating_add(size.width() / 2),
+                position.y().saturating_add(size.height() / 2),
+            );
+            // Clipped away, so nobody reads it
+            if clip.is_some_and(|clip| !clip.contains(center)) {
+                continue;
+            }
+            let color = fade(color, *opacity);
+            let Some((ratio, background)) = backdrops(&commands[..index], center)
+                .into_iter()
+                .map(|background| (color.contrast_ratio(&background), background))
+                .min_by(|a, b| a.0.total_cmp(&b.0))
+            else {
+                continue;
+            };
+            let required = level.minimum_ratio(is_large_text(typography));
+            if ratio < required {
+                issues.push(ContrastIssue {
+                    text: text.clone(),
+                    position: *position,
+                    foreground: color,
+                    background,
+                    ratio,
+                    required,
+                });
+            }
+        }
+        issues
+    }
+}
+
+// 24px, or 19px when bold, which is what WCAG calls 18pt and 14pt bold
+fn is_large_text(typography: &TypoGraphy) -> bool {
+    let bold = typography.font_type().clone() as u16 >= FontWeight::BOLD as u16;
+    typography.font_size() >= 24 || (bold && typography.font_size() >= 19)
+}
+
+// Commands paired with the opacity they are painted with, the product of the
+// opacities pushed around them, and the area left by the clips pushed around
+// them, if any
+fn painted<'a>(
+    commands: impl Iterator<Item = &'a DrawCommand>,
+) -> Vec<(&'a DrawCommand, f32, Option<Boundaries>)> {
+    let mut opacities = vec![1.0f32];
+    let mut clips: Vec<Boundaries> = Vec::new();
+    let mut painted = Vec::new();
+    for command in commands {
+        let opacity = *opacities.last().unwrap_or(&1.0);
+        let clip = clips.last().copied();
+        match command {
+            DrawCommand::PushOpacity(value) => opacities.push(opacity * value.clamp(0.0, 1.0)),
+            DrawCommand::PopOpacity => {
+                if opacities.len() > 1 {
+                    opacities.pop();
+                }
+            }
+            DrawCommand::PushClip(bounds) => clips.push(match clip {
+                Some(clip) => intersect(&clip, bounds),
+                None => *bounds,
+            }),
+            DrawCommand::PopClip => {
+                clips.pop();
+            }
+            _ => painted.push((command, opacity, clip)),
+        }
+    }
+    painted
+}
+
+// Overlap of two areas, empty at the corner of the second when they are apart
+fn intersect(a: &Boundaries, b: &Boundaries) -> Boundaries {
+    let left = a.top_left().x().max(b.top_left().x());
+    let top = a.top_left().y().max(b.top_left().y());
+    let right = a.bottom_right().x().min(b.bottom_right().x()).max(left);
+    let bottom = a.bottom_right().y().min(b.bottom_right().y()).max(top);
+    Boundaries::from_rect(
+        Position::new(left, top),
+        Size::new(bottom - top, right - left),
+    )
+}
+
+fn fade(color: &Color, opacity: f32) -> Color {
+    if opacity >= 1.0 {
+        return color.clone();
+    }
+    color.with_alpha((color.alpha() as f32 * opacity).round() as u8)
+}
+
+// Opaque colors that can be behind `point`, one for each mix of gradient
+// stops of the fills stacked there
+fn backdrops(commands: &[(&DrawCommand, f32, Option<Boundaries>)], point: Position) -> Vec<Color> {
+    let mut layers = Vec::new();
+    for (command, opacity, clip) in commands.iter().rev() {
+        let DrawCommand::FillRect { bounds, background } = command else {
+            continue;
+        };
+        if !bounds.contains(point) || clip.is_some_and(|clip| !clip.contains(point)) {
+            continue;
+        }
+        let colors: Vec<Color> = background
+            .colors()
+            .iter()
+            .map(|color| fade(color, *opacity))
+            .collect();
+        if colors.is_empty() {
+            continue;
+        }
+        // Only an opaque fill painted normally hides what is below it
+        let blend_mode = background.blend_mode();
+        let opaque = colors.iter().all(|color| color.alpha() == 255);
+        layers.push((colors, blend_mode));
+        if opaque && blend_mode.is_normal() {
+            break;
+        }
+    }
+
+    let mut backdrops = vec![Color::rgb(255, 255, 255)];
+    for (colors, blend_mode) in layers.into_iter().rev() {
+        let mut blended: Vec<Color> = Vec::new();
+        for below in &backdrops {
+            for color in &colors {
+                let color = composite(color, below, blend_mode);
+                if !blended.contains(&color) {
+                    blended.push(color);
+                }
+            }
+        }
+        backdrops = blended;
+    }
+    backdrops
+}
+
+// A fill over an opaque backdrop as the rasterizer composites it: the blend
+// mode mixes the channels first, then the fill's alpha lays the mix over
+fn composite(color: &Color, below: &Color, blend_mode: BlendMode) -> Color {
+    if blend_mode.is_normal() {
+        return color.over(below);
+    }
+    let (source, backdrop) = (color.to_rgba(), below.to_rgba());
+    let mix = |source: u8, backdrop: u8| {
+        let mixed = blend_mode.blend(backdrop as f32 / 255.0, source as f32 / 255.0);
+        (mixed.clamp(0.0, 1.0) * 255.0).round() as u8
+    };
+    Color::rgba(
+        mix(source.r, backdrop.r),
+        mix(source.g, backdrop.g),
+        mix(source.b, backdrop.b),
+        source.a,
+    )
+    .over(below)
 }
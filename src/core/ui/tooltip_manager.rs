@@ -0,0 +1,388 @@
+use std::{collections::HashMap, time::Duration};
+
+use crate::core::{
+    components::{
+        elements::icon::Icon,
+        properties::{boundaries::Boundaries, position::Position, size::Size},
+        styles::tooltip_style::TooltipStyle,
+    },
+    ui::{
+        popup::{Popup, PopupPlacement},
+        render::render_context::RenderContext,
+    },
+    utils::traits::event_listener::EventListener,
+    window::events::{input::InputEvent, types::EventType},
+};
+
+/// What a tooltip shows: a line of text, or a bold title over the text with
+/// an optional icon on the left. Text breaks into lines at `\n`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TooltipContent {
+    Text(String),
+    Rich {
+        title: String,
+        text: String,
+        icon: Option<Icon>,
+    },
+}
+
+impl TooltipContent {
+    pub fn text(text: &str) -> Self {
+        TooltipContent::Text(text.to_string())
+    }
+
+    pub fn rich(title: &str, text: &str, icon: Option<Icon>) -> Self {
+        TooltipContent::Rich {
+            title: title.to_string(),
+            text: text.to_string(),
+            icon,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum TooltipState {
+    Hidden,
+    // Counting down to show the tooltip of a component
+    Pending { id: usize, remaining: Duration },
+    Shown { id: usize, remaining: Duration },
+}
+
+/// Tooltips of every component, shown like the WinForms `ToolTip`.
+///
+/// Components declare a tooltip by id. Subscribed to `HoverEntered` and
+/// `HoverExited`, which containers report for each of their children, the
+/// manager starts counting when a component with a tooltip is hovered; `tick`
+/// shows it once the delay has passed, below the pointer or above it near the
+/// bottom of the viewport. Leaving the component, a
+/// click or a key press hide it, and it pops down on its own after a while.
+/// The owner feeds it every input with `handle_input` to follow the pointer.
+#[derive(Debug, Clone)]
+pub struct TooltipManager {
+    tooltips: HashMap<usize, TooltipContent>,
+    enabled: bool,
+    initial_delay: Duration,
+    // How long a tooltip stays up
+    auto_pop_delay: Duration,
+    // Delay when moving straight from one tooltip to another
+    reshow_delay: Duration,
+    style: TooltipStyle,
+    popup: Popup,
+    state: TooltipState,
+    pointer: Position,
+    hovered: Option<usize>,
+    // Dismissed tooltip that stays down until its component is left
+    suppressed: Option<usize>,
+    // Time since a tooltip hid because the pointer left its component
+    since_hidden: Option<Duration>,
+}
+
+impl Default for TooltipManager {
+    fn default() -> Self {
+        Self {
+            tooltips: HashMap::new(),
+            enabled: true,
+            initial_delay: Duration::from_millis(500),
+            auto_pop_delay: Duration::from_millis(5000),
+            reshow_delay: Duration::from_millis(100),
+            style: TooltipStyle::default(),
+            popup: Popup::new(PopupPlacement::Below),
+            state: TooltipState::Hidden,
+            pointer: Position::default(),
+            hovered: None,
+            suppressed: None,
+            since_hidden: None,
+        }
+    }
+}
+
+impl TooltipManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_style(mut self, style: TooltipStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    // Setters
+    pub fn set_tooltip(&mut self, id: usize, content: TooltipContent) {
+        self.tooltips.insert(id, content);
+    }
+
+    pub fn remove_tooltip(&mut self, id: usize) {
+        self.tooltips.remove(&id);
+        if self.current() == Some(id) {
+            self.hide();
+        }
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.hide();
+        }
+    }
+
+    pub fn set_initial_delay(&mut self, delay: Duration) {
+        self.initial_delay = delay;
+    }
+
+    pub fn set_auto_pop_delay(&mut self, delay: Duration) {
+        self.auto_pop_delay = delay;
+    }
+
+    pub fn set_reshow_delay(&mut self, delay: Duration) {
+        self.reshow_delay = delay;
+    }
+
+    /// Area tooltips must stay inside, usually the window client area
+    pub fn set_viewport(&mut self, viewport: Boundaries) {
+        self.popup.set_viewport(viewport);
+    }
+
+    pub fn set_style(&mut self, style: TooltipStyle) {
+        self.style = style;
+    }
+
+    /// Follows the pointer and hides the tooltip on clicks and key presses.
+    /// Input is never consumed; returns whether the tooltip was hidden
+    pub fn handle_input(&mut self, input: &InputEvent) -> bool {
+        match *input {
+            InputEvent::MouseMoved(position) => {
+                self.pointer = position;
+                false
+            }
+            InputEvent::MouseButtonPressed { position, .. } => {
+                self.pointer = position;
+                self.dismiss()
+            }
+            InputEvent::KeyPressed { .. } => self.dismiss(),
+            _ => false,
+        }
+    }
+
+    /// Advances the delays, returning whether the tooltip appeared or hid
+    pub fn tick(&mut self, elapsed: Duration) -> bool {
+        if let Some(since_hidden) = &mut self.since_hidden {
+            *since_hidden += elapsed;
+            if *since_hidden > self.initial_delay {
+                self.since_hidden = None;
+            }
+        }
+
+        match self.state {
+            TooltipState::Hidden => false,
+            TooltipState::Pending { id, remaining } => {
+                match remaining
+                    .checked_sub(elapsed)
+                    .filter(|left| !left.is_zero())
+                {
+                    Some(remaining) => {
+                        self.state = TooltipState::Pending { id, remaining };
+                        false
+                    }
+                    None => self.show(id),
+                }
+            }
+            TooltipState::Shown { id, remaining } => {
+                match remaining
+                    .checked_sub(elapsed)
+                    .filter(|left| !left.is_zero())
+                {
+                    Some(remaining) => {
+                        self.state = TooltipState::Shown { id, remaining };
+                        false
+                    }
+                    None => self.dismiss(),
+                }
+            }
+        }
+    }
+
+    /// Shows the tooltip of a component right away, at the pointer
+    pub fn show(&mut self, id: usize) -> bool {
+        let Some(content) = self.tooltips.get(&id) else {
+            return false;
+        };
+        if !self.enabled {
+            return false;
+        }
+
+        let size = self.measure(content);
+        let cursor = Boundaries::from_rect(self.pointer, Size::new(self.style.cursor_height, 0));
+        self.popup.open(cursor, size);
+        self.state = TooltipState::Shown {
+            id,
+            remaining: self.auto_pop_delay,
+        };
+        self.since_hidden = None;
+        true
+    }
+
+    pub fn hide(&mut self) -> bool {
+        let visible = self.is_visible();
+        self.popup.close();
+        self.state = TooltipState::Hidden;
+        visible
+    }
+
+    // Getters
+    pub fn tooltip(&self, id: usize) -> Option<&TooltipContent> {
+        self.tooltips.get(&id)
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn style(&self) -> &TooltipStyle {
+        &self.style
+    }
+
+    pub fn is_visible(&self) -> bool {
+        matches!(self.state, TooltipState::Shown { .. })
+    }
+
+    /// Component whose tooltip is showing
+    pub fn visible_tooltip(&self) -> Option<usize> {
+        match self.state {
+            TooltipState::Shown { id, .. } => Some(id),
+            _ => None,
+        }
+    }
+
+    pub fn bounds(&self) -> Option<Boundaries> {
+        self.popup.bounds()
+    }
+
+    /// Size of the tooltip for some content, border and padding included
+    pub fn measure(&self, content: &TooltipContent) -> Size {
+        let style = &self.style;
+        // Widest line and total height of the text
+        let lines = |text: &str| {
+            text.lines().fold((0, 0), |(width, height), line| {
+                let size = style.typography.measure_text(line);
+                (u16::max(width, size.width()), height + size.height())
+            })
+        };
+        let (width, height) = match content {
+            TooltipContent::Text(text) => lines(text),
+            TooltipContent::Rich { title, text, icon } => {
+                let title_size = style.title_typography.measure_text(title);
+                let (text_width, text_height) = lines(text);
+                let mut width = title_size.width().max(text_width);
+                let mut height = title_size.height();
+                if text_height > 0 {
+                    height += style.spacing + text_height;
+                }
+                if let Some(icon) = icon {
+                    width += icon.size.0 as u16 + style.spacing;
+                    height = height.max(icon.size.1 as u16);
+                }
+                (width, height)
+            }
+        };
+        let inset = (style.padding + 1) * 2;
+        Size::new(height + inset, width + inset)
+    }
+
+    fn current(&self) -> Option<usize> {
+        match self.state {
+            TooltipState::Hidden => None,
+            TooltipState::Pending { id, .. } | TooltipState::Shown { id, .. } => Some(id),
+        }
+    }
+
+    // Hides the tooltip until the pointer leaves its component
+    fn dismiss(&mut self) -> bool {
+        if self.state == TooltipState::Hidden {
+            return false;
+        }
+        self.suppressed = self.hovered;
+        self.hide()
+    }
+
+    pub fn render(&self, ctx: &mut RenderContext) {
+        let (Some(bounds), Some(id)) = (self.bounds(), self.visible_tooltip()) else {
+            return;
+        };
+        let Some(content) = self.tooltips.get(&id) else {
+            return;
+        };
+
+        let style = &self.style;
+        ctx.push_layer();
+        ctx.fill_rect(bounds, &style.background);
+        ctx.stroke_rect(bounds, &style.border);
+
+        let inset = style.padding + 1;
+        let mut x = bounds.top_left().x() + inset;
+        let mut y = bounds.top_left().y() + inset;
+        let text = match content {
+            TooltipContent::Text(text) => text,
+            TooltipContent::Rich { title, text, icon } => {
+                if let Some(icon) = icon {
+                    ctx.draw_icon(Position::new(x, y), icon);
+                    x += icon.size.0 as u16 + style.spacing;
+                }
+                ctx.draw_text(
+                    Position::new(x, y),
+                    title,
+                    &style.title_typography,
+                    &style.text_color,
+                );
+                y += style.title_typography.line_height() + style.spacing;
+                text
+            }
+        };
+        for line in text.lines() {
+            ctx.draw_text(
+                Position::new(x, y),
+                line,
+                &style.typography,
+                &style.text_color,
+            );
+            y += style.typography.line_height();
+        }
+        ctx.pop_layer();
+    }
+}
+
+impl EventListener for TooltipManager {
+    fn on_event(&mut self, event: &EventType, caller_id: usize) {
+        match event {
+            // Components tracking hover themselves report it a second time
+            EventType::HoverEntered if self.hovered != Some(caller_id) => {
+                self.hovered = Some(caller_id);
+                let was_visible = self.is_visible();
+                self.hide();
+                if !self.enabled
+                    || !self.tooltips.contains_key(&caller_id)
+                    || self.suppressed == Some(caller_id)
+                {
+                    return;
+                }
+                // Moving straight from another tooltip shows this one sooner
+                let remaining = if was_visible || self.since_hidden.is_some() {
+                    self.reshow_delay
+                } else {
+                    self.initial_delay
+                };
+                self.state = TooltipState::Pending {
+                    id: caller_id,
+                    remaining,
+                };
+            }
+            EventType::HoverExited if self.hovered == Some(caller_id) => {
+                self.hovered = None;
+                self.suppressed = None;
+                if self.hide() {
+                    self.since_hidden = Some(Duration::ZERO);
+                }
+            }
+            _ => {}
+        }
+    }
+}
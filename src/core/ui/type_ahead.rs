@@ -0,0 +1,67 @@
+use std::time::{Duration, Instant};
+
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Incremental keyboard search over a list of item labels.
+///
+/// Typed characters accumulate into a prefix until the user pauses or the
+/// prefix stops matching; typing the same character repeatedly cycles through
+/// the items starting with it.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TypeAhead {
+    buffer: String,
+    last_key: Option<Instant>,
+}
+
+impl TypeAhead {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn search<S: AsRef<str>>(
+        &mut self,
+        c: char,
+        items: &[S],
+        current: Option<usize>,
+    ) -> Option<usize> {
+        let now = Instant::now();
+        if self
+            .last_key
+            .is_none_or(|last| now.duration_since(last) > TYPE_AHEAD_TIMEOUT)
+        {
+            self.buffer.clear();
+        }
+        self.last_key = Some(now);
+        self.buffer.extend(c.to_lowercase());
+
+        if items.is_empty() {
+            return None;
+        }
+
+        // A prefix nothing matches starts a fresh search with the last character
+        self.find(items, current).or_else(|| {
+            self.buffer = c.to_lowercase().collect();
+            self.find(items, current)
+        })
+    }
+
+    fn find<S: AsRef<str>>(&self, items: &[S], current: Option<usize>) -> Option<usize> {
+        let mut chars = self.buffer.chars();
+        let first = chars.next()?;
+        let cycling = chars.all(|other| other == first);
+        let (prefix, start) = if cycling {
+            (first.to_string(), current.map_or(0, |index| index + 1))
+        } else {
+            (self.buffer.clone(), current.unwrap_or(0))
+        };
+
+        (0..items.len())
+            .map(|offset| (start + offset) % items.len())
+            .find(|index| items[*index].as_ref().to_lowercase().starts_with(&prefix))
+    }
+
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+        self.last_key = None;
+    }
+}
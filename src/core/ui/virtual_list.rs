@@ -0,0 +1,118 @@
+use std::ops::Range;
+
+/// Scroll model for lists of fixed-height rows where only the rows inside the
+/// viewport are materialized.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VirtualList {
+    item_count: usize,
+    row_height: u16,
+    viewport_height: u16,
+    offset: usize,
+}
+
+impl VirtualList {
+    pub fn new(item_count: usize, row_height: u16, viewport_height: u16) -> Self {
+        Self {
+            item_count,
+            row_height: row_height.max(1),
+            viewport_height,
+            offset: 0,
+        }
+    }
+
+    pub fn set_item_count(&mut self, item_count: usize) {
+        self.item_count = item_count;
+        self.offset = self.offset.min(self.max_offset());
+    }
+
+    pub fn set_row_height(&mut self, row_height: u16) {
+        self.row_height = row_height.max(1);
+        self.offset = self.offset.min(self.max_offset());
+    }
+
+    pub fn set_viewport_height(&mut self, viewport_height: u16) {
+        self.viewport_height = viewport_height;
+        self.offset = self.offset.min(self.max_offset());
+    }
+
+    pub fn item_count(&self) -> usize {
+        self.item_count
+    }
+
+    pub fn row_height(&self) -> u16 {
+        self.row_height
+    }
+
+    pub fn viewport_height(&self) -> u16 {
+        self.viewport_height
+    }
+
+    /// Index of the first visible row
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Rows that fit completely in the viewport (at least one)
+    pub fn page_size(&self) -> usize {
+        ((self.viewport_height / self.row_height.max(1)) as usize).max(1)
+    }
+
+    pub fn max_offset(&self) -> usize {
+        self.item_count.saturating_sub(self.page_size())
+    }
+
+    /// Rows that need to be materialized, including a partially visible last row
+    pub fn visible_range(&self) -> Range<usize> {
+        let rows = (self.viewport_height as usize).div_ceil(self.row_height.max(1) as usize);
+        self.offset..(self.offset + rows).min(self.item_count)
+    }
+
+    pub fn scroll_to(&mut self, offset: usize) {
+        self.offset = offset.min(self.max_offset());
+    }
+
+    pub fn scroll_by(&mut self, rows: isize) {
+        self.scroll_to(self.offset.saturating_add_signed(rows));
+    }
+
+    /// Scrolls the minimum amount needed for `index` to be fully visible
+    pub fn ensure_visible(&mut self, index: usize) {
+        if index < self.offset {
+            self.scroll_to(index);
+        } else if index >= self.offset + self.page_size() {
+            self.scroll_to(index + 1 - self.page_size());
+        }
+    }
+
+    /// Row under a y coordinate relative to the top of the viewport
+    pub fn row_at(&self, y: u16) -> Option<usize> {
+        if y >= self.viewport_height {
+            return None;
+        }
+        let index = self.offset + (y / self.row_height.max(1)) as usize;
+        (index < self.item_count).then_some(index)
+    }
+
+    /// Top of a row relative to the top of the viewport, if it is visible
+    pub fn row_top(&self, index: usize) -> Option<u16> {
+        self.visible_range()
+            .contains(&index)
+            .then(|| ((index - self.offset) as u16).saturating_mul(self.row_height))
+    }
+
+    /// Offset and length of a scrollbar thumb on a track of `track` pixels,
+    /// or `None` when every row fits and there is nothing to scroll
+    pub fn thumb(&self, track: u16) -> Option<(u16, u16)> {
+        let page = self.page_size();
+        if self.item_count <= page {
+            return None;
+        }
+
+        let length = ((track as usize * page) / self.item_count)
+            .max(8)
+            .min(track as usize) as u16;
+        let travel = track.saturating_sub(length) as usize;
+        let offset = (travel * self.offset / self.max_offset().max(1)) as u16;
+        Some((offset, length))
+    }
+}
@@ -0,0 +1,30 @@
+use crate::core::{
+    components::{base_component::BaseComponent, properties::boundaries::Boundaries},
+    ui::render::render_context::RenderContext,
+    utils::traits::{interactive::Interactive, renderable::Renderable},
+};
+
+/// Anything a `Container` can hold: it paints itself, takes input and exposes
+/// its `BaseComponent` so the parent can place, show and hide it
+pub trait Component: Renderable + Interactive {
+    fn base(&self) -> &BaseComponent;
+
+    fn base_mut(&mut self) -> &mut BaseComponent;
+
+    /// Frame of the component with the given id, searching this component and
+    /// everything it contains. Components holding children override it.
+    fn find_frame(&self, id: usize) -> Option<Boundaries> {
+        (*self.base().id() == id).then(|| self.base().frame())
+    }
+
+    /// Renders the component with its opacity applied to everything it
+    /// paints. Parents paint their children through it, so opacities
+    /// multiply down the tree.
+    ///
+    /// The opacity fades each primitive on its own rather than the subtree as
+    /// a whole, so where translucent children overlap the lower one shows
+    /// through the upper one
+    fn paint(&self, ctx: &mut RenderContext) {
+        ctx.with_opacity(self.base().opacity(), |ctx| self.render(ctx));
+    }
+}
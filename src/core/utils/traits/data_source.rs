@@ -0,0 +1,40 @@
+use crate::core::components::properties::cell_value::CellValue;
+
+/// Tabular data shown by a `DataGrid`. The grid never copies the data, it asks
+/// for the cells it needs to paint and writes edits back through `set_value`.
+pub trait DataSource {
+    fn row_count(&self) -> usize;
+
+    fn value(&self, row: usize, column: usize) -> CellValue;
+
+    /// Stores an edited value, returning whether it was accepted.
+    /// Sources are read-only unless they override it.
+    fn set_value(&mut self, _row: usize, _column: usize, _value: CellValue) -> bool {
+        false
+    }
+}
+
+// Plain rows of cells, mostly useful for small grids and tests
+impl DataSource for Vec<Vec<CellValue>> {
+    fn row_count(&self) -> usize {
+        self.len()
+    }
+
+    fn value(&self, row: usize, column: usize) -> CellValue {
+        self.get(row)
+            .and_then(|cells| cells.get(column))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn set_value(&mut self, row: usize, column: usize, value: CellValue) -> bool {
+        let Some(cells) = self.get_mut(row) else {
+            return false;
+        };
+        if cells.len() <= column {
+            cells.resize(column + 1, CellValue::Empty);
+        }
+        cells[column] = value;
+        true
+    }
+}
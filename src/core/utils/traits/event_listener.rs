@@ -1,5 +1,11 @@
-use crate::core::window::events::types::EventType;
+use crate::core::window::events::types::{EventPayload, EventType};
 
 pub trait EventListener {
     fn on_event(&mut self, event: &EventType, caller_id: usize);
+
+    /// Called for events emitted with a payload; listeners that only care about
+    /// the event itself can rely on the default, which forwards to `on_event`
+    fn on_event_with(&mut self, event: &EventType, caller_id: usize, _payload: &EventPayload) {
+        self.on_event(event, caller_id);
+    }
 }
@@ -0,0 +1,12 @@
+use crate::core::window::events::input::InputEvent;
+
+pub trait Interactive {
+    /// Handles a raw input event, returning `true` when the component consumed it
+    fn handle_input(&mut self, input: &InputEvent) -> bool;
+
+    fn focusable(&self) -> bool {
+        false
+    }
+
+    fn set_focused(&mut self, _focused: bool) {}
+}
@@ -1,3 +1,7 @@
+pub mod component;
+pub mod data_source;
 pub mod event_listener;
+pub mod interactive;
 pub mod renderable;
 pub mod style_merge;
+pub mod uri_handler;
@@ -1,3 +1,5 @@
+use crate::core::ui::render::render_context::RenderContext;
+
 pub trait Renderable {
-    fn render(&self);
+    fn render(&self, ctx: &mut RenderContext);
 }
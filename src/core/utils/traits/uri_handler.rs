@@ -0,0 +1,6 @@
+/// Opens targets that live outside the application, such as web pages or
+/// `mailto:` addresses. The platform layer decides how to do it.
+pub trait UriHandler {
+    /// Returns whether the URI was opened
+    fn open_uri(&mut self, uri: &str) -> bool;
+}
@@ -1,6 +1,9 @@
 use std::{cell::RefCell, collections::HashMap, rc::Weak};
 
-use crate::core::{utils::traits::event_listener::EventListener, window::events::types::EventType};
+use crate::core::{
+    utils::traits::event_listener::EventListener,
+    window::events::types::{EventPayload, EventType},
+};
 
 #[derive(Debug, Clone, Default)]
 pub struct EventSystem {
@@ -25,11 +28,24 @@ impl EventSystem {
     }
 
     pub fn emit(&mut self, event: EventType, caller_id: usize) {
+        self.dispatch(event, caller_id, None);
+    }
+
+    pub fn emit_with(&mut self, event: EventType, caller_id: usize, payload: EventPayload) {
+        self.dispatch(event, caller_id, Some(&payload));
+    }
+
+    fn dispatch(&mut self, event: EventType, caller_id: usize, payload: Option<&EventPayload>) {
         if let Some(listeners) = self.listeners.get_mut(&event) {
             // Filtrar listeners que ya no existen y notificar a los válidos
             listeners.retain(|weak_listener| {
                 if let Some(listener) = weak_listener.upgrade() {
-                    listener.borrow_mut().on_event(&event, caller_id);
+                    match payload {
+                        Some(payload) => listener
+                            .borrow_mut()
+                            .on_event_with(&event, caller_id, payload),
+                        None => listener.borrow_mut().on_event(&event, caller_id),
+                    }
                     true
                 } else {
                     false // Remover listeners muertos
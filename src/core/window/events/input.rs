@@ -0,0 +1,245 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::components::properties::position::Position;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+}
+
+impl Modifiers {
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    pub fn shift() -> Self {
+        Self {
+            shift: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn ctrl() -> Self {
+        Self {
+            ctrl: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn alt() -> Self {
+        Self {
+            alt: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.shift && !self.ctrl && !self.alt
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Key {
+    Char(char),
+    Enter,
+    Space,
+    Tab,
+    Escape,
+    Backspace,
+    Delete,
+    Left,
+    Right,
+    Up,
+    Down,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    F(u8),
+}
+
+/// Key plus modifiers, as used by shortcuts and accelerators. Letters are kept
+/// lowercase so `Ctrl+S` matches whether or not Shift was held for the letter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct KeyCombination {
+    pub key: Key,
+    pub modifiers: Modifiers,
+}
+
+impl KeyCombination {
+    pub fn new(key: Key, modifiers: Modifiers) -> Self {
+        let key = match key {
+            Key::Char(c) => Key::Char(c.to_ascii_lowercase()),
+            key => key,
+        };
+        Self { key, modifiers }
+    }
+
+    /// Parses labels such as `Ctrl+Shift+S`, `Alt+F4` or `Del`
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut modifiers = Modifiers::none();
+        let mut parts = text.split('+').map(str::trim).peekable();
+        let mut key = None;
+        while let Some(part) = parts.next() {
+            let last = parts.peek().is_none();
+            match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" if !last => modifiers.ctrl = true,
+                "shift" if !last => modifiers.shift = true,
+                "alt" if !last => modifiers.alt = true,
+                name if last => key = Some(Self::parse_key(name)?),
+                _ => return None,
+            }
+        }
+        Some(Self::new(key?, modifiers))
+    }
+
+    fn parse_key(name: &str) -> Option<Key> {
+        let mut chars = name.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Some(Key::Char(c));
+        }
+
+        Some(match name {
+            "enter" | "return" => Key::Enter,
+            "space" => Key::Space,
+            "tab" => Key::Tab,
+            "esc" | "escape" => Key::Escape,
+            "backspace" => Key::Backspace,
+            "del" | "delete" => Key::Delete,
+            "left" => Key::Left,
+            "right" => Key::Right,
+            "up" => Key::Up,
+            "down" => Key::Down,
+            "home" => Key::Home,
+            "end" => Key::End,
+            "pgup" | "pageup" => Key::PageUp,
+            "pgdn" | "pagedown" => Key::PageDown,
+            _ => {
+                let number = name.strip_prefix('f')?.parse().ok()?;
+                if !(1..=24).contains(&number) {
+                    return None;
+                }
+                Key::F(number)
+            }
+        })
+    }
+
+    /// Combination of a key press that can trigger a shortcut: anything held
+    /// with Ctrl or Alt, plus the function keys and Delete on their own
+    pub fn from_input(input: &InputEvent) -> Option<Self> {
+        let InputEvent::KeyPressed { key, modifiers } = *input else {
+            return None;
+        };
+        let standalone = matches!(key, Key::F(_) | Key::Delete);
+        (modifiers.ctrl || modifiers.alt || standalone).then(|| Self::new(key, modifiers))
+    }
+}
+
+impl fmt::Display for KeyCombination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.ctrl {
+            f.write_str("Ctrl+")?;
+        }
+        if self.modifiers.shift {
+            f.write_str("Shift+")?;
+        }
+        if self.modifiers.alt {
+            f.write_str("Alt+")?;
+        }
+        let name = match self.key {
+            Key::Char(c) => return write!(f, "{}", c.to_ascii_uppercase()),
+            Key::F(number) => return write!(f, "F{number}"),
+            Key::Enter => "Enter",
+            Key::Space => "Space",
+            Key::Tab => "Tab",
+            Key::Escape => "Esc",
+            Key::Backspace => "Backspace",
+            Key::Delete => "Del",
+            Key::Left => "Left",
+            Key::Right => "Right",
+            Key::Up => "Up",
+            Key::Down => "Down",
+            Key::Home => "Home",
+            Key::End => "End",
+            Key::PageUp => "PgUp",
+            Key::PageDown => "PgDn",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Raw input as delivered by the window, before any component handles it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputEvent {
+    MouseMoved(Position),
+    MouseButtonPressed {
+        button: MouseButton,
+        position: Position,
+        modifiers: Modifiers,
+    },
+    MouseButtonReleased {
+        button: MouseButton,
+        position: Position,
+        modifiers: Modifiers,
+    },
+    // Deltas are in wheel notches, positive values scroll up and left
+    MouseScrolled {
+        delta_x: i16,
+        delta_y: i16,
+        position: Position,
+    },
+    KeyPressed {
+        key: Key,
+        modifiers: Modifiers,
+    },
+    KeyReleased {
+        key: Key,
+        modifiers: Modifiers,
+    },
+    CharTyped(char),
+}
+
+impl InputEvent {
+    pub fn mouse_position(&self) -> Option<Position> {
+        match self {
+            InputEvent::MouseMoved(position)
+            | InputEvent::MouseButtonPressed { position, .. }
+            | InputEvent::MouseButtonReleased { position, .. }
+            | InputEvent::MouseScrolled { position, .. } => Some(*position),
+            _ => None,
+        }
+    }
+
+    /// Same event with the pointer at another position; keyboard events are
+    /// returned unchanged
+    pub fn with_mouse_position(mut self, new_position: Position) -> Self {
+        match &mut self {
+            InputEvent::MouseMoved(position)
+            | InputEvent::MouseButtonPressed { position, .. }
+            | InputEvent::MouseButtonReleased { position, .. }
+            | InputEvent::MouseScrolled { position, .. } => *position = new_position,
+            _ => {}
+        }
+        self
+    }
+
+    pub fn is_keyboard(&self) -> bool {
+        matches!(
+            self,
+            InputEvent::KeyPressed { .. }
+                | InputEvent::KeyReleased { .. }
+                | InputEvent::CharTyped(_)
+        )
+    }
+}
@@ -1,2 +1,3 @@
 pub mod event_system;
+pub mod input;
 pub mod types;
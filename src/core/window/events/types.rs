@@ -1,3 +1,11 @@
+use crate::core::{
+    components::properties::{
+        check_state::CheckState, date_time::DateTime, dialog_result::DialogResult,
+        graphics::color::Color,
+    },
+    window::events::input::KeyCombination,
+};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EventType {
     // Component events
@@ -8,6 +16,7 @@ pub enum EventType {
     ComponentPaddingChanged,
     ComponentMarginChanged,
     ComponentVisibilityChanged,
+    ComponentScrolled,
 
     // Window events
     WindowOpened,
@@ -68,7 +77,42 @@ pub enum EventType {
     FieldValidating,
     FieldFocusMove,
 
+    // Item events
+    ItemExpanded,
+    ItemCollapsed,
+    ItemChecked,
+    ItemClosed,
+    ItemMoved,
+
+    // Command events
+    CommandInvoked,
+
     // System events
     RenderRequested,
     UpdateRequested,
 }
+
+/// Data carried along with an event, for listeners that need more than the caller id
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventPayload {
+    Text(String),
+    Bool(bool),
+    CheckState(CheckState),
+    Index(usize),
+    Indices(Vec<usize>),
+    // Check state of the item at an index or with an id
+    ItemCheckState(usize, CheckState),
+    // Cell as (data source row, grid column)
+    Cell(usize, usize),
+    // Old and new index of an item that changed places
+    Moved(usize, usize),
+    KeyCombination(KeyCombination),
+    // Value of a range control
+    Number(f64),
+    // How a dialog was closed
+    DialogResult(DialogResult),
+    // Value of a date picker
+    DateTime(DateTime),
+    // Value of a color picker
+    Color(Color),
+}
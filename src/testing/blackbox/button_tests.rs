@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod button_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::{
+                button::{Button, IconPosition},
+                icon::Icon,
+            },
+            properties::{graphics::action_state::ActionState, position::Position, size::Size},
+        },
+        ui::render::{draw_command::DrawCommand, render_context::RenderContext},
+        utils::traits::{interactive::Interactive, renderable::Renderable},
+        window::events::{event_system::EventSystem, input::Key, types::EventType},
+    };
+
+    fn new_button(event_system: &Rc<RefCell<EventSystem>>) -> Button {
+        let base = new_base(event_system, Position::new(10, 10), Size::new(30, 100));
+        Button::new(base, "OK")
+    }
+
+    #[test]
+    fn test_button_state_machine() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut button = new_button(&event_system);
+
+        assert_eq!(button.state(), ActionState::Normal);
+
+        button.handle_input(&mouse_move(20, 20));
+        assert_eq!(button.state(), ActionState::Hovered);
+
+        button.handle_input(&mouse_down(20, 20));
+        assert_eq!(button.state(), ActionState::Pressed);
+
+        button.handle_input(&mouse_up(20, 20));
+        assert_eq!(button.state(), ActionState::Hovered);
+
+        button.handle_input(&mouse_move(200, 200));
+        assert_eq!(button.state(), ActionState::Normal);
+    }
+
+    #[test]
+    fn test_button_click_requires_press_and_release_inside() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::MouseButtonClicked]);
+        let mut button = new_button(&event_system);
+
+        // Press inside, release outside: no click
+        button.handle_input(&mouse_down(20, 20));
+        button.handle_input(&mouse_move(200, 200));
+        assert_eq!(button.state(), ActionState::Normal);
+        button.handle_input(&mouse_up(200, 200));
+        assert_eq!(recorder.borrow().count(EventType::MouseButtonClicked), 0);
+
+        // Press outside, release inside: no click
+        button.handle_input(&mouse_down(200, 200));
+        button.handle_input(&mouse_up(20, 20));
+        assert_eq!(recorder.borrow().count(EventType::MouseButtonClicked), 0);
+
+        // Press and release inside: click
+        button.handle_input(&mouse_down(20, 20));
+        button.handle_input(&mouse_up(25, 25));
+        assert_eq!(
+            recorder.borrow().events,
+            vec![(EventType::MouseButtonClicked, *button.base().id())]
+        );
+    }
+
+    #[test]
+    fn test_button_hit_test_excludes_far_edges() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut button = new_button(&event_system);
+
+        button.handle_input(&mouse_move(109, 39));
+        assert_eq!(button.state(), ActionState::Hovered);
+
+        button.handle_input(&mouse_move(110, 39));
+        assert_eq!(button.state(), ActionState::Normal);
+
+        button.handle_input(&mouse_move(109, 40));
+        assert_eq!(button.state(), ActionState::Normal);
+
+        button.handle_input(&mouse_move(10, 10));
+        assert_eq!(button.state(), ActionState::Hovered);
+    }
+
+    #[test]
+    fn test_button_disabled() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::MouseButtonClicked]);
+        let mut button = new_button(&event_system);
+
+        button.set_enabled(false);
+        assert_eq!(button.state(), ActionState::Disabled);
+        assert!(!button.focusable());
+
+        assert!(!button.handle_input(&mouse_move(20, 20)));
+        assert!(!button.handle_input(&mouse_down(20, 20)));
+        assert!(!button.handle_input(&mouse_up(20, 20)));
+        assert_eq!(button.state(), ActionState::Disabled);
+        assert_eq!(recorder.borrow().count(EventType::MouseButtonClicked), 0);
+
+        button.set_enabled(true);
+        assert_eq!(button.state(), ActionState::Normal);
+    }
+
+    #[test]
+    fn test_button_keyboard_activation() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::MouseButtonClicked]);
+        let mut button = new_button(&event_system);
+
+        // Keys are ignored without focus
+        assert!(!button.handle_input(&key_down(Key::Enter)));
+
+        button.set_focused(true);
+        button.handle_input(&key_down(Key::Space));
+        assert_eq!(button.state(), ActionState::Pressed);
+        assert_eq!(recorder.borrow().count(EventType::MouseButtonClicked), 0);
+        button.handle_input(&key_up(Key::Space));
+        assert_eq!(button.state(), ActionState::Normal);
+        assert_eq!(recorder.borrow().count(EventType::MouseButtonClicked), 1);
+
+        button.handle_input(&key_down(Key::Enter));
+        assert_eq!(recorder.borrow().count(EventType::MouseButtonClicked), 2);
+
+        // Escape cancels a pending space press
+        button.handle_input(&key_down(Key::Space));
+        button.handle_input(&key_down(Key::Escape));
+        button.handle_input(&key_up(Key::Space));
+        assert_eq!(recorder.borrow().count(EventType::MouseButtonClicked), 2);
+    }
+
+    #[test]
+    fn test_button_icon_layout() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let icon = Icon::new("icons/ok.ico", (16, 16));
+
+        let button = new_button(&event_system).with_icon(icon.clone(), IconPosition::Start);
+        let (icon_at, text_at) = button.content_layout();
+        let icon_at = icon_at.unwrap();
+        assert!(icon_at.x() < text_at.x());
+        assert_eq!(text_at.x(), icon_at.x() + 16 + 4);
+
+        let button = new_button(&event_system).with_icon(icon.clone(), IconPosition::End);
+        let (icon_at, text_at) = button.content_layout();
+        assert!(icon_at.unwrap().x() > text_at.x());
+
+        let button =
+            new_button(&event_system).with_icon(icon, IconPosition::Located(Position::new(2, 3)));
+        let (icon_at, _) = button.content_layout();
+        assert_eq!(icon_at, Some(Position::new(12, 13)));
+    }
+
+    #[test]
+    fn test_button_render() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut button = new_button(&event_system);
+        let mut ctx = RenderContext::new();
+
+        button.render(&mut ctx);
+        let normal_background = match &ctx.commands()[0] {
+            DrawCommand::FillRect { background, .. } => background.clone(),
+            other => panic!("unexpected command {other:?}"),
+        };
+        assert_eq!(
+            &normal_background,
+            button.style().background(&ActionState::Normal)
+        );
+        assert!(
+            ctx.commands()
+                .iter()
+                .any(|command| matches!(command, DrawCommand::Text { text, .. } if text == "OK"))
+        );
+
+        button.handle_input(&mouse_move(20, 20));
+        ctx.clear();
+        button.render(&mut ctx);
+        assert!(matches!(
+            &ctx.commands()[0],
+            DrawCommand::FillRect { background, .. } if background == button.style().background(&ActionState::Hovered)
+        ));
+    }
+
+    #[test]
+    fn test_button_serialization_keeps_disabled_state() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut button = new_button(&event_system);
+        button.set_enabled(false);
+
+        let json = serde_json::to_string(&button).unwrap();
+        let deserialized: Button = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized.text(), "OK");
+        assert_eq!(deserialized.state(), ActionState::Disabled);
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod check_box_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::check_box::CheckBox,
+            properties::{
+                check_state::CheckState, graphics::action_state::ActionState, position::Position,
+                size::Size,
+            },
+        },
+        utils::traits::interactive::Interactive,
+        window::events::{
+            event_system::EventSystem,
+            input::Key,
+            types::{EventPayload, EventType},
+        },
+    };
+
+    fn new_check_box(event_system: &Rc<RefCell<EventSystem>>) -> CheckBox {
+        let base = new_base(event_system, Position::new(0, 0), Size::new(20, 120));
+        CheckBox::new(base, "Remember me")
+    }
+
+    fn click(check_box: &mut CheckBox) {
+        check_box.handle_input(&mouse_down(5, 5));
+        check_box.handle_input(&mouse_up(5, 5));
+    }
+
+    #[test]
+    fn test_check_box_two_state_cycle() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let mut check_box = new_check_box(&event_system);
+
+        click(&mut check_box);
+        assert_eq!(check_box.check_state(), CheckState::Checked);
+        assert!(check_box.checked());
+        click(&mut check_box);
+        assert_eq!(check_box.check_state(), CheckState::Unchecked);
+
+        assert_eq!(recorder.borrow().count(EventType::FieldValueChanged), 2);
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::CheckState(CheckState::Unchecked))
+        );
+    }
+
+    #[test]
+    fn test_check_box_three_state_cycle() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut check_box = new_check_box(&event_system).with_three_state(true);
+
+        let mut seen = Vec::new();
+        for _ in 0..3 {
+            click(&mut check_box);
+            seen.push(check_box.check_state());
+        }
+        assert_eq!(
+            seen,
+            vec![
+                CheckState::Checked,
+                CheckState::Indeterminate,
+                CheckState::Unchecked
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_box_keyboard_and_state() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut check_box = new_check_box(&event_system);
+
+        check_box.set_focused(true);
+        check_box.handle_input(&key_down(Key::Space));
+        assert_eq!(check_box.state(), ActionState::Pressed);
+        check_box.handle_input(&key_up(Key::Space));
+        assert!(check_box.checked());
+
+        // Enter does not toggle a check box
+        check_box.handle_input(&key_down(Key::Enter));
+        assert!(check_box.checked());
+
+        check_box.set_enabled(false);
+        assert_eq!(check_box.state(), ActionState::Disabled);
+        click(&mut check_box);
+        assert!(check_box.checked());
+    }
+
+    #[test]
+    fn test_check_box_serialization() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let check_box = new_check_box(&event_system)
+            .with_three_state(true)
+            .with_check_state(CheckState::Indeterminate);
+
+        let json = serde_json::to_value(&check_box).unwrap();
+        assert_eq!(json["check_state"], "Indeterminate");
+        assert_eq!(json["three_state"], true);
+
+        let deserialized: CheckBox = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.check_state(), CheckState::Indeterminate);
+        assert_eq!(deserialized.text(), "Remember me");
+    }
+}
@@ -0,0 +1,231 @@
+#[cfg(test)]
+mod color_picker_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::color_picker::{ColorFormat, ColorPicker, ColorPickerPart},
+            properties::{
+                boundaries::Boundaries,
+                graphics::{
+                    background::{Background, BackgroundShape},
+                    color::Color,
+                },
+                position::Position,
+                size::Size,
+            },
+        },
+        ui::render::{draw_command::DrawCommand, render_context::RenderContext},
+        utils::traits::{interactive::Interactive, renderable::Renderable},
+        window::events::{
+            event_system::EventSystem,
+            input::Key,
+            types::{EventPayload, EventType},
+        },
+    };
+
+    /// Selector de 260x240 en el origen: cuadro en (8, 8), barras de tono
+    /// y alfa en x = 176 y x = 200, campo hex en (56, 176)
+    fn picker(event_system: &Rc<RefCell<EventSystem>>) -> ColorPicker {
+        ColorPicker::new(new_base(
+            event_system,
+            Position::new(0, 0),
+            Size::new(240, 260),
+        ))
+    }
+
+    fn retype(picker: &mut ColorPicker, text: &str) {
+        for _ in 0..picker.text().chars().count() {
+            picker.handle_input(&key_down(Key::Backspace));
+        }
+        for input in type_text(text) {
+            picker.handle_input(&input);
+        }
+        picker.handle_input(&key_down(Key::Enter));
+    }
+
+    #[test]
+    fn test_square_drag_picks_saturation_and_value() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let mut picker = picker(&event_system);
+        assert_eq!(
+            picker.part_bounds(ColorPickerPart::Square),
+            Some(Boundaries::from_rect(
+                Position::new(8, 8),
+                Size::new(160, 160)
+            ))
+        );
+        assert_eq!(picker.color(), Color::rgba(0, 0, 0, 255));
+
+        // Top right corner is the pure hue
+        picker.handle_input(&mouse_down(167, 8));
+        assert_eq!(picker.color(), Color::rgba(255, 0, 0, 255));
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Color(Color::rgba(255, 0, 0, 255)))
+        );
+        picker.handle_input(&mouse_move(8, 8));
+        assert_eq!(picker.text(), "#FFFFFF");
+        // The drag keeps going outside the square, clamped to its edge
+        picker.handle_input(&mouse_move(300, 300));
+        assert_eq!((picker.saturation(), picker.value()), (1.0, 0.0));
+        assert!(picker.recent_colors().is_empty());
+
+        picker.handle_input(&mouse_up(300, 300));
+        assert_eq!(picker.recent_colors(), &[Color::rgba(0, 0, 0, 255)]);
+        // Changes reported by the picker itself, not by its hex field
+        let changes = recorder
+            .borrow()
+            .events
+            .iter()
+            .filter(|(event, id)| {
+                *event == EventType::FieldValueChanged && id == picker.base().id()
+            })
+            .count();
+        assert_eq!(changes, 3);
+
+        // Moving after the release no longer changes the color
+        picker.handle_input(&mouse_move(8, 8));
+        assert_eq!(picker.color(), Color::rgba(0, 0, 0, 255));
+    }
+
+    #[test]
+    fn test_hue_and_alpha_bars_and_formats() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut picker = picker(&event_system);
+        picker.set_saturation(1.0);
+        picker.set_value(1.0);
+
+        picker.handle_input(&mouse_down(180, 61));
+        picker.handle_input(&mouse_up(180, 61));
+        assert_eq!(picker.hue(), 120.0);
+        assert_eq!(picker.color(), Color::rgba(0, 255, 0, 255));
+
+        // Bottom of the alpha bar is fully transparent
+        picker.handle_input(&mouse_down(204, 167));
+        picker.handle_input(&mouse_up(204, 167));
+        assert_eq!(picker.color(), Color::rgba(0, 255, 0, 0));
+        assert_eq!(picker.text(), "#00FF0000");
+
+        picker.handle_input(&scroll(204, 100, 1));
+        assert_eq!(picker.text(), "#00FF000D");
+
+        picker.set_hue(240.0);
+        picker.set_format(ColorFormat::Rgb);
+        assert_eq!(picker.color(), Color::rgb(0, 0, 255));
+        picker.set_format(ColorFormat::Hsv);
+        assert_eq!(picker.color(), Color::hsv(120, 255, 255));
+        picker.set_format(ColorFormat::Hex);
+        let Color::HEX(hex) = picker.color() else {
+            panic!("expected a HEX color");
+        };
+        assert_eq!(hex.value(), "#0000FF0D");
+
+        // Colors in any representation can be loaded
+        picker.set_color(&Color::hsv(30, 255, 255));
+        assert_eq!(picker.hue(), 60.0);
+        picker.set_color(&Color::hex("#FF8040"));
+        assert_eq!(picker.text(), "#FF8040");
+    }
+
+    #[test]
+    fn test_hex_field_is_validated() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValidating]);
+        let mut picker = picker(&event_system);
+        picker.set_focused(true);
+
+        retype(&mut picker, "#3C8");
+        assert_eq!(picker.color(), Color::rgba(51, 204, 136, 255));
+        assert_eq!(picker.text(), "#33CC88");
+        assert_eq!(picker.recent_colors().len(), 1);
+
+        retype(&mut picker, "0x11223344");
+        assert_eq!(picker.color(), Color::rgba(17, 34, 51, 68));
+
+        retype(&mut picker, "blue-ish");
+        assert_eq!(picker.text(), "#11223344");
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValidating),
+            Some(EventPayload::Text("blue-ish".to_string()))
+        );
+
+        // Greys keep the hue the square was showing
+        picker.set_color(&Color::rgb(128, 128, 128));
+        assert_eq!(picker.saturation(), 0.0);
+        assert_eq!(picker.hue(), 210.0);
+    }
+
+    #[test]
+    fn test_recent_palette() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut picker = picker(&event_system).with_recent_capacity(3);
+        for color in [
+            Color::rgb(1, 2, 3),
+            Color::rgb(4, 5, 6),
+            Color::rgb(7, 8, 9),
+            Color::rgba(1, 2, 3, 255),
+            Color::rgb(10, 11, 12),
+        ] {
+            picker.add_recent(color);
+        }
+        // The same color in another representation is not repeated
+        assert_eq!(
+            picker.recent_colors(),
+            &[
+                Color::rgb(10, 11, 12),
+                Color::rgba(1, 2, 3, 255),
+                Color::rgb(7, 8, 9)
+            ]
+        );
+        assert_eq!(
+            picker.part_bounds(ColorPickerPart::Swatch(1)),
+            Some(Boundaries::from_rect(
+                Position::new(28, 207),
+                Size::new(16, 16)
+            ))
+        );
+        assert_eq!(picker.part_bounds(ColorPickerPart::Swatch(3)), None);
+
+        picker.handle_input(&mouse_down(50, 210));
+        picker.handle_input(&mouse_up(50, 210));
+        assert_eq!(picker.color(), Color::rgba(7, 8, 9, 255));
+        assert_eq!(picker.recent_colors()[0], Color::rgb(7, 8, 9));
+
+        let mut ctx = RenderContext::new();
+        picker.render(&mut ctx);
+        let swatch = Background::new_solid(Color::rgb(10, 11, 12), BackgroundShape::Rectangle);
+        assert!(ctx.commands().iter().any(|command| matches!(
+            command,
+            DrawCommand::FillRect { bounds, background }
+                if bounds.top_left() == Position::new(28, 207) && *background == swatch
+        )));
+    }
+
+    #[test]
+    fn test_restored_and_moved_picker_keeps_its_hex_field() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let picker = picker(&event_system).with_color(&Color::rgb(51, 204, 136));
+        let json = serde_json::to_string(&picker).unwrap();
+        let mut restored: ColorPicker = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.text(), "#33CC88");
+
+        // The field is painted where the moved picker has it, without moving
+        // the editor while painting
+        restored.base_mut().set_position(Position::new(0, 100));
+        let event_system = restored.base().event_system().clone();
+        let ctx = render_quietly(&event_system, &restored);
+        assert!(ctx.commands().iter().any(|command| matches!(
+            command,
+            DrawCommand::Text { text, position, .. } if text == "#33CC88" && *position == Position::new(56, 276)
+        )));
+
+        restored.set_focused(true);
+        retype(&mut restored, "0x11223344");
+        assert_eq!(restored.color(), Color::rgba(17, 34, 51, 68));
+        let editor = restored.editor().unwrap().base().frame();
+        assert_eq!(editor.top_left(), Position::new(56, 276));
+    }
+}
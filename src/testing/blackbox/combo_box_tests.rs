@@ -0,0 +1,232 @@
+#[cfg(test)]
+mod combo_box_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::combo_box::{ComboBox, DropDownMode},
+            properties::{boundaries::Boundaries, position::Position, size::Size},
+        },
+        ui::render::{draw_command::DrawCommand, render_context::RenderContext},
+        utils::traits::{interactive::Interactive, renderable::Renderable},
+        window::events::{
+            event_system::EventSystem,
+            input::{InputEvent, Key, Modifiers},
+            types::{EventPayload, EventType},
+        },
+    };
+
+    const FRUITS: [&str; 5] = ["Apple", "Banana", "Blueberry", "Cherry", "Date"];
+
+    // 120x20 box at (10, 10)
+    fn new_combo_box(event_system: &Rc<RefCell<EventSystem>>) -> ComboBox {
+        let base = new_base(event_system, Position::new(10, 10), Size::new(20, 120));
+        ComboBox::new(base).with_items(&FRUITS)
+    }
+
+    fn texts(commands: &[DrawCommand]) -> Vec<String> {
+        commands
+            .iter()
+            .filter_map(|command| match command {
+                DrawCommand::Text { text, .. } => Some(text.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_combo_box_opens_and_selects_with_mouse() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let mut combo_box = new_combo_box(&event_system);
+
+        combo_box.handle_input(&mouse_down(50, 15));
+        assert!(combo_box.is_open());
+        assert!(combo_box.focused());
+
+        // The list opens right below the box; row height is 18 plus 1px border
+        let popup = combo_box.popup_bounds().unwrap();
+        assert_eq!(popup.top_left(), Position::new(10, 30));
+        let third_row_y = popup.top_left().y() + 1 + 2 * 18 + 5;
+        combo_box.handle_input(&mouse_move(50, third_row_y));
+        assert_eq!(combo_box.highlighted_index(), Some(2));
+        combo_box.handle_input(&mouse_up(50, third_row_y));
+
+        assert!(!combo_box.is_open());
+        assert_eq!(combo_box.selected_item(), Some("Blueberry"));
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Index(2))
+        );
+    }
+
+    #[test]
+    fn test_combo_box_closes_on_outside_click_and_escape() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut combo_box = new_combo_box(&event_system);
+
+        combo_box.open();
+        combo_box.handle_input(&key_down(Key::Down));
+        assert!(combo_box.handle_input(&mouse_down(400, 400)));
+        assert!(!combo_box.is_open());
+        assert_eq!(combo_box.selected_index(), None);
+
+        combo_box.set_focused(true);
+        combo_box.handle_input(&key_down_with(Key::Down, Modifiers::alt()));
+        assert!(combo_box.is_open());
+        combo_box.handle_input(&key_down(Key::Down));
+        combo_box.handle_input(&key_down(Key::Escape));
+        assert!(!combo_box.is_open());
+        assert_eq!(combo_box.selected_index(), None);
+    }
+
+    #[test]
+    fn test_combo_box_keyboard_navigation() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut combo_box = new_combo_box(&event_system);
+        combo_box.set_focused(true);
+
+        // Closed: arrows change the selection directly
+        combo_box.handle_input(&key_down(Key::Down));
+        combo_box.handle_input(&key_down(Key::Down));
+        assert_eq!(combo_box.selected_index(), Some(1));
+        combo_box.handle_input(&key_down(Key::End));
+        assert_eq!(combo_box.selected_index(), Some(4));
+
+        // Open: arrows move the highlight and Enter commits it
+        combo_box.handle_input(&key_down(Key::F(4)));
+        assert_eq!(combo_box.highlighted_index(), Some(4));
+        combo_box.handle_input(&key_down(Key::Home));
+        combo_box.handle_input(&key_down(Key::Down));
+        assert_eq!(combo_box.selected_index(), Some(4));
+        combo_box.handle_input(&key_down(Key::Enter));
+        assert!(!combo_box.is_open());
+        assert_eq!(combo_box.selected_item(), Some("Banana"));
+    }
+
+    #[test]
+    fn test_combo_box_type_ahead() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut combo_box = new_combo_box(&event_system);
+        combo_box.set_focused(true);
+
+        combo_box.handle_input(&InputEvent::CharTyped('c'));
+        assert_eq!(combo_box.selected_item(), Some("Cherry"));
+
+        // Repeating a letter cycles through the matching items
+        combo_box.handle_input(&InputEvent::CharTyped('b'));
+        assert_eq!(combo_box.selected_item(), Some("Banana"));
+        combo_box.handle_input(&InputEvent::CharTyped('b'));
+        assert_eq!(combo_box.selected_item(), Some("Blueberry"));
+
+        // Longer prefixes narrow the match, even while open
+        combo_box.close();
+        combo_box.open();
+        for c in "blu".chars() {
+            combo_box.handle_input(&InputEvent::CharTyped(c));
+        }
+        assert_eq!(combo_box.highlighted_index(), Some(2));
+    }
+
+    #[test]
+    fn test_combo_box_editable_mode() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut combo_box = new_combo_box(&event_system).with_mode(DropDownMode::Editable);
+
+        // Clicking the text area edits, clicking the button opens the list
+        combo_box.handle_input(&mouse_down(20, 15));
+        assert!(!combo_box.is_open());
+        for input in type_text("Kiwi") {
+            combo_box.handle_input(&input);
+        }
+        assert_eq!(combo_box.text(), "Kiwi");
+        assert_eq!(combo_box.selected_index(), None);
+
+        combo_box.handle_input(&mouse_down(125, 15));
+        assert!(combo_box.is_open());
+        combo_box.handle_input(&key_down(Key::Down));
+        combo_box.handle_input(&key_down(Key::Enter));
+        assert_eq!(combo_box.text(), "Apple");
+    }
+
+    #[test]
+    fn test_combo_box_virtualizes_long_lists() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let items: Vec<String> = (0..10_000).map(|i| format!("Item {i}")).collect();
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(20, 120));
+        let mut combo_box = ComboBox::new(base).with_items(&items);
+
+        combo_box.set_selected_index(Some(5_000));
+        combo_box.open();
+        assert_eq!(combo_box.visible_items().len(), 8);
+        assert!(combo_box.visible_items().contains(&5_000));
+
+        let mut ctx = RenderContext::new();
+        combo_box.render(&mut ctx);
+        assert_eq!(ctx.layer_count(), 2);
+        assert_eq!(texts(ctx.layer(1)).len(), 8);
+        assert_eq!(texts(ctx.commands()), vec!["Item 5000"]);
+
+        combo_box.handle_input(&InputEvent::MouseScrolled {
+            delta_x: 0,
+            delta_y: -1,
+            position: Position::new(10, 40),
+        });
+        assert_eq!(combo_box.visible_items().start, 4_996);
+    }
+
+    #[test]
+    fn test_combo_box_popup_flips_above_near_viewport_edge() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let base = new_base(&event_system, Position::new(10, 180), Size::new(20, 120));
+        let mut combo_box = ComboBox::new(base).with_items(&FRUITS);
+        combo_box.set_viewport(Boundaries::from_rect(
+            Position::new(0, 0),
+            Size::new(200, 300),
+        ));
+
+        combo_box.open();
+        let popup = combo_box.popup_bounds().unwrap();
+        assert_eq!(popup.bottom_right().y(), 180);
+    }
+
+    #[test]
+    fn test_restored_editable_combo_box_keeps_its_editor() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut combo_box = new_combo_box(&event_system).with_mode(DropDownMode::Editable);
+        combo_box.set_selected_index(Some(2));
+
+        // A selection past the items is dropped rather than kept dangling
+        let mut json = serde_json::to_value(&combo_box).unwrap();
+        json["selected"] = serde_json::json!(9);
+        let dangling: ComboBox = serde_json::from_value(json).unwrap();
+        assert_eq!(dangling.selected_index(), None);
+
+        let json = serde_json::to_string(&combo_box).unwrap();
+        let mut restored: ComboBox = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.text(), "Blueberry");
+
+        // The editor is painted over the moved text area without being moved
+        restored.base_mut().set_position(Position::new(10, 100));
+        let event_system = restored.base().event_system().clone();
+        let ctx = render_quietly(&event_system, &restored);
+        assert!(ctx.commands().iter().any(|command| matches!(
+            command,
+            DrawCommand::Text { text, position, .. } if text == "Blueberry" && position.y() >= 100
+        )));
+
+        // Typing reaches the editor, now over the moved text area
+        restored.handle_input(&mouse_down(20, 105));
+        restored.handle_input(&key_down(Key::End));
+        for _ in 0.."Blueberry".len() {
+            restored.handle_input(&key_down(Key::Backspace));
+        }
+        for input in type_text("Kiwi") {
+            restored.handle_input(&input);
+        }
+        assert_eq!(restored.text(), "Kiwi");
+        let editor = restored.editor().unwrap().base().frame();
+        assert_eq!(editor.top_left(), Position::new(10, 100));
+    }
+}
@@ -0,0 +1,189 @@
+#[cfg(test)]
+mod contrast_lint_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::button::Button,
+            layouts::{container::Container, scroll_viewer::ScrollViewer},
+            properties::{
+                graphics::{
+                    background::{Background, BackgroundShape},
+                    blend_mode::BlendMode,
+                    color::{Color, WcagLevel},
+                    gradient::{Gradient, GradientType},
+                },
+                position::Position,
+                size::Size,
+                typography::{FontWeight, TypoGraphy},
+            },
+            styles::button_style::ButtonStyle,
+        },
+        ui::systems::theme_system::ThemeSystem,
+        window::events::event_system::EventSystem,
+    };
+
+    fn button(
+        event_system: &Rc<RefCell<EventSystem>>,
+        y: u16,
+        text: &str,
+        style: ButtonStyle,
+    ) -> Button {
+        Button::new(
+            new_base(event_system, Position::new(10, y), Size::new(30, 120)),
+            text,
+        )
+        .with_style(style)
+    }
+
+    /// Botones con el estilo por defecto, texto gris claro, un degradado que
+    /// llega al blanco del texto y un título grande gris sobre blanco
+    fn window(event_system: &Rc<RefCell<EventSystem>>, faint: &Color) -> Container {
+        let faint = ButtonStyle {
+            text_color: faint.clone(),
+            ..ButtonStyle::default()
+        };
+        let banner = ButtonStyle {
+            normal: Background::new_gradient(
+                Gradient::new(
+                    GradientType::Linear(90),
+                    vec![
+                        (Color::rgb(0, 51, 153), 0),
+                        (Color::rgb(255, 255, 255), 100),
+                    ],
+                ),
+                BackgroundShape::Rectangle,
+            ),
+            text_color: Color::rgb(255, 255, 255),
+            ..ButtonStyle::default()
+        };
+        let title = ButtonStyle {
+            normal: Background::new_solid(Color::rgb(255, 255, 255), BackgroundShape::Rectangle),
+            text_color: Color::rgb(130, 130, 130),
+            typography: TypoGraphy::new(24, "Segoe UI", FontWeight::NORMAL),
+            ..ButtonStyle::default()
+        };
+        Container::new(new_base(
+            event_system,
+            Position::new(0, 0),
+            Size::new(200, 300),
+        ))
+        .with_child(button(event_system, 10, "OK", ButtonStyle::default()))
+        .with_child(button(event_system, 50, "Cancel", faint))
+        .with_child(button(event_system, 90, "Banner", banner))
+        .with_child(button(event_system, 130, "Title", title))
+    }
+
+    fn texts(container: &Container, level: WcagLevel) -> Vec<String> {
+        ThemeSystem::lint_contrast(container, level)
+            .into_iter()
+            .map(|issue| issue.text)
+            .collect()
+    }
+
+    #[test]
+    fn test_lint_reports_pairs_below_the_level() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let window = window(&event_system, &Color::rgb(150, 150, 150));
+
+        let issues = ThemeSystem::lint_contrast(&window, WcagLevel::Aa);
+        assert_eq!(
+            issues
+                .iter()
+                .map(|issue| issue.text.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Cancel", "Banner"]
+        );
+        assert_eq!(issues[0].background, Color::rgba(225, 225, 225, 255));
+        assert!((issues[0].ratio - 2.26).abs() < 0.01, "{}", issues[0].ratio);
+        assert_eq!(issues[0].required, 4.5);
+        // The gradient is judged by its worst stop
+        assert_eq!(issues[1].background, Color::rgba(255, 255, 255, 255));
+        assert_eq!(issues[1].ratio, 1.0);
+
+        // Large text only needs 3:1 for AA but 4.5:1 for AAA
+        assert_eq!(
+            texts(&window, WcagLevel::Aaa),
+            vec!["Cancel", "Banner", "Title"]
+        );
+    }
+
+    #[test]
+    fn test_lint_fades_text_and_fills_by_opacity() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut window = Container::new(new_base(
+            &event_system,
+            Position::new(0, 0),
+            Size::new(200, 300),
+        ))
+        .with_child(button(&event_system, 10, "OK", ButtonStyle::default()));
+        assert!(texts(&window, WcagLevel::Aa).is_empty());
+
+        // The faded fill lets the white window through and the text fades too
+        window.base_mut().set_opacity(0.3);
+        let issues = ThemeSystem::lint_contrast(&window, WcagLevel::Aa);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].text, "OK");
+        assert_eq!(issues[0].foreground.alpha(), 77);
+        assert_eq!(issues[0].background, Color::rgba(246, 246, 246, 255));
+        assert!(issues[0].ratio < 3.0, "{}", issues[0].ratio);
+    }
+
+    #[test]
+    fn test_lint_blends_fills_and_skips_clipped_text() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        // Black screened over the white window leaves it white, under white text
+        let screened = ButtonStyle {
+            normal: Background::new_solid(Color::rgb(0, 0, 0), BackgroundShape::Rectangle)
+                .with_blend_mode(BlendMode::Screen),
+            text_color: Color::rgb(255, 255, 255),
+            ..ButtonStyle::default()
+        };
+        let faint = ButtonStyle {
+            text_color: Color::rgb(150, 150, 150),
+            ..ButtonStyle::default()
+        };
+        // The faint button is scrolled out of the viewer, so it is not read
+        let viewer = ScrollViewer::new(new_base(
+            &event_system,
+            Position::new(0, 100),
+            Size::new(100, 200),
+        ))
+        .with_child(button(&event_system, 300, "Hidden", faint));
+        let mut window = Container::new(new_base(
+            &event_system,
+            Position::new(0, 0),
+            Size::new(200, 300),
+        ))
+        .with_child(button(&event_system, 10, "Screened", screened));
+        window.add_child(viewer);
+
+        let issues = ThemeSystem::lint_contrast(&window, WcagLevel::Aa);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].text, "Screened");
+        assert_eq!(issues[0].background, Color::rgba(255, 255, 255, 255));
+    }
+
+    #[test]
+    fn test_nudged_color_passes_lint() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let faint = Color::rgb(150, 150, 150);
+        let background = Color::rgb(225, 225, 225);
+
+        let fixed = faint.ensure_contrast(&background, WcagLevel::Aa, false);
+        assert!(fixed.meets_contrast(&background, WcagLevel::Aa, false));
+        // Only as dark as needed
+        assert!(fixed.contrast_ratio(&background) < 4.6);
+        assert_eq!(
+            texts(&window(&event_system, &fixed), WcagLevel::Aa),
+            vec!["Banner"]
+        );
+
+        let fixed = faint.ensure_contrast(&background, WcagLevel::Aaa, false);
+        assert!(fixed.meets_contrast(&background, WcagLevel::Aaa, false));
+        assert!(
+            !texts(&window(&event_system, &fixed), WcagLevel::Aaa).contains(&"Cancel".to_string())
+        );
+    }
+}
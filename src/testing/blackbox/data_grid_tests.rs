@@ -0,0 +1,308 @@
+#[cfg(test)]
+mod data_grid_blackbox_tests {
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    };
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::data_grid::{DataGrid, DataGridColumn, SortDirection},
+            properties::{cell_value::CellValue, position::Position, size::Size},
+        },
+        ui::render::{draw_command::DrawCommand, render_context::RenderContext},
+        utils::traits::{
+            data_source::DataSource, interactive::Interactive, renderable::Renderable,
+        },
+        window::events::{
+            event_system::EventSystem,
+            input::{InputEvent, Key, Modifiers},
+            types::{EventPayload, EventType},
+        },
+    };
+
+    type Rows = Rc<RefCell<Vec<Vec<CellValue>>>>;
+
+    fn people() -> Rows {
+        let row = |name: &str, age: f64, active: bool, role: &str| {
+            vec![name.into(), age.into(), active.into(), role.into()]
+        };
+        Rc::new(RefCell::new(vec![
+            row("Carol", 41.0, true, "Admin"),
+            row("alice", 29.0, false, "User"),
+            row("Bob", 35.0, false, "Guest"),
+        ]))
+    }
+
+    // 300x200 grid at the origin. The header takes y 1..24 and rows are 22px;
+    // columns start at x 1, 101, 161 and 221
+    fn new_data_grid(event_system: &Rc<RefCell<EventSystem>>, rows: &Rows) -> DataGrid {
+        let base = new_base(event_system, Position::new(0, 0), Size::new(200, 300));
+        DataGrid::new(base)
+            .with_column(DataGridColumn::text("Name", 0))
+            .with_column(DataGridColumn::number("Age", 1).with_width(60))
+            .with_column(DataGridColumn::check_box("Active", 2).with_width(60))
+            .with_column(DataGridColumn::combo_box(
+                "Role",
+                3,
+                &["Admin", "User", "Guest"],
+            ))
+            .with_source(rows.clone())
+    }
+
+    fn row_y(row: u16) -> u16 {
+        24 + row * 22 + 11
+    }
+
+    fn type_into(data_grid: &mut DataGrid, text: &str) {
+        for input in type_text(text) {
+            data_grid.handle_input(&input);
+        }
+    }
+
+    #[test]
+    fn test_data_grid_sorts_by_header_click() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let rows = people();
+        let mut data_grid = new_data_grid(&event_system, &rows);
+        data_grid.set_current_cell(0, 1);
+
+        data_grid.handle_input(&mouse_down(50, 12));
+        data_grid.handle_input(&mouse_up(50, 12));
+        assert_eq!(data_grid.sort(), Some((0, SortDirection::Ascending)));
+        // Text sorts case-insensitively: alice, Bob, Carol
+        assert_eq!(data_grid.source_row(0), Some(1));
+        assert_eq!(data_grid.cell_value(2, 0), CellValue::from("Carol"));
+        assert_eq!(data_grid.current_cell(), Some((2, 1)));
+
+        data_grid.handle_input(&mouse_down(50, 12));
+        data_grid.handle_input(&mouse_up(50, 12));
+        assert_eq!(data_grid.sort(), Some((0, SortDirection::Descending)));
+        assert_eq!(data_grid.cell_value(0, 0), CellValue::from("Carol"));
+
+        data_grid.sort_by(1, SortDirection::Ascending);
+        assert_eq!(data_grid.cell_value(0, 1), CellValue::Number(29.0));
+        data_grid.clear_sort();
+        assert_eq!(data_grid.source_row(0), Some(0));
+    }
+
+    #[test]
+    fn test_data_grid_edits_text_cells() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let rows = people();
+        let mut data_grid = new_data_grid(&event_system, &rows);
+
+        data_grid.set_focused(true);
+        assert_eq!(data_grid.current_cell(), Some((0, 0)));
+
+        // Typing replaces the cell text
+        type_into(&mut data_grid, "Zoe");
+        assert!(data_grid.is_editing());
+        assert_eq!(data_grid.editor_text(), Some("Zoe"));
+        data_grid.handle_input(&key_down(Key::Enter));
+        assert!(!data_grid.is_editing());
+        assert_eq!(rows.borrow()[0][0], CellValue::from("Zoe"));
+        assert_eq!(data_grid.current_cell(), Some((1, 0)));
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Cell(0, 0))
+        );
+
+        // F2 opens the editor with the current text, Escape drops the changes
+        data_grid.handle_input(&key_down(Key::F(2)));
+        assert_eq!(data_grid.editor_text(), Some("alice"));
+        type_into(&mut data_grid, "x");
+        data_grid.handle_input(&key_down(Key::Escape));
+        assert!(!data_grid.is_editing());
+        assert_eq!(rows.borrow()[1][0], CellValue::from("alice"));
+        assert_eq!(recorder.borrow().count(EventType::FieldValueChanged), 1);
+    }
+
+    #[test]
+    fn test_data_grid_validates_number_cells() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let rows = people();
+        let mut data_grid = new_data_grid(&event_system, &rows);
+
+        data_grid.set_focused(true);
+        data_grid.set_current_cell(0, 1);
+        type_into(&mut data_grid, "abc");
+        data_grid.handle_input(&key_down(Key::Enter));
+        assert!(data_grid.is_editing());
+        assert_eq!(rows.borrow()[0][1], CellValue::Number(41.0));
+
+        for _ in 0..3 {
+            data_grid.handle_input(&key_down(Key::Backspace));
+        }
+        type_into(&mut data_grid, "12.5");
+        data_grid.handle_input(&key_down(Key::Enter));
+        assert!(!data_grid.is_editing());
+        assert_eq!(rows.borrow()[0][1], CellValue::Number(12.5));
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Cell(0, 1))
+        );
+    }
+
+    #[test]
+    fn test_data_grid_check_box_and_combo_box_cells() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let rows = people();
+        let mut data_grid = new_data_grid(&event_system, &rows);
+
+        // A click on a check box cell toggles it right away
+        data_grid.handle_input(&mouse_down(190, row_y(0)));
+        assert_eq!(rows.borrow()[0][2], CellValue::Bool(false));
+        data_grid.handle_input(&key_down(Key::Space));
+        assert_eq!(rows.borrow()[0][2], CellValue::Bool(true));
+        assert_eq!(recorder.borrow().count(EventType::FieldValueChanged), 2);
+
+        // The second click on a combo cell drops its list down
+        data_grid.handle_input(&mouse_down(250, row_y(1)));
+        assert!(!data_grid.is_editing());
+        data_grid.handle_input(&mouse_down(250, row_y(1)));
+        assert_eq!(data_grid.editor_text(), Some("User"));
+        data_grid.handle_input(&key_down(Key::Down));
+        data_grid.handle_input(&key_down(Key::Enter));
+        assert!(!data_grid.is_editing());
+        assert_eq!(rows.borrow()[1][3], CellValue::from("Guest"));
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Cell(1, 3))
+        );
+
+        data_grid.set_read_only(true);
+        data_grid.handle_input(&mouse_down(190, row_y(2)));
+        assert_eq!(rows.borrow()[2][2], CellValue::Bool(false));
+    }
+
+    #[test]
+    fn test_data_grid_resizes_and_reorders_columns() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let rows = people();
+        let mut data_grid = new_data_grid(&event_system, &rows);
+
+        // Dragging the right edge of Name
+        data_grid.handle_input(&mouse_down(101, 12));
+        data_grid.handle_input(&mouse_move(131, 12));
+        assert_eq!(data_grid.columns()[0].width, 130);
+        data_grid.handle_input(&mouse_move(0, 12));
+        data_grid.handle_input(&mouse_up(0, 12));
+        assert_eq!(data_grid.columns()[0].width, 20);
+        assert_eq!(data_grid.sort(), None);
+
+        // Dragging Age before Name, Name now spans x 1..21
+        data_grid.handle_input(&mouse_down(50, 12));
+        data_grid.handle_input(&mouse_move(40, 12));
+        data_grid.handle_input(&mouse_move(5, 12));
+        data_grid.handle_input(&mouse_up(5, 12));
+        assert_eq!(data_grid.column_order(), &[1, 0, 2, 3]);
+        assert_eq!(data_grid.sort(), None);
+        assert_eq!(data_grid.header_bounds(1).unwrap().top_left().x(), 1);
+        assert_eq!(data_grid.cell_bounds(0, 0).unwrap().top_left().x(), 61);
+    }
+
+    #[test]
+    fn test_data_grid_frozen_columns_stay_while_scrolling() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let rows = people();
+        let mut data_grid = new_data_grid(&event_system, &rows).with_frozen_columns(1);
+
+        // 220px of scrolling columns in the 198px left of Name
+        data_grid.handle_input(&InputEvent::MouseScrolled {
+            delta_x: -1,
+            delta_y: 0,
+            position: Position::new(150, 50),
+        });
+        assert_eq!(data_grid.scroll_x(), 22);
+        assert_eq!(data_grid.header_bounds(0).unwrap().top_left().x(), 1);
+        let age = data_grid.header_bounds(1).unwrap();
+        assert_eq!(age.top_left().x(), 101);
+        assert_eq!(age.width(), 38);
+
+        // Moving to a hidden column scrolls it back into view
+        data_grid.set_current_cell(0, 1);
+        assert_eq!(data_grid.scroll_x(), 0);
+    }
+
+    #[test]
+    fn test_data_grid_virtualizes_and_navigates_with_keys() {
+        struct Counting {
+            reads: Cell<usize>,
+        }
+
+        impl DataSource for Counting {
+            fn row_count(&self) -> usize {
+                10_000
+            }
+
+            fn value(&self, row: usize, _column: usize) -> CellValue {
+                self.reads.set(self.reads.get() + 1);
+                CellValue::Number(row as f64)
+            }
+        }
+
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldFocusMove]);
+        let source = Rc::new(RefCell::new(Counting {
+            reads: Cell::new(0),
+        }));
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(200, 300));
+        let mut data_grid = DataGrid::new(base)
+            .with_column(DataGridColumn::number("A", 0))
+            .with_column(DataGridColumn::number("B", 1))
+            .with_source(source.clone());
+
+        data_grid.render(&mut RenderContext::new());
+        assert_eq!(data_grid.visible_rows(), 0..8);
+        assert_eq!(source.borrow().reads.get(), 16);
+
+        data_grid.set_focused(true);
+        data_grid.handle_input(&key_down(Key::Tab));
+        data_grid.handle_input(&key_down(Key::Tab));
+        assert_eq!(data_grid.current_cell(), Some((1, 0)));
+        data_grid.handle_input(&key_down_with(Key::Tab, Modifiers::shift()));
+        assert_eq!(data_grid.current_cell(), Some((0, 1)));
+
+        data_grid.handle_input(&key_down_with(Key::End, Modifiers::ctrl()));
+        assert_eq!(data_grid.current_cell(), Some((9_999, 1)));
+        assert_eq!(data_grid.visible_rows().end, 10_000);
+        assert!(!data_grid.handle_input(&key_down(Key::Tab)));
+        data_grid.handle_input(&key_down(Key::PageUp));
+        data_grid.handle_input(&key_down(Key::Home));
+        assert_eq!(data_grid.current_cell(), Some((9_992, 0)));
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldFocusMove),
+            Some(EventPayload::Cell(9_992, 0))
+        );
+    }
+
+    #[test]
+    fn test_data_grid_lays_out_again_after_resize() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let rows = people();
+        let mut data_grid = new_data_grid(&event_system, &rows);
+        let rendered = |data_grid: &DataGrid| {
+            render_quietly(&event_system, data_grid)
+                .commands()
+                .iter()
+                .filter_map(|command| match command {
+                    DrawCommand::Text { text, .. } => Some(text.clone()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        assert!(rendered(&data_grid).contains(&"Bob".to_string()));
+
+        // 35px of body fit the first two rows only, painting them emits nothing
+        data_grid.base_mut().set_size(Size::new(60, 300));
+        assert!(!rendered(&data_grid).contains(&"Bob".to_string()));
+
+        data_grid.handle_input(&key_down(Key::Down));
+        assert_eq!(data_grid.visible_rows(), 0..2);
+    }
+}
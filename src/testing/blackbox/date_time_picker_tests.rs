@@ -0,0 +1,347 @@
+#[cfg(test)]
+mod date_time_picker_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::date_time_picker::{CalendarPart, DateTimePicker},
+            properties::{
+                boundaries::Boundaries,
+                date_time::{Date, DateTime, Time, Weekday},
+                position::Position,
+                size::Size,
+            },
+        },
+        ui::{
+            date_locale::{DateLocale, DateTimeFormat},
+            render::draw_command::DrawCommand,
+        },
+        utils::traits::interactive::Interactive,
+        window::events::{
+            event_system::EventSystem,
+            input::{Key, Modifiers},
+            types::{EventPayload, EventType},
+        },
+    };
+
+    fn date(year: u16, month: u8, day: u8) -> Date {
+        Date::new(year, month, day).unwrap()
+    }
+
+    fn at(year: u16, month: u8, day: u8, hour: u8, minute: u8) -> DateTime {
+        DateTime::new(date(year, month, day), Time::new(hour, minute, 0).unwrap())
+    }
+
+    /// Selector de 150x23 en (10, 10) con el 15 de marzo de 2024
+    fn picker(event_system: &Rc<RefCell<EventSystem>>) -> DateTimePicker {
+        DateTimePicker::new(new_base(
+            event_system,
+            Position::new(10, 10),
+            Size::new(23, 150),
+        ))
+        .with_value(DateTime::from_date(date(2024, 3, 15)))
+    }
+
+    fn click_part(picker: &mut DateTimePicker, part: CalendarPart) {
+        let point = picker.part_bounds(part).unwrap().top_left();
+        picker.handle_input(&mouse_down(point.x() + 2, point.y() + 2));
+        picker.handle_input(&mouse_up(point.x() + 2, point.y() + 2));
+    }
+
+    /// Eventos emitidos por el propio selector, sin los de su editor
+    fn count(
+        recorder: &Rc<RefCell<EventRecorder>>,
+        picker: &DateTimePicker,
+        event: EventType,
+    ) -> usize {
+        recorder
+            .borrow()
+            .events
+            .iter()
+            .filter(|(e, id)| *e == event && id == picker.base().id())
+            .count()
+    }
+
+    fn retype(picker: &mut DateTimePicker, text: &str) {
+        for _ in 0..picker.text().chars().count() {
+            picker.handle_input(&key_down(Key::Backspace));
+        }
+        for input in type_text(text) {
+            picker.handle_input(&input);
+        }
+    }
+
+    #[test]
+    fn test_locale_formats_and_parses() {
+        let value = at(2024, 3, 5, 14, 7);
+        let us = DateLocale::en_us();
+        assert_eq!(us.format(&value, "M/d/yyyy h:mm tt"), "3/5/2024 2:07 PM");
+        assert_eq!(
+            us.format(&value, &us.pattern(&DateTimeFormat::Long, false)),
+            "Tuesday, March 5, 2024"
+        );
+
+        let es = DateLocale::es_es();
+        assert_eq!(
+            es.format(&value, &es.pattern(&DateTimeFormat::Long, false)),
+            "martes, 5 de marzo de 2024"
+        );
+        assert_eq!(
+            es.parse("05/03/2024 14:07", "dd/MM/yyyy H:mm", &DateTime::MIN),
+            Some(value)
+        );
+
+        // Names ignore case and missing parts come from the base value
+        let de = DateLocale::de_de();
+        assert_eq!(
+            de.parse("5. MÄRZ 2024", "d. MMMM yyyy", &value),
+            Some(value)
+        );
+        assert_eq!(us.parse("2/30/2024", "M/d/yyyy", &value), None);
+        assert_eq!(us.parse("3/5", "M/d/yyyy", &value), None);
+    }
+
+    #[test]
+    fn test_typed_text_is_validated() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(
+            &event_system,
+            &[EventType::FieldValueChanged, EventType::FieldValidating],
+        );
+        let mut picker = picker(&event_system).with_range(
+            DateTime::from_date(date(2024, 1, 1)),
+            DateTime::from_date(date(2024, 12, 31)),
+        );
+        assert_eq!(picker.text(), "3/15/2024");
+        picker.set_focused(true);
+
+        retype(&mut picker, "4/1/2024");
+        picker.handle_input(&key_down(Key::Enter));
+        assert_eq!(picker.value(), DateTime::from_date(date(2024, 4, 1)));
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::DateTime(picker.value()))
+        );
+
+        // Out of range text is reported and reverted when the focus leaves
+        retype(&mut picker, "1/1/2025");
+        picker.set_focused(false);
+        assert_eq!(picker.text(), "4/1/2024");
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValidating),
+            Some(EventPayload::Text("1/1/2025".to_string()))
+        );
+
+        picker.set_focused(true);
+        retype(&mut picker, "soon");
+        picker.handle_input(&key_down(Key::Enter));
+        assert_eq!(picker.text(), "4/1/2024");
+        assert_eq!(count(&recorder, &picker, EventType::FieldValidating), 2);
+        assert_eq!(count(&recorder, &picker, EventType::FieldValueChanged), 1);
+
+        picker.set_locale(DateLocale::es_es());
+        assert_eq!(picker.text(), "01/04/2024");
+    }
+
+    #[test]
+    fn test_calendar_navigation_and_selection() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let mut picker = picker(&event_system);
+        picker.set_focused(true);
+
+        picker.handle_input(&key_down_with(
+            Key::Down,
+            Modifiers {
+                alt: true,
+                ..Modifiers::none()
+            },
+        ));
+        assert!(picker.is_open());
+        assert_eq!(picker.displayed_month(), Some(date(2024, 3, 1)));
+        // March 2024 starts on a Friday, the sixth column from Sunday
+        assert_eq!(
+            picker.part_bounds(CalendarPart::Day(date(2024, 3, 15))),
+            Some(Boundaries::from_rect(
+                Position::new(154, 121),
+                Size::new(20, 28)
+            ))
+        );
+        assert_eq!(
+            picker.part_bounds(CalendarPart::Day(date(2024, 4, 1))),
+            None
+        );
+
+        click_part(&mut picker, CalendarPart::PreviousMonth);
+        assert_eq!(picker.displayed_month(), Some(date(2024, 2, 1)));
+        click_part(&mut picker, CalendarPart::NextYear);
+        assert_eq!(picker.highlighted_day(), Some(date(2025, 2, 15)));
+
+        picker.handle_input(&key_down(Key::Down));
+        picker.handle_input(&key_down(Key::Right));
+        picker.handle_input(&key_down(Key::PageUp));
+        assert_eq!(picker.highlighted_day(), Some(date(2025, 1, 23)));
+        picker.handle_input(&key_down(Key::Enter));
+        assert!(!picker.is_open());
+        assert_eq!(picker.value(), DateTime::from_date(date(2025, 1, 23)));
+        assert_eq!(picker.text(), "1/23/2025");
+
+        // Reopening shows the new month; a click picks and closes
+        let button = picker.button_bounds().top_left();
+        picker.handle_input(&mouse_down(button.x() + 2, button.y() + 2));
+        assert_eq!(picker.displayed_month(), Some(date(2025, 1, 1)));
+        click_part(&mut picker, CalendarPart::Day(date(2025, 1, 2)));
+        assert!(!picker.is_open());
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::DateTime(DateTime::from_date(date(
+                2025, 1, 2
+            ))))
+        );
+
+        picker.handle_input(&key_down(Key::F(4)));
+        picker.handle_input(&key_down(Key::Escape));
+        assert!(!picker.is_open());
+        assert_eq!(count(&recorder, &picker, EventType::FieldValueChanged), 2);
+    }
+
+    #[test]
+    fn test_calendar_range_and_disabled_days() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(
+            &event_system,
+            &[EventType::FieldValueChanged, EventType::FieldValidating],
+        );
+        let mut picker = picker(&event_system)
+            .with_range(
+                DateTime::from_date(date(2024, 3, 10)),
+                DateTime::from_date(date(2024, 4, 20)),
+            )
+            .with_disabled_weekdays(vec![Weekday::Sunday])
+            .with_disabled_days(vec![date(2024, 3, 20)]);
+
+        assert!(!picker.is_selectable(date(2024, 3, 17)));
+        assert!(!picker.is_selectable(date(2024, 3, 20)));
+        assert!(!picker.is_selectable(date(2024, 3, 9)));
+        assert!(picker.is_selectable(date(2024, 3, 21)));
+
+        picker.open();
+        click_part(&mut picker, CalendarPart::Day(date(2024, 3, 17)));
+        click_part(&mut picker, CalendarPart::Day(date(2024, 3, 5)));
+        assert!(picker.is_open());
+        assert_eq!(picker.value(), DateTime::from_date(date(2024, 3, 15)));
+
+        // Navigation stops at the limits of the range
+        click_part(&mut picker, CalendarPart::NextYear);
+        assert_eq!(picker.highlighted_day(), Some(date(2024, 4, 20)));
+        picker.handle_input(&key_down(Key::Home));
+        assert_eq!(picker.highlighted_day(), Some(date(2024, 4, 1)));
+        picker.handle_input(&key_down_with(
+            Key::PageUp,
+            Modifiers {
+                ctrl: true,
+                ..Modifiers::none()
+            },
+        ));
+        assert_eq!(picker.highlighted_day(), Some(date(2024, 3, 10)));
+        picker.close();
+
+        assert!(!picker.set_value(DateTime::from_date(date(2024, 3, 20))));
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValidating),
+            Some(EventPayload::Text("3/20/2024".to_string()))
+        );
+        assert_eq!(count(&recorder, &picker, EventType::FieldValueChanged), 0);
+
+        // Narrowing the range moves the value inside it
+        picker.set_range(
+            DateTime::from_date(date(2024, 4, 1)),
+            DateTime::from_date(date(2024, 4, 30)),
+        );
+        assert_eq!(picker.value(), DateTime::from_date(date(2024, 4, 1)));
+        assert_eq!(count(&recorder, &picker, EventType::FieldValueChanged), 1);
+    }
+
+    #[test]
+    fn test_time_selection() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut picker = picker(&event_system)
+            .with_show_time(true)
+            .with_locale(DateLocale::de_de());
+        assert_eq!(picker.pattern(), "dd.MM.yyyy HH:mm");
+        assert_eq!(picker.text(), "15.03.2024 00:00");
+
+        // Picking a day keeps the calendar open for the time row
+        picker.open();
+        click_part(&mut picker, CalendarPart::Day(date(2024, 3, 18)));
+        assert!(picker.is_open());
+
+        picker.handle_input(&key_down(Key::Tab));
+        picker.handle_input(&key_down(Key::Down));
+        picker.handle_input(&key_down(Key::Right));
+        picker.handle_input(&key_down(Key::Up));
+        assert_eq!(picker.value(), at(2024, 3, 18, 23, 1));
+
+        let minute = picker.part_bounds(CalendarPart::Minute).unwrap().top_left();
+        picker.handle_input(&scroll(minute.x() + 2, minute.y() + 2, -1));
+        picker.handle_input(&scroll(minute.x() + 2, minute.y() + 2, -1));
+        assert_eq!(picker.value(), at(2024, 3, 18, 23, 59));
+
+        picker.handle_input(&key_down(Key::Enter));
+        assert!(!picker.is_open());
+        assert_eq!(picker.text(), "18.03.2024 23:59");
+    }
+
+    #[test]
+    fn test_deserialization_rejects_invalid_dates_and_times() {
+        let value = at(2024, 2, 29, 13, 45);
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<DateTime>(&json).unwrap(), value);
+
+        let february_30 = r#"{"year":2023,"month":2,"day":30}"#;
+        assert!(serde_json::from_str::<Date>(february_30).is_err());
+        let year_zero = r#"{"year":0,"month":1,"day":1}"#;
+        assert!(serde_json::from_str::<Date>(year_zero).is_err());
+        let late = r#"{"hour":24,"minute":0,"second":0}"#;
+        assert!(serde_json::from_str::<Time>(late).is_err());
+    }
+
+    #[test]
+    fn test_restored_picker_orders_its_range_and_keeps_its_editor() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let picker = picker(&event_system).with_range(
+            DateTime::from_date(date(2024, 1, 1)),
+            DateTime::from_date(date(2024, 12, 31)),
+        );
+
+        // A hand-edited file with the range swapped and the value outside it
+        let mut json = serde_json::to_value(&picker).unwrap();
+        let min = json["min_date"].take();
+        json["min_date"] = json["max_date"].take();
+        json["max_date"] = min;
+        json["value"] = serde_json::to_value(DateTime::from_date(date(2025, 6, 1))).unwrap();
+        let mut restored: DateTimePicker = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.min_date(), DateTime::from_date(date(2024, 1, 1)));
+        assert_eq!(restored.max_date(), DateTime::from_date(date(2024, 12, 31)));
+        assert_eq!(restored.value(), DateTime::from_date(date(2024, 12, 31)));
+        assert_eq!(restored.text(), "12/31/2024");
+
+        // The field is painted where the moved picker has it, and typing
+        // reaches the editor placed there
+        restored.base_mut().set_position(Position::new(10, 100));
+        let event_system = restored.base().event_system().clone();
+        let ctx = render_quietly(&event_system, &restored);
+        assert!(ctx.commands().iter().any(|command| matches!(
+            command,
+            DrawCommand::Text { text, position, .. } if text == "12/31/2024" && *position == Position::new(10, 100)
+        )));
+
+        restored.set_focused(true);
+        retype(&mut restored, "4/1/2024");
+        restored.handle_input(&key_down(Key::Enter));
+        assert_eq!(restored.value(), DateTime::from_date(date(2024, 4, 1)));
+        let editor = restored.editor().unwrap().base().frame();
+        assert_eq!(editor.top_left(), Position::new(10, 100));
+    }
+}
@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod dialog_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::message_box::{InputBox, MessageBox, MessageBoxButtons, MessageBoxIcon},
+            properties::{
+                boundaries::Boundaries,
+                dialog_result::DialogResult,
+                graphics::{
+                    background::{Background, BackgroundShape},
+                    color::Color,
+                },
+                position::Position,
+                size::Size,
+            },
+            styles::dialog_style::DialogStyle,
+        },
+        ui::{
+            dialog_host::DialogHost,
+            render::{draw_command::DrawCommand, render_context::RenderContext},
+        },
+        utils::traits::{interactive::Interactive, renderable::Renderable},
+        window::events::{
+            event_system::EventSystem,
+            input::{InputEvent, Key, Modifiers},
+            types::{EventPayload, EventType},
+        },
+    };
+
+    /// Host de 400x300 que recibe las entradas indicadas y luego se cierra
+    fn new_host(event_system: &Rc<RefCell<EventSystem>>, inputs: Vec<InputEvent>) -> DialogHost {
+        let mut inputs = inputs.into_iter();
+        DialogHost::new(
+            event_system.clone(),
+            Boundaries::from_rect(Position::new(0, 0), Size::new(300, 400)),
+            move || inputs.next(),
+        )
+    }
+
+    fn save_changes(host: &mut DialogHost) -> DialogResult {
+        MessageBox::show(
+            host,
+            "Editor",
+            "Save changes?",
+            MessageBoxButtons::YesNoCancel,
+            MessageBoxIcon::Question,
+        )
+    }
+
+    #[test]
+    fn test_message_box_answers_from_keyboard() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::WindowClosed]);
+
+        // Yes starts focused, Tab moves to No
+        let mut host = new_host(
+            &event_system,
+            vec![key_down(Key::Tab), key_down(Key::Enter)],
+        );
+        assert_eq!(save_changes(&mut host), DialogResult::No);
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::WindowClosed),
+            Some(EventPayload::DialogResult(DialogResult::No))
+        );
+
+        let mut host = new_host(&event_system, vec![key_down(Key::Escape)]);
+        assert_eq!(save_changes(&mut host), DialogResult::Cancel);
+
+        let shift_tab = key_down_with(
+            Key::Tab,
+            Modifiers {
+                shift: true,
+                ..Modifiers::none()
+            },
+        );
+        let mut host = new_host(&event_system, vec![shift_tab, key_down(Key::Enter)]);
+        assert_eq!(save_changes(&mut host), DialogResult::Cancel);
+    }
+
+    #[test]
+    fn test_message_box_layout_and_mouse_clicks() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let host = new_host(&event_system, Vec::new());
+        let mut dialog = MessageBox::build(
+            &host,
+            "Editor",
+            "Save changes?",
+            MessageBoxButtons::YesNoCancel,
+            MessageBoxIcon::Question,
+        );
+        dialog.open(host.viewport());
+
+        // Three 75 wide buttons and two gaps of 8, plus padding, centered
+        assert_eq!(
+            dialog.base().frame(),
+            Boundaries::from_rect(Position::new(67, 89), Size::new(121, 265))
+        );
+        assert_eq!(
+            dialog.button_bounds(DialogResult::Cancel),
+            Some(Boundaries::from_rect(
+                Position::new(245, 175),
+                Size::new(23, 75)
+            ))
+        );
+
+        // Input outside the dialog is swallowed while it is open
+        assert!(dialog.handle_input(&mouse_down(5, 5)));
+        assert!(dialog.is_open());
+
+        dialog.handle_input(&mouse_down(250, 180));
+        assert!(dialog.is_open());
+        assert_eq!(dialog.focused_button(), Some(DialogResult::Cancel));
+        dialog.handle_input(&mouse_up(250, 180));
+        assert!(!dialog.is_open());
+        assert_eq!(dialog.result(), DialogResult::Cancel);
+        assert!(!dialog.handle_input(&key_down(Key::Enter)));
+    }
+
+    #[test]
+    fn test_yes_no_requires_an_answer() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        // Escape does nothing, and the window closing leaves no answer
+        let mut host = new_host(&event_system, vec![key_down(Key::Escape)]);
+        let result = MessageBox::show(
+            &mut host,
+            "Delete",
+            "Delete the file?",
+            MessageBoxButtons::YesNo,
+            MessageBoxIcon::Warning,
+        );
+        assert_eq!(result, DialogResult::None);
+
+        let mut host = new_host(&event_system, vec![key_down(Key::Enter)]);
+        assert!(MessageBox::confirm(&mut host, "Delete", "Delete the file?"));
+        let mut host = new_host(
+            &event_system,
+            vec![key_down(Key::Right), key_down(Key::Enter)],
+        );
+        assert!(!MessageBox::confirm(
+            &mut host,
+            "Delete",
+            "Delete the file?"
+        ));
+    }
+
+    #[test]
+    fn test_input_box_returns_typed_text() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut inputs = type_text("de");
+        inputs.push(key_down(Key::Enter));
+        let mut host = new_host(&event_system, inputs);
+        assert_eq!(
+            InputBox::show(&mut host, "Rename", "New name:", "abc"),
+            Some("abcde".to_string())
+        );
+
+        let mut inputs = type_text("xyz");
+        inputs.push(key_down(Key::Escape));
+        let mut host = new_host(&event_system, inputs);
+        assert_eq!(
+            InputBox::show(&mut host, "Rename", "New name:", "abc"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_dialogs_render_on_top_with_host_style() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut style = DialogStyle::default();
+        style.button.normal =
+            Background::new_solid(Color::rgb(10, 20, 30), BackgroundShape::Rectangle);
+        let host = new_host(&event_system, Vec::new()).with_style(style.clone());
+
+        let mut dialog = MessageBox::build(
+            &host,
+            "Editor",
+            "Saved",
+            MessageBoxButtons::Ok,
+            MessageBoxIcon::Information,
+        );
+        assert_eq!(dialog.icon(), Some(&style.information_icon));
+        dialog.open(host.viewport());
+
+        let mut ctx = RenderContext::new();
+        dialog.render(&mut ctx);
+        assert_eq!(ctx.layer_count(), 2);
+        let layer = ctx.layer(1);
+        assert!(matches!(
+            &layer[0],
+            DrawCommand::FillRect { bounds, background }
+                if *bounds == host.viewport() && *background == style.overlay
+        ));
+        assert!(layer.iter().any(|command| matches!(
+            command,
+            DrawCommand::Text { text, .. } if text == "Editor"
+        )));
+        assert!(layer.iter().any(|command| matches!(
+            command,
+            DrawCommand::FillRect { background, .. } if *background == style.button.normal
+        )));
+    }
+}
@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod link_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::link::Link,
+            properties::{graphics::action_state::ActionState, position::Position, size::Size},
+        },
+        ui::systems::navigation_system::{LinkTarget, NavigationSystem},
+        utils::traits::{interactive::Interactive, uri_handler::UriHandler},
+        window::events::{
+            event_system::EventSystem,
+            input::Key,
+            types::{EventPayload, EventType},
+        },
+    };
+
+    /// Handler que guarda las URIs abiertas
+    #[derive(Debug, Default)]
+    struct UriRecorder {
+        opened: Vec<String>,
+    }
+
+    impl UriHandler for UriRecorder {
+        fn open_uri(&mut self, uri: &str) -> bool {
+            self.opened.push(uri.to_string());
+            true
+        }
+    }
+
+    // "Help" measures 32x18, so it covers (0, 3)..(32, 21) of the 100x24 frame
+    fn new_link(event_system: &Rc<RefCell<EventSystem>>, target: &str) -> Link {
+        let base = new_base(event_system, Position::new(0, 0), Size::new(24, 100));
+        Link::new(base, "Help", LinkTarget::parse(target))
+    }
+
+    #[test]
+    fn test_link_target_parses_uris_and_routes() {
+        assert_eq!(
+            LinkTarget::parse("https://example.com"),
+            LinkTarget::Uri("https://example.com".to_string())
+        );
+        assert_eq!(
+            LinkTarget::parse("mailto:someone@example.com"),
+            LinkTarget::Uri("mailto:someone@example.com".to_string())
+        );
+        assert_eq!(
+            LinkTarget::parse("settings/display"),
+            LinkTarget::Route("settings/display".to_string())
+        );
+        assert_eq!(
+            LinkTarget::parse("C:\\readme.txt"),
+            LinkTarget::Route("C:\\readme.txt".to_string())
+        );
+    }
+
+    #[test]
+    fn test_link_underlines_on_hover_over_its_text() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::HoverEntered]);
+        let mut link = new_link(&event_system, "home");
+
+        assert!(!link.underlined());
+        // Past the end of the text is not part of the link
+        assert!(!link.handle_input(&mouse_move(60, 10)));
+        assert!(link.handle_input(&mouse_move(10, 10)));
+        assert_eq!(link.state(), ActionState::Hovered);
+        assert!(link.underlined());
+        assert_eq!(recorder.borrow().count(EventType::HoverEntered), 1);
+
+        link.set_enabled(false);
+        assert_eq!(link.state(), ActionState::Disabled);
+        assert!(!link.handle_input(&mouse_down(10, 10)));
+    }
+
+    #[test]
+    fn test_link_routes_internal_targets() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::MouseButtonClicked]);
+        let navigation = Rc::new(RefCell::new(NavigationSystem::new()));
+        navigation.borrow_mut().navigate("home");
+        let mut link = new_link(&event_system, "settings").with_navigation(navigation.clone());
+
+        link.handle_input(&mouse_down(10, 10));
+        link.handle_input(&mouse_up(10, 10));
+        assert!(link.visited());
+        assert_eq!(navigation.borrow().current_route(), Some("settings"));
+        assert_eq!(
+            recorder
+                .borrow()
+                .last_payload(EventType::MouseButtonClicked),
+            Some(EventPayload::Text("settings".to_string()))
+        );
+
+        assert!(navigation.borrow_mut().back());
+        assert_eq!(navigation.borrow().current_route(), Some("home"));
+        assert!(navigation.borrow().can_go_forward());
+        // Navigating from the middle drops the forward history
+        navigation.borrow_mut().navigate("about");
+        assert!(!navigation.borrow().can_go_forward());
+        assert_eq!(navigation.borrow().history(), ["home", "about"]);
+    }
+
+    #[test]
+    fn test_link_opens_uris_with_the_keyboard() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let navigation = Rc::new(RefCell::new(NavigationSystem::new()));
+        let mut link =
+            new_link(&event_system, "https://example.com").with_navigation(navigation.clone());
+
+        // Without a handler nothing opens, but the link is still visited
+        assert!(!link.activate());
+        assert!(link.visited());
+
+        let handler = Rc::new(RefCell::new(UriRecorder::default()));
+        navigation
+            .borrow_mut()
+            .set_uri_handler(Rc::downgrade(&handler));
+        link.set_focused(true);
+        assert!(link.handle_input(&key_down(Key::Enter)));
+        link.handle_input(&key_down(Key::Space));
+        link.handle_input(&key_up(Key::Space));
+        assert_eq!(handler.borrow().opened, ["https://example.com"; 2]);
+        assert_eq!(navigation.borrow().current_route(), None);
+
+        // A new target has not been visited yet
+        link.set_target(LinkTarget::parse("https://example.org"));
+        assert!(!link.visited());
+    }
+
+    #[test]
+    fn test_navigation_equality_compares_handlers_by_identity() {
+        let mut first = NavigationSystem::new();
+        let mut second = NavigationSystem::new();
+        first.navigate("home");
+        second.navigate("home");
+        assert_eq!(first, second);
+
+        let handler = Rc::new(RefCell::new(UriRecorder::default()));
+        first.set_uri_handler(Rc::downgrade(&handler));
+        assert_ne!(first, second);
+        second.set_uri_handler(Rc::downgrade(&handler));
+        assert_eq!(first, second);
+
+        let other = Rc::new(RefCell::new(UriRecorder::default()));
+        second.set_uri_handler(Rc::downgrade(&other));
+        assert_ne!(first, second);
+    }
+}
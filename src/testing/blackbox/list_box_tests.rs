@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod list_box_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::list_box::ListBox,
+            properties::{position::Position, selection_mode::SelectionMode, size::Size},
+        },
+        ui::render::{draw_command::DrawCommand, render_context::RenderContext},
+        utils::traits::{interactive::Interactive, renderable::Renderable},
+        window::events::{
+            event_system::EventSystem,
+            input::{Key, Modifiers},
+            types::{EventPayload, EventType},
+        },
+    };
+
+    // 200x100 list at the origin: 98px of viewport fit 5 full rows of 18px plus a partial one
+    fn new_list_box(event_system: &Rc<RefCell<EventSystem>>, count: usize) -> ListBox {
+        let items: Vec<String> = (0..count).map(|i| format!("Item {i}")).collect();
+        let base = new_base(event_system, Position::new(0, 0), Size::new(100, 200));
+        ListBox::new(base).with_items(&items)
+    }
+
+    // Middle of a visible row
+    fn row_y(row: u16) -> u16 {
+        1 + row * 18 + 9
+    }
+
+    #[test]
+    fn test_list_box_only_realizes_visible_rows() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut list_box = new_list_box(&event_system, 10_000);
+
+        let rows = list_box.visible_rows();
+        assert_eq!(rows.len(), 6);
+        assert_eq!(rows[0].text(), "Item 0");
+        let ids: Vec<usize> = rows.iter().map(|row| *row.base().id()).collect();
+
+        // Scrolling rebinds the same components to other items
+        list_box.handle_input(&scroll(50, 50, -1));
+        assert_eq!(list_box.top_index(), 3);
+        let rows = list_box.visible_rows();
+        assert_eq!(rows[0].index(), 3);
+        assert_eq!(rows[0].text(), "Item 3");
+        assert_eq!(rows[0].base().position(), &Position::new(1, 1));
+        assert_eq!(
+            rows.iter().map(|row| *row.base().id()).collect::<Vec<_>>(),
+            ids
+        );
+
+        let mut ctx = RenderContext::new();
+        list_box.render(&mut ctx);
+        let texts = ctx
+            .commands()
+            .iter()
+            .filter(|command| matches!(command, DrawCommand::Text { .. }))
+            .count();
+        assert_eq!(texts, 6);
+    }
+
+    #[test]
+    fn test_list_box_single_selection_emits_indices() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let mut list_box = new_list_box(&event_system, 20);
+
+        list_box.handle_input(&mouse_down(50, row_y(2)));
+        assert!(list_box.focused());
+        assert_eq!(list_box.selected_indices(), vec![2]);
+        list_box.handle_input(&mouse_down_with(50, row_y(4), Modifiers::ctrl()));
+        assert_eq!(list_box.selected_indices(), vec![4]);
+        assert!(list_box.visible_rows()[4].selected());
+        assert!(!list_box.visible_rows()[2].selected());
+
+        // Clicking the selected row again changes nothing
+        list_box.handle_input(&mouse_down(50, row_y(4)));
+        assert_eq!(recorder.borrow().count(EventType::FieldValueChanged), 2);
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Indices(vec![4]))
+        );
+    }
+
+    #[test]
+    fn test_list_box_extended_selection_with_modifiers() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut list_box =
+            new_list_box(&event_system, 20).with_selection_mode(SelectionMode::MultiExtended);
+
+        list_box.handle_input(&mouse_down(50, row_y(1)));
+        list_box.handle_input(&mouse_down_with(50, row_y(3), Modifiers::shift()));
+        assert_eq!(list_box.selected_indices(), vec![1, 2, 3]);
+        list_box.handle_input(&mouse_down_with(50, row_y(2), Modifiers::ctrl()));
+        assert_eq!(list_box.selected_indices(), vec![1, 3]);
+
+        list_box.handle_input(&key_down_with(Key::Down, Modifiers::shift()));
+        assert_eq!(list_box.selected_indices(), vec![2, 3]);
+        list_box.handle_input(&key_down_with(Key::Char('a'), Modifiers::ctrl()));
+        assert_eq!(list_box.selected_indices().len(), 20);
+        assert_eq!(list_box.selected_items()[19], "Item 19");
+    }
+
+    #[test]
+    fn test_list_box_multi_simple_moves_caret_and_toggles() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut list_box =
+            new_list_box(&event_system, 20).with_selection_mode(SelectionMode::MultiSimple);
+
+        list_box.set_focused(true);
+        assert_eq!(list_box.caret_index(), Some(0));
+        list_box.handle_input(&key_down(Key::Down));
+        list_box.handle_input(&key_down(Key::Down));
+        assert!(list_box.selected_indices().is_empty());
+        list_box.handle_input(&key_down(Key::Space));
+        list_box.handle_input(&mouse_down(50, row_y(4)));
+        assert_eq!(list_box.selected_indices(), vec![2, 4]);
+        list_box.handle_input(&mouse_down(50, row_y(2)));
+        assert_eq!(list_box.selected_indices(), vec![4]);
+        assert!(list_box.visible_rows()[2].caret());
+    }
+
+    #[test]
+    fn test_list_box_keyboard_scrolls_selection_into_view() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut list_box = new_list_box(&event_system, 1_000);
+
+        list_box.set_focused(true);
+        list_box.handle_input(&key_down(Key::End));
+        assert_eq!(list_box.selected_index(), Some(999));
+        assert_eq!(list_box.top_index(), 995);
+        assert_eq!(list_box.visible_rows().last().unwrap().text(), "Item 999");
+
+        list_box.handle_input(&key_down(Key::PageUp));
+        assert_eq!(list_box.selected_index(), Some(995));
+        list_box.handle_input(&key_down(Key::Home));
+        assert_eq!(list_box.top_index(), 0);
+
+        for event in type_text("Item 42") {
+            list_box.handle_input(&event);
+        }
+        assert_eq!(list_box.selected_items(), vec!["Item 42"]);
+    }
+
+    #[test]
+    fn test_list_box_remove_item_shifts_selection() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let mut list_box =
+            new_list_box(&event_system, 5).with_selection_mode(SelectionMode::MultiSimple);
+
+        list_box.set_selected(1, true);
+        list_box.set_selected(3, true);
+        assert_eq!(list_box.remove_item(2), Some("Item 2".to_string()));
+        assert_eq!(list_box.selected_indices(), vec![1, 2]);
+        assert_eq!(list_box.selected_items(), vec!["Item 1", "Item 3"]);
+        assert_eq!(list_box.visible_rows().len(), 4);
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Indices(vec![1, 2]))
+        );
+    }
+
+    #[test]
+    fn test_list_box_lays_out_again_after_resize_and_deserialization() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut list_box = new_list_box(&event_system, 50);
+
+        let json = serde_json::to_string(&list_box).unwrap();
+        let deserialized: ListBox = serde_json::from_str(&json).unwrap();
+        let mut ctx = RenderContext::new();
+        deserialized.render(&mut ctx);
+        let texts = ctx
+            .commands()
+            .iter()
+            .filter(|command| matches!(command, DrawCommand::Text { .. }))
+            .count();
+        assert_eq!(texts, 6);
+
+        // Twice the height fits eleven rows without an explicit refresh, and
+        // painting them leaves the event system alone
+        list_box.base_mut().set_size(Size::new(200, 200));
+        let ctx = render_quietly(&event_system, &list_box);
+        let texts = ctx
+            .commands()
+            .iter()
+            .filter(|command| matches!(command, DrawCommand::Text { .. }))
+            .count();
+        assert_eq!(texts, 11);
+
+        list_box.handle_input(&mouse_down(50, row_y(8)));
+        assert_eq!(list_box.selected_items(), vec!["Item 8"]);
+        assert_eq!(list_box.visible_rows().len(), 11);
+    }
+}
@@ -0,0 +1,188 @@
+#[cfg(test)]
+mod list_view_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::{
+                icon::Icon,
+                list_view::{ListView, ListViewColumn, ListViewItem, ListViewMode},
+            },
+            properties::{position::Position, selection_mode::SelectionMode, size::Size},
+        },
+        ui::render::{draw_command::DrawCommand, render_context::RenderContext},
+        utils::traits::{interactive::Interactive, renderable::Renderable},
+        window::events::{
+            event_system::EventSystem,
+            input::{Key, Modifiers},
+            types::{EventPayload, EventType},
+        },
+    };
+
+    // 300x200 view at the origin with two columns
+    fn new_list_view(event_system: &Rc<RefCell<EventSystem>>, count: usize) -> ListView {
+        let items = (0..count)
+            .map(|i| {
+                ListViewItem::new(&format!("File {i}"))
+                    .with_sub_items(&[format!("{i} KB")])
+                    .with_icon(Icon::new("file.ico", (32, 32)))
+            })
+            .collect();
+        let base = new_base(event_system, Position::new(0, 0), Size::new(200, 300));
+        ListView::new(base)
+            .with_column(ListViewColumn::new("Name", 100))
+            .with_column(ListViewColumn::new("Size", 80))
+            .with_items(items)
+    }
+
+    fn texts(commands: &[DrawCommand]) -> Vec<String> {
+        commands
+            .iter()
+            .filter_map(|command| match command {
+                DrawCommand::Text { text, .. } => Some(text.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_list_view_details_realizes_visible_rows_with_cells() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut list_view = new_list_view(&event_system, 5_000);
+
+        // 174px below the 24px header: 9 full rows and a partial one
+        assert_eq!(list_view.visible_items(), 0..10);
+        assert_eq!(list_view.visible_rows().len(), 10);
+        assert_eq!(
+            list_view.visible_rows()[0].base().position(),
+            &Position::new(1, 25)
+        );
+
+        let mut ctx = RenderContext::new();
+        list_view.render(&mut ctx);
+        let texts = texts(ctx.commands());
+        assert_eq!(&texts[..2], ["Name", "Size"]);
+        assert!(texts.contains(&"File 9".to_string()));
+        assert!(texts.contains(&"9 KB".to_string()));
+        assert!(!texts.contains(&"File 10".to_string()));
+
+        list_view.set_focused(true);
+        list_view.handle_input(&key_down(Key::End));
+        assert_eq!(list_view.selected_indices(), vec![4_999]);
+        // The last page has no partial row below it
+        assert_eq!(list_view.visible_items(), 4_991..5_000);
+        assert_eq!(list_view.visible_rows().len(), 9);
+    }
+
+    #[test]
+    fn test_list_view_selection_events_and_empty_click() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let mut list_view = new_list_view(&event_system, 3);
+
+        // Rows start right below the header
+        list_view.handle_input(&mouse_down(50, 25 + 9));
+        list_view.handle_input(&mouse_down_with(50, 25 + 2 * 18 + 9, Modifiers::shift()));
+        assert_eq!(list_view.selected_indices(), vec![0, 1, 2]);
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Indices(vec![0, 1, 2]))
+        );
+
+        // The header is not empty space
+        list_view.handle_input(&mouse_down(50, 10));
+        assert_eq!(list_view.selected_indices().len(), 3);
+        list_view.handle_input(&mouse_down(50, 180));
+        assert!(list_view.selected_indices().is_empty());
+        assert_eq!(recorder.borrow().count(EventType::FieldValueChanged), 3);
+    }
+
+    #[test]
+    fn test_list_view_list_mode_flows_in_columns() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut list_view = new_list_view(&event_system, 100).with_mode(ListViewMode::List);
+
+        // The horizontal scrollbar leaves 192px: 10 items per 120px column, 3 columns on screen
+        assert_eq!(list_view.visible_items(), 0..30);
+        list_view.handle_input(&mouse_down(1 + 120 + 10, 1 + 2 * 18 + 9));
+        assert_eq!(list_view.selected_indices(), vec![12]);
+
+        list_view.handle_input(&key_down(Key::Right));
+        assert_eq!(list_view.selected_indices(), vec![22]);
+        list_view.handle_input(&key_down(Key::Up));
+        assert_eq!(list_view.selected_indices(), vec![21]);
+        list_view.handle_input(&key_down(Key::Right));
+        list_view.handle_input(&key_down(Key::Right));
+        assert_eq!(list_view.selected_indices(), vec![41]);
+        assert_eq!(list_view.visible_items(), 30..60);
+    }
+
+    #[test]
+    fn test_list_view_icon_mode_flows_in_rows() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut list_view = new_list_view(&event_system, 100)
+            .with_mode(ListViewMode::Icon)
+            .with_selection_mode(SelectionMode::One);
+
+        // 3 tiles of 76px per row, 3 rows of 70px touch the 198px viewport
+        assert_eq!(list_view.visible_items(), 0..9);
+        list_view.handle_input(&mouse_down(1 + 76 + 10, 1 + 70 + 10));
+        assert_eq!(list_view.selected_indices(), vec![4]);
+        list_view.handle_input(&key_down(Key::Down));
+        assert_eq!(list_view.selected_indices(), vec![7]);
+        list_view.handle_input(&key_down(Key::Right));
+        assert_eq!(list_view.selected_indices(), vec![8]);
+
+        let mut ctx = RenderContext::new();
+        list_view.render(&mut ctx);
+        let icons = ctx
+            .commands()
+            .iter()
+            .filter(|command| matches!(command, DrawCommand::Icon { .. }))
+            .count();
+        assert_eq!(icons, list_view.visible_rows().len());
+    }
+
+    #[test]
+    fn test_list_view_mode_switch_keeps_caret_visible() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut list_view = new_list_view(&event_system, 1_000);
+
+        list_view.set_focused(true);
+        list_view.handle_input(&key_down(Key::End));
+        list_view.set_mode(ListViewMode::Icon);
+        assert!(list_view.visible_items().contains(&999));
+        list_view.set_mode(ListViewMode::List);
+        assert!(list_view.visible_items().contains(&999));
+        assert!(
+            list_view
+                .visible_rows()
+                .iter()
+                .any(|row| row.index() == 999 && row.selected())
+        );
+    }
+
+    #[test]
+    fn test_list_view_lays_out_again_after_move_and_deserialization() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut list_view = new_list_view(&event_system, 3);
+
+        let json = serde_json::to_string(&list_view).unwrap();
+        let deserialized: ListView = serde_json::from_str(&json).unwrap();
+        let mut ctx = RenderContext::new();
+        deserialized.render(&mut ctx);
+        let rendered = texts(ctx.commands());
+        assert!(rendered.contains(&"File 2".to_string()));
+
+        // Moving the view carries its rows along on the next render, which
+        // leaves the event system alone
+        list_view.base_mut().set_position(Position::new(0, 100));
+        let ctx = render_quietly(&event_system, &list_view);
+        let first_row = ctx.commands().iter().find_map(|command| match command {
+            DrawCommand::Text { text, position, .. } if text == "File 0" => Some(*position),
+            _ => None,
+        });
+        assert!(first_row.is_some_and(|position| position.y() > 100));
+    }
+}
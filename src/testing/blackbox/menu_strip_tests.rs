@@ -0,0 +1,296 @@
+#[cfg(test)]
+mod menu_strip_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::{context_menu::ContextMenu, menu_item::MenuItem, menu_strip::MenuStrip},
+            properties::{position::Position, size::Size},
+        },
+        ui::accelerator_table::{AcceleratorTable, emit_key_combination},
+        utils::traits::interactive::Interactive,
+        window::events::{
+            event_system::EventSystem,
+            input::{InputEvent, Key, KeyCombination, Modifiers, MouseButton},
+            types::{EventPayload, EventType},
+        },
+    };
+
+    fn shortcut(text: &str) -> KeyCombination {
+        KeyCombination::parse(text).unwrap()
+    }
+
+    // 400x20 bar at the origin with "File" (x 0..46), "Edit" (x 46..92) and
+    // "View" (x 92..138). The File drop-down opens at (0, 20) with rows of
+    // 22 pixels from y 21; the separator takes 7
+    fn new_menu_strip(event_system: &Rc<RefCell<EventSystem>>) -> MenuStrip {
+        let base = new_base(event_system, Position::new(0, 0), Size::new(20, 400));
+        MenuStrip::new(base)
+            .with_menu(
+                MenuItem::new("&File")
+                    .with_item(
+                        MenuItem::new("&New")
+                            .with_command(1)
+                            .with_shortcut(shortcut("Ctrl+N")),
+                    )
+                    .with_item(
+                        MenuItem::new("&Open...")
+                            .with_command(2)
+                            .with_shortcut(shortcut("Ctrl+O")),
+                    )
+                    .with_item(MenuItem::separator())
+                    .with_item(
+                        MenuItem::new("&Recent")
+                            .with_item(MenuItem::new("a.txt").with_command(10))
+                            .with_item(MenuItem::new("b.txt").with_command(11)),
+                    )
+                    .with_item(
+                        MenuItem::new("&Save")
+                            .with_command(3)
+                            .with_shortcut(shortcut("Ctrl+S"))
+                            .with_enabled(false),
+                    )
+                    .with_item(MenuItem::new("E&xit").with_command(4)),
+            )
+            .with_menu(
+                MenuItem::new("&Edit").with_item(
+                    MenuItem::new("&Undo")
+                        .with_command(30)
+                        .with_shortcut(shortcut("Ctrl+Z")),
+                ),
+            )
+            .with_menu(
+                MenuItem::new("&View").with_item(
+                    MenuItem::new("&Status Bar")
+                        .with_command(20)
+                        .with_checked(true),
+                ),
+            )
+    }
+
+    fn alt(c: char) -> InputEvent {
+        key_down_with(Key::Char(c), Modifiers::alt())
+    }
+
+    #[test]
+    fn test_menu_item_mnemonics_and_shortcut_labels() {
+        let item = MenuItem::new("E&xit");
+        assert_eq!(item.display_text(), "Exit");
+        assert_eq!(item.mnemonic(), Some('x'));
+        assert_eq!(item.mnemonic_index(), Some(1));
+
+        let item = MenuItem::new("Fish && &Chips");
+        assert_eq!(item.display_text(), "Fish & Chips");
+        assert_eq!(item.mnemonic(), Some('c'));
+        assert_eq!(MenuItem::new("Plain").mnemonic(), None);
+
+        let save = shortcut("ctrl+shift+s");
+        assert_eq!(save.to_string(), "Ctrl+Shift+S");
+        assert_eq!(shortcut("Alt+F4").to_string(), "Alt+F4");
+        assert_eq!(shortcut("Delete").to_string(), "Del");
+        assert_eq!(KeyCombination::parse("Ctrl+"), None);
+        assert_eq!(KeyCombination::parse("Shift+Ctrl"), None);
+
+        let pressed = key_down_with(
+            Key::Char('S'),
+            Modifiers {
+                shift: true,
+                ..Modifiers::ctrl()
+            },
+        );
+        assert_eq!(KeyCombination::from_input(&pressed), Some(save));
+        assert_eq!(KeyCombination::from_input(&key_down(Key::Char('s'))), None);
+        assert!(KeyCombination::from_input(&key_down(Key::F(5))).is_some());
+    }
+
+    #[test]
+    fn test_menu_strip_opens_submenus_with_the_mouse() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::CommandInvoked]);
+        let mut menu = new_menu_strip(&event_system);
+
+        assert!(menu.handle_input(&mouse_down(10, 10)));
+        assert!(menu.is_open());
+        assert_eq!(menu.active_menu(), Some(0));
+        assert_eq!(
+            menu.popup_bounds(0).unwrap().top_left(),
+            Position::new(0, 20)
+        );
+
+        // Hovering "Recent" cascades its submenu to the right
+        menu.handle_input(&mouse_move(50, 80));
+        assert_eq!(menu.open_depth(), 2);
+        let recent = menu.item_bounds(0, 3).unwrap();
+        let submenu = menu.popup_bounds(1).unwrap();
+        assert_eq!(submenu.top_left().x(), recent.bottom_right().x());
+
+        // Sliding over the bar switches menus
+        menu.handle_input(&mouse_move(60, 10));
+        assert_eq!(menu.active_menu(), Some(1));
+        assert_eq!(menu.open_depth(), 1);
+        menu.handle_input(&mouse_move(10, 10));
+        menu.handle_input(&mouse_move(50, 80));
+
+        // Disabled items ignore clicks and keep the menu open
+        menu.handle_input(&mouse_down(50, 100));
+        menu.handle_input(&mouse_up(50, 100));
+        assert!(menu.is_open());
+        assert_eq!(recorder.borrow().count(EventType::CommandInvoked), 0);
+
+        let a_txt = menu.item_bounds(1, 0).unwrap().top_left();
+        menu.handle_input(&mouse_move(a_txt.x() + 5, a_txt.y() + 5));
+        menu.handle_input(&mouse_down(a_txt.x() + 5, a_txt.y() + 5));
+        menu.handle_input(&mouse_up(a_txt.x() + 5, a_txt.y() + 5));
+        assert!(!menu.is_open());
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::CommandInvoked),
+            Some(EventPayload::Index(10))
+        );
+
+        // Clicking the open title or outside closes the menu
+        menu.handle_input(&mouse_down(10, 10));
+        menu.handle_input(&mouse_down(10, 10));
+        assert!(!menu.is_open());
+        menu.handle_input(&mouse_down(10, 10));
+        assert!(!menu.handle_input(&mouse_down(300, 300)));
+        assert_eq!(menu.active_menu(), None);
+    }
+
+    #[test]
+    fn test_menu_strip_keyboard_navigation() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::CommandInvoked]);
+        let mut menu = new_menu_strip(&event_system);
+
+        // Alt+F opens File on its first item, arrows skip the separator
+        assert!(menu.handle_input(&alt('f')));
+        assert_eq!(menu.highlighted_path(), Some(vec![0]));
+        menu.handle_input(&key_down(Key::Down));
+        menu.handle_input(&key_down(Key::Down));
+        assert_eq!(menu.highlighted_path(), Some(vec![3]));
+        menu.handle_input(&key_down(Key::Right));
+        assert_eq!(menu.highlighted_path(), Some(vec![3, 0]));
+        menu.handle_input(&key_down(Key::Left));
+        assert_eq!(menu.highlighted_path(), Some(vec![3]));
+
+        // Escape closes the drop-down but leaves the bar active
+        menu.handle_input(&key_down(Key::Escape));
+        assert!(!menu.is_open());
+        assert_eq!(menu.active_menu(), Some(0));
+        menu.handle_input(&key_down(Key::Right));
+        menu.handle_input(&key_down(Key::Down));
+        assert_eq!(menu.active_menu(), Some(1));
+        assert!(menu.is_open());
+
+        // Right on a plain item moves to the next menu
+        menu.handle_input(&key_down(Key::Right));
+        assert_eq!(menu.active_menu(), Some(2));
+        menu.handle_input(&key_down(Key::Enter));
+        assert!(!menu.is_open());
+        assert!(!menu.item(20).unwrap().checked());
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::CommandInvoked),
+            Some(EventPayload::Index(20))
+        );
+
+        // A mnemonic inside the drop-down runs its item
+        menu.handle_input(&alt('f'));
+        for input in type_text("x") {
+            menu.handle_input(&input);
+        }
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::CommandInvoked),
+            Some(EventPayload::Index(4))
+        );
+
+        // F10 activates the bar without opening a menu
+        menu.handle_input(&key_down(Key::F(10)));
+        assert_eq!(menu.active_menu(), Some(0));
+        assert!(!menu.is_open());
+        menu.handle_input(&key_down(Key::Escape));
+        assert_eq!(menu.active_menu(), None);
+        assert!(!menu.handle_input(&key_down(Key::Down)));
+    }
+
+    #[test]
+    fn test_accelerator_table_invokes_menu_commands() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::CommandInvoked]);
+        let mut menu = new_menu_strip(&event_system);
+        let table = Rc::new(RefCell::new(AcceleratorTable::new()));
+        event_system
+            .borrow_mut()
+            .subscribe(EventType::KeyCombinationPressed, Rc::downgrade(&table));
+        menu.register_shortcuts(&mut table.borrow_mut());
+        assert_eq!(table.borrow().len(), 4);
+
+        let ctrl = |c| key_down_with(Key::Char(c), Modifiers::ctrl());
+        assert!(emit_key_combination(&event_system, 0, &ctrl('N')));
+        assert!(emit_key_combination(&event_system, 0, &ctrl('s')));
+        assert!(emit_key_combination(&event_system, 0, &ctrl('q')));
+        assert!(!emit_key_combination(
+            &event_system,
+            0,
+            &key_down(Key::Char('n'))
+        ));
+
+        let pending = table.borrow_mut().take_pending();
+        assert_eq!(pending, [1, 3]);
+        let invoked: Vec<bool> = pending.into_iter().map(|c| menu.invoke(c)).collect();
+        // Save is disabled, so its shortcut does nothing
+        assert_eq!(invoked, [true, false]);
+        assert_eq!(recorder.borrow().count(EventType::CommandInvoked), 1);
+        assert!(table.borrow_mut().take_pending().is_empty());
+
+        menu.set_item_enabled(3, true);
+        table.borrow_mut().unregister(shortcut("Ctrl+N"));
+        assert_eq!(table.borrow().command(shortcut("Ctrl+N")), None);
+        assert_eq!(table.borrow().command(shortcut("Ctrl+S")), Some(3));
+        assert!(menu.invoke(3));
+    }
+
+    #[test]
+    fn test_context_menu_opens_on_right_click() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::CommandInvoked]);
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(200, 200));
+        let mut context_menu = ContextMenu::new(base)
+            .with_item(MenuItem::new("&Cut").with_command(1))
+            .with_item(
+                MenuItem::new("Word &Wrap")
+                    .with_command(2)
+                    .with_checkable(true),
+            );
+
+        // Left clicks and clicks outside the frame do nothing
+        assert!(!context_menu.handle_input(&mouse_up(50, 50)));
+        let right_up = |x, y| InputEvent::MouseButtonReleased {
+            button: MouseButton::Right,
+            position: Position::new(x, y),
+            modifiers: Modifiers::none(),
+        };
+        assert!(!context_menu.handle_input(&right_up(250, 50)));
+
+        assert!(context_menu.handle_input(&right_up(50, 50)));
+        assert_eq!(
+            context_menu.popup_bounds(0).unwrap().top_left(),
+            Position::new(50, 50)
+        );
+        let wrap = context_menu.item_bounds(0, 1).unwrap().top_left();
+        context_menu.handle_input(&mouse_down(wrap.x() + 5, wrap.y() + 5));
+        context_menu.handle_input(&mouse_up(wrap.x() + 5, wrap.y() + 5));
+        assert!(!context_menu.is_open());
+        assert!(context_menu.item(2).unwrap().checked());
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::CommandInvoked),
+            Some(EventPayload::Index(2))
+        );
+
+        // Shift+F10 opens it from the keyboard, Escape closes it
+        context_menu.handle_input(&key_down_with(Key::F(10), Modifiers::shift()));
+        assert_eq!(context_menu.highlighted_path(), Some(vec![0]));
+        context_menu.handle_input(&key_down(Key::Escape));
+        assert!(!context_menu.is_open());
+    }
+}
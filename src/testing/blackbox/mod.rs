@@ -0,0 +1,184 @@
+//! Tests de caja negra para los componentes interactivos de WinR
+//!
+//! Los componentes se manejan únicamente a través de su API pública,
+//! `InputEvent` y los eventos que emiten en el `EventSystem`.
+
+pub mod button_tests;
+pub mod check_box_tests;
+pub mod color_picker_tests;
+pub mod combo_box_tests;
+pub mod contrast_lint_tests;
+pub mod data_grid_tests;
+pub mod date_time_picker_tests;
+pub mod dialog_tests;
+pub mod link_tests;
+pub mod list_box_tests;
+pub mod list_view_tests;
+pub mod menu_strip_tests;
+pub mod notification_tests;
+pub mod panel_tests;
+pub mod picture_box_tests;
+pub mod radio_group_tests;
+pub mod range_control_tests;
+pub mod rasterizer_tests;
+pub mod scroll_viewer_tests;
+pub mod tab_control_tests;
+pub mod text_box_tests;
+pub mod tool_strip_tests;
+pub mod tooltip_tests;
+pub mod tree_view_tests;
+
+#[cfg(test)]
+mod test_utils {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::core::{
+        components::{
+            base_component::BaseComponent,
+            properties::{margin::Margin, padding::Padding, position::Position, size::Size},
+        },
+        ui::render::render_context::RenderContext,
+        utils::traits::{event_listener::EventListener, renderable::Renderable},
+        window::events::{
+            event_system::EventSystem,
+            input::{InputEvent, Key, Modifiers, MouseButton},
+            types::{EventPayload, EventType},
+        },
+    };
+
+    /// Listener que guarda todos los eventos recibidos
+    #[derive(Debug, Default)]
+    pub struct EventRecorder {
+        pub events: Vec<(EventType, usize)>,
+        pub payloads: Vec<(EventType, EventPayload)>,
+    }
+
+    impl EventListener for EventRecorder {
+        fn on_event(&mut self, event: &EventType, caller_id: usize) {
+            self.events.push((*event, caller_id));
+        }
+
+        fn on_event_with(&mut self, event: &EventType, caller_id: usize, payload: &EventPayload) {
+            self.events.push((*event, caller_id));
+            self.payloads.push((*event, payload.clone()));
+        }
+    }
+
+    impl EventRecorder {
+        pub fn count(&self, event: EventType) -> usize {
+            self.events.iter().filter(|(e, _)| *e == event).count()
+        }
+
+        pub fn last_payload(&self, event: EventType) -> Option<EventPayload> {
+            self.payloads
+                .iter()
+                .rev()
+                .find(|(e, _)| *e == event)
+                .map(|(_, payload)| payload.clone())
+        }
+    }
+
+    /// Crea un recorder suscrito a los eventos indicados
+    pub fn record(
+        event_system: &Rc<RefCell<EventSystem>>,
+        events: &[EventType],
+    ) -> Rc<RefCell<EventRecorder>> {
+        let recorder = Rc::new(RefCell::new(EventRecorder::default()));
+        for event in events {
+            event_system
+                .borrow_mut()
+                .subscribe(*event, Rc::downgrade(&recorder));
+        }
+        recorder
+    }
+
+    /// Renderiza el componente varias veces comprobando que no emite eventos
+    /// ni toma ids del `EventSystem`; devuelve lo dibujado la última vez
+    pub fn render_quietly(
+        event_system: &Rc<RefCell<EventSystem>>,
+        component: &dyn Renderable,
+    ) -> RenderContext {
+        let recorder = record(
+            event_system,
+            &[
+                EventType::ComponentMoved,
+                EventType::ComponentResized,
+                EventType::ComponentAdded,
+            ],
+        );
+        let next_id = event_system.borrow_mut().get_next_id();
+        let mut ctx = RenderContext::new();
+        for _ in 0..3 {
+            ctx = RenderContext::new();
+            component.render(&mut ctx);
+        }
+        assert_eq!(recorder.borrow().events, vec![]);
+        assert_eq!(event_system.borrow_mut().get_next_id(), next_id + 1);
+        ctx
+    }
+
+    pub fn new_base(
+        event_system: &Rc<RefCell<EventSystem>>,
+        position: Position,
+        size: Size,
+    ) -> BaseComponent {
+        BaseComponent::new(
+            size,
+            position,
+            Margin::default(),
+            Padding::default(),
+            event_system.clone(),
+        )
+    }
+
+    pub fn mouse_move(x: u16, y: u16) -> InputEvent {
+        InputEvent::MouseMoved(Position::new(x, y))
+    }
+
+    pub fn mouse_down(x: u16, y: u16) -> InputEvent {
+        mouse_down_with(x, y, Modifiers::none())
+    }
+
+    pub fn mouse_down_with(x: u16, y: u16, modifiers: Modifiers) -> InputEvent {
+        InputEvent::MouseButtonPressed {
+            button: MouseButton::Left,
+            position: Position::new(x, y),
+            modifiers,
+        }
+    }
+
+    pub fn scroll(x: u16, y: u16, delta_y: i16) -> InputEvent {
+        InputEvent::MouseScrolled {
+            delta_x: 0,
+            delta_y,
+            position: Position::new(x, y),
+        }
+    }
+
+    pub fn mouse_up(x: u16, y: u16) -> InputEvent {
+        InputEvent::MouseButtonReleased {
+            button: MouseButton::Left,
+            position: Position::new(x, y),
+            modifiers: Modifiers::none(),
+        }
+    }
+
+    pub fn key_down(key: Key) -> InputEvent {
+        key_down_with(key, Modifiers::none())
+    }
+
+    pub fn key_down_with(key: Key, modifiers: Modifiers) -> InputEvent {
+        InputEvent::KeyPressed { key, modifiers }
+    }
+
+    pub fn type_text(text: &str) -> Vec<InputEvent> {
+        text.chars().map(InputEvent::CharTyped).collect()
+    }
+
+    pub fn key_up(key: Key) -> InputEvent {
+        InputEvent::KeyReleased {
+            key,
+            modifiers: Modifiers::none(),
+        }
+    }
+}
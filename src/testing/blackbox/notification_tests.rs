@@ -0,0 +1,193 @@
+#[cfg(test)]
+mod notification_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc, time::Duration};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::properties::{
+            boundaries::Boundaries,
+            graphics::background::{Background, BackgroundShape},
+            position::Position,
+            size::Size,
+        },
+        ui::{
+            elements::view::View,
+            notification_service::{NotificationService, Toast, ToastCorner, ToastPart},
+            render::{draw_command::DrawCommand, render_context::RenderContext},
+        },
+        window::events::{
+            event_system::EventSystem,
+            types::{EventPayload, EventType},
+        },
+    };
+
+    fn bounds(x: u16, y: u16, width: u16, height: u16) -> Boundaries {
+        Boundaries::from_rect(Position::new(x, y), Size::new(height, width))
+    }
+
+    /// Servicio sobre una ventana de 800x600, sin animaciones. Un aviso con
+    /// título y una línea mide 320x62
+    fn service(event_system: &Rc<RefCell<EventSystem>>) -> NotificationService {
+        let mut service = NotificationService::new(event_system.clone(), bounds(0, 0, 800, 600));
+        service.set_animation_duration(Duration::ZERO);
+        service
+    }
+
+    #[test]
+    fn test_toasts_stack_in_corner_and_expire() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::ItemClosed]);
+        let mut service = service(&event_system);
+
+        let saved =
+            service.show(Toast::info("Saved", "report.docx").with_duration(Duration::from_secs(3)));
+        assert_eq!(service.toast_bounds(saved), Some(bounds(468, 526, 320, 62)));
+
+        // The newest toast takes the corner and pushes the older one up
+        let failed = service.show(Toast::error("Upload failed", "Server unavailable"));
+        assert_eq!(
+            service.toast_bounds(failed),
+            Some(bounds(468, 526, 320, 62))
+        );
+        assert_eq!(service.toast_bounds(saved), Some(bounds(468, 458, 320, 62)));
+
+        assert!(service.tick(Duration::from_secs(3)));
+        assert_eq!(service.visible_toasts(), vec![failed]);
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::ItemClosed),
+            Some(EventPayload::Index(saved))
+        );
+        assert_eq!(service.remaining(failed), Some(Duration::from_secs(2)));
+
+        service.set_corner(ToastCorner::TopLeft);
+        assert_eq!(service.toast_bounds(failed), Some(bounds(12, 12, 320, 62)));
+        service.set_viewport(bounds(0, 0, 400, 300));
+        service.set_corner(ToastCorner::BottomRight);
+        assert_eq!(service.toast_bounds(failed), Some(bounds(68, 226, 320, 62)));
+    }
+
+    #[test]
+    fn test_toasts_slide_in_and_out() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::ItemClosed]);
+        let mut service = NotificationService::new(event_system.clone(), bounds(0, 0, 800, 600));
+
+        let id = service.show(Toast::success("Done", "Export finished"));
+        assert_eq!(service.progress(id), Some(0.0));
+        // Starts just past the right edge
+        assert_eq!(service.toast_bounds(id), Some(bounds(800, 526, 320, 62)));
+
+        assert!(service.tick(Duration::from_millis(100)));
+        assert_eq!(service.progress(id), Some(0.5));
+        assert_eq!(service.toast_bounds(id), Some(bounds(510, 526, 320, 62)));
+        service.tick(Duration::from_millis(100));
+        assert_eq!(service.toast_bounds(id), Some(bounds(468, 526, 320, 62)));
+        // Resting toasts only count down
+        assert!(!service.tick(Duration::from_millis(100)));
+
+        assert!(service.dismiss(id));
+        assert!(!service.dismiss(id));
+        assert_eq!(service.progress(id), Some(1.0));
+        service.tick(Duration::from_millis(150));
+        assert_eq!(service.visible_toasts(), vec![id]);
+        service.tick(Duration::from_millis(50));
+        assert!(service.visible_toasts().is_empty());
+        assert_eq!(recorder.borrow().count(EventType::ItemClosed), 1);
+    }
+
+    #[test]
+    fn test_hover_pauses_and_actions_run() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::CommandInvoked]);
+        let mut service = service(&event_system);
+
+        let id = service.show(
+            Toast::warning("Deleted", "3 files")
+                .with_duration(Duration::from_secs(1))
+                .with_action("Undo", 7)
+                .with_action("Open bin", 8),
+        );
+        // Room for the buttons below the message
+        assert_eq!(service.toast_bounds(id), Some(bounds(468, 497, 320, 91)));
+        assert_eq!(
+            service.part_bounds(id, ToastPart::Action(1)),
+            Some(bounds(540, 555, 84, 23))
+        );
+        assert_eq!(
+            service.part_bounds(id, ToastPart::Close),
+            Some(bounds(762, 507, 16, 16))
+        );
+
+        assert!(service.handle_input(&mouse_move(600, 520)));
+        service.tick(Duration::from_secs(2));
+        assert_eq!(service.visible_toasts(), vec![id]);
+        assert!(!service.handle_input(&mouse_move(100, 100)));
+        assert!(!service.handle_input(&mouse_down(100, 100)));
+
+        // The second button runs its command and closes the toast
+        assert!(service.handle_input(&mouse_down(550, 570)));
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::CommandInvoked),
+            Some(EventPayload::Index(8))
+        );
+        assert!(service.visible_toasts().is_empty());
+
+        let id = service.show(Toast::info("Update", "Restart to finish").persistent());
+        service.tick(Duration::from_secs(60));
+        assert_eq!(service.remaining(id), None);
+        // Pressing the body, just above the close button, keeps it
+        assert!(service.handle_input(&mouse_down(765, 534)));
+        assert_eq!(service.visible_toasts(), vec![id]);
+        assert!(service.handle_input(&mouse_down(765, 540)));
+        assert!(service.visible_toasts().is_empty());
+        assert_eq!(recorder.borrow().count(EventType::CommandInvoked), 1);
+    }
+
+    #[test]
+    fn test_view_equality_follows_its_event_system_and_toasts() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let view = View::new(event_system.clone(), bounds(0, 0, 800, 600));
+        let mut copy = view.clone();
+        assert_eq!(copy, view);
+
+        copy.notifications_mut().show(Toast::info("One", ""));
+        assert_ne!(copy, view);
+
+        // Same settings on another event system are still another view
+        assert_ne!(View::default(), View::default());
+    }
+
+    #[test]
+    fn test_queue_and_view_service() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut view = View::new(event_system.clone(), bounds(0, 0, 800, 600));
+        let service = view.notifications_mut();
+        service.set_animation_duration(Duration::ZERO);
+        service.set_max_visible(2);
+
+        let first = service.show(Toast::info("One", ""));
+        service.show(Toast::error("Two", "Disk full"));
+        let third = service.show(Toast::info("Three", ""));
+        assert_eq!(service.visible_toasts().len(), 2);
+        assert_eq!(service.queued(), 1);
+        assert!(service.toast_bounds(third).is_none());
+
+        service.dismiss(first);
+        assert_eq!(service.queued(), 0);
+        // A toast without a message is only the title row
+        assert_eq!(service.toast_bounds(third), Some(bounds(468, 550, 320, 38)));
+
+        let mut ctx = RenderContext::new();
+        view.notifications().render(&mut ctx);
+        assert_eq!(ctx.layer_count(), 2);
+        let error = Background::new_solid(
+            view.notifications().style().error_color.clone(),
+            BackgroundShape::Rectangle,
+        );
+        assert!(ctx.layer(1).iter().any(|command| matches!(
+            command,
+            DrawCommand::FillRect { bounds: at, background }
+                if at.width() == 4 && *background == error
+        )));
+    }
+}
@@ -0,0 +1,242 @@
+#[cfg(test)]
+mod panel_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::button::Button,
+            layouts::{
+                group_box::GroupBox,
+                panel::Panel,
+                split_container::{SplitContainer, SplitPane, SplitterState},
+            },
+            properties::{
+                boundaries::Boundaries, orientation::Orientation, position::Position, size::Size,
+            },
+        },
+        ui::render::{draw_command::DrawCommand, render_context::RenderContext},
+        utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+        window::events::{
+            event_system::EventSystem,
+            input::Key,
+            types::{EventPayload, EventType},
+        },
+    };
+
+    fn bounds(x: u16, y: u16, width: u16, height: u16) -> Boundaries {
+        Boundaries::from_rect(Position::new(x, y), Size::new(height, width))
+    }
+
+    #[test]
+    fn test_group_box_frames_its_title() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let button = Button::new(
+            new_base(&event_system, Position::new(10, 30), Size::new(20, 50)),
+            "OK",
+        );
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(100, 200));
+        let mut group = GroupBox::new(base, "Options").with_child(button);
+
+        // Below the title and inside the border plus padding
+        assert_eq!(group.display_rectangle(), bounds(4, 21, 192, 75));
+        assert_eq!(group.title_bounds(), bounds(10, 0, 56, 18));
+
+        let mut ctx = RenderContext::new();
+        group.render(&mut ctx);
+        let lines: Vec<_> = ctx
+            .commands()
+            .iter()
+            .filter_map(|command| match command {
+                DrawCommand::Line { from, to, .. } => Some((*from, *to)),
+                _ => None,
+            })
+            .collect();
+        // The top border stops around the title
+        assert!(lines.contains(&(Position::new(0, 9), Position::new(8, 9))));
+        assert!(lines.contains(&(Position::new(68, 9), Position::new(199, 9))));
+
+        assert!(group.handle_input(&mouse_down(20, 40)));
+        group.set_enabled(false);
+        assert!(!group.handle_input(&mouse_down(20, 40)));
+        assert!(!group.focusable());
+    }
+
+    #[test]
+    fn test_group_box_and_panel_fade_their_content_once() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let button = |y: u16| {
+            Button::new(
+                new_base(&event_system, Position::new(10, y), Size::new(20, 50)),
+                "OK",
+            )
+        };
+        let mut group = GroupBox::new(
+            new_base(&event_system, Position::new(0, 0), Size::new(100, 200)),
+            "Options",
+        )
+        .with_child(button(30));
+        let mut panel = Panel::new(new_base(
+            &event_system,
+            Position::new(0, 100),
+            Size::new(100, 200),
+        ))
+        .with_child(button(130));
+        group.base_mut().set_opacity(0.5);
+        panel.base_mut().set_opacity(0.5);
+
+        // The content shares the base, so its opacity must not stack on the
+        // one of the frame
+        for component in [&group as &dyn Component, &panel] {
+            let mut ctx = RenderContext::new();
+            component.paint(&mut ctx);
+            let mut depth = 0;
+            for command in ctx.commands() {
+                match command {
+                    DrawCommand::PushOpacity(opacity) => {
+                        assert_eq!(*opacity, 0.5);
+                        depth += 1;
+                        assert_eq!(depth, 1);
+                    }
+                    DrawCommand::PopOpacity => depth -= 1,
+                    DrawCommand::Text { .. } => assert_eq!(depth, 1),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_panel_scrolls_only_with_auto_scroll() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let button = Button::new(
+            new_base(&event_system, Position::new(0, 150), Size::new(20, 50)),
+            "Far",
+        );
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(100, 100));
+        let mut panel = Panel::new(base).with_child(button);
+
+        assert_eq!(panel.viewport(), bounds(0, 0, 100, 100));
+        assert!(!panel.handle_input(&scroll(50, 50, -1)));
+        assert_eq!(panel.scroll_offset(), Position::new(0, 0));
+
+        panel.set_auto_scroll(true);
+        assert_eq!(panel.viewport(), bounds(0, 0, 83, 100));
+        assert!(panel.handle_input(&scroll(50, 50, -1)));
+        assert_eq!(panel.scroll_offset(), Position::new(0, 48));
+        assert!(panel.handle_input(&scroll(50, 50, -1)));
+        assert_eq!(panel.scroll_offset(), Position::new(0, 70));
+    }
+
+    #[test]
+    fn test_splitter_drags_and_keeps_its_ratio() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(100, 200));
+        let mut split = SplitContainer::new(base);
+
+        assert_eq!(split.splitter_distance(), 98);
+        assert_eq!(split.panel_bounds(SplitPane::First), bounds(0, 0, 98, 100));
+        assert_eq!(split.splitter_bounds(), bounds(98, 0, 4, 100));
+        assert_eq!(
+            *split.panel(SplitPane::Second).base().position(),
+            Position::new(102, 0)
+        );
+
+        split.handle_input(&mouse_down(99, 50));
+        assert!(split.is_dragging());
+        split.handle_input(&mouse_move(131, 50));
+        split.handle_input(&mouse_move(151, 50));
+        assert_eq!(split.splitter_distance(), 150);
+        assert_eq!(recorder.borrow().count(EventType::FieldValueChanged), 0);
+        split.handle_input(&mouse_up(151, 50));
+        assert_eq!(recorder.borrow().count(EventType::FieldValueChanged), 1);
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Number((150.0f32 / 196.0) as f64))
+        );
+
+        // Growing the container scales both panels
+        split.set_size(Size::new(100, 400));
+        assert_eq!(split.splitter_distance(), 303);
+        assert_eq!(
+            *split.panel(SplitPane::Second).base().size(),
+            Size::new(100, 93)
+        );
+
+        // Stacked panels move the splitter with the arrow keys once focused
+        split.set_orientation(Orientation::Horizontal);
+        let distance = split.splitter_distance();
+        split.handle_input(&mouse_down(200, distance + 1));
+        split.handle_input(&mouse_up(200, distance + 1));
+        split.handle_input(&key_down(Key::Up));
+        assert_eq!(split.splitter_distance(), distance - 1);
+    }
+
+    #[test]
+    fn test_splitter_lays_out_again_after_resize() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(100, 200));
+        let mut split = SplitContainer::new(base);
+
+        split.set_size(Size::new(100, 400));
+        assert_eq!(
+            split.panel(SplitPane::Second).base().frame(),
+            split.panel_bounds(SplitPane::Second)
+        );
+        assert_eq!(
+            *split.panel(SplitPane::Second).base().size(),
+            Size::new(100, 198)
+        );
+    }
+
+    #[test]
+    fn test_splitter_respects_min_sizes_and_collapses() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(100, 200));
+        let mut split = SplitContainer::new(base).with_panel_min_size(SplitPane::First, 40);
+
+        split.handle_input(&mouse_down(98, 50));
+        split.handle_input(&mouse_move(30, 50));
+        assert_eq!(split.splitter_distance(), 40);
+
+        // More than halfway into the minimum collapses the panel
+        split.handle_input(&mouse_move(10, 50));
+        assert_eq!(split.collapsed(), Some(SplitPane::First));
+        assert_eq!(split.splitter_distance(), 0);
+        assert!(!split.panel(SplitPane::First).base().visible());
+        assert_eq!(
+            split.panel_bounds(SplitPane::Second),
+            bounds(4, 0, 196, 100)
+        );
+
+        split.handle_input(&mouse_move(60, 50));
+        assert_eq!(split.collapsed(), None);
+        assert_eq!(split.splitter_distance(), 60);
+        split.handle_input(&mouse_up(60, 50));
+
+        split.set_collapsed(Some(SplitPane::Second));
+        assert_eq!(split.splitter_bounds(), bounds(196, 0, 4, 100));
+        assert!(!split.panel(SplitPane::Second).base().visible());
+    }
+
+    #[test]
+    fn test_splitter_state_round_trips() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(100, 200));
+        let mut split = SplitContainer::new(base).with_splitter_ratio(0.25);
+        split.set_collapsed(Some(SplitPane::Second));
+
+        let json = serde_json::to_string(&split.state()).unwrap();
+        let state: SplitterState = serde_json::from_str(&json).unwrap();
+        assert_eq!(state, split.state());
+
+        // Restored into a larger container, the ratio is what matters
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(100, 404));
+        let mut restored = SplitContainer::new(base);
+        restored.restore(state);
+        assert_eq!(restored.collapsed(), Some(SplitPane::Second));
+        restored.set_collapsed(None);
+        assert_eq!(restored.splitter_distance(), 100);
+    }
+}
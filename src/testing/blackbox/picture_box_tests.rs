@@ -0,0 +1,280 @@
+#[cfg(test)]
+mod picture_box_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::{
+                icon::Icon,
+                picture_box::{PictureBox, PictureBoxSizeMode},
+            },
+            properties::{boundaries::Boundaries, position::Position, size::Size},
+        },
+        ui::{
+            image::{
+                bitmap::Bitmap,
+                decoder::{ImageError, decode, decode_sized},
+                image_cache::ImageCache,
+            },
+            render::{draw_command::DrawCommand, render_context::RenderContext},
+        },
+        utils::traits::renderable::Renderable,
+        window::events::{event_system::EventSystem, types::EventType},
+    };
+
+    const RED: [u8; 4] = [255, 0, 0, 255];
+    const BLUE: [u8; 4] = [0, 0, 255, 255];
+
+    fn crc32(bytes: &[u8]) -> u32 {
+        let mut crc = !0u32;
+        for byte in bytes {
+            crc ^= *byte as u32;
+            for _ in 0..8 {
+                crc = if crc & 1 != 0 {
+                    (crc >> 1) ^ 0xEDB8_8320
+                } else {
+                    crc >> 1
+                };
+            }
+        }
+        !crc
+    }
+
+    fn chunk(png: &mut Vec<u8>, kind: &[u8], body: &[u8]) {
+        png.extend_from_slice(&(body.len() as u32).to_be_bytes());
+        let start = png.len();
+        png.extend_from_slice(kind);
+        png.extend_from_slice(body);
+        let crc = crc32(&png[start..]);
+        png.extend_from_slice(&crc.to_be_bytes());
+    }
+
+    /// PNG RGBA de 8 bits con un filtro Sub en cada fila
+    fn rgba_png(width: u32, height: u32, pixels: &[[u8; 4]]) -> Vec<u8> {
+        let mut raw = Vec::new();
+        for row in pixels.chunks(width as usize) {
+            raw.push(1);
+            let mut previous = [0u8; 4];
+            for pixel in row {
+                for channel in 0..4 {
+                    raw.push(pixel[channel].wrapping_sub(previous[channel]));
+                }
+                previous = *pixel;
+            }
+        }
+        let mut header = Vec::new();
+        header.extend_from_slice(&width.to_be_bytes());
+        header.extend_from_slice(&height.to_be_bytes());
+        header.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        chunk(&mut png, b"IHDR", &header);
+        chunk(&mut png, b"tEXt", b"Comment\0ignored");
+        chunk(
+            &mut png,
+            b"IDAT",
+            &miniz_oxide::deflate::compress_to_vec_zlib(&raw, 6),
+        );
+        chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    /// DIB de 24 bits sin cabecera de archivo, con la altura doble y la
+    /// máscara AND cuando es para un icono
+    fn dib_24(width: u32, height: u32, pixels: &[[u8; 4]], mask: Option<&[bool]>) -> Vec<u8> {
+        let stored_height = if mask.is_some() { height * 2 } else { height };
+        let mut dib = Vec::new();
+        dib.extend_from_slice(&40u32.to_le_bytes());
+        dib.extend_from_slice(&(width as i32).to_le_bytes());
+        dib.extend_from_slice(&(stored_height as i32).to_le_bytes());
+        dib.extend_from_slice(&1u16.to_le_bytes());
+        dib.extend_from_slice(&24u16.to_le_bytes());
+        dib.extend_from_slice(&[0; 24]);
+        // Bottom-up rows padded to 4 bytes
+        let stride = (width as usize * 3).div_ceil(4) * 4;
+        for row in pixels.chunks(width as usize).rev() {
+            let start = dib.len();
+            for pixel in row {
+                dib.extend_from_slice(&[pixel[2], pixel[1], pixel[0]]);
+            }
+            dib.resize(start + stride, 0);
+        }
+        if let Some(mask) = mask {
+            let stride = (width as usize).div_ceil(32) * 4;
+            for row in mask.chunks(width as usize).rev() {
+                let mut bits = vec![0u8; stride];
+                for (x, transparent) in row.iter().enumerate() {
+                    if *transparent {
+                        bits[x / 8] |= 0x80 >> (x % 8);
+                    }
+                }
+                dib.extend_from_slice(&bits);
+            }
+        }
+        dib
+    }
+
+    fn bmp_24(width: u32, height: u32, pixels: &[[u8; 4]]) -> Vec<u8> {
+        let dib = dib_24(width, height, pixels, None);
+        let mut bmp = b"BM".to_vec();
+        bmp.extend_from_slice(&(14 + dib.len() as u32).to_le_bytes());
+        bmp.extend_from_slice(&[0; 4]);
+        bmp.extend_from_slice(&54u32.to_le_bytes());
+        bmp.extend_from_slice(&dib);
+        bmp
+    }
+
+    fn ico(images: &[(u32, u32, Vec<u8>)]) -> Vec<u8> {
+        let mut ico = vec![0, 0, 1, 0];
+        ico.extend_from_slice(&(images.len() as u16).to_le_bytes());
+        let mut offset = 6 + images.len() * 16;
+        for (width, height, data) in images {
+            ico.extend_from_slice(&[*width as u8, *height as u8, 0, 0]);
+            ico.extend_from_slice(&1u16.to_le_bytes());
+            ico.extend_from_slice(&32u16.to_le_bytes());
+            ico.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            ico.extend_from_slice(&(offset as u32).to_le_bytes());
+            offset += data.len();
+        }
+        for (_, _, data) in images {
+            ico.extend_from_slice(data);
+        }
+        ico
+    }
+
+    #[test]
+    fn test_decodes_png_and_bmp() {
+        let pixels = [RED, BLUE, [0, 255, 0, 128], [10, 20, 30, 0]];
+        let png = decode(&rgba_png(2, 2, &pixels)).unwrap();
+        assert_eq!(png.size(), (2, 2));
+        for (i, pixel) in pixels.iter().enumerate() {
+            assert_eq!(png.pixel(i as u32 % 2, i as u32 / 2), Some(*pixel));
+        }
+
+        // Three pixels per row need a byte of padding
+        let pixels = [RED, BLUE, RED, BLUE, RED, BLUE];
+        let bmp = decode(&bmp_24(3, 2, &pixels)).unwrap();
+        assert_eq!(bmp.pixel(0, 0), Some(RED));
+        assert_eq!(bmp.pixel(2, 1), Some(BLUE));
+
+        let mut corrupt = rgba_png(2, 2, &[RED; 4]);
+        corrupt[20] ^= 1;
+        assert_eq!(
+            decode(&corrupt),
+            Err(ImageError::Invalid("chunk checksum mismatch"))
+        );
+        // Interlace methods other than none and Adam7 do not exist
+        let mut interlace = rgba_png(2, 2, &[RED; 4]);
+        interlace[28] = 2;
+        let crc = crc32(&interlace[12..29]);
+        interlace[29..33].copy_from_slice(&crc.to_be_bytes());
+        assert_eq!(
+            decode(&interlace),
+            Err(ImageError::Invalid("bad PNG interlace method"))
+        );
+        assert_eq!(decode(b"GIF89a"), Err(ImageError::UnknownFormat));
+        assert_eq!(
+            decode(&bmp_24(3, 2, &pixels)[..60]),
+            Err(ImageError::Truncated)
+        );
+    }
+
+    #[test]
+    fn test_decodes_the_best_icon_entry() {
+        let mask = [false, true, false, false];
+        let small = dib_24(2, 2, &[RED; 4], Some(&mask));
+        let large = rgba_png(4, 4, &[BLUE; 16]);
+        let icon = ico(&[(2, 2, small), (4, 4, large)]);
+
+        // The AND mask makes pixels transparent
+        let bitmap = decode_sized(&icon, Some((2, 2))).unwrap();
+        assert_eq!(bitmap.size(), (2, 2));
+        assert_eq!(bitmap.pixel(0, 0), Some(RED));
+        assert_eq!(bitmap.pixel(1, 0), Some([0; 4]));
+
+        // Larger entries are preferred over scaling a smaller one up
+        assert_eq!(decode_sized(&icon, Some((3, 3))).unwrap().size(), (4, 4));
+        assert_eq!(decode(&icon).unwrap().pixel(3, 3), Some(BLUE));
+    }
+
+    #[test]
+    fn test_icons_resolve_to_cached_bitmaps() {
+        let mut cache = ImageCache::new();
+        let mut source = Bitmap::new(4, 4);
+        for y in 0..4 {
+            for x in 0..4 {
+                source.set_pixel(x, y, if x < 2 { RED } else { BLUE });
+            }
+        }
+        cache.insert("app.ico", source);
+
+        let icon = Icon::new("app.ico", (2, 1));
+        let bitmap = icon.bitmap(&mut cache).unwrap();
+        assert_eq!(bitmap.size(), (2, 1));
+        assert_eq!(bitmap.pixel(0, 0), Some(RED));
+        assert_eq!(bitmap.pixel(1, 0), Some(BLUE));
+        assert!(Rc::ptr_eq(&bitmap, &icon.bitmap(&mut cache).unwrap()));
+        assert!(cache.contains("app.ico", Some((2, 1))));
+
+        let missing = Icon::new("missing.png", (16, 16));
+        assert!(matches!(missing.bitmap(&mut cache), Err(ImageError::Io(_))));
+    }
+
+    #[test]
+    fn test_picture_box_places_image_by_size_mode() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let image = Rc::new(Bitmap::from_rgba(40, 20, vec![255; 40 * 20 * 4]).unwrap());
+        let base = new_base(&event_system, Position::new(10, 10), Size::new(60, 100));
+        let mut picture = PictureBox::new(base).with_image(image.clone());
+
+        let bounds = |position: (u16, u16), size: (u16, u16)| {
+            Some(Boundaries::from_rect(
+                Position::new(position.0, position.1),
+                Size::new(size.1, size.0),
+            ))
+        };
+        assert_eq!(picture.image_bounds(), bounds((10, 10), (40, 20)));
+        picture.set_size_mode(PictureBoxSizeMode::Stretch);
+        assert_eq!(picture.image_bounds(), bounds((10, 10), (100, 60)));
+        // 100x50 keeps the 2:1 proportion and leaves 5 pixels above and below
+        picture.set_size_mode(PictureBoxSizeMode::Zoom);
+        assert_eq!(picture.image_bounds(), bounds((10, 15), (100, 50)));
+        picture.set_size_mode(PictureBoxSizeMode::Center);
+        assert_eq!(picture.image_bounds(), bounds((40, 30), (40, 20)));
+
+        let mut ctx = RenderContext::new();
+        picture.render(&mut ctx);
+        assert!(ctx.commands().iter().any(|command| matches!(
+            command,
+            DrawCommand::Image { position, bitmap }
+                if *position == Position::new(40, 30) && bitmap.size() == (40, 20)
+        )));
+    }
+
+    #[test]
+    fn test_picture_box_auto_sizes_and_cuts_large_images() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::ComponentResized]);
+        let image = Rc::new(Bitmap::from_rgba(40, 20, vec![255; 40 * 20 * 4]).unwrap());
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(10, 10));
+        let mut picture = PictureBox::new(base)
+            .with_size_mode(PictureBoxSizeMode::Center)
+            .with_image(image.clone());
+
+        // Only the middle 10x10 of the image shows
+        let mut ctx = RenderContext::new();
+        picture.render(&mut ctx);
+        assert!(ctx.commands().iter().any(|command| matches!(
+            command,
+            DrawCommand::Image { position, bitmap }
+                if *position == Position::new(0, 0) && bitmap.size() == (10, 10)
+        )));
+
+        picture.set_size_mode(PictureBoxSizeMode::AutoSize);
+        assert_eq!(*picture.base().size(), Size::new(20, 40));
+        assert_eq!(recorder.borrow().count(EventType::ComponentResized), 1);
+        picture.set_image(None);
+        assert!(picture.image_bounds().is_none());
+    }
+}
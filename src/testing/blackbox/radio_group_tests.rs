@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod radio_group_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::radio_button::RadioButton,
+            layouts::radio_group::RadioGroup,
+            properties::{position::Position, size::Size},
+        },
+        ui::render::{draw_command::DrawCommand, render_context::RenderContext},
+        utils::traits::{interactive::Interactive, renderable::Renderable},
+        window::events::{
+            event_system::EventSystem,
+            input::Key,
+            types::{EventPayload, EventType},
+        },
+    };
+
+    // Three stacked options, 20px high each
+    fn new_group(event_system: &Rc<RefCell<EventSystem>>) -> RadioGroup {
+        let mut group = RadioGroup::new(new_base(
+            event_system,
+            Position::new(0, 0),
+            Size::new(60, 100),
+        ));
+        for (row, text) in ["Small", "Medium", "Large"].iter().enumerate() {
+            let base = new_base(
+                event_system,
+                Position::new(0, row as u16 * 20),
+                Size::new(19, 100),
+            );
+            group.add_button(RadioButton::new(base, text));
+        }
+        group
+    }
+
+    fn checked(group: &RadioGroup) -> Vec<bool> {
+        group
+            .buttons()
+            .iter()
+            .map(|button| button.checked())
+            .collect()
+    }
+
+    #[test]
+    fn test_radio_group_mouse_selection_is_exclusive() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut group = new_group(&event_system);
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+
+        assert_eq!(group.selected_index(), None);
+
+        group.handle_input(&mouse_down(5, 25));
+        group.handle_input(&mouse_up(5, 25));
+        assert_eq!(checked(&group), vec![false, true, false]);
+
+        group.handle_input(&mouse_down(5, 45));
+        group.handle_input(&mouse_up(5, 45));
+        assert_eq!(checked(&group), vec![false, false, true]);
+        assert_eq!(group.selected().unwrap().text(), "Large");
+
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Index(2))
+        );
+
+        // Clicking the checked option again changes nothing
+        let before = recorder.borrow().events.len();
+        group.handle_input(&mouse_down(5, 45));
+        group.handle_input(&mouse_up(5, 45));
+        assert_eq!(recorder.borrow().events.len(), before);
+    }
+
+    #[test]
+    fn test_radio_group_keyboard_navigation() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut group = new_group(&event_system);
+        group.select(1);
+
+        group.set_focused(true);
+        assert_eq!(group.focus_index(), Some(1));
+        assert!(group.buttons()[1].focused());
+
+        group.handle_input(&key_down(Key::Down));
+        assert_eq!(group.selected_index(), Some(2));
+        assert_eq!(group.focus_index(), Some(2));
+
+        // Arrows wrap around and skip disabled options
+        group.button_mut(0).unwrap().set_enabled(false);
+        group.handle_input(&key_down(Key::Down));
+        assert_eq!(group.selected_index(), Some(1));
+        group.handle_input(&key_down(Key::Up));
+        assert_eq!(group.selected_index(), Some(2));
+
+        group.set_focused(false);
+        assert!(group.buttons().iter().all(|button| !button.focused()));
+    }
+
+    #[test]
+    fn test_radio_group_space_checks_focused_button() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut group = new_group(&event_system);
+
+        group.set_focused(true);
+        assert_eq!(group.focus_index(), Some(0));
+        group.handle_input(&key_down(Key::Space));
+        group.handle_input(&key_up(Key::Space));
+        assert_eq!(checked(&group), vec![true, false, false]);
+    }
+
+    #[test]
+    fn test_radio_group_paints_buttons_with_their_opacity() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut group = new_group(&event_system);
+        group.button_mut(1).unwrap().base_mut().set_opacity(0.5);
+
+        let mut ctx = RenderContext::new();
+        group.render(&mut ctx);
+        let opacities: Vec<_> = ctx
+            .commands()
+            .iter()
+            .filter(|command| {
+                matches!(
+                    command,
+                    DrawCommand::PushOpacity(_) | DrawCommand::PopOpacity
+                )
+            })
+            .collect();
+        assert_eq!(
+            opacities,
+            vec![&DrawCommand::PushOpacity(0.5), &DrawCommand::PopOpacity]
+        );
+    }
+
+    #[test]
+    fn test_radio_group_serialization() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut group = new_group(&event_system);
+        group.select(2);
+
+        let json = serde_json::to_string(&group).unwrap();
+        let deserialized: RadioGroup = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.selected_index(), Some(2));
+        assert_eq!(deserialized.buttons().len(), 3);
+    }
+
+    #[test]
+    fn test_radio_group_deserialization_keeps_one_checked() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let group = new_group(&event_system);
+
+        let mut json = serde_json::to_value(&group).unwrap();
+        json["buttons"][1]["checked"] = true.into();
+        json["buttons"][2]["checked"] = true.into();
+
+        let deserialized: RadioGroup = serde_json::from_value(json).unwrap();
+        assert_eq!(checked(&deserialized), vec![false, true, false]);
+        assert_eq!(deserialized.selected_index(), Some(1));
+    }
+}
@@ -0,0 +1,266 @@
+#[cfg(test)]
+mod range_control_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc, time::Duration};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::{
+                numeric_up_down::{NumericUpDown, SpinButton},
+                progress_bar::{ProgressBar, ProgressMode},
+                slider::{Slider, TickStyle},
+            },
+            properties::{orientation::Orientation, position::Position, size::Size},
+        },
+        ui::{range_model::RangeModel, render::draw_command::DrawCommand},
+        utils::traits::interactive::Interactive,
+        window::events::{
+            event_system::EventSystem,
+            input::Key,
+            types::{EventPayload, EventType},
+        },
+    };
+
+    #[test]
+    fn test_range_model_clamps_and_snaps() {
+        let mut range = RangeModel::new(10.0, 0.0).with_step(3.0);
+        assert_eq!((range.minimum(), range.maximum()), (0.0, 10.0));
+
+        assert!(range.set_value(4.0));
+        assert_eq!(range.value(), 3.0);
+        assert!(!range.set_value(3.4));
+        // The maximum is reachable even off the step
+        assert!(range.set_value(25.0));
+        assert_eq!(range.value(), 10.0);
+        assert!(range.set_value(9.2));
+        assert_eq!(range.value(), 9.0);
+        assert!(!range.set_value(f64::NAN));
+
+        assert!(range.page(-1));
+        assert_eq!(range.value(), 0.0);
+        assert_eq!(range.value_at(0.5), 6.0);
+    }
+
+    #[test]
+    fn test_range_model_deserialization_keeps_its_invariants() {
+        let range = RangeModel::new(0.0, 10.0).with_step(3.0).with_value(6.0);
+        let json = serde_json::to_value(range).unwrap();
+        assert_eq!(
+            serde_json::from_value::<RangeModel>(json.clone()).unwrap(),
+            range
+        );
+
+        let edited = |field: &str, value: f64| {
+            let mut json = json.clone();
+            json[field] = serde_json::json!(value);
+            serde_json::from_value::<RangeModel>(json)
+        };
+        assert!(edited("minimum", 20.0).is_err());
+        assert!(edited("value", 12.0).is_err());
+        assert!(edited("value", 4.0).is_err());
+        assert!(edited("step", -1.0).is_err());
+        // The maximum stays valid off the step, as `set_value` allows it
+        assert!(edited("value", 10.0).is_ok());
+    }
+
+    #[test]
+    fn test_slider_drags_and_steps_with_keys() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        // 111 pixels wide, so the 11 pixel thumb travels 100 pixels
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(30, 111));
+        let mut slider = Slider::new(base).with_range(RangeModel::new(0.0, 100.0).with_step(5.0));
+
+        assert_eq!(slider.thumb_bounds().top_left().x(), 0);
+        assert!(slider.handle_input(&mouse_down(5, 15)));
+        slider.handle_input(&mouse_move(57, 15));
+        assert_eq!(slider.value(), 50.0);
+        assert!(slider.handle_input(&mouse_up(57, 15)));
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Number(50.0))
+        );
+
+        // Clicking the track pages toward the pointer
+        slider.handle_input(&mouse_down(100, 15));
+        slider.handle_input(&mouse_up(100, 15));
+        assert_eq!(slider.value(), 60.0);
+
+        // Keys only work with the focus
+        assert!(!slider.handle_input(&key_down(Key::End)));
+        slider.set_focused(true);
+        slider.handle_input(&key_down(Key::End));
+        assert_eq!(slider.value(), 100.0);
+        slider.handle_input(&key_down(Key::PageDown));
+        assert_eq!(slider.value(), 90.0);
+        slider.handle_input(&key_down(Key::Home));
+        assert_eq!(slider.value(), 0.0);
+        assert_eq!(recorder.borrow().count(EventType::FieldValueChanged), 5);
+    }
+
+    #[test]
+    fn test_slider_vertical_has_minimum_at_the_bottom() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(111, 30));
+        let mut slider = Slider::new(base)
+            .with_orientation(Orientation::Vertical)
+            .with_tick_style(TickStyle::Both)
+            .with_tick_frequency(25.0);
+
+        assert_eq!(slider.thumb_bounds().top_left().y(), 100);
+        assert_eq!(slider.ticks(), vec![105, 80, 55, 30, 5]);
+
+        // Above the thumb means a higher value
+        slider.handle_input(&mouse_down(15, 20));
+        slider.handle_input(&mouse_up(15, 20));
+        assert_eq!(slider.value(), 10.0);
+        slider.set_focused(true);
+        slider.handle_input(&key_down(Key::Up));
+        assert_eq!(slider.value(), 11.0);
+        slider.handle_input(&scroll(15, 50, -1));
+        assert_eq!(slider.value(), 10.0);
+
+        slider.set_tick_style(TickStyle::None);
+        assert!(slider.ticks().is_empty());
+    }
+
+    #[test]
+    fn test_progress_bar_fills_and_animates() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        // 100 pixels inside the border
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(20, 102));
+        let mut bar = ProgressBar::new(base).with_range(RangeModel::new(0.0, 50.0));
+
+        assert!(bar.fill_bounds().is_none());
+        bar.set_value(20.0);
+        let fill = bar.fill_bounds().unwrap();
+        assert_eq!(fill.top_left(), Position::new(1, 1));
+        assert_eq!(fill.width(), 40);
+        bar.perform_step();
+        assert_eq!(bar.value(), 21.0);
+        assert_eq!(recorder.borrow().count(EventType::FieldValueChanged), 2);
+        assert!(!bar.tick(Duration::from_secs(1)));
+
+        // The block slides in from the left and wraps once it has left
+        bar.set_mode(ProgressMode::Indeterminate);
+        assert!(bar.tick(Duration::from_millis(500)));
+        let fill = bar.fill_bounds().unwrap();
+        assert_eq!((fill.top_left().x(), fill.width()), (1, 60));
+        bar.tick(Duration::from_secs(1));
+        let fill = bar.fill_bounds().unwrap();
+        assert_eq!((fill.top_left().x(), fill.width()), (1, 20));
+        assert!(!bar.handle_input(&mouse_down(10, 10)));
+    }
+
+    #[test]
+    fn test_numeric_up_down_spins_and_formats_decimals() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(24, 120));
+        let mut numeric = NumericUpDown::new(base)
+            .with_range(RangeModel::new(-10.0, 10.0).with_small_change(0.5))
+            .with_decimals(2);
+        assert_eq!(numeric.text(), "-10.00");
+
+        // The buttons split the 17 pixels on the right
+        assert_eq!(
+            numeric.button_bounds(SpinButton::Down).top_left(),
+            Position::new(103, 12)
+        );
+        numeric.handle_input(&mouse_down(110, 5));
+        numeric.handle_input(&mouse_up(110, 5));
+        assert_eq!(numeric.value(), -9.5);
+        assert_eq!(numeric.text(), "-9.50");
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Number(-9.5))
+        );
+
+        numeric.set_value(1.234);
+        assert_eq!(numeric.text(), "1.23");
+        numeric.handle_input(&mouse_down(110, 18));
+        assert_eq!(numeric.value(), 0.73);
+
+        numeric.set_focused(true);
+        numeric.handle_input(&key_down(Key::Down));
+        assert_eq!(numeric.value(), 0.23);
+        numeric.handle_input(&scroll(50, 10, 3));
+        assert_eq!(numeric.value(), 1.73);
+        numeric.set_decimals(0);
+        assert_eq!(numeric.text(), "2");
+    }
+
+    #[test]
+    fn test_numeric_up_down_commits_typed_text() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(24, 120));
+        let mut numeric = NumericUpDown::new(base)
+            .with_range(RangeModel::new(-10.0, 10.0))
+            .with_decimals(1);
+        numeric.set_focused(true);
+
+        for _ in 0..5 {
+            numeric.handle_input(&key_down(Key::Backspace));
+        }
+        // Letters are swallowed, the rest waits for Enter
+        for input in type_text("7a.5") {
+            numeric.handle_input(&input);
+        }
+        assert_eq!(numeric.text(), "7.5");
+        assert_eq!(numeric.value(), -10.0);
+        numeric.handle_input(&key_down(Key::Enter));
+        assert_eq!(numeric.value(), 7.5);
+
+        // Out of range text is clamped, invalid text reverts
+        for _ in 0..3 {
+            numeric.handle_input(&key_down(Key::Backspace));
+        }
+        for input in type_text("99") {
+            numeric.handle_input(&input);
+        }
+        numeric.set_focused(false);
+        assert_eq!(numeric.text(), "10.0");
+        numeric.set_focused(true);
+        for _ in 0..4 {
+            numeric.handle_input(&key_down(Key::Backspace));
+        }
+        numeric.handle_input(&type_text("-")[0]);
+        numeric.handle_input(&key_down(Key::Enter));
+        assert_eq!(numeric.text(), "10.0");
+        assert_eq!(numeric.value(), 10.0);
+    }
+
+    #[test]
+    fn test_restored_numeric_up_down_keeps_its_editor() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(24, 120));
+        let numeric = NumericUpDown::new(base)
+            .with_range(RangeModel::new(-10.0, 10.0).with_value(2.5))
+            .with_decimals(1);
+        let json = serde_json::to_string(&numeric).unwrap();
+        let mut restored: NumericUpDown = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.text(), "2.5");
+
+        // The editor is painted over the moved field without being moved
+        restored.base_mut().set_position(Position::new(0, 100));
+        let event_system = restored.base().event_system().clone();
+        let ctx = render_quietly(&event_system, &restored);
+        assert!(ctx.commands().iter().any(|command| matches!(
+            command,
+            DrawCommand::Text { text, position, .. } if text == "2.5" && position.y() >= 100
+        )));
+
+        restored.set_focused(true);
+        for _ in 0..3 {
+            restored.handle_input(&key_down(Key::Backspace));
+        }
+        for input in type_text("7.5") {
+            restored.handle_input(&input);
+        }
+        restored.handle_input(&key_down(Key::Enter));
+        assert_eq!(restored.value(), 7.5);
+        let editor = restored.editor().unwrap().base().frame();
+        assert_eq!(editor.top_left().y(), 100);
+    }
+}
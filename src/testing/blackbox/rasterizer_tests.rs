@@ -0,0 +1,225 @@
+#[cfg(test)]
+mod rasterizer_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::button::Button,
+            layouts::container::Container,
+            properties::{
+                boundaries::Boundaries,
+                graphics::{
+                    background::{Background, BackgroundShape},
+                    blend_mode::BlendMode,
+                    color::{Color, RGBA},
+                    gradient::{Gradient, GradientType},
+                },
+                position::Position,
+                size::Size,
+            },
+            styles::button_style::ButtonStyle,
+        },
+        ui::render::{
+            draw_command::DrawCommand, rasterizer::Rasterizer, render_context::RenderContext,
+        },
+        utils::traits::component::Component,
+        window::events::event_system::EventSystem,
+    };
+
+    fn bounds(x: u16, y: u16, width: u16, height: u16) -> Boundaries {
+        Boundaries::from_rect(Position::new(x, y), Size::new(height, width))
+    }
+
+    fn solid(color: Color) -> Background {
+        Background::new_solid(color, BackgroundShape::Rectangle)
+    }
+
+    #[test]
+    fn test_source_over_with_premultiplied_alpha() {
+        let mut canvas = Rasterizer::new(4, 4);
+        let mut ctx = RenderContext::new();
+        ctx.fill_rect(bounds(0, 0, 2, 4), &solid(Color::rgb(0, 0, 255)));
+        ctx.fill_rect(bounds(0, 0, 4, 2), &solid(Color::rgba(255, 0, 0, 128)));
+        ctx.fill_rect(bounds(0, 0, 4, 2), &solid(Color::rgba(255, 0, 0, 128)));
+        canvas.paint(&ctx);
+
+        // Over nothing the color stays red and only the coverage builds up
+        assert_eq!(canvas.pixel(3, 0), Some(RGBA::new(255, 0, 0, 192)));
+        assert_eq!(canvas.pixel(0, 0), Some(RGBA::new(192, 0, 63, 255)));
+        assert_eq!(canvas.pixel(0, 3), Some(RGBA::new(0, 0, 255, 255)));
+        assert_eq!(canvas.pixel(3, 3), Some(RGBA::new(0, 0, 0, 0)));
+        assert_eq!(canvas.pixel(4, 0), None);
+
+        let bitmap = canvas.to_bitmap();
+        assert_eq!(bitmap.pixel(3, 0), Some([255, 0, 0, 192]));
+    }
+
+    #[test]
+    fn test_blend_modes() {
+        let cases = [
+            (BlendMode::Normal, RGBA::new(100, 150, 200, 255)),
+            (BlendMode::Multiply, RGBA::new(78, 59, 39, 255)),
+            (BlendMode::Screen, RGBA::new(222, 191, 211, 255)),
+            (BlendMode::Overlay, RGBA::new(188, 118, 78, 255)),
+        ];
+        for (blend_mode, expected) in cases {
+            let mut canvas = Rasterizer::new(2, 1);
+            canvas.clear(&Color::rgb(200, 100, 50));
+            let background = solid(Color::rgb(100, 150, 200)).with_blend_mode(blend_mode);
+            canvas.draw(&DrawCommand::FillRect {
+                bounds: bounds(0, 0, 1, 1),
+                background,
+            });
+            assert_eq!(canvas.pixel(0, 0), Some(expected), "{blend_mode:?}");
+            assert_eq!(canvas.pixel(1, 0), Some(RGBA::new(200, 100, 50, 255)));
+        }
+
+        // Over a transparent backdrop there is nothing to blend with
+        let mut canvas = Rasterizer::new(1, 1);
+        canvas.draw(&DrawCommand::FillRect {
+            bounds: bounds(0, 0, 1, 1),
+            background: solid(Color::rgb(100, 150, 200)).with_blend_mode(BlendMode::Multiply),
+        });
+        assert_eq!(canvas.pixel(0, 0), Some(RGBA::new(100, 150, 200, 255)));
+    }
+
+    #[test]
+    fn test_opacity_multiplies_down_the_tree() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let style = ButtonStyle {
+            normal: solid(Color::rgb(0, 0, 255)),
+            ..ButtonStyle::default()
+        };
+        let mut button = Button::new(
+            new_base(&event_system, Position::new(10, 10), Size::new(20, 40)),
+            "",
+        )
+        .with_style(style);
+        button.base_mut().set_opacity(0.5);
+        let mut window = Container::new(new_base(
+            &event_system,
+            Position::new(0, 0),
+            Size::new(40, 60),
+        ))
+        .with_child(button);
+        window.base_mut().set_opacity(0.5);
+
+        let mut ctx = RenderContext::new();
+        window.paint(&mut ctx);
+        let commands = ctx.commands();
+        assert_eq!(
+            commands[..2],
+            [DrawCommand::PushOpacity(0.5), DrawCommand::PushOpacity(0.5)]
+        );
+        assert_eq!(
+            commands[commands.len() - 2..],
+            [DrawCommand::PopOpacity, DrawCommand::PopOpacity]
+        );
+
+        let mut canvas = Rasterizer::new(60, 40);
+        canvas.clear(&Color::rgb(255, 255, 255));
+        canvas.paint(&ctx);
+        assert_eq!(canvas.pixel(30, 20), Some(RGBA::new(191, 191, 255, 255)));
+        // Commands after the pops are opaque again
+        canvas.draw(&DrawCommand::FillRect {
+            bounds: bounds(0, 0, 1, 1),
+            background: solid(Color::rgb(0, 0, 0)),
+        });
+        assert_eq!(canvas.pixel(0, 0), Some(RGBA::new(0, 0, 0, 255)));
+
+        window.base_mut().set_opacity(3.0);
+        assert_eq!(window.base().opacity(), 1.0);
+    }
+
+    #[test]
+    fn test_opacity_applies_to_each_primitive() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let square = |x: u16, color: Color| {
+            Button::new(
+                new_base(&event_system, Position::new(x, 0), Size::new(10, 20)),
+                "",
+            )
+            .with_style(ButtonStyle {
+                normal: solid(color),
+                ..ButtonStyle::default()
+            })
+        };
+        let mut window = Container::new(new_base(
+            &event_system,
+            Position::new(0, 0),
+            Size::new(10, 30),
+        ))
+        .with_child(square(0, Color::rgb(255, 0, 0)))
+        .with_child(square(10, Color::rgb(0, 0, 255)));
+        window.base_mut().set_opacity(0.5);
+
+        let mut ctx = RenderContext::new();
+        window.paint(&mut ctx);
+        let mut canvas = Rasterizer::new(30, 10);
+        canvas.clear(&Color::rgb(255, 255, 255));
+        canvas.paint(&ctx);
+        assert_eq!(canvas.pixel(5, 5), Some(RGBA::new(255, 128, 128, 255)));
+        // Where the children overlap the red shows through the blue, instead
+        // of the group fading as one
+        assert_eq!(canvas.pixel(15, 5), Some(RGBA::new(128, 64, 191, 255)));
+        assert_eq!(canvas.pixel(25, 5), Some(RGBA::new(128, 128, 255, 255)));
+    }
+
+    #[test]
+    fn test_opacity_is_normalized_when_set_and_deserialized() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut button = Button::new(
+            new_base(&event_system, Position::new(0, 0), Size::new(20, 40)),
+            "",
+        );
+        button.base_mut().set_opacity(f32::NAN);
+        assert_eq!(button.base().opacity(), 1.0);
+        button.base_mut().set_opacity(-0.0);
+        assert_eq!(button.base().opacity().to_bits(), 0.0f32.to_bits());
+
+        let mut json = serde_json::to_value(&button).unwrap();
+        json["base"]["opacity"] = serde_json::json!(-0.0);
+        let deserialized: Button = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(deserialized.base().opacity().to_bits(), 0.0f32.to_bits());
+        json["base"]["opacity"] = serde_json::json!(2.5);
+        let deserialized: Button = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.base().opacity(), 1.0);
+    }
+
+    #[test]
+    fn test_shapes_gradients_and_clips() {
+        let mut canvas = Rasterizer::new(10, 10);
+        let mut ctx = RenderContext::new();
+        ctx.push_clip(bounds(0, 0, 5, 10));
+        ctx.fill_rect(
+            bounds(0, 0, 10, 1),
+            &Background::new_gradient(
+                Gradient::new(
+                    GradientType::Linear(90),
+                    vec![(Color::rgb(0, 0, 0), 0), (Color::rgb(255, 255, 255), 100)],
+                ),
+                BackgroundShape::Rectangle,
+            ),
+        );
+        ctx.pop_clip();
+        ctx.fill_rect(
+            bounds(0, 2, 8, 8),
+            &Background::new_solid(
+                Color::rgb(0, 128, 0),
+                BackgroundShape::Circle { radius: 4.0 },
+            ),
+        );
+        canvas.paint(&ctx);
+
+        // The gradient lightens to the right until the clip cuts it
+        let grey = |x| canvas.pixel(x, 0).unwrap().r;
+        assert!((0..4).all(|x| grey(x) < grey(x + 1)));
+        assert_eq!(canvas.pixel(5, 0), Some(RGBA::new(0, 0, 0, 0)));
+
+        // Corners of the circle stay empty
+        assert_eq!(canvas.pixel(4, 6), Some(RGBA::new(0, 128, 0, 255)));
+        assert_eq!(canvas.pixel(0, 2), Some(RGBA::new(0, 0, 0, 0)));
+        assert_eq!(canvas.pixel(7, 9), Some(RGBA::new(0, 0, 0, 0)));
+    }
+}
@@ -0,0 +1,202 @@
+#[cfg(test)]
+mod scroll_viewer_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::text_box::TextBox,
+            layouts::scroll_viewer::ScrollViewer,
+            properties::{
+                boundaries::Boundaries, orientation::Orientation, overflow::Overflow,
+                position::Position, size::Size,
+            },
+        },
+        ui::render::{draw_command::DrawCommand, render_context::RenderContext},
+        utils::traits::{component::Component, interactive::Interactive, renderable::Renderable},
+        window::events::{
+            event_system::EventSystem,
+            types::{EventPayload, EventType},
+        },
+    };
+
+    // 200x100 viewer at the origin holding two 100x24 text boxes at (10, 10)
+    // and (10, 300), so the content is 324 pixels tall. Returns the viewer and
+    // the ids of both text boxes
+    fn new_scroll_viewer(event_system: &Rc<RefCell<EventSystem>>) -> (ScrollViewer, usize, usize) {
+        let first = TextBox::new(new_base(
+            event_system,
+            Position::new(10, 10),
+            Size::new(24, 100),
+        ));
+        let second = TextBox::new(new_base(
+            event_system,
+            Position::new(10, 300),
+            Size::new(24, 100),
+        ));
+        let ids = (*first.base().id(), *second.base().id());
+        let base = new_base(event_system, Position::new(0, 0), Size::new(100, 200));
+        let viewer = ScrollViewer::new(base).with_child(first).with_child(second);
+        (viewer, ids.0, ids.1)
+    }
+
+    #[test]
+    fn test_scroll_viewer_shows_scrollbars_by_overflow() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let (mut viewer, _, _) = new_scroll_viewer(&event_system);
+
+        // Auto only shows the vertical bar, which takes 17 pixels of width
+        assert_eq!(viewer.content_size(), Size::new(324, 110));
+        assert!(viewer.scroll_bar_bounds(Orientation::Horizontal).is_none());
+        let bar = viewer.scroll_bar_bounds(Orientation::Vertical).unwrap();
+        assert_eq!(bar.top_left(), Position::new(183, 0));
+        assert_eq!(viewer.viewport().width(), 183);
+        assert_eq!(viewer.max_offset(), Position::new(0, 224));
+
+        // Scroll shows both bars, with no thumb where nothing scrolls
+        viewer.set_overflow(Overflow::Scroll);
+        assert!(viewer.scroll_bar_bounds(Orientation::Horizontal).is_some());
+        assert!(viewer.thumb_bounds(Orientation::Horizontal).is_none());
+        assert_eq!(viewer.viewport().height(), 83);
+
+        // Hidden shows none and only scrolls from code
+        viewer.set_overflow(Overflow::Hidden);
+        assert!(viewer.scroll_bar_bounds(Orientation::Vertical).is_none());
+        assert!(!viewer.handle_input(&scroll(50, 50, -1)));
+        viewer.scroll_to(0, 50);
+        assert_eq!(viewer.scroll_offset(), Position::new(0, 50));
+
+        viewer.set_overflow(Overflow::Visible);
+        assert_eq!(viewer.scroll_offset(), Position::new(0, 0));
+    }
+
+    #[test]
+    fn test_scroll_viewer_scrolls_with_the_wheel() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::ComponentScrolled]);
+        let (mut viewer, _, _) = new_scroll_viewer(&event_system);
+
+        assert!(viewer.handle_input(&scroll(50, 50, -1)));
+        assert_eq!(viewer.scroll_offset(), Position::new(0, 48));
+        assert_eq!(recorder.borrow().count(EventType::ComponentScrolled), 1);
+
+        for _ in 0..10 {
+            viewer.handle_input(&scroll(50, 50, -1));
+        }
+        assert_eq!(viewer.scroll_offset(), Position::new(0, 224));
+        // Nothing left to scroll, so the wheel is left to the parent
+        assert!(!viewer.handle_input(&scroll(50, 50, -1)));
+        assert!(!viewer.handle_input(&scroll(250, 50, 1)));
+    }
+
+    #[test]
+    fn test_scroll_viewer_hit_tests_scrolled_content() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let (mut viewer, _, _) = new_scroll_viewer(&event_system);
+        viewer.scroll_to(0, 224);
+
+        // The second text box now shows at y 76..100
+        viewer.handle_input(&mouse_down(20, 80));
+        viewer.handle_input(&mouse_up(20, 80));
+        assert_eq!(viewer.content().focused_child(), Some(1));
+        for input in type_text("ok") {
+            viewer.handle_input(&input);
+        }
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Text("ok".to_string()))
+        );
+
+        // The first one was scrolled away from (20, 20)
+        viewer.handle_input(&mouse_down(20, 20));
+        viewer.handle_input(&mouse_up(20, 20));
+        assert_eq!(viewer.content().focused_child(), Some(1));
+
+        let mut ctx = RenderContext::new();
+        viewer.render(&mut ctx);
+        assert_eq!(
+            ctx.commands().first(),
+            Some(&DrawCommand::PushClip(viewer.viewport()))
+        );
+        assert!(ctx.commands().iter().any(|command| matches!(
+            command,
+            DrawCommand::FillRect { bounds, .. } if bounds.top_left() == Position::new(10, 76)
+        )));
+    }
+
+    #[test]
+    fn test_scroll_viewer_drags_thumb_and_content() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let (mut viewer, _, _) = new_scroll_viewer(&event_system);
+
+        // 30 pixel thumb on a 100 pixel track, 70 pixels of travel
+        let thumb = viewer.thumb_bounds(Orientation::Vertical).unwrap();
+        assert_eq!(
+            thumb,
+            Boundaries::from_rect(Position::new(183, 0), Size::new(30, 17))
+        );
+        viewer.handle_input(&mouse_down(190, 10));
+        viewer.handle_input(&mouse_move(190, 45));
+        viewer.handle_input(&mouse_up(190, 45));
+        assert_eq!(viewer.scroll_offset(), Position::new(0, 112));
+
+        // Pressing the track pages toward the pointer
+        viewer.handle_input(&mouse_down(190, 5));
+        viewer.handle_input(&mouse_up(190, 5));
+        assert_eq!(viewer.scroll_offset(), Position::new(0, 12));
+
+        // Dragging empty content pans it once past the threshold
+        viewer.handle_input(&mouse_down(150, 50));
+        viewer.handle_input(&mouse_move(150, 48));
+        assert_eq!(viewer.scroll_offset(), Position::new(0, 12));
+        viewer.handle_input(&mouse_move(150, 20));
+        assert_eq!(viewer.scroll_offset(), Position::new(0, 42));
+        assert!(viewer.handle_input(&mouse_up(150, 20)));
+    }
+
+    #[test]
+    fn test_scroll_viewer_scrolls_components_into_view() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let (mut viewer, first, second) = new_scroll_viewer(&event_system);
+
+        assert!(viewer.scroll_into_view(second));
+        assert_eq!(viewer.scroll_offset(), Position::new(0, 224));
+        assert!(viewer.scroll_into_view(first));
+        assert_eq!(viewer.scroll_offset(), Position::new(0, 10));
+        assert!(!viewer.scroll_into_view(usize::MAX));
+    }
+
+    #[test]
+    fn test_scroll_viewer_keeps_its_content_in_step() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::ComponentMoved]);
+        let (mut viewer, _, _) = new_scroll_viewer(&event_system);
+        viewer.content_mut().set_overflow(Overflow::Hidden);
+        viewer.base_mut().set_opacity(0.5);
+
+        // Only the viewer reports the move; the content clips to the new frame
+        viewer.set_position(Position::new(0, 50));
+        assert_eq!(recorder.borrow().count(EventType::ComponentMoved), 1);
+        assert_eq!(viewer.content().base().frame(), viewer.base().frame());
+        let mut ctx = RenderContext::new();
+        viewer.paint(&mut ctx);
+        assert!(
+            ctx.commands()
+                .contains(&DrawCommand::PushClip(viewer.base().frame()))
+        );
+
+        // The viewer's opacity is applied once, not again by the content
+        let fades = ctx
+            .commands()
+            .iter()
+            .filter(|command| matches!(command, DrawCommand::PushOpacity(_)))
+            .count();
+        assert_eq!(fades, 1);
+
+        // A frame changed through the base is picked up on input
+        viewer.base_mut().set_size(Size::new(150, 200));
+        viewer.handle_input(&mouse_move(0, 0));
+        assert_eq!(viewer.content().base().frame(), viewer.base().frame());
+    }
+}
@@ -0,0 +1,220 @@
+#[cfg(test)]
+mod tab_control_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::text_box::TextBox,
+            layouts::{container::Container, tab_control::TabControl},
+            properties::{position::Position, size::Size},
+        },
+        utils::traits::interactive::Interactive,
+        window::events::{
+            event_system::EventSystem,
+            input::{Key, Modifiers},
+            types::{EventPayload, EventType},
+        },
+    };
+
+    // 300x200 control at the origin with tabs "One", "Two" and "Three". Each
+    // page gets a text box and records when it is built
+    fn new_tab_control(
+        event_system: &Rc<RefCell<EventSystem>>,
+        built: &Rc<RefCell<Vec<String>>>,
+    ) -> TabControl {
+        let base = new_base(event_system, Position::new(0, 0), Size::new(200, 300));
+        let mut tab_control = TabControl::new(base);
+        for title in ["One", "Two", "Three"] {
+            let event_system = event_system.clone();
+            let built = built.clone();
+            tab_control.add_page(title, move |page: &mut Container| {
+                built.borrow_mut().push(title.to_string());
+                let frame = page.base().frame();
+                let position = Position::new(frame.top_left().x() + 10, frame.top_left().y() + 10);
+                page.add_child(TextBox::new(new_base(
+                    &event_system,
+                    position,
+                    Size::new(24, 100),
+                )));
+            });
+        }
+        tab_control
+    }
+
+    #[test]
+    fn test_tab_control_builds_pages_lazily() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let built = Rc::new(RefCell::new(Vec::new()));
+        let mut tab_control = new_tab_control(&event_system, &built);
+
+        assert_eq!(tab_control.selected_index(), Some(0));
+        assert_eq!(built.borrow().as_slice(), ["One"]);
+        assert!(!tab_control.is_built(1));
+        assert_eq!(tab_control.page(0).unwrap().len(), 1);
+
+        // "Three" spans x 80..136 of the strip
+        tab_control.handle_input(&mouse_down(100, 10));
+        assert_eq!(tab_control.selected_index(), Some(2));
+        assert_eq!(built.borrow().as_slice(), ["One", "Three"]);
+        assert!(!tab_control.page(0).unwrap().base().visible());
+        assert!(tab_control.page(2).unwrap().base().visible());
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Index(2))
+        );
+
+        tab_control.select(0);
+        tab_control.select(2);
+        assert_eq!(built.borrow().len(), 2);
+        assert_eq!(
+            tab_control.page(2).unwrap().base().frame().top_left().y(),
+            23
+        );
+    }
+
+    #[test]
+    fn test_tab_control_closes_tabs() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::ItemClosed]);
+        let built = Rc::new(RefCell::new(Vec::new()));
+        let mut tab_control = new_tab_control(&event_system, &built).with_closable_tabs(true);
+
+        // The close button of the first tab spans x 36..48, y 5..17
+        let close = tab_control.close_bounds(0).unwrap();
+        assert_eq!(close.top_left(), Position::new(36, 5));
+
+        // Releasing away from the button cancels
+        tab_control.handle_input(&mouse_down(42, 11));
+        tab_control.handle_input(&mouse_up(20, 11));
+        assert_eq!(tab_control.tab_count(), 3);
+
+        tab_control.handle_input(&mouse_down(42, 11));
+        tab_control.handle_input(&mouse_up(42, 11));
+        assert_eq!(tab_control.tab_count(), 2);
+        assert_eq!(tab_control.title(0), Some("Two"));
+        assert_eq!(tab_control.selected_index(), Some(0));
+        assert!(tab_control.page(0).unwrap().base().visible());
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::ItemClosed),
+            Some(EventPayload::Index(0))
+        );
+
+        tab_control.select(1);
+        tab_control.close_tab(1);
+        assert_eq!(tab_control.selected_index(), Some(0));
+        tab_control.close_tab(0);
+        assert_eq!(tab_control.selected_index(), None);
+    }
+
+    #[test]
+    fn test_tab_control_reorders_tabs_by_dragging() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::ItemMoved]);
+        let built = Rc::new(RefCell::new(Vec::new()));
+        let mut tab_control = new_tab_control(&event_system, &built);
+
+        tab_control.handle_input(&mouse_down(20, 10));
+        tab_control.handle_input(&mouse_move(22, 10));
+        assert_eq!(tab_control.title(0), Some("One"));
+        tab_control.handle_input(&mouse_move(60, 10));
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::ItemMoved),
+            Some(EventPayload::Moved(0, 1))
+        );
+        tab_control.handle_input(&mouse_move(120, 10));
+        tab_control.handle_input(&mouse_up(120, 10));
+        let titles: Vec<_> = (0..3).filter_map(|i| tab_control.title(i)).collect();
+        assert_eq!(titles, ["Two", "Three", "One"]);
+        assert_eq!(tab_control.selected_index(), Some(2));
+
+        tab_control.set_reorderable_tabs(false);
+        tab_control.handle_input(&mouse_down(20, 10));
+        tab_control.handle_input(&mouse_move(120, 10));
+        tab_control.handle_input(&mouse_up(120, 10));
+        assert_eq!(tab_control.title(0), Some("Two"));
+        assert_eq!(recorder.borrow().count(EventType::ItemMoved), 2);
+    }
+
+    #[test]
+    fn test_tab_control_switches_tabs_with_keyboard() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let built = Rc::new(RefCell::new(Vec::new()));
+        let mut tab_control = new_tab_control(&event_system, &built);
+
+        // Arrows move along the strip while it has the focus
+        tab_control.set_focused(true);
+        tab_control.handle_input(&key_down(Key::Right));
+        assert_eq!(tab_control.selected_index(), Some(1));
+        tab_control.handle_input(&key_down(Key::Left));
+        tab_control.handle_input(&key_down(Key::Left));
+        assert_eq!(tab_control.selected_index(), Some(0));
+
+        // Tab steps into the page and typing reaches its text box
+        tab_control.handle_input(&key_down(Key::Tab));
+        for input in type_text("hi") {
+            tab_control.handle_input(&input);
+        }
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Text("hi".to_string()))
+        );
+
+        // Ctrl+Tab works from inside the page and wraps around
+        tab_control.handle_input(&key_down_with(Key::Tab, Modifiers::ctrl()));
+        assert_eq!(tab_control.selected_index(), Some(1));
+        assert_eq!(tab_control.page(1).unwrap().focused_child(), Some(0));
+        let shift_ctrl = Modifiers {
+            shift: true,
+            ..Modifiers::ctrl()
+        };
+        tab_control.handle_input(&key_down_with(Key::Tab, shift_ctrl));
+        tab_control.handle_input(&key_down_with(Key::Tab, shift_ctrl));
+        assert_eq!(tab_control.selected_index(), Some(2));
+        assert_eq!(tab_control.page(1).unwrap().focused_child(), None);
+    }
+
+    #[test]
+    fn test_tab_control_routes_clicks_into_the_page() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let built = Rc::new(RefCell::new(Vec::new()));
+        let mut tab_control = new_tab_control(&event_system, &built);
+
+        // The text box of the page sits at (11, 33)
+        tab_control.handle_input(&mouse_down(20, 40));
+        tab_control.handle_input(&mouse_up(20, 40));
+        assert!(tab_control.focused());
+        assert_eq!(tab_control.page(0).unwrap().focused_child(), Some(0));
+        for input in type_text("ok") {
+            tab_control.handle_input(&input);
+        }
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Text("ok".to_string()))
+        );
+
+        // Hidden pages ignore the pointer
+        tab_control.select(1);
+        tab_control.select(0);
+        tab_control.page_mut(1).unwrap().set_visible(false);
+        assert!(!tab_control.handle_input(&mouse_down(250, 150)));
+    }
+
+    #[test]
+    fn test_tab_control_places_pages_after_moving() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let built = Rc::new(RefCell::new(Vec::new()));
+        let mut tab_control = new_tab_control(&event_system, &built);
+
+        tab_control.set_position(Position::new(0, 100));
+        tab_control.handle_input(&mouse_down(100, 110));
+        assert_eq!(tab_control.selected_index(), Some(2));
+
+        let page = tab_control.page(2).unwrap().base().frame();
+        assert_eq!(page, tab_control.page_bounds());
+        assert_eq!(tab_control.page(0).unwrap().base().frame(), page);
+    }
+}
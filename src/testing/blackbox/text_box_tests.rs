@@ -0,0 +1,287 @@
+#[cfg(test)]
+mod text_box_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::text_box::TextBox,
+            properties::{position::Position, size::Size},
+        },
+        ui::render::{draw_command::DrawCommand, render_context::RenderContext},
+        utils::traits::{interactive::Interactive, renderable::Renderable},
+        window::events::{
+            event_system::EventSystem,
+            input::{Key, Modifiers},
+            types::{EventPayload, EventType},
+        },
+    };
+
+    fn new_text_box(event_system: &Rc<RefCell<EventSystem>>) -> TextBox {
+        let base = new_base(event_system, Position::new(0, 0), Size::new(100, 200));
+        TextBox::new(base)
+    }
+
+    fn feed(text_box: &mut TextBox, inputs: &[crate::core::window::events::input::InputEvent]) {
+        for input in inputs {
+            text_box.handle_input(input);
+        }
+    }
+
+    #[test]
+    fn test_text_box_typing_and_deleting() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let mut text_box = new_text_box(&event_system);
+
+        // Typing without focus does nothing
+        feed(&mut text_box, &type_text("abc"));
+        assert_eq!(text_box.text(), "");
+
+        text_box.set_focused(true);
+        feed(&mut text_box, &type_text("hello"));
+        assert_eq!(text_box.text(), "hello");
+        assert_eq!(text_box.caret(), 5);
+
+        text_box.handle_input(&key_down(Key::Left));
+        text_box.handle_input(&key_down(Key::Backspace));
+        assert_eq!(text_box.text(), "helo");
+        text_box.handle_input(&key_down(Key::Delete));
+        assert_eq!(text_box.text(), "hel");
+
+        assert_eq!(recorder.borrow().count(EventType::FieldValueChanged), 7);
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Text("hel".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_text_box_shift_selection() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut text_box = new_text_box(&event_system).with_text("hello world");
+        text_box.set_focused(true);
+
+        text_box.handle_input(&key_down(Key::Home));
+        text_box.handle_input(&key_down_with(Key::Right, Modifiers::shift()));
+        text_box.handle_input(&key_down_with(Key::Right, Modifiers::shift()));
+        assert_eq!(text_box.selection(), Some((0, 2)));
+        assert_eq!(text_box.selected_text(), "he");
+
+        // Typing replaces the selection
+        feed(&mut text_box, &type_text("J"));
+        assert_eq!(text_box.text(), "Jllo world");
+        assert_eq!(text_box.selection(), None);
+
+        text_box.handle_input(&key_down_with(Key::Char('a'), Modifiers::ctrl()));
+        assert_eq!(text_box.selected_text(), "Jllo world");
+        text_box.handle_input(&key_down(Key::Left));
+        assert_eq!(text_box.caret(), 0);
+        assert_eq!(text_box.selection(), None);
+    }
+
+    #[test]
+    fn test_text_box_word_navigation() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut text_box = new_text_box(&event_system).with_text("one two, three");
+        text_box.set_focused(true);
+
+        let ctrl = Modifiers::ctrl();
+        text_box.handle_input(&key_down_with(Key::Left, ctrl));
+        assert_eq!(text_box.caret(), 9);
+        text_box.handle_input(&key_down_with(Key::Left, ctrl));
+        assert_eq!(text_box.caret(), 7);
+        text_box.handle_input(&key_down_with(Key::Left, ctrl));
+        assert_eq!(text_box.caret(), 4);
+        text_box.handle_input(&key_down_with(Key::Right, ctrl));
+        assert_eq!(text_box.caret(), 7);
+
+        text_box.handle_input(&key_down(Key::End));
+        text_box.handle_input(&key_down_with(Key::Backspace, ctrl));
+        assert_eq!(text_box.text(), "one two, ");
+    }
+
+    #[test]
+    fn test_text_box_mouse_selection() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut text_box = new_text_box(&event_system).with_text("abcdef");
+        let char_width = text_box.style().typography.char_width();
+
+        text_box.handle_input(&mouse_down(char_width, 5));
+        assert!(text_box.focused());
+        assert_eq!(text_box.caret(), 1);
+        text_box.handle_input(&mouse_move(char_width * 4, 5));
+        text_box.handle_input(&mouse_up(char_width * 4, 5));
+        assert_eq!(text_box.selected_text(), "bcd");
+
+        // Moving after release no longer extends the selection
+        text_box.handle_input(&mouse_move(char_width * 6, 5));
+        assert_eq!(text_box.selected_text(), "bcd");
+    }
+
+    #[test]
+    fn test_text_box_multiline() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut text_box = new_text_box(&event_system).with_multiline(true);
+        text_box.set_focused(true);
+
+        feed(&mut text_box, &type_text("first"));
+        text_box.handle_input(&key_down(Key::Enter));
+        feed(&mut text_box, &type_text("second"));
+        assert_eq!(text_box.text(), "first\nsecond");
+
+        text_box.handle_input(&key_down(Key::Up));
+        assert_eq!(text_box.caret(), 5);
+        text_box.handle_input(&key_down(Key::Home));
+        assert_eq!(text_box.caret(), 0);
+        text_box.handle_input(&key_down(Key::Down));
+        assert_eq!(text_box.caret(), 6);
+
+        // Single line boxes ignore Enter and drop pasted line breaks
+        let mut single = new_text_box(&event_system);
+        single.set_focused(true);
+        assert!(!single.handle_input(&key_down(Key::Enter)));
+        single.insert_text("one\ntwo");
+        assert_eq!(single.text(), "one");
+    }
+
+    #[test]
+    fn test_text_box_max_length_and_read_only() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut text_box = new_text_box(&event_system).with_max_length(Some(4));
+        text_box.set_focused(true);
+
+        feed(&mut text_box, &type_text("abcdef"));
+        assert_eq!(text_box.text(), "abcd");
+        text_box.select(1, 3);
+        text_box.insert_text("XYZ");
+        assert_eq!(text_box.text(), "aXYd");
+
+        text_box.set_read_only(true);
+        feed(&mut text_box, &type_text("q"));
+        text_box.handle_input(&key_down(Key::Backspace));
+        assert_eq!(text_box.text(), "aXYd");
+
+        // Navigation still works in read-only mode
+        text_box.handle_input(&key_down(Key::Home));
+        assert_eq!(text_box.caret(), 0);
+    }
+
+    #[test]
+    fn test_text_box_max_length_truncates_text() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+
+        let built = new_text_box(&event_system)
+            .with_max_length(Some(3))
+            .with_text("añoranza");
+        assert_eq!(built.text(), "año");
+        assert_eq!(built.caret(), 3);
+
+        let mut text_box = new_text_box(&event_system).with_max_length(Some(5));
+        text_box.set_text("señales");
+        assert_eq!(text_box.text(), "señal");
+        assert_eq!(text_box.caret(), 5);
+
+        text_box.set_max_length(Some(2));
+        assert_eq!(text_box.text(), "se");
+        assert_eq!(text_box.caret(), 2);
+        assert_eq!(recorder.borrow().count(EventType::FieldValueChanged), 2);
+
+        // Raising the limit keeps the text as it is
+        text_box.set_max_length(None);
+        assert_eq!(text_box.text(), "se");
+        assert_eq!(recorder.borrow().count(EventType::FieldValueChanged), 2);
+    }
+
+    #[test]
+    fn test_text_box_builders_and_deserialization_keep_the_rules() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+
+        // The text comes out the same whatever order the builders run in
+        let text_first = new_text_box(&event_system)
+            .with_text("one\ntwo")
+            .with_multiline(true)
+            .with_max_length(Some(6));
+        let text_last = new_text_box(&event_system)
+            .with_max_length(Some(6))
+            .with_multiline(true)
+            .with_text("one\ntwo");
+        assert_eq!(text_first.text(), "one\ntw");
+        assert_eq!(text_last.text(), text_first.text());
+
+        // Hand-edited files cannot break the limit or the single line
+        let mut json = serde_json::to_value(&text_first).unwrap();
+        json["multiline"] = serde_json::json!(false);
+        json["text"] = serde_json::json!("first line\nsecond line");
+        let restored: TextBox = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.text(), "first ");
+        assert_eq!(restored.caret(), 6);
+    }
+
+    #[test]
+    fn test_text_box_password_and_placeholder() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut text_box = new_text_box(&event_system)
+            .with_placeholder("Password")
+            .with_password_char(Some('*'));
+
+        let mut ctx = RenderContext::new();
+        text_box.render(&mut ctx);
+        assert!(ctx.commands().iter().any(
+            |command| matches!(command, DrawCommand::Text { text, .. } if text == "Password")
+        ));
+
+        text_box.set_text("secret");
+        assert_eq!(text_box.display_text(), "******");
+
+        ctx.clear();
+        text_box.render(&mut ctx);
+        let texts: Vec<&String> = ctx
+            .commands()
+            .iter()
+            .filter_map(|command| match command {
+                DrawCommand::Text { text, .. } => Some(text),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(texts, vec!["******"]);
+    }
+
+    #[test]
+    fn test_text_box_focus_events() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(
+            &event_system,
+            &[
+                EventType::FieldFocused,
+                EventType::FieldValidating,
+                EventType::FieldFocusedLost,
+            ],
+        );
+        let mut text_box = new_text_box(&event_system).with_text("value");
+
+        text_box.set_focused(true);
+        text_box.set_focused(true);
+        text_box.set_focused(false);
+
+        let events: Vec<EventType> = recorder
+            .borrow()
+            .events
+            .iter()
+            .map(|(event, _)| *event)
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                EventType::FieldFocused,
+                EventType::FieldValidating,
+                EventType::FieldFocusedLost
+            ]
+        );
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValidating),
+            Some(EventPayload::Text("value".to_string()))
+        );
+    }
+}
@@ -0,0 +1,323 @@
+#[cfg(test)]
+mod tool_strip_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::{
+                button::Button,
+                menu_item::MenuItem,
+                progress_bar::ProgressBar,
+                status_strip::StatusStrip,
+                tool_strip::{ToolStrip, ToolStripTarget},
+                tool_strip_item::ToolStripItem,
+            },
+            layouts::container::Container,
+            properties::{
+                boundaries::Boundaries,
+                orientation::Orientation,
+                position::Position,
+                size::{Size, SizePolicy},
+            },
+        },
+        ui::{
+            elements::theme::Theme,
+            render::{draw_command::DrawCommand, render_context::RenderContext},
+        },
+        utils::traits::{interactive::Interactive, renderable::Renderable},
+        window::events::{
+            event_system::EventSystem,
+            types::{EventPayload, EventType},
+        },
+    };
+
+    fn bounds(x: u16, y: u16, width: u16, height: u16) -> Boundaries {
+        Boundaries::from_rect(Position::new(x, y), Size::new(height, width))
+    }
+
+    fn click(strip: &mut ToolStrip, x: u16, y: u16) {
+        strip.handle_input(&mouse_move(x, y));
+        strip.handle_input(&mouse_down(x, y));
+        strip.handle_input(&mouse_up(x, y));
+    }
+
+    /// Barra de 300x25 con un botón, un interruptor y un separador
+    fn editing_strip(event_system: &Rc<RefCell<EventSystem>>) -> ToolStrip {
+        ToolStrip::new(new_base(
+            event_system,
+            Position::new(0, 0),
+            Size::new(25, 300),
+        ))
+        .with_item(ToolStripItem::button("New").with_command(1))
+        .with_item(ToolStripItem::toggle("Bold").with_command(2))
+        .with_item(ToolStripItem::separator())
+    }
+
+    #[test]
+    fn test_container_flows_children_by_policy() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let child = |width| {
+            Button::new(
+                new_base(&event_system, Position::new(0, 0), Size::new(20, width)),
+                "",
+            )
+        };
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(40, 300));
+        let mut container = Container::new(base)
+            .with_flow(Orientation::Horizontal)
+            .with_spacing(10);
+        container.add_child(child(50));
+        container.add_child_with_policy(child(10), SizePolicy::Fill);
+        container.add_child_with_policy(child(60), SizePolicy::Fit);
+        container.layout();
+
+        let frames: Vec<_> = container
+            .children()
+            .iter()
+            .map(|child| child.base().frame())
+            .collect();
+        // Fixed is centered, Fit stretches across, Fill takes what is left
+        assert_eq!(frames[0], bounds(0, 10, 50, 20));
+        assert_eq!(frames[1], bounds(60, 0, 170, 40));
+        assert_eq!(frames[2], bounds(240, 0, 60, 40));
+
+        container.remove_child(0);
+        container.layout();
+        assert_eq!(
+            container.children()[0].base().frame(),
+            bounds(0, 0, 230, 40)
+        );
+    }
+
+    #[test]
+    fn test_status_strip_springs_and_progress() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let bar = ProgressBar::new(new_base(
+            &event_system,
+            Position::new(0, 0),
+            Size::new(10, 100),
+        ));
+        let mut status = StatusStrip::new(new_base(
+            &event_system,
+            Position::new(0, 0),
+            Size::new(10, 10),
+        ))
+        .with_label("Ready")
+        .with_spring("")
+        .with_progress(bar);
+        status.dock(bounds(0, 0, 300, 200));
+
+        // Below the top border, left of the sizing grip
+        assert_eq!(status.base().frame(), bounds(0, 178, 300, 22));
+        assert_eq!(status.panel_bounds(0), Some(bounds(0, 179, 48, 21)));
+        assert_eq!(status.panel_bounds(1), Some(bounds(50, 179, 136, 21)));
+        assert_eq!(status.grip_bounds(), Some(bounds(288, 188, 12, 12)));
+        assert_eq!(
+            status.progress(2).unwrap().base().frame(),
+            bounds(188, 181, 100, 16)
+        );
+
+        // Longer text shrinks the spring, the progress cell stays put
+        status.set_text(0, "Loading");
+        assert_eq!(status.panel_bounds(1), Some(bounds(66, 179, 120, 21)));
+        assert_eq!(status.panel_bounds(2), Some(bounds(188, 181, 100, 16)));
+
+        status.set_value(2, 40.0);
+        assert_eq!(status.progress(2).unwrap().value(), 40.0);
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Number(40.0))
+        );
+
+        let mut theme = Theme::default();
+        theme.status_strip.height = 30;
+        status.apply_theme(&theme);
+        status.dock(bounds(0, 0, 300, 200));
+        assert_eq!(status.base().frame(), bounds(0, 170, 300, 30));
+    }
+
+    #[test]
+    fn test_tool_strip_buttons_and_toggles() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::CommandInvoked]);
+        let mut strip = editing_strip(&event_system);
+
+        assert_eq!(strip.item_bounds(0), Some(bounds(2, 0, 32, 25)));
+        assert_eq!(strip.item_bounds(1), Some(bounds(34, 0, 40, 25)));
+        assert_eq!(strip.item_bounds(2), Some(bounds(74, 0, 6, 25)));
+        assert_eq!(strip.overflow_bounds(), None);
+
+        click(&mut strip, 10, 10);
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::CommandInvoked),
+            Some(EventPayload::Index(1))
+        );
+
+        click(&mut strip, 50, 10);
+        assert!(strip.item(1).unwrap().checked());
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::CommandInvoked),
+            Some(EventPayload::Index(2))
+        );
+
+        // Releasing away from the pressed item cancels the click
+        strip.handle_input(&mouse_down(50, 10));
+        strip.handle_input(&mouse_up(200, 10));
+        assert!(strip.item(1).unwrap().checked());
+        assert_eq!(recorder.borrow().count(EventType::CommandInvoked), 2);
+
+        strip.handle_input(&mouse_move(200, 10));
+        let mut ctx = RenderContext::new();
+        strip.render(&mut ctx);
+        let checked = strip.style().checked_background.clone();
+        assert!(ctx.commands().iter().any(|command| matches!(
+            command,
+            DrawCommand::FillRect { bounds: at, background }
+                if *at == bounds(34, 0, 40, 25) && *background == checked
+        )));
+
+        assert!(strip.invoke(2));
+        assert!(!strip.item(1).unwrap().checked());
+        strip.set_item_enabled(1, false);
+        click(&mut strip, 10, 10);
+        assert_eq!(recorder.borrow().count(EventType::CommandInvoked), 3);
+    }
+
+    #[test]
+    fn test_tool_strip_drop_down_button() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::CommandInvoked]);
+        let zoom = MenuItem::new("")
+            .with_item(MenuItem::new("50%").with_command(10))
+            .with_item(MenuItem::new("100%").with_command(11).with_checkable(true));
+        let mut strip = ToolStrip::new(new_base(
+            &event_system,
+            Position::new(0, 0),
+            Size::new(25, 300),
+        ))
+        .with_item(ToolStripItem::drop_down("Zoom", zoom));
+
+        // Room for the arrow after the text
+        assert_eq!(strip.item_bounds(0), Some(bounds(2, 0, 51, 25)));
+        strip.handle_input(&mouse_down(10, 10));
+        assert_eq!(strip.open_target(), Some(ToolStripTarget::Item(0)));
+        assert_eq!(strip.popup_bounds(0).unwrap().top_left().y(), 25);
+
+        // Pressing the button again closes the menu
+        strip.handle_input(&mouse_up(10, 10));
+        strip.handle_input(&mouse_down(10, 10));
+        assert!(!strip.is_open());
+
+        strip.handle_input(&mouse_down(10, 10));
+        let entry = strip.menu_item_bounds(0, 1).unwrap().top_left();
+        strip.handle_input(&mouse_move(entry.x() + 5, entry.y() + 5));
+        strip.handle_input(&mouse_down(entry.x() + 5, entry.y() + 5));
+        strip.handle_input(&mouse_up(entry.x() + 5, entry.y() + 5));
+        assert!(!strip.is_open());
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::CommandInvoked),
+            Some(EventPayload::Index(11))
+        );
+        assert!(strip.item(0).unwrap().menu().unwrap().items()[1].checked());
+    }
+
+    #[test]
+    fn test_tool_strip_overflows_into_chevron_menu() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::CommandInvoked]);
+        let mut strip = ToolStrip::new(new_base(
+            &event_system,
+            Position::new(0, 0),
+            Size::new(25, 100),
+        ))
+        .with_item(ToolStripItem::button("One").with_command(1))
+        .with_item(ToolStripItem::button("Two").with_command(2))
+        .with_item(ToolStripItem::separator())
+        .with_item(ToolStripItem::button("Three").with_command(3));
+
+        // The separator would end the row, so it overflows too
+        assert_eq!(strip.item_bounds(1), Some(bounds(34, 0, 32, 25)));
+        assert_eq!(strip.item_bounds(2), None);
+        assert_eq!(strip.overflow_items().len(), 2);
+        assert_eq!(strip.overflow_bounds(), Some(bounds(86, 0, 12, 25)));
+
+        strip.handle_input(&mouse_down(90, 10));
+        assert_eq!(strip.open_target(), Some(ToolStripTarget::Overflow));
+        let entry = strip.menu_item_bounds(0, 1).unwrap().top_left();
+        strip.handle_input(&mouse_down(entry.x() + 5, entry.y() + 5));
+        strip.handle_input(&mouse_up(entry.x() + 5, entry.y() + 5));
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::CommandInvoked),
+            Some(EventPayload::Index(3))
+        );
+
+        strip.base_mut().set_size(Size::new(25, 200));
+        strip.refresh();
+        assert_eq!(strip.overflow_bounds(), None);
+        assert_eq!(strip.item_bounds(3), Some(bounds(72, 0, 48, 25)));
+    }
+
+    #[test]
+    fn test_strips_lay_out_again_after_resize_and_deserialization() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::CommandInvoked]);
+        let mut strip = ToolStrip::new(new_base(
+            &event_system,
+            Position::new(0, 0),
+            Size::new(25, 100),
+        ))
+        .with_item(ToolStripItem::button("One").with_command(1))
+        .with_item(ToolStripItem::button("Two").with_command(2))
+        .with_item(ToolStripItem::button("Three").with_command(3));
+
+        // "Three" only fits once the strip is wider, and is painted there
+        // before any input lays the strip out again
+        strip.base_mut().set_size(Size::new(25, 200));
+        let ctx = render_quietly(&event_system, &strip);
+        assert!(ctx.commands().iter().any(|command| matches!(
+            command,
+            DrawCommand::Text { text, .. } if text == "Three"
+        )));
+        click(&mut strip, 90, 10);
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::CommandInvoked),
+            Some(EventPayload::Index(3))
+        );
+
+        let bar = ProgressBar::new(new_base(
+            &event_system,
+            Position::new(0, 0),
+            Size::new(10, 100),
+        ));
+        let mut status = StatusStrip::new(new_base(
+            &event_system,
+            Position::new(0, 0),
+            Size::new(22, 300),
+        ))
+        .with_label("Ready")
+        .with_progress(bar);
+        let json = serde_json::to_string(&status).unwrap();
+        let deserialized: StatusStrip = serde_json::from_str(&json).unwrap();
+        let mut ctx = RenderContext::new();
+        deserialized.render(&mut ctx);
+        assert!(ctx.commands().iter().any(|command| matches!(
+            command,
+            DrawCommand::Text { text, .. } if text == "Ready"
+        )));
+
+        // The label and the progress cell follow the strip without moving
+        // anything while painting
+        status.base_mut().set_position(Position::new(0, 100));
+        let ctx = render_quietly(&event_system, &status);
+        assert!(ctx.commands().iter().any(|command| matches!(
+            command,
+            DrawCommand::Text { text, position, .. } if text == "Ready" && position.y() > 100
+        )));
+        assert!(ctx.commands().iter().all(|command| match command {
+            DrawCommand::FillRect { bounds, .. } => bounds.top_left().y() >= 100,
+            _ => true,
+        }));
+    }
+}
@@ -0,0 +1,221 @@
+#[cfg(test)]
+mod tooltip_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc, time::Duration};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::{button::Button, icon::Icon, text_box::TextBox},
+            layouts::container::Container,
+            properties::{boundaries::Boundaries, position::Position, size::Size},
+        },
+        ui::{
+            render::{draw_command::DrawCommand, render_context::RenderContext},
+            tooltip_manager::{TooltipContent, TooltipManager},
+        },
+        utils::traits::{component::Component, interactive::Interactive},
+        window::events::{
+            event_system::EventSystem,
+            input::{InputEvent, Key},
+            types::EventType,
+        },
+    };
+
+    struct Fixture {
+        manager: Rc<RefCell<TooltipManager>>,
+        buttons: Vec<Button>,
+    }
+
+    impl Fixture {
+        /// Dos botones con tooltip en una ventana de 400x300
+        fn new() -> Self {
+            let event_system = Rc::new(RefCell::new(EventSystem::default()));
+            let manager = Rc::new(RefCell::new(TooltipManager::new()));
+            for event in [EventType::HoverEntered, EventType::HoverExited] {
+                event_system
+                    .borrow_mut()
+                    .subscribe(event, Rc::downgrade(&manager));
+            }
+            manager.borrow_mut().set_viewport(Boundaries::from_rect(
+                Position::new(0, 0),
+                Size::new(300, 400),
+            ));
+
+            let buttons: Vec<Button> = [(10, 10), (10, 270)]
+                .into_iter()
+                .map(|(x, y)| {
+                    let base = new_base(&event_system, Position::new(x, y), Size::new(20, 100));
+                    Button::new(base, "OK")
+                })
+                .collect();
+            for (i, button) in buttons.iter().enumerate() {
+                manager.borrow_mut().set_tooltip(
+                    *button.base().id(),
+                    TooltipContent::text(&format!("Button {i}")),
+                );
+            }
+            Self { manager, buttons }
+        }
+
+        fn input(&mut self, input: InputEvent) {
+            self.manager.borrow_mut().handle_input(&input);
+            for button in &mut self.buttons {
+                button.handle_input(&input);
+            }
+        }
+
+        fn tick(&self, millis: u64) -> bool {
+            self.manager
+                .borrow_mut()
+                .tick(Duration::from_millis(millis))
+        }
+
+        fn id(&self, index: usize) -> usize {
+            *self.buttons[index].base().id()
+        }
+
+        fn visible(&self) -> Option<usize> {
+            self.manager.borrow().visible_tooltip()
+        }
+    }
+
+    #[test]
+    fn test_tooltip_appears_after_delay_below_pointer() {
+        let mut fixture = Fixture::new();
+        fixture.input(mouse_move(20, 15));
+        assert!(!fixture.tick(499));
+        assert_eq!(fixture.visible(), None);
+        assert!(fixture.tick(1));
+        assert_eq!(fixture.visible(), Some(fixture.id(0)));
+
+        // "Button 0" is 8 characters plus padding and border
+        let bounds = fixture.manager.borrow().bounds().unwrap();
+        assert_eq!(bounds.top_left(), Position::new(20, 35));
+        assert_eq!(bounds.size(), Size::new(18 + 10, 64 + 10));
+
+        let mut ctx = RenderContext::new();
+        fixture.manager.borrow().render(&mut ctx);
+        assert_eq!(ctx.layer_count(), 2);
+        assert!(ctx.layer(1).iter().any(|command| matches!(
+            command,
+            DrawCommand::Text { text, .. } if text == "Button 0"
+        )));
+    }
+
+    #[test]
+    fn test_tooltip_avoids_window_edges() {
+        let mut fixture = Fixture::new();
+        // Shifted left to stay inside the right edge
+        fixture.input(mouse_move(360, 15));
+        fixture.manager.borrow_mut().show(fixture.id(0));
+        let bounds = fixture.manager.borrow().bounds().unwrap();
+        assert_eq!(bounds.top_left().x(), 400 - 74);
+
+        // No room below the cursor near the bottom edge, so it goes above
+        fixture.manager.borrow_mut().hide();
+        fixture.input(mouse_move(50, 285));
+        fixture.tick(500);
+        let bounds = fixture.manager.borrow().bounds().unwrap();
+        assert_eq!(fixture.visible(), Some(fixture.id(1)));
+        assert_eq!(bounds.bottom_right().y(), 285);
+    }
+
+    #[test]
+    fn test_tooltip_hides_on_exit_and_key_press() {
+        let mut fixture = Fixture::new();
+        fixture.input(mouse_move(20, 15));
+        fixture.tick(500);
+        fixture.input(mouse_move(200, 100));
+        assert_eq!(fixture.visible(), None);
+
+        fixture.input(mouse_move(20, 15));
+        fixture.tick(500);
+        assert!(fixture.visible().is_some());
+        fixture.input(key_down(Key::Char('a')));
+        assert_eq!(fixture.visible(), None);
+
+        // It stays down while the pointer remains over the component
+        fixture.input(mouse_move(25, 15));
+        fixture.tick(5000);
+        assert_eq!(fixture.visible(), None);
+    }
+
+    #[test]
+    fn test_tooltip_reshows_quickly_and_pops_down() {
+        let mut fixture = Fixture::new();
+        fixture.input(mouse_move(20, 15));
+        fixture.tick(500);
+        fixture.input(mouse_move(20, 275));
+        assert_eq!(fixture.visible(), None);
+        fixture.tick(100);
+        assert_eq!(fixture.visible(), Some(fixture.id(1)));
+
+        assert!(!fixture.tick(4999));
+        assert!(fixture.tick(1));
+        assert_eq!(fixture.visible(), None);
+    }
+
+    #[test]
+    fn test_tooltip_shows_for_components_inside_a_container() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let manager = Rc::new(RefCell::new(TooltipManager::new()));
+        for event in [EventType::HoverEntered, EventType::HoverExited] {
+            event_system
+                .borrow_mut()
+                .subscribe(event, Rc::downgrade(&manager));
+        }
+
+        // A text box reports no hover of its own, its container does it
+        let text_box = TextBox::new(new_base(
+            &event_system,
+            Position::new(10, 10),
+            Size::new(24, 100),
+        ));
+        let button = Button::new(
+            new_base(&event_system, Position::new(10, 50), Size::new(20, 100)),
+            "OK",
+        );
+        let ids = (*text_box.base().id(), *button.base().id());
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(300, 400));
+        let mut container = Container::new(base).with_child(text_box).with_child(button);
+        manager
+            .borrow_mut()
+            .set_tooltip(ids.0, TooltipContent::text("Name"));
+        manager
+            .borrow_mut()
+            .set_tooltip(ids.1, TooltipContent::text("Confirm"));
+
+        let mut input = |input: InputEvent| {
+            manager.borrow_mut().handle_input(&input);
+            container.handle_input(&input);
+        };
+        input(mouse_move(20, 20));
+        manager.borrow_mut().tick(Duration::from_millis(500));
+        assert_eq!(manager.borrow().visible_tooltip(), Some(ids.0));
+
+        input(mouse_move(200, 200));
+        assert_eq!(manager.borrow().visible_tooltip(), None);
+
+        // The button reports its hover as well as the container, and the
+        // tooltip comes with the shorter delay right after the previous one
+        input(mouse_move(20, 55));
+        assert!(!manager.borrow_mut().tick(Duration::from_millis(99)));
+        assert!(manager.borrow_mut().tick(Duration::from_millis(1)));
+        assert_eq!(manager.borrow().visible_tooltip(), Some(ids.1));
+    }
+
+    #[test]
+    fn test_rich_tooltip_measures_title_icon_and_lines() {
+        let manager = TooltipManager::new();
+        let content = TooltipContent::rich(
+            "Save",
+            "Saves the file\nCtrl+S",
+            Some(Icon::new("save.ico", (16, 16))),
+        );
+        // Title 18 high, 4 of spacing and two lines of 18; 16 + 4 for the icon
+        assert_eq!(
+            manager.measure(&content),
+            Size::new(18 + 4 + 36 + 10, 16 + 4 + 14 * 8 + 10)
+        );
+    }
+}
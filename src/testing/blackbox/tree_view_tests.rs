@@ -0,0 +1,259 @@
+#[cfg(test)]
+mod tree_view_blackbox_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::test_utils::*;
+    use crate::core::{
+        components::{
+            elements::tree_view::{NodeId, TreeNode, TreeView},
+            properties::{
+                check_state::CheckState, position::Position, selection_mode::SelectionMode,
+                size::Size,
+            },
+        },
+        ui::render::draw_command::DrawCommand,
+        utils::traits::interactive::Interactive,
+        window::events::{
+            event_system::EventSystem,
+            input::{Key, Modifiers},
+            types::{EventPayload, EventType},
+        },
+    };
+
+    // 200x100 tree at the origin with rows of 18px: Root > [A > [A1], B]
+    fn new_tree_view(event_system: &Rc<RefCell<EventSystem>>) -> TreeView {
+        let base = new_base(event_system, Position::new(0, 0), Size::new(100, 200));
+        TreeView::new(base).with_node(
+            TreeNode::new("Root")
+                .with_child(TreeNode::new("A").with_child(TreeNode::new("A1")))
+                .with_child(TreeNode::new("B")),
+        )
+    }
+
+    // (Root, A, A1, B)
+    fn nodes(tree_view: &TreeView) -> (NodeId, NodeId, NodeId, NodeId) {
+        let root = tree_view.roots()[0];
+        let [a, b] = tree_view.children(root)[..] else {
+            unreachable!()
+        };
+        (root, a, tree_view.children(a)[0], b)
+    }
+
+    // Middle of the expander of a row at the given depth
+    fn expander_at(row: u16, depth: u16) -> (u16, u16) {
+        (1 + depth * 19 + 9, 1 + row * 18 + 9)
+    }
+
+    #[test]
+    fn test_tree_view_expands_and_collapses_with_mouse() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(
+            &event_system,
+            &[EventType::ItemExpanded, EventType::ItemCollapsed],
+        );
+        let mut tree_view = new_tree_view(&event_system);
+        let (root, a, _, b) = nodes(&tree_view);
+
+        assert_eq!(tree_view.visible_nodes(), &[root]);
+        assert_eq!(tree_view.visible_rows()[0].expander(), Some(false));
+
+        let (x, y) = expander_at(0, 0);
+        tree_view.handle_input(&mouse_down(x, y));
+        assert!(tree_view.is_expanded(root));
+        assert_eq!(tree_view.visible_nodes(), &[root, a, b]);
+        assert_eq!(tree_view.visible_rows()[1].depth(), 1);
+        assert!(tree_view.selected_nodes().is_empty());
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::ItemExpanded),
+            Some(EventPayload::Index(root.index()))
+        );
+
+        tree_view.handle_input(&mouse_down(x, y));
+        assert_eq!(tree_view.visible_nodes().len(), 1);
+        assert_eq!(recorder.borrow().count(EventType::ItemCollapsed), 1);
+    }
+
+    #[test]
+    fn test_tree_view_arrows_expand_collapse_and_move() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(
+            &event_system,
+            &[EventType::ItemExpanded, EventType::ItemCollapsed],
+        );
+        let mut tree_view = new_tree_view(&event_system);
+        let (root, a, a1, _) = nodes(&tree_view);
+
+        tree_view.set_focused(true);
+        tree_view.handle_input(&key_down(Key::Right));
+        assert!(tree_view.is_expanded(root));
+        tree_view.handle_input(&key_down(Key::Right));
+        assert_eq!(tree_view.selected_node(), Some(a));
+        tree_view.handle_input(&key_down(Key::Right));
+        tree_view.handle_input(&key_down(Key::Right));
+        assert_eq!(tree_view.selected_node(), Some(a1));
+
+        tree_view.handle_input(&key_down(Key::Left));
+        assert_eq!(tree_view.selected_node(), Some(a));
+        tree_view.handle_input(&key_down(Key::Left));
+        assert!(!tree_view.is_expanded(a));
+        tree_view.handle_input(&key_down(Key::Left));
+        tree_view.handle_input(&key_down(Key::Left));
+        assert_eq!(tree_view.selected_node(), Some(root));
+        assert!(!tree_view.is_expanded(root));
+
+        let recorder = recorder.borrow();
+        assert_eq!(recorder.count(EventType::ItemExpanded), 2);
+        assert_eq!(recorder.count(EventType::ItemCollapsed), 2);
+    }
+
+    #[test]
+    fn test_tree_view_collapse_moves_selection_to_parent() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let mut tree_view = new_tree_view(&event_system);
+        let (root, _, a1, _) = nodes(&tree_view);
+
+        tree_view.select_node(a1);
+        assert_eq!(tree_view.visible_nodes().len(), 4);
+        assert_eq!(tree_view.selected_nodes(), vec![a1]);
+
+        tree_view.collapse(root);
+        assert_eq!(tree_view.selected_nodes(), vec![root]);
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Indices(vec![root.index()]))
+        );
+
+        tree_view.remove_node(root);
+        assert!(tree_view.visible_nodes().is_empty());
+        assert!(tree_view.selected_nodes().is_empty());
+    }
+
+    #[test]
+    fn test_tree_view_loads_lazy_children_once() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::ItemExpanded]);
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let loader_calls = calls.clone();
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(100, 200));
+        let mut tree_view = TreeView::new(base)
+            .with_node(TreeNode::new("Drive").with_lazy_children())
+            .with_node(TreeNode::new("Empty").with_lazy_children())
+            .with_loader(move |_: NodeId, text: &str| {
+                loader_calls.borrow_mut().push(text.to_string());
+                if text == "Drive" {
+                    vec![TreeNode::new("Docs"), TreeNode::new("Music")]
+                } else {
+                    Vec::new()
+                }
+            });
+        let [drive, empty] = tree_view.roots()[..] else {
+            unreachable!()
+        };
+
+        assert_eq!(tree_view.visible_rows()[0].expander(), Some(false));
+        assert!(calls.borrow().is_empty());
+
+        tree_view.expand(drive);
+        assert_eq!(tree_view.children(drive).len(), 2);
+        assert_eq!(tree_view.visible_rows()[2].text(), "Music");
+        tree_view.collapse(drive);
+        tree_view.expand(drive);
+        assert_eq!(calls.borrow().as_slice(), ["Drive"]);
+
+        // A node whose loader finds nothing loses its expander
+        tree_view.expand(empty);
+        assert!(!tree_view.is_expanded(empty));
+        assert_eq!(tree_view.visible_rows()[3].expander(), None);
+        assert_eq!(recorder.borrow().count(EventType::ItemExpanded), 2);
+    }
+
+    #[test]
+    fn test_tree_view_check_boxes_cascade() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::ItemChecked]);
+        let mut tree_view = new_tree_view(&event_system).with_check_boxes(true);
+        let (root, a, a1, b) = nodes(&tree_view);
+        tree_view.expand(root);
+
+        // The check box sits right after the expander column of the row
+        tree_view.handle_input(&mouse_down(1 + 19 + 19 + 5, 1 + 18 + 9));
+        assert_eq!(tree_view.check_state(a), Some(CheckState::Checked));
+        assert_eq!(tree_view.check_state(a1), Some(CheckState::Checked));
+        assert_eq!(tree_view.check_state(root), Some(CheckState::Indeterminate));
+        assert!(tree_view.selected_nodes().is_empty());
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::ItemChecked),
+            Some(EventPayload::ItemCheckState(a.index(), CheckState::Checked))
+        );
+
+        tree_view.set_focused(true);
+        tree_view.select_node(b);
+        tree_view.handle_input(&key_down(Key::Space));
+        assert_eq!(tree_view.check_state(root), Some(CheckState::Checked));
+
+        tree_view.set_check_state(root, CheckState::Unchecked);
+        assert!(tree_view.checked_nodes().is_empty());
+        assert_eq!(
+            tree_view.visible_rows()[0].check_state(),
+            Some(CheckState::Unchecked)
+        );
+    }
+
+    #[test]
+    fn test_tree_view_virtualizes_and_multi_selects() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let recorder = record(&event_system, &[EventType::FieldValueChanged]);
+        let base = new_base(&event_system, Position::new(0, 0), Size::new(100, 200));
+        let mut tree_view = TreeView::new(base).with_selection_mode(SelectionMode::MultiExtended);
+        for i in 0..1_000 {
+            tree_view.add_root(TreeNode::new(&format!("Node {i}")).with_child(TreeNode::new("x")));
+        }
+
+        assert_eq!(tree_view.visible_rows().len(), 6);
+        tree_view.expand_all();
+        assert_eq!(tree_view.visible_nodes().len(), 2_000);
+        assert_eq!(tree_view.visible_rows().len(), 6);
+
+        // Click on the text of the first row, then shift-click the third
+        tree_view.handle_input(&mouse_down(80, 10));
+        tree_view.handle_input(&mouse_down_with(80, 1 + 2 * 18 + 9, Modifiers::shift()));
+        assert_eq!(tree_view.selected_nodes().len(), 3);
+        assert_eq!(
+            recorder.borrow().last_payload(EventType::FieldValueChanged),
+            Some(EventPayload::Indices(vec![0, 1, 2]))
+        );
+
+        tree_view.handle_input(&key_down(Key::End));
+        assert_eq!(tree_view.visible_rows().last().unwrap().text(), "x");
+        assert_eq!(tree_view.selected_nodes().len(), 1);
+    }
+
+    #[test]
+    fn test_tree_view_lays_out_again_after_move_and_deserialization() {
+        let event_system = Rc::new(RefCell::new(EventSystem::default()));
+        let mut tree_view = new_tree_view(&event_system);
+        let (root, a, _, b) = nodes(&tree_view);
+
+        let json = serde_json::to_string(&tree_view).unwrap();
+        let mut deserialized: TreeView = serde_json::from_str(&json).unwrap();
+        let (x, y) = expander_at(0, 0);
+        deserialized.handle_input(&mouse_down(x, y));
+        assert_eq!(deserialized.visible_nodes(), &[root, a, b]);
+
+        // The expander follows the tree to its new position
+        deserialized.base_mut().set_position(Position::new(0, 50));
+        deserialized.handle_input(&mouse_down(x, y + 50));
+        assert_eq!(deserialized.visible_nodes(), &[root]);
+        assert_eq!(deserialized.visible_rows()[0].base().position().y(), 51);
+
+        // Painting a moved tree leaves the event system alone
+        tree_view.base_mut().set_position(Position::new(0, 50));
+        let ctx = render_quietly(&event_system, &tree_view);
+        let root_text = ctx.commands().iter().find_map(|command| match command {
+            DrawCommand::Text { text, position, .. } if text == "Root" => Some(*position),
+            _ => None,
+        });
+        assert!(root_text.is_some_and(|position| position.y() > 50));
+    }
+}
@@ -2,6 +2,7 @@
 mod background_serialization_tests {
     use crate::core::components::properties::graphics::{
         background::*,
+        blend_mode::BlendMode,
         color::Color,
         gradient::{Gradient, GradientType},
     };
@@ -69,7 +70,7 @@ mod background_serialization_tests {
         let colors = vec![
             Color::rgb(255, 0, 0),
             Color::rgba(0, 255, 0, 128),
-            Color::hsv(240, 100, 100),
+            Color::hsv(120, 255, 255),
             Color::hex("#FFFF00"),
         ];
         
@@ -184,4 +185,20 @@ mod background_serialization_tests {
         let background2 = Background::new_solid(Color::rgb(100, 100, 100), shape2);
         assert_eq!(background1, background2);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_background_blend_mode_serialization() {
+        // Normal blending is left out so older files keep their shape
+        let normal = Background::new_solid(Color::rgb(1, 2, 3), BackgroundShape::Rectangle);
+        let expected_json = r#"{"color":{"Solid":{"RGB":{"r":1,"g":2,"b":3}}},"shape":"Rectangle"}"#;
+        test_json_serialization(&normal, expected_json).unwrap();
+        test_json_deserialization(expected_json, &normal).unwrap();
+
+        let multiply = normal.clone().with_blend_mode(BlendMode::Multiply);
+        let expected_json = r#"{"color":{"Solid":{"RGB":{"r":1,"g":2,"b":3}}},"shape":"Rectangle","blend_mode":"Multiply"}"#;
+        test_json_serialization(&multiply, expected_json).unwrap();
+        let deserialized = test_serialization_roundtrip(&multiply).unwrap();
+        assert_eq!(deserialized.blend_mode(), BlendMode::Multiply);
+        assert_ne!(multiply, normal);
+    }
+}
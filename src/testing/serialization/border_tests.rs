@@ -43,7 +43,7 @@ mod border_serialization_tests {
         let colors = vec![
             Color::rgb(255, 0, 0),
             Color::rgba(0, 255, 0, 128),
-            Color::hsv(240, 100, 100),
+            Color::hsv(120, 255, 255),
             Color::hex("#FFFF00"),
         ];
 
@@ -111,8 +111,8 @@ mod border_serialization_tests {
 
         // Test double border deserialization
         let double_json =
-            r#"{"color":{"HSV":{"h":240,"s":100,"v":100}},"width":4.0,"border_type":"Double"}"#;
-        let expected_border = Border::new(Color::hsv(240, 100, 100), 4.0, BorderType::Double);
+            r#"{"color":{"HSV":{"h":120,"s":255,"v":255}},"width":4.0,"border_type":"Double"}"#;
+        let expected_border = Border::new(Color::hsv(120, 255, 255), 4.0, BorderType::Double);
         test_json_deserialization(double_json, &expected_border).unwrap();
     }
 
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod color_serialization_tests {
     use super::super::test_utils::*;
-    use crate::core::components::properties::graphics::color::*;
+    use crate::core::components::properties::graphics::{color::*, color_space::*};
 
     #[test]
     fn test_rgba_serialization() {
@@ -31,14 +31,15 @@ mod color_serialization_tests {
 
     #[test]
     fn test_hsv_serialization() {
-        let hsv = HSV::new(120, 100, 80);
+        // Green at 80% value, with the hue in half degrees
+        let hsv = HSV::new(60, 255, 204);
 
         // Test roundtrip
         let deserialized = test_serialization_roundtrip(&hsv).unwrap();
         assert_eq!(hsv, deserialized);
 
         // Test specific JSON output
-        let expected_json = r#"{"h":120,"s":100,"v":80}"#;
+        let expected_json = r#"{"h":60,"s":255,"v":204}"#;
         test_json_serialization(&hsv, expected_json).unwrap();
     }
 
@@ -68,7 +69,7 @@ mod color_serialization_tests {
         assert_eq!(color_rgb, deserialized_rgb);
 
         // Test HSV variant
-        let color_hsv = Color::hsv(120, 100, 80);
+        let color_hsv = Color::hsv(60, 255, 204);
         let deserialized_hsv = test_serialization_roundtrip(&color_hsv).unwrap();
         assert_eq!(color_hsv, deserialized_hsv);
 
@@ -88,8 +89,8 @@ mod color_serialization_tests {
         let expected_json = r#"{"RGB":{"r":255,"g":128,"b":64}}"#;
         test_json_serialization(&color_rgb, expected_json).unwrap();
 
-        let color_hsv = Color::hsv(120, 100, 80);
-        let expected_json = r#"{"HSV":{"h":120,"s":100,"v":80}}"#;
+        let color_hsv = Color::hsv(60, 255, 204);
+        let expected_json = r#"{"HSV":{"h":60,"s":255,"v":204}}"#;
         test_json_serialization(&color_hsv, expected_json).unwrap();
 
         let color_hex = Color::hex("#FF8040");
@@ -110,8 +111,8 @@ mod color_serialization_tests {
         test_json_deserialization(rgb_json, &expected_rgb).unwrap();
 
         // Test HSV deserialization
-        let hsv_json = r#"{"HSV":{"h":120,"s":100,"v":80}}"#;
-        let expected_hsv = Color::hsv(120, 100, 80);
+        let hsv_json = r#"{"HSV":{"h":60,"s":255,"v":204}}"#;
+        let expected_hsv = Color::hsv(60, 255, 204);
         test_json_deserialization(hsv_json, &expected_hsv).unwrap();
 
         // Test HEX deserialization
@@ -144,8 +145,8 @@ mod color_serialization_tests {
 
     #[test]
     fn test_hsv_from_tuple() {
-        let hsv = HSV::from((120, 100, 80));
-        let expected = HSV::new(120, 100, 80);
+        let hsv = HSV::from((60, 255, 204));
+        let expected = HSV::new(60, 255, 204);
         assert_eq!(hsv, expected);
 
         // Test serialization after creation from tuple
@@ -196,4 +197,339 @@ mod color_serialization_tests {
             assert_eq!(hex, deserialized);
         }
     }
+
+    #[test]
+    fn test_hex_to_rgba_conversion() {
+        let cases = [
+            ("#FF8040", RGBA::new(255, 128, 64, 255)),
+            ("#abc", RGBA::new(170, 187, 204, 255)),
+            ("#ABCD1234", RGBA::new(171, 205, 18, 52)),
+            ("0xFF8040", RGBA::new(255, 128, 64, 255)),
+            ("0xABC", RGBA::new(170, 187, 204, 255)),
+            ("0xABCD1234", RGBA::new(171, 205, 18, 52)),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(HEX::new(value).to_rgba(), expected, "{value}");
+            assert_eq!(Color::hex(value).to_rgba(), expected, "{value}");
+        }
+
+        // A HEX cannot hold a value it could not read
+        assert!(HEX::parse("#12345").is_err());
+    }
+
+    #[test]
+    fn test_rgba_hex_roundtrip() {
+        for rgba in [
+            RGBA::new(255, 128, 64, 255),
+            RGBA::new(0, 0, 0, 0),
+            RGBA::new(18, 52, 86, 120),
+            RGBA::new(255, 255, 255, 255),
+        ] {
+            let hex = rgba.to_hex();
+            assert_eq!(hex.to_rgba(), rgba);
+
+            // The HEX survives serialization and still converts back
+            let deserialized = test_serialization_roundtrip(&Color::HEX(hex)).unwrap();
+            assert_eq!(deserialized.to_rgba(), rgba);
+        }
+        assert_eq!(RGBA::new(255, 128, 64, 255).to_hex().value(), "#FF8040");
+        assert_eq!(RGBA::new(255, 128, 64, 200).to_hex().value(), "#FF8040C8");
+        assert_eq!(RGB::new(1, 2, 3).to_hex().value(), "#010203");
+
+        // HEX colors keep the way they were written
+        assert_eq!(Color::hex("0xabc").to_hex().value(), "0xabc");
+    }
+
+    #[test]
+    fn test_hsv_conversions() {
+        // Hue in half degrees, saturation and value in 0-255
+        let cases = [
+            (RGB::new(255, 0, 0), HSV::new(0, 255, 255)),
+            (RGB::new(0, 255, 0), HSV::new(60, 255, 255)),
+            (RGB::new(0, 0, 255), HSV::new(120, 255, 255)),
+            (RGB::new(255, 255, 0), HSV::new(30, 255, 255)),
+            (RGB::new(128, 128, 128), HSV::new(0, 0, 128)),
+            (RGB::new(0, 0, 0), HSV::new(0, 0, 0)),
+        ];
+        for (rgb, hsv) in cases {
+            assert_eq!(rgb.to_hsv(), hsv);
+            assert_eq!(hsv.to_rgb(), rgb);
+            assert_eq!(Color::HSV(hsv).to_rgba(), rgb.to_rgba(255));
+            assert_eq!(Color::RGB(rgb).to_hsv(), hsv);
+        }
+
+        // HSV holds no alpha
+        assert_eq!(Color::rgba(0, 0, 255, 10).to_hsv(), HSV::new(120, 255, 255));
+        assert_eq!(Color::hsv(120, 255, 255).to_hex().value(), "#0000FF");
+        assert_eq!(Color::hex("#00F").to_hsv(), HSV::new(120, 255, 255));
+    }
+
+    #[test]
+    fn test_hsv_degrees() {
+        assert_eq!(HSV::from_degrees(240.0, 1.0, 1.0), HSV::new(120, 255, 255));
+        assert_eq!(HSV::new(120, 255, 255).to_degrees(), (240.0, 1.0, 1.0));
+        assert_eq!(HSV::from_degrees(90.0, 0.5, 0.2), HSV::new(45, 128, 51));
+
+        // Angles wrap around a full turn, in and out of half degrees
+        assert_eq!(HSV::from_degrees(-120.0, 1.0, 1.0), HSV::new(120, 255, 255));
+        assert_eq!(HSV::from_degrees(359.5, 1.0, 1.0), HSV::new(0, 255, 255));
+        assert_eq!(HSV::new(240, 255, 255).to_degrees().0, 120.0);
+        assert_eq!(HSV::new(240, 255, 255).to_rgb(), RGB::new(0, 255, 0));
+
+        assert_eq!(
+            Color::parse("hsv(240, 100%, 100%)"),
+            Ok(Color::HSV(HSV::new(120, 255, 255)))
+        );
+    }
+
+    #[test]
+    fn test_rgb_hsv_roundtrip_is_close() {
+        // Rounding the hue to half degrees moves a channel by a few units at most
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let rgb = RGB::new(r as u8, g as u8, b as u8);
+                    let back = rgb.to_hsv().to_rgb();
+                    let error = [(rgb.r, back.r), (rgb.g, back.g), (rgb.b, back.b)]
+                        .iter()
+                        .map(|(a, b)| a.abs_diff(*b))
+                        .max()
+                        .unwrap();
+                    assert!(error <= 5, "{rgb:?} came back as {back:?}");
+                }
+            }
+        }
+
+        // HSV colors survive a trip through RGB exactly when saturated
+        for h in 0..180 {
+            let hsv = HSV::new(h, 255, 255);
+            assert_eq!(hsv.to_rgb().to_hsv(), hsv);
+        }
+    }
+
+    #[test]
+    fn test_parse_hex_and_names() {
+        assert_eq!(Color::parse("#ff8040"), Ok(Color::hex("#ff8040")));
+        assert_eq!(Color::parse(" 0xABCD1234 "), Ok(Color::hex("0xABCD1234")));
+        // Bare digits are read as hex when they are not a name
+        assert_eq!(Color::parse("ABC"), Ok(Color::hex("ABC")));
+
+        assert_eq!(Color::parse("red"), Ok(Color::rgb(255, 0, 0)));
+        assert_eq!(Color::parse("tan"), Ok(Color::rgb(210, 180, 140)));
+        assert_eq!(Color::parse("RebeccaPurple"), Ok(Color::rgb(102, 51, 153)));
+        assert_eq!(Color::parse("transparent"), Ok(Color::rgba(0, 0, 0, 0)));
+        assert_eq!("navy".parse::<Color>(), Ok(Color::rgb(0, 0, 128)));
+    }
+
+    #[test]
+    fn test_parse_functional_notation() {
+        let cases = [
+            ("rgb(255, 128, 64)", Color::rgb(255, 128, 64)),
+            ("RGB(100%, 0%, 50%)", Color::rgb(255, 0, 128)),
+            ("rgba(0, 0, 0, 0.5)", Color::rgba(0, 0, 0, 128)),
+            ("rgba(255 128 64 / 50%)", Color::rgba(255, 128, 64, 128)),
+            ("rgba(1, 2, 3)", Color::rgba(1, 2, 3, 255)),
+            ("hsl(120, 100%, 50%)", Color::rgb(0, 255, 0)),
+            ("hsl(0, 0%, 0%)", Color::rgb(0, 0, 0)),
+            ("hsla(240deg 100% 25% / 1)", Color::rgba(0, 0, 128, 255)),
+            ("hsv(90, 100%, 100%)", Color::hsv(45, 255, 255)),
+            ("hsv(-90, 100, 100)", Color::hsv(135, 255, 255)),
+            ("hsva(0, 0, 100, 0.25)", Color::rgba(255, 255, 255, 64)),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(Color::parse(text), Ok(expected), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = [
+            ("   ", ColorParseError::Empty),
+            ("#12345", ColorParseError::InvalidHex("#12345".to_string())),
+            (
+                "rgb(1, 2, 3",
+                ColorParseError::Syntax("rgb(1, 2, 3".to_string()),
+            ),
+            (
+                "cmyk(0, 0, 0, 0)",
+                ColorParseError::UnknownFunction("cmyk".to_string()),
+            ),
+            (
+                "rgb(1, 2)",
+                ColorParseError::ArgumentCount {
+                    function: "rgb".to_string(),
+                    found: 2,
+                },
+            ),
+            (
+                "rgb(256, 0, 0)",
+                ColorParseError::InvalidArgument("256".to_string()),
+            ),
+            (
+                "rgba(0, 0, 0, 2)",
+                ColorParseError::InvalidArgument("2".to_string()),
+            ),
+            (
+                "hsl(red, 0, 0)",
+                ColorParseError::InvalidArgument("red".to_string()),
+            ),
+            (
+                "blurple",
+                ColorParseError::UnknownName("blurple".to_string()),
+            ),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(Color::parse(text), Err(expected), "{text}");
+        }
+        assert_eq!(
+            ColorParseError::UnknownName("blurple".to_string()).to_string(),
+            "unknown color name: blurple"
+        );
+    }
+
+    #[test]
+    fn test_hex_deserialization_is_validated() {
+        test_json_deserialization(r##"{"value":"0xabc"}"##, &HEX::new("0xabc")).unwrap();
+
+        assert!(serde_json::from_str::<HEX>(r##"{"value":"#GG0000"}"##).is_err());
+        assert!(serde_json::from_str::<Color>(r##"{"HEX":{"value":"red"}}"##).is_err());
+        assert_eq!(
+            HEX::parse("#12"),
+            Err(ColorParseError::InvalidHex("#12".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_perceptual_color_spaces() {
+        let red = Color::rgb(255, 0, 0).to_oklab();
+        assert!((red.l - 0.628).abs() < 0.001);
+        assert!((red.a - 0.225).abs() < 0.001);
+        assert!((red.b - 0.126).abs() < 0.001);
+        assert!((Color::rgb(255, 0, 0).to_oklch().h - 29.23).abs() < 0.01);
+        assert_eq!(
+            Color::rgb(255, 255, 255).to_linear_rgb(),
+            LinearRgb::new(1.0, 1.0, 1.0)
+        );
+
+        // Every step of the sRGB cube survives both round trips
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let rgb = RGB::new(r, g, b);
+                    assert_eq!(OkLab::from_rgb(rgb).to_rgb(), rgb);
+                    assert_eq!(OkLch::from_rgb(rgb).to_rgb(), rgb);
+                }
+            }
+        }
+
+        // Too vivid to show: the chroma is reduced instead of clipping channels
+        let vivid = OkLch::new(0.9, 0.4, 29.23).to_rgb();
+        let hue = OkLch::from_rgb(vivid).h;
+        assert!((hue - 29.23).abs() < 2.0, "{hue}");
+    }
+
+    #[test]
+    fn test_color_manipulation() {
+        let accent = Color::rgba(0, 120, 215, 200);
+        assert_eq!(accent.lighten(0.1), Color::rgba(54, 152, 249, 200));
+        assert_eq!(accent.darken(0.1), Color::rgba(0, 91, 166, 200));
+        assert_eq!(accent.darken(1.0), Color::rgba(0, 0, 0, 200));
+        assert_eq!(
+            Color::hex("#F00").lighten(1.0),
+            Color::rgba(255, 255, 255, 255)
+        );
+
+        assert_eq!(
+            Color::rgb(255, 0, 0).desaturate(1.0),
+            Color::rgba(136, 136, 136, 255)
+        );
+        assert_eq!(
+            Color::rgb(100, 120, 140).saturate(1.0),
+            Color::rgba(80, 121, 162, 255)
+        );
+
+        assert_eq!(accent.alpha(), 200);
+        assert_eq!(Color::rgb(1, 2, 3).alpha(), 255);
+        assert_eq!(accent.with_alpha(10), Color::rgba(0, 120, 215, 10));
+        assert_eq!(accent.fade(0.5), Color::rgba(0, 120, 215, 100));
+    }
+
+    #[test]
+    fn test_color_interpolation() {
+        let (black, white) = (Color::rgb(0, 0, 0), Color::rgb(255, 255, 255));
+        let (red, blue) = (Color::rgb(255, 0, 0), Color::rgb(0, 0, 255));
+        let cases = [
+            (ColorSpace::Srgb, (128, 128, 128), (128, 0, 128)),
+            (ColorSpace::LinearRgb, (188, 188, 188), (188, 0, 188)),
+            (ColorSpace::OkLab, (99, 99, 99), (140, 83, 162)),
+            (ColorSpace::OkLch, (99, 99, 99), (178, 0, 184)),
+        ];
+        for (space, grey, purple) in cases {
+            assert_eq!(
+                black.interpolate(&white, 0.5, space),
+                Color::rgba(grey.0, grey.1, grey.2, 255),
+                "{space:?}"
+            );
+            assert_eq!(
+                red.interpolate(&blue, 0.5, space),
+                Color::rgba(purple.0, purple.1, purple.2, 255),
+                "{space:?}"
+            );
+        }
+        assert_eq!(red.mix(&blue, 0.0), Color::rgba(255, 0, 0, 255));
+        assert_eq!(red.mix(&blue, 2.0), Color::rgba(0, 0, 255, 255));
+
+        // Fading out keeps the visible color instead of going through black
+        assert_eq!(
+            red.mix(&Color::rgba(0, 0, 0, 0), 0.5),
+            Color::rgba(255, 0, 0, 128)
+        );
+    }
+
+    #[test]
+    fn test_wcag_contrast() {
+        let (black, white) = (Color::rgb(0, 0, 0), Color::rgb(255, 255, 255));
+        assert_eq!(black.relative_luminance(), 0.0);
+        assert!((white.relative_luminance() - 1.0).abs() < 0.001);
+        assert!((black.contrast_ratio(&white) - 21.0).abs() < 0.001);
+        assert!((white.contrast_ratio(&black) - 21.0).abs() < 0.001);
+        assert_eq!(white.contrast_ratio(&white), 1.0);
+
+        // #767676 is the lightest grey that passes AA on white
+        let grey = Color::hex("#767676");
+        assert!(grey.meets_contrast(&white, WcagLevel::Aa, false));
+        assert!(!Color::hex("#777777").meets_contrast(&white, WcagLevel::Aa, false));
+        assert!(!grey.meets_contrast(&white, WcagLevel::Aaa, false));
+        assert!(grey.meets_contrast(&white, WcagLevel::Aaa, true));
+
+        // Translucent text is blended into the background first
+        assert_eq!(
+            black.with_alpha(128).over(&white),
+            Color::rgba(127, 127, 127, 255)
+        );
+        assert!(black.with_alpha(128).contrast_ratio(&white) < black.contrast_ratio(&white));
+    }
+
+    #[test]
+    fn test_ensure_contrast() {
+        let white = Color::rgb(255, 255, 255);
+        let navy = Color::rgb(0, 0, 128);
+        // Colors that already pass are kept
+        assert_eq!(navy.ensure_contrast(&white, WcagLevel::Aaa, false), navy);
+
+        // Light text on a dark background gets lighter, dark text darker
+        let dark = Color::rgb(40, 40, 40);
+        let light = Color::rgb(90, 90, 90).ensure_contrast(&dark, WcagLevel::Aa, false);
+        assert!(light.relative_luminance() > Color::rgb(90, 90, 90).relative_luminance());
+        assert!(light.meets_contrast(&dark, WcagLevel::Aa, false));
+        let blue = Color::rgb(100, 150, 255).ensure_contrast(&white, WcagLevel::Aa, false);
+        assert!(blue.meets_contrast(&white, WcagLevel::Aa, false));
+        // The hue is kept while darkening
+        assert!((blue.to_oklch().h - Color::rgb(100, 150, 255).to_oklch().h).abs() < 5.0);
+
+        // No grey reaches 7:1 over a mid grey, the best one is returned
+        let mid = Color::rgb(119, 119, 119);
+        let best = Color::rgb(130, 130, 130).ensure_contrast(&mid, WcagLevel::Aaa, false);
+        assert_eq!(best, Color::rgba(0, 0, 0, 255));
+    }
 }
@@ -1,7 +1,9 @@
 #[cfg(test)]
 mod gradient_serialization_tests {
     use super::super::test_utils::*;
-    use crate::core::components::properties::graphics::{color::Color, gradient::*};
+    use crate::core::components::properties::graphics::{
+        color::Color, color_space::ColorSpace, gradient::*,
+    };
 
     #[test]
     fn test_gradient_type_linear_serialization() {
@@ -127,7 +129,7 @@ mod gradient_serialization_tests {
         let stops = vec![
             (Color::rgb(255, 0, 0), 0),
             (Color::rgba(0, 255, 0, 128), 25),
-            (Color::hsv(240, 100, 100), 50),
+            (Color::hsv(120, 255, 255), 50),
             (Color::hex("#FFFF00"), 75),
             (Color::rgb(255, 0, 255), 100),
         ];
@@ -232,4 +234,46 @@ mod gradient_serialization_tests {
         let deserialized = test_serialization_roundtrip(&gradient).unwrap();
         assert_eq!(gradient, deserialized);
     }
+
+    #[test]
+    fn test_gradient_color_at() {
+        let gradient = Gradient::new(
+            GradientType::Linear(90),
+            vec![
+                (Color::rgb(255, 0, 0), 20),
+                (Color::rgb(0, 0, 255), 60),
+                (Color::rgba(0, 0, 255, 0), 60),
+            ],
+        );
+
+        // Outside the stops the end colors are kept
+        assert_eq!(
+            gradient.color_at(0.0, ColorSpace::OkLab),
+            Some(Color::rgb(255, 0, 0))
+        );
+        assert_eq!(
+            gradient.color_at(100.0, ColorSpace::OkLab),
+            Some(Color::rgba(0, 0, 255, 0))
+        );
+        assert_eq!(
+            gradient.color_at(40.0, ColorSpace::OkLab),
+            Some(Color::rgba(140, 83, 162, 255))
+        );
+        assert_eq!(
+            gradient.color_at(40.0, ColorSpace::Srgb),
+            Some(Color::rgba(128, 0, 128, 255))
+        );
+        // Two stops at the same position make a hard edge
+        assert_eq!(
+            gradient.color_at(60.0, ColorSpace::OkLab),
+            Some(Color::rgba(0, 0, 255, 255))
+        );
+        assert_eq!(
+            gradient.color_at(60.5, ColorSpace::OkLab),
+            Some(Color::rgba(0, 0, 255, 0))
+        );
+
+        let empty = Gradient::new(GradientType::Linear(0), vec![]);
+        assert_eq!(empty.color_at(50.0, ColorSpace::OkLab), None);
+    }
 }